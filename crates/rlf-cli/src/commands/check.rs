@@ -1,11 +1,20 @@
 //! Implementation of the `rlf check` command.
 
+use crate::input::{expand_inputs, InputSource};
 use crate::output::RlfDiagnostic;
-use rlf::parser::{parse_file, ParseError};
+use rlf::parser::{
+    parse_file, parse_file_recovering, walk_definition, ParseError, PhraseDefinition, Reference,
+    TemplateVisitor,
+};
+use rlf::{
+    lint_literal_numbers, lint_max_length, lint_mixed_language, lint_plural_categories,
+    LoadWarning, PhraseRegistry,
+};
 use serde::Serialize;
-use std::collections::HashSet;
-use std::fs::read_to_string;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{read_dir, read_to_string};
+use std::io;
+use std::path::{Path, PathBuf};
 
 /// Maximum number of errors to display per file before truncating.
 const MAX_ERRORS_PER_FILE: usize = 10;
@@ -13,10 +22,16 @@ const MAX_ERRORS_PER_FILE: usize = 10;
 /// Arguments for the check command.
 #[derive(Debug, clap::Args)]
 pub struct CheckArgs {
-    /// Files to check (.rlf)
-    #[arg(required = true)]
+    /// Files to check (.rlf); accepts glob patterns and `-` for stdin
+    #[arg(required_unless_present = "project")]
     pub files: Vec<PathBuf>,
 
+    /// Check every .rlf file in a directory, resolving phrase references
+    /// across all files of the same language (`en.rlf` and `en.cards.rlf`
+    /// are both English)
+    #[arg(long, conflicts_with_all = ["files", "strict"])]
+    pub project: Option<PathBuf>,
+
     /// Output as JSON
     #[arg(long)]
     pub json: bool,
@@ -24,6 +39,12 @@ pub struct CheckArgs {
     /// Check against source file for phrase coverage
     #[arg(long)]
     pub strict: Option<PathBuf>,
+
+    /// Also warn about literal ordinals and grouped numbers in template text,
+    /// transforms from other languages, and (with --strict) phrases identical
+    /// to the source
+    #[arg(long)]
+    pub pedantic: bool,
 }
 
 /// JSON output for check results.
@@ -39,6 +60,8 @@ pub struct FileResult {
     pub path: String,
     pub status: FileStatus,
     pub errors: Vec<ErrorInfo>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 /// Status of a file check.
@@ -62,18 +85,22 @@ pub struct ErrorInfo {
 
 /// Run the check command.
 pub fn run_check(args: CheckArgs) -> miette::Result<i32> {
+    if let Some(dir) = &args.project {
+        return run_project_check(dir, &args);
+    }
+
     // If --strict mode, parse source file first to get phrase names
-    let source_phrases = if let Some(source_path) = &args.strict {
+    let source = if let Some(source_path) = &args.strict {
         let content = read_to_string(source_path).map_err(|e| {
             miette::miette!("Cannot read source file {}: {}", source_path.display(), e)
         })?;
         let defs =
             parse_file(&content).map_err(|e| miette::miette!("Source file parse error: {}", e))?;
-        Some(
-            defs.into_iter()
-                .map(|d| d.name)
-                .collect::<HashSet<String>>(),
-        )
+        Some(Source {
+            language: file_language(source_path),
+            names: defs.iter().map(|d| d.name.clone()).collect(),
+            defs,
+        })
     } else {
         None
     };
@@ -81,13 +108,19 @@ pub fn run_check(args: CheckArgs) -> miette::Result<i32> {
     let mut total_errors = 0;
     let mut results = Vec::new();
 
-    for path in &args.files {
-        let (file_result, errors) = check_file(path, source_phrases.as_ref(), args.json);
+    let inputs = expand_inputs(&args.files);
+    for input in &inputs {
+        let (file_result, errors) = check_file(input, source.as_ref(), args.pedantic, args.json);
         total_errors += errors;
         results.push(file_result);
     }
 
-    if args.json {
+    finish(results, total_errors, args.json)
+}
+
+/// Print the JSON report or multi-file summary and pick the exit code.
+fn finish(results: Vec<FileResult>, total_errors: usize, json_mode: bool) -> miette::Result<i32> {
+    if json_mode {
         let output = CheckResult {
             files: results,
             total_errors,
@@ -96,13 +129,8 @@ pub fn run_check(args: CheckArgs) -> miette::Result<i32> {
             "{}",
             serde_json::to_string_pretty(&output).expect("JSON serialization should not fail")
         );
-    } else if total_errors == 0 {
-        let file_count = args.files.len();
-        if file_count == 1 {
-            // Single file - already printed OK
-        } else {
-            println!("Checked {} files, no errors", file_count);
-        }
+    } else if results.len() > 1 {
+        print_summary(&results, total_errors);
     }
 
     Ok(if total_errors > 0 {
@@ -112,117 +140,365 @@ pub fn run_check(args: CheckArgs) -> miette::Result<i32> {
     })
 }
 
-/// Check a single file and return results.
+/// Check every `.rlf` file in a project directory.
+///
+/// Files are grouped by language, and references are resolved against every
+/// definition of that language, as the runtime registry does once all of a
+/// language's files are loaded.
+fn run_project_check(dir: &Path, args: &CheckArgs) -> miette::Result<i32> {
+    let entries = read_dir(dir)
+        .map_err(|e| miette::miette!("Cannot read project directory {}: {}", dir.display(), e))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "rlf"))
+        .collect();
+    paths.sort();
+
+    let mut total_errors = 0;
+    let mut results = Vec::new();
+    let mut languages: BTreeMap<String, Vec<(PathBuf, Vec<PhraseDefinition>)>> = BTreeMap::new();
+    for path in paths {
+        let input = InputSource::File(path.clone());
+        let content = match input.read() {
+            Ok(content) => content,
+            Err(e) => {
+                let (result, errors) = unreadable_result(&path, &e, args.json);
+                total_errors += errors;
+                results.push(result);
+                continue;
+            }
+        };
+        let (defs, parse_errors) = parse_file_recovering(&content);
+        if parse_errors.is_empty() {
+            languages
+                .entry(project_language(&path))
+                .or_default()
+                .push((path, defs));
+        } else {
+            let (result, errors) = parse_error_result(&path, &content, &parse_errors, args.json);
+            total_errors += errors;
+            results.push(result);
+        }
+    }
+
+    for (language, files) in &languages {
+        let names: HashSet<&str> = files
+            .iter()
+            .flat_map(|(_, defs)| defs.iter().map(|def| def.name.as_str()))
+            .collect();
+        let mut registry = PhraseRegistry::new();
+        for def in files.iter().flat_map(|(_, defs)| defs) {
+            registry
+                .insert(def.clone())
+                .map_err(|e| miette::miette!("Cannot register '{}': {}", def.name, e))?;
+        }
+        for (path, defs) in files {
+            let unresolved = unresolved_references(defs, &names);
+            total_errors += unresolved.len();
+            if !args.json {
+                for error in &unresolved {
+                    eprintln!("{}: {}", path.display(), error.message);
+                }
+            }
+            let warnings = if unresolved.is_empty() {
+                lint_warnings(language, defs, &registry, None, args.pedantic)
+            } else {
+                vec![]
+            };
+            if !args.json {
+                for warning in &warnings {
+                    eprintln!("{}: {}", path.display(), warning);
+                }
+                if unresolved.is_empty() {
+                    println!("{}: OK", path.display());
+                }
+            }
+            results.push(FileResult {
+                path: path.display().to_string(),
+                status: if unresolved.is_empty() {
+                    FileStatus::Ok
+                } else {
+                    FileStatus::Error
+                },
+                errors: unresolved,
+                warnings,
+            });
+        }
+    }
+
+    results.sort_by(|a, b| a.path.cmp(&b.path));
+    finish(results, total_errors, args.json)
+}
+
+/// The language of a project file: its file name up to the first `.`.
+fn project_language(path: &Path) -> String {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    name.split('.').next().unwrap_or_default().to_string()
+}
+
+/// One error per reference in `defs` that names no definition in `names`.
+fn unresolved_references(defs: &[PhraseDefinition], names: &HashSet<&str>) -> Vec<ErrorInfo> {
+    let mut errors = Vec::new();
+    for def in defs {
+        let mut collector = PhraseRefs::default();
+        walk_definition(def, &mut collector);
+        for name in collector.names {
+            if !names.contains(name.as_str()) {
+                errors.push(ErrorInfo {
+                    line: 0,
+                    column: 0,
+                    message: format!("unknown phrase '{}' referenced by '{}'", name, def.name),
+                    suggestion: None,
+                });
+            }
+        }
+    }
+    errors
+}
+
+/// Collects the distinct phrase and term names a definition references.
+#[derive(Default)]
+struct PhraseRefs {
+    names: Vec<String>,
+}
+
+impl TemplateVisitor for PhraseRefs {
+    fn visit_phrase_ref(&mut self, name: &str, _args: &[Reference]) {
+        if !self.names.iter().any(|seen| seen == name) {
+            self.names.push(name.to_string());
+        }
+    }
+}
+
+/// Print a per-file status summary after checking multiple files.
+fn print_summary(results: &[FileResult], total_errors: usize) {
+    println!("\nSummary:");
+    for result in results {
+        let status = match result.status {
+            FileStatus::Ok if result.warnings.is_empty() => "OK".to_string(),
+            FileStatus::Ok => format!("OK, {} warning(s)", result.warnings.len()),
+            FileStatus::Error => format!("{} error(s)", result.errors.len()),
+            FileStatus::NotFound => "not found".to_string(),
+        };
+        println!("  {}: {}", result.path, status);
+    }
+    let failed = results
+        .iter()
+        .filter(|r| !matches!(r.status, FileStatus::Ok))
+        .count();
+    if total_errors == 0 {
+        println!("Checked {} files, no errors", results.len());
+    } else {
+        println!(
+            "Checked {} files, {} failed with {} error(s)",
+            results.len(),
+            failed,
+            total_errors
+        );
+    }
+}
+
+/// The `--strict` source file, parsed once for every checked file.
+struct Source {
+    language: String,
+    names: HashSet<String>,
+    defs: Vec<PhraseDefinition>,
+}
+
+/// Check a single input and return results.
 fn check_file(
-    path: &PathBuf,
-    source_phrases: Option<&HashSet<String>>,
+    input: &InputSource,
+    source: Option<&Source>,
+    pedantic: bool,
     json_mode: bool,
 ) -> (FileResult, usize) {
-    let content = match read_to_string(path) {
+    let path = input.path();
+    let content = match input.read() {
         Ok(c) => c,
-        Err(e) => {
+        Err(e) => return unreadable_result(path, &e, json_mode),
+    };
+
+    let (defs, parse_errors) = parse_file_recovering(&content);
+    if !parse_errors.is_empty() {
+        return parse_error_result(path, &content, &parse_errors, json_mode);
+    }
+
+    // Check for missing phrases if --strict mode
+    if let Some(Source {
+        names: source_names,
+        ..
+    }) = source
+    {
+        let target_names: HashSet<String> = defs.iter().map(|d| d.name.clone()).collect();
+        let missing: Vec<_> = source_names
+            .iter()
+            .filter(|n| !target_names.contains(*n))
+            .cloned()
+            .collect();
+
+        if !missing.is_empty() {
+            let error_count = missing.len();
             if !json_mode {
-                eprintln!("{}: cannot read file: {}", path.display(), e);
+                eprintln!(
+                    "{}: missing {} phrases from source:",
+                    path.display(),
+                    error_count
+                );
+                for (i, name) in missing.iter().enumerate() {
+                    if i < MAX_ERRORS_PER_FILE {
+                        eprintln!("  - {}", name);
+                    }
+                }
+                if missing.len() > MAX_ERRORS_PER_FILE {
+                    eprintln!("  ... and {} more", missing.len() - MAX_ERRORS_PER_FILE);
+                }
             }
             return (
                 FileResult {
                     path: path.display().to_string(),
-                    status: FileStatus::NotFound,
-                    errors: vec![ErrorInfo {
-                        line: 0,
-                        column: 0,
-                        message: format!("cannot read file: {}", e),
-                        suggestion: None,
-                    }],
+                    status: FileStatus::Error,
+                    errors: missing
+                        .into_iter()
+                        .map(|name| ErrorInfo {
+                            line: 0,
+                            column: 0,
+                            message: format!("missing phrase: {}", name),
+                            suggestion: None,
+                        })
+                        .collect(),
+                    warnings: vec![],
                 },
-                1,
+                error_count,
             );
         }
-    };
+    }
 
-    match parse_file(&content) {
-        Ok(defs) => {
-            // Check for missing phrases if --strict mode
-            if let Some(source_names) = source_phrases {
-                let target_names: HashSet<String> = defs.iter().map(|d| d.name.clone()).collect();
-                let missing: Vec<_> = source_names
-                    .iter()
-                    .filter(|n| !target_names.contains(*n))
-                    .cloned()
-                    .collect();
-
-                if !missing.is_empty() {
-                    let error_count = missing.len();
-                    if !json_mode {
-                        eprintln!(
-                            "{}: missing {} phrases from source:",
-                            path.display(),
-                            error_count
-                        );
-                        for (i, name) in missing.iter().enumerate() {
-                            if i < MAX_ERRORS_PER_FILE {
-                                eprintln!("  - {}", name);
-                            }
-                        }
-                        if missing.len() > MAX_ERRORS_PER_FILE {
-                            eprintln!("  ... and {} more", missing.len() - MAX_ERRORS_PER_FILE);
-                        }
-                    }
-                    return (
-                        FileResult {
-                            path: path.display().to_string(),
-                            status: FileStatus::Error,
-                            errors: missing
-                                .into_iter()
-                                .map(|name| ErrorInfo {
-                                    line: 0,
-                                    column: 0,
-                                    message: format!("missing phrase: {}", name),
-                                    suggestion: None,
-                                })
-                                .collect(),
-                        },
-                        error_count,
-                    );
-                }
-            }
+    let mut registry = PhraseRegistry::new();
+    if let Err(e) = registry.load_phrases(&content) {
+        return parse_error_result(path, &content, &[e], json_mode);
+    }
 
-            // File parsed successfully
-            if !json_mode {
-                println!("{}: OK", path.display());
-            }
-            (
-                FileResult {
-                    path: path.display().to_string(),
-                    status: FileStatus::Ok,
-                    errors: vec![],
-                },
-                0,
-            )
+    // File parsed successfully
+    let warnings = lint_warnings(&file_language(path), &defs, &registry, source, pedantic);
+    if !json_mode {
+        for warning in &warnings {
+            eprintln!("{}: {}", path.display(), warning);
         }
-        Err(e) => {
-            let (line, column, message) = extract_error_info(&e);
+        println!("{}: OK", path.display());
+    }
+    (
+        FileResult {
+            path: path.display().to_string(),
+            status: FileStatus::Ok,
+            errors: vec![],
+            warnings,
+        },
+        0,
+    )
+}
 
-            if !json_mode {
-                let diagnostic = RlfDiagnostic::from_parse_error(path, &content, &e);
-                eprintln!("{:?}", miette::Report::new(diagnostic));
-            }
+/// Report a file that could not be read.
+fn unreadable_result(path: &Path, error: &io::Error, json_mode: bool) -> (FileResult, usize) {
+    if !json_mode {
+        eprintln!("{}: cannot read file: {}", path.display(), error);
+    }
+    (
+        FileResult {
+            path: path.display().to_string(),
+            status: FileStatus::NotFound,
+            errors: vec![ErrorInfo {
+                line: 0,
+                column: 0,
+                message: format!("cannot read file: {}", error),
+                suggestion: None,
+            }],
+            warnings: vec![],
+        },
+        1,
+    )
+}
 
-            (
-                FileResult {
-                    path: path.display().to_string(),
-                    status: FileStatus::Error,
-                    errors: vec![ErrorInfo {
+/// Report every syntax error in a file that failed to parse.
+fn parse_error_result(
+    path: &Path,
+    content: &str,
+    errors: &[ParseError],
+    json_mode: bool,
+) -> (FileResult, usize) {
+    if !json_mode {
+        for err in errors.iter().take(MAX_ERRORS_PER_FILE) {
+            let diagnostic = RlfDiagnostic::from_parse_error(path, content, err);
+            eprintln!("{:?}", miette::Report::new(diagnostic));
+        }
+        if errors.len() > MAX_ERRORS_PER_FILE {
+            eprintln!(
+                "{}: ... and {} more errors",
+                path.display(),
+                errors.len() - MAX_ERRORS_PER_FILE
+            );
+        }
+    }
+    (
+        FileResult {
+            path: path.display().to_string(),
+            status: FileStatus::Error,
+            errors: errors
+                .iter()
+                .map(|err| {
+                    let (line, column, message) = extract_error_info(err);
+                    ErrorInfo {
                         line,
                         column,
                         message,
                         suggestion: None,
-                    }],
-                },
-                1,
-            )
-        }
+                    }
+                })
+                .collect(),
+            warnings: vec![],
+        },
+        errors.len(),
+    )
+}
+
+/// Run the file lints for a file in `language`.
+///
+/// Unreachable plural categories and `@maxlen` limits are always checked; the
+/// literal-number and mixed-language lints run only with `--pedantic`. Terms
+/// are rendered from `registry`, which holds every definition of the language,
+/// but only the `@maxlen` limits of `defs` are reported.
+fn lint_warnings(
+    language: &str,
+    defs: &[PhraseDefinition],
+    registry: &PhraseRegistry,
+    source: Option<&Source>,
+    pedantic: bool,
+) -> Vec<String> {
+    let mut warnings = lint_plural_categories(defs, language);
+    warnings.extend(
+        lint_max_length(registry, language)
+            .into_iter()
+            .filter(|warning| {
+                matches!(warning, LoadWarning::ExceedsMaxLength { name, .. }
+                    if defs.iter().any(|def| &def.name == name))
+            }),
+    );
+    if pedantic {
+        warnings.extend(lint_literal_numbers(defs, language));
+        let reference = source.map(|source| (source.defs.as_slice(), source.language.as_str()));
+        warnings.extend(lint_mixed_language(defs, language, reference));
     }
+    warnings.iter().map(ToString::to_string).collect()
+}
+
+/// The language of a translation file, taken from its file stem.
+fn file_language(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
 }
 
 /// Extract line, column, and message from a ParseError.
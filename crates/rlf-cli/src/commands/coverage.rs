@@ -1,7 +1,6 @@
 //! Coverage command implementation.
 
 use std::collections::HashSet;
-use std::fs::read_to_string;
 use std::path::{Path, PathBuf};
 
 use clap::Args;
@@ -9,13 +8,14 @@ use miette::{miette, IntoDiagnostic, Result};
 use rlf::parser::parse_file;
 use serde::Serialize;
 
+use crate::input::{expand_inputs, InputSource};
 use crate::output::table::{format_coverage_table, LanguageCoverage};
 use crate::output::RlfDiagnostic;
 
 /// Arguments for the coverage command.
 #[derive(Debug, Args)]
 pub struct CoverageArgs {
-    /// Source language file (e.g., en.rlf).
+    /// Source language file (e.g., en.rlf), or `-` for stdin.
     #[arg(long)]
     pub source: PathBuf,
 
@@ -23,10 +23,18 @@ pub struct CoverageArgs {
     #[arg(long, value_delimiter = ',')]
     pub lang: Vec<String>,
 
+    /// Translation files to check, named `<lang>.rlf`; accepts glob patterns.
+    pub files: Vec<PathBuf>,
+
     /// Directory containing translation files. Defaults to source file directory.
     #[arg(long)]
     pub translations: Option<PathBuf>,
 
+    /// Measure coverage against this language's translation file instead of
+    /// the source file: only phrases the reference defines are counted.
+    #[arg(long)]
+    pub reference: Option<String>,
+
     /// Exit with non-zero code if any translation is incomplete.
     #[arg(long)]
     pub strict: bool,
@@ -40,29 +48,35 @@ pub struct CoverageArgs {
 #[derive(Debug, Serialize)]
 struct CoverageJson {
     language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
     translated: usize,
     total: usize,
     missing: Vec<String>,
 }
 
 /// Run the coverage command.
+///
+/// A translation file that fails to parse stops the command with its
+/// diagnostic, as the source file does, rather than being left out of the
+/// report.
 pub fn run_coverage(args: CoverageArgs) -> Result<i32> {
     // Parse source file to get phrase names
-    let source_content = read_to_string(&args.source)
+    let source_input = InputSource::from_arg(&args.source);
+    let source_content = source_input
+        .read()
         .into_diagnostic()
         .map_err(|e| miette!("Failed to read source file {:?}: {}", args.source, e))?;
 
     let source_phrases = match parse_file(&source_content) {
         Ok(phrases) => phrases,
         Err(e) => {
-            let diagnostic = RlfDiagnostic::from_parse_error(&args.source, &source_content, &e);
+            let diagnostic =
+                RlfDiagnostic::from_parse_error(source_input.path(), &source_content, &e);
             return Err(diagnostic.into());
         }
     };
 
-    let source_names: HashSet<String> = source_phrases.iter().map(|p| p.name.clone()).collect();
-    let source_count = source_names.len();
-
     // Determine base directory for translation files
     let base_dir = args
         .translations
@@ -70,14 +84,47 @@ pub fn run_coverage(args: CoverageArgs) -> Result<i32> {
         .or_else(|| args.source.parent().map(Path::to_path_buf))
         .unwrap_or_else(|| PathBuf::from("."));
 
+    // Coverage is measured against the reference language's phrases if given,
+    // otherwise against the source file's
+    let source_names: HashSet<String> = match &args.reference {
+        Some(reference) => read_reference_names(&base_dir.join(format!("{}.rlf", reference)))?,
+        None => source_phrases.iter().map(|p| p.name.clone()).collect(),
+    };
+    let source_count = source_names.len();
+
+    // Translation files come from --lang lookups plus explicit file arguments
+    let mut targets: Vec<(String, InputSource)> = args
+        .lang
+        .iter()
+        .map(|lang| {
+            let path = base_dir.join(format!("{}.rlf", lang));
+            (lang.clone(), InputSource::File(path))
+        })
+        .collect();
+    for input in expand_inputs(&args.files) {
+        let lang = input
+            .path()
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        targets.push((lang, input));
+    }
+    if let Some(reference) = &args.reference {
+        targets.retain(|(lang, _)| lang != reference);
+    }
+
     // Collect coverage data for each language
     let mut coverage_data: Vec<LanguageCoverage> = Vec::new();
 
-    for lang in &args.lang {
-        let lang_file = base_dir.join(format!("{}.rlf", lang));
-
-        let (translated_names, missing): (HashSet<String>, Vec<String>) = if lang_file.exists() {
-            let lang_content = read_to_string(&lang_file)
+    for (lang, input) in &targets {
+        let lang_file = input.path();
+        let exists = match input {
+            InputSource::Stdin => true,
+            InputSource::File(path) => path.exists(),
+        };
+        let (translated_names, missing): (HashSet<String>, Vec<String>) = if exists {
+            let lang_content = input
+                .read()
                 .into_diagnostic()
                 .map_err(|e| miette!("Failed to read translation file {:?}: {}", lang_file, e))?;
 
@@ -85,21 +132,24 @@ pub fn run_coverage(args: CoverageArgs) -> Result<i32> {
                 Ok(phrases) => {
                     let translated: HashSet<String> =
                         phrases.iter().map(|p| p.name.clone()).collect();
-                    let missing: Vec<String> = source_names
+                    let mut missing: Vec<String> = source_names
                         .iter()
                         .filter(|name| !translated.contains(*name))
                         .cloned()
                         .collect();
+                    missing.sort();
                     (translated, missing)
                 }
                 Err(e) => {
-                    let diagnostic = RlfDiagnostic::from_parse_error(&lang_file, &lang_content, &e);
+                    let diagnostic = RlfDiagnostic::from_parse_error(lang_file, &lang_content, &e);
                     return Err(diagnostic.into());
                 }
             }
         } else {
             // File doesn't exist - all phrases are missing
-            (HashSet::new(), source_names.iter().cloned().collect())
+            let mut missing: Vec<String> = source_names.iter().cloned().collect();
+            missing.sort();
+            (HashSet::new(), missing)
         };
 
         let translated_count = source_names.intersection(&translated_names).count();
@@ -120,6 +170,7 @@ pub fn run_coverage(args: CoverageArgs) -> Result<i32> {
             .iter()
             .map(|c| CoverageJson {
                 language: c.language.clone(),
+                reference: args.reference.clone(),
                 translated: c.translated,
                 total: source_count,
                 missing: c.missing.clone(),
@@ -130,13 +181,22 @@ pub fn run_coverage(args: CoverageArgs) -> Result<i32> {
         println!("{}", json_output);
     } else {
         // Print ASCII table
+        if let Some(reference) = &args.reference {
+            println!("Relative to {} ({} phrases):", reference, source_count);
+        }
         let table = format_coverage_table(source_count, &coverage_data);
         println!("{}", table);
 
         // Print missing phrases per language
         for lang_coverage in &coverage_data {
             if !lang_coverage.missing.is_empty() {
-                println!("\nMissing in {}:", lang_coverage.language);
+                match &args.reference {
+                    Some(reference) => println!(
+                        "\nMissing in {} (relative to {}):",
+                        lang_coverage.language, reference
+                    ),
+                    None => println!("\nMissing in {}:", lang_coverage.language),
+                }
                 for name in &lang_coverage.missing {
                     println!("  - {}", name);
                 }
@@ -151,3 +211,15 @@ pub fn run_coverage(args: CoverageArgs) -> Result<i32> {
         Ok(exitcode::OK)
     }
 }
+
+/// Read the phrase names defined in the reference language's file.
+fn read_reference_names(path: &Path) -> Result<HashSet<String>> {
+    let content = InputSource::File(path.to_path_buf())
+        .read()
+        .into_diagnostic()
+        .map_err(|e| miette!("Failed to read reference file {:?}: {}", path, e))?;
+    match parse_file(&content) {
+        Ok(phrases) => Ok(phrases.into_iter().map(|p| p.name).collect()),
+        Err(e) => Err(RlfDiagnostic::from_parse_error(path, &content, &e).into()),
+    }
+}
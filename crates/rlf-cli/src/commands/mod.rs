@@ -3,7 +3,11 @@
 mod check;
 mod coverage;
 mod eval;
+mod po;
+mod stats;
 
 pub use check::{run_check, CheckArgs};
 pub use coverage::{run_coverage, CoverageArgs};
 pub use eval::{run_eval, EvalArgs};
+pub use po::{run_po, PoArgs};
+pub use stats::{run_stats, StatsArgs};
@@ -0,0 +1,221 @@
+//! Implementation of the `rlf po` command.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use clap::Args;
+use miette::{miette, IntoDiagnostic, Result};
+use rlf::parser::{
+    parse_file, MatchBranch, PhraseAttribute, PhraseBody, PhraseDefinition, Reference, Segment,
+    Selector, Template, Transform, TransformContext, VariantEntryBody,
+};
+
+use crate::input::InputSource;
+use crate::output::RlfDiagnostic;
+
+/// Arguments for the po command.
+#[derive(Debug, Args)]
+pub struct PoArgs {
+    /// Source file to export (.rlf), or `-` for stdin
+    pub file: PathBuf,
+}
+
+/// Run the po command, printing a PO template for the file's phrases.
+pub fn run_po(args: PoArgs) -> Result<i32> {
+    let input = InputSource::from_arg(&args.file);
+    let content = input
+        .read()
+        .into_diagnostic()
+        .map_err(|e| miette!("Failed to read file {:?}: {}", args.file, e))?;
+
+    let definitions = match parse_file(&content) {
+        Ok(definitions) => definitions,
+        Err(e) => {
+            let diagnostic = RlfDiagnostic::from_parse_error(input.path(), &content, &e);
+            return Err(diagnostic.into());
+        }
+    };
+
+    print!("{}", po_template(&definitions));
+    Ok(exitcode::OK)
+}
+
+/// Render definitions as a PO template.
+///
+/// Each template becomes one entry: variant and match branches get their own
+/// entry, with the keys after the phrase name in `msgctxt` (`card:one`). The
+/// `msgid` is the template in RLF syntax. `@note` attributes become `#.`
+/// extracted comments, followed by the `@maxlen` limit if there is one.
+fn po_template(definitions: &[PhraseDefinition]) -> String {
+    let mut out =
+        String::from("msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    for def in definitions {
+        let mut comments = String::new();
+        for attribute in &def.attributes {
+            match attribute {
+                PhraseAttribute::Note(note) => {
+                    for line in note.lines() {
+                        writeln!(comments, "#. {line}").unwrap();
+                    }
+                }
+                PhraseAttribute::MaxLength(max) => {
+                    writeln!(comments, "#. Max length: {max}").unwrap();
+                }
+            }
+        }
+        for (context, template) in definition_entries(def) {
+            write!(
+                out,
+                "\n{comments}msgctxt {}\nmsgid {}\nmsgstr \"\"\n",
+                po_string(&context),
+                po_string(&template_source(template, false)),
+            )
+            .unwrap();
+        }
+    }
+    out
+}
+
+/// Every template of a definition, paired with its `msgctxt`.
+fn definition_entries(def: &PhraseDefinition) -> Vec<(String, &Template)> {
+    match &def.body {
+        PhraseBody::Simple(template) => vec![(def.name.clone(), template)],
+        PhraseBody::Match(branches) => branch_entries(&def.name, branches),
+        PhraseBody::Variants(entries) => entries
+            .iter()
+            .flat_map(|entry| {
+                let context = format!("{}:{}", def.name, entry.keys.join(","));
+                match &entry.body {
+                    VariantEntryBody::Template(template) => vec![(context, template)],
+                    VariantEntryBody::Match { branches, .. } => branch_entries(&context, branches),
+                }
+            })
+            .collect(),
+    }
+}
+
+fn branch_entries<'a>(prefix: &str, branches: &'a [MatchBranch]) -> Vec<(String, &'a Template)> {
+    branches
+        .iter()
+        .map(|branch| {
+            let keys: Vec<&str> = branch.keys.iter().map(|key| key.value.as_str()).collect();
+            (format!("{prefix}:{}", keys.join(",")), &branch.template)
+        })
+        .collect()
+}
+
+/// Write a template back in RLF syntax, escaping braces and backslashes, and
+/// also quotes inside a conditional body.
+fn template_source(template: &Template, quoted: bool) -> String {
+    let mut out = String::new();
+    for segment in &template.segments {
+        match segment {
+            Segment::Literal(text) => {
+                for c in text.chars() {
+                    match c {
+                        '{' => out.push_str("{{"),
+                        '}' => out.push_str("}}"),
+                        '\\' => out.push_str("\\\\"),
+                        '"' if quoted => out.push_str("\\\""),
+                        c => out.push(c),
+                    }
+                }
+            }
+            Segment::Interpolation {
+                transforms,
+                reference,
+                selectors,
+            } => {
+                out.push('{');
+                for transform in transforms {
+                    write_transform(&mut out, transform);
+                    out.push(' ');
+                }
+                write_reference(&mut out, reference);
+                for selector in selectors {
+                    match selector {
+                        Selector::Identifier(name) => write!(out, ":{name}"),
+                        Selector::Parameter(name) => write!(out, ":${name}"),
+                        Selector::Ordinal(name) => write!(out, ":#${name}"),
+                        Selector::Default => write!(out, ":*"),
+                    }
+                    .unwrap();
+                }
+                out.push('}');
+            }
+            Segment::Conditional { parameter, body } => {
+                write!(
+                    out,
+                    "{{?${parameter}: \"{}\"}}",
+                    template_source(body, true)
+                )
+                .unwrap();
+            }
+        }
+    }
+    out
+}
+
+fn write_transform(out: &mut String, transform: &Transform) {
+    write!(out, "@{}", transform.name).unwrap();
+    match &transform.context {
+        TransformContext::None => {}
+        TransformContext::Static(context) => write!(out, ":{context}").unwrap(),
+        TransformContext::Dynamic(param) => write!(out, "(${param})").unwrap(),
+        TransformContext::Both(context, param) => write!(out, ":{context}(${param})").unwrap(),
+    }
+}
+
+fn write_reference(out: &mut String, reference: &Reference) {
+    match reference {
+        Reference::Identifier(name) => out.push_str(name),
+        Reference::Parameter(name) => write!(out, "${name}").unwrap(),
+        Reference::PhraseCall { name, args } => {
+            write!(out, "{name}(").unwrap();
+            for (i, arg) in args.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_reference(out, arg);
+            }
+            out.push(')');
+        }
+        Reference::NumberLiteral(n) => write!(out, "{n}").unwrap(),
+        Reference::StringLiteral(text) => {
+            write!(
+                out,
+                "\"{}\"",
+                text.replace('\\', "\\\\").replace('"', "\\\"")
+            )
+            .unwrap();
+        }
+        Reference::Named { name, value } => {
+            write!(out, "{name}: ").unwrap();
+            write_reference(out, value);
+        }
+        Reference::Coalesce(alternatives) => {
+            for (i, alternative) in alternatives.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(" || ");
+                }
+                write_reference(out, alternative);
+            }
+        }
+    }
+}
+
+/// Quote `text` as a PO string.
+fn po_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
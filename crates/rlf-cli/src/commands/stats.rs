@@ -0,0 +1,203 @@
+//! Implementation of the `rlf stats` command.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use clap::Args;
+use miette::{miette, IntoDiagnostic, Result};
+use rlf::parser::{parse_file, PhraseBody, PhraseDefinition, Segment, Template, VariantEntryBody};
+use serde::Serialize;
+
+use crate::input::InputSource;
+use crate::output::table::format_transform_table;
+use crate::output::RlfDiagnostic;
+
+/// Number of longest phrases listed in the report.
+const LONGEST_PHRASES: usize = 5;
+
+/// Arguments for the stats command.
+#[derive(Debug, Args)]
+pub struct StatsArgs {
+    /// File to analyze (.rlf), or `-` for stdin
+    pub file: PathBuf,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Aggregate statistics for a single file.
+#[derive(Debug, Serialize)]
+pub struct FileStats {
+    /// Number of phrase and term definitions.
+    pub phrases: usize,
+    /// Number of templates, counting each variant and match branch separately.
+    pub templates: usize,
+    /// Whitespace-separated words of literal text across all templates.
+    pub words: usize,
+    /// Average literal length of a template, in characters.
+    pub average_length: f64,
+    /// Longest literal length of any template, in characters.
+    pub max_length: usize,
+    /// Definitions with the longest templates, longest first.
+    pub longest: Vec<PhraseLength>,
+    /// Number of uses of each transform, keyed by name without `@`.
+    pub transforms: BTreeMap<String, usize>,
+    /// Number of definitions declaring metadata tags.
+    pub tagged: usize,
+    /// Number of definitions with a variant block.
+    pub with_variants: usize,
+}
+
+/// A definition name paired with the literal length of its longest template.
+#[derive(Debug, Serialize)]
+pub struct PhraseLength {
+    pub name: String,
+    pub length: usize,
+}
+
+/// Run the stats command.
+pub fn run_stats(args: StatsArgs) -> Result<i32> {
+    let input = InputSource::from_arg(&args.file);
+    let content = input
+        .read()
+        .into_diagnostic()
+        .map_err(|e| miette!("Failed to read file {:?}: {}", args.file, e))?;
+
+    let definitions = match parse_file(&content) {
+        Ok(definitions) => definitions,
+        Err(e) => {
+            let diagnostic = RlfDiagnostic::from_parse_error(input.path(), &content, &e);
+            return Err(diagnostic.into());
+        }
+    };
+
+    let stats = collect_stats(&definitions);
+
+    if args.json {
+        let json_output = serde_json::to_string_pretty(&stats).into_diagnostic()?;
+        println!("{}", json_output);
+    } else {
+        println!("Phrases: {}", stats.phrases);
+        println!("Templates: {}", stats.templates);
+        println!("Words: {}", stats.words);
+        println!(
+            "Template length: {:.1} average, {} max",
+            stats.average_length, stats.max_length
+        );
+        println!("With tags: {}", stats.tagged);
+        println!("With variants: {}", stats.with_variants);
+
+        if !stats.longest.is_empty() {
+            println!("\nLongest phrases:");
+            for phrase in &stats.longest {
+                println!("  {} ({})", phrase.name, phrase.length);
+            }
+        }
+        if !stats.transforms.is_empty() {
+            println!("\n{}", format_transform_table(&stats.transforms));
+        }
+    }
+
+    Ok(exitcode::OK)
+}
+
+/// Walk parsed definitions and aggregate their statistics.
+fn collect_stats(definitions: &[PhraseDefinition]) -> FileStats {
+    let mut templates = 0;
+    let mut words = 0;
+    let mut total_length = 0;
+    let mut max_length = 0;
+    let mut transforms: BTreeMap<String, usize> = BTreeMap::new();
+    let mut lengths = Vec::new();
+
+    for def in definitions {
+        let mut longest = 0;
+        for template in definition_templates(def) {
+            let text = literal_text(template);
+            let length = text.chars().count();
+            templates += 1;
+            words += text.split_whitespace().count();
+            total_length += length;
+            longest = longest.max(length);
+            count_transforms(template, &mut transforms);
+        }
+        max_length = max_length.max(longest);
+        lengths.push(PhraseLength {
+            name: def.name.clone(),
+            length: longest,
+        });
+    }
+
+    lengths.sort_by(|a, b| b.length.cmp(&a.length).then_with(|| a.name.cmp(&b.name)));
+    lengths.truncate(LONGEST_PHRASES);
+
+    FileStats {
+        phrases: definitions.len(),
+        templates,
+        words,
+        average_length: if templates == 0 {
+            0.0
+        } else {
+            total_length as f64 / templates as f64
+        },
+        max_length,
+        longest: lengths,
+        transforms,
+        tagged: definitions.iter().filter(|d| !d.tags.is_empty()).count(),
+        with_variants: definitions
+            .iter()
+            .filter(|d| matches!(d.body, PhraseBody::Variants(_)))
+            .count(),
+    }
+}
+
+/// Every template in a definition body, in source order.
+fn definition_templates(def: &PhraseDefinition) -> Vec<&Template> {
+    match &def.body {
+        PhraseBody::Simple(template) => vec![template],
+        PhraseBody::Match(branches) => branches.iter().map(|b| &b.template).collect(),
+        PhraseBody::Variants(entries) => entries
+            .iter()
+            .flat_map(|entry| match &entry.body {
+                VariantEntryBody::Template(template) => vec![template],
+                VariantEntryBody::Match { branches, .. } => {
+                    branches.iter().map(|b| &b.template).collect()
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Add the transforms applied in a template, including conditional bodies.
+fn count_transforms(template: &Template, transforms: &mut BTreeMap<String, usize>) {
+    for segment in &template.segments {
+        match segment {
+            Segment::Literal(_) => {}
+            Segment::Interpolation {
+                transforms: applied,
+                ..
+            } => {
+                for transform in applied {
+                    *transforms.entry(transform.name.clone()).or_default() += 1;
+                }
+            }
+            Segment::Conditional { body, .. } => count_transforms(body, transforms),
+        }
+    }
+}
+
+/// The literal (translatable) text of a template, without interpolations.
+///
+/// Conditional bodies are included as if present.
+fn literal_text(template: &Template) -> String {
+    template
+        .segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Literal(text) => text.clone(),
+            Segment::Interpolation { .. } => String::new(),
+            Segment::Conditional { body, .. } => literal_text(body),
+        })
+        .collect()
+}
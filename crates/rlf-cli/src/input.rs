@@ -0,0 +1,99 @@
+//! Resolution of file arguments into input sources.
+//!
+//! File arguments may be literal paths, glob patterns (`src/**/*.rlf`), or `-`
+//! for standard input. Patterns are expanded here so quoted globs work the same
+//! way in pre-commit hooks as unquoted globs do in an interactive shell.
+
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use glob::glob;
+
+/// Display name used for diagnostics on standard input.
+const STDIN_NAME: &str = "<stdin>";
+
+/// Standard input, buffered on first read so every `-` argument sees it.
+static STDIN_CONTENT: OnceLock<String> = OnceLock::new();
+
+/// A single input to read: a file on disk or standard input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Read from standard input (`-`).
+    Stdin,
+    /// Read from a file path.
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Interpret a single argument without glob expansion (`-` is stdin).
+    pub fn from_arg(arg: &Path) -> Self {
+        if arg.as_os_str() == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.to_path_buf())
+        }
+    }
+
+    /// Path used for diagnostics and output (`<stdin>` for standard input).
+    pub fn path(&self) -> &Path {
+        match self {
+            InputSource::Stdin => Path::new(STDIN_NAME),
+            InputSource::File(path) => path,
+        }
+    }
+
+    /// Read the full contents of this input.
+    ///
+    /// Standard input is read once and buffered, so reading it again, as when
+    /// `-` is given for more than one argument, returns the same content.
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                if let Some(content) = STDIN_CONTENT.get() {
+                    return Ok(content.clone());
+                }
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+                Ok(STDIN_CONTENT.get_or_init(|| content).clone())
+            }
+            InputSource::File(path) => read_to_string(path),
+        }
+    }
+}
+
+/// Expand file arguments into input sources, preserving argument order.
+///
+/// `-` becomes [`InputSource::Stdin`]. Arguments containing glob
+/// metacharacters that don't name an existing file are expanded in sorted
+/// order; a pattern that matches nothing is kept as a literal path so the
+/// caller reports it as missing.
+pub fn expand_inputs(args: &[PathBuf]) -> Vec<InputSource> {
+    let mut inputs = Vec::new();
+    for arg in args {
+        let matches = glob_matches(arg);
+        if matches.is_empty() {
+            inputs.push(InputSource::from_arg(arg));
+        } else {
+            inputs.extend(matches.into_iter().map(InputSource::File));
+        }
+    }
+    inputs
+}
+
+/// Expand a glob pattern, returning no matches for literal or invalid patterns.
+fn glob_matches(arg: &Path) -> Vec<PathBuf> {
+    let Some(pattern) = arg.to_str() else {
+        return Vec::new();
+    };
+    if arg.exists() || !pattern.contains(['*', '?', '[']) {
+        return Vec::new();
+    }
+    let Ok(paths) = glob(pattern) else {
+        return Vec::new();
+    };
+    let mut matches: Vec<PathBuf> = paths.filter_map(Result::ok).collect();
+    matches.sort();
+    matches
+}
@@ -2,14 +2,20 @@
 //!
 //! Provides command-line tools for working with RLF localization files:
 //! - `rlf check` - Validate .rlf file syntax
+//! - `rlf po` - Export phrases as a PO template for translators
+//! - `rlf stats` - Summarize phrase counts and transform usage
 
 mod commands;
+mod input;
 mod output;
 
 use std::process::exit;
 
 use clap::{Parser, Subcommand, ValueEnum};
-use commands::{run_check, run_coverage, run_eval, CheckArgs, CoverageArgs, EvalArgs};
+use commands::{
+    run_check, run_coverage, run_eval, run_po, run_stats, CheckArgs, CoverageArgs, EvalArgs,
+    PoArgs, StatsArgs,
+};
 
 /// RLF localization file tools.
 #[derive(Debug, Parser)]
@@ -46,6 +52,10 @@ pub enum Commands {
     Coverage(CoverageArgs),
     /// Evaluate an RLF template string
     Eval(EvalArgs),
+    /// Export phrases as a PO template, with `@note` attributes as comments
+    Po(PoArgs),
+    /// Report phrase counts, template lengths, and transform usage
+    Stats(StatsArgs),
 }
 
 /// Set up color output based on user preference.
@@ -81,6 +91,8 @@ fn main() -> miette::Result<()> {
         Commands::Check(args) => run_check(args),
         Commands::Coverage(args) => run_coverage(args),
         Commands::Eval(args) => run_eval(args),
+        Commands::Po(args) => run_po(args),
+        Commands::Stats(args) => run_stats(args),
     };
 
     match result {
@@ -1,5 +1,7 @@
 //! Table formatting utilities for CLI output.
 
+use std::collections::BTreeMap;
+
 use comfy_table::{presets, ContentArrangement, Table};
 
 /// Coverage data for a single language.
@@ -29,3 +31,19 @@ pub fn format_coverage_table(source_count: usize, coverage: &[LanguageCoverage])
 
     table
 }
+
+/// Format a transform usage histogram as an ASCII table, most used first.
+pub fn format_transform_table(transforms: &BTreeMap<String, usize>) -> Table {
+    let mut table = Table::new();
+    table.load_preset(presets::UTF8_BORDERS_ONLY);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec!["Transform", "Uses"]);
+
+    let mut rows: Vec<_> = transforms.iter().collect();
+    rows.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    for (name, count) in rows {
+        table.add_row(vec![format!("@{}", name), count.to_string()]);
+    }
+
+    table
+}
@@ -0,0 +1,362 @@
+//! Integration tests for `rlf check` and `rlf coverage` file handling and lints.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+/// Create a fresh directory under the cargo test scratch area.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn rlf(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rlf"))
+        .args(["--color", "never"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut input = child.stdin.take().unwrap();
+    input
+        .write_all(stdin.unwrap_or_default().as_bytes())
+        .unwrap();
+    drop(input);
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn check_glob_reports_broken_file_and_summary() {
+    let dir = scratch_dir("check_glob");
+    fs::write(dir.join("good.rlf"), r#"hello = "Hello";"#).unwrap();
+    fs::write(dir.join("bad.rlf"), r#"hello = "Hello"#).unwrap();
+
+    let pattern = dir.join("*.rlf");
+    let output = rlf(&["check", pattern.to_str().unwrap()], None);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("bad.rlf"), "stderr: {stderr}");
+    assert!(stdout.contains("Summary:"), "stdout: {stdout}");
+    assert!(stdout.contains("good.rlf: OK"), "stdout: {stdout}");
+    assert!(stdout.contains("bad.rlf: 1 error(s)"), "stdout: {stdout}");
+    assert!(
+        stdout.contains("Checked 2 files, 1 failed"),
+        "stdout: {stdout}"
+    );
+}
+
+#[test]
+fn check_glob_of_valid_files_succeeds() {
+    let dir = scratch_dir("check_glob_ok");
+    fs::write(dir.join("a.rlf"), r#"a = "A";"#).unwrap();
+    fs::write(dir.join("b.rlf"), r#"b = "B";"#).unwrap();
+
+    let pattern = dir.join("*.rlf");
+    let output = rlf(&["check", pattern.to_str().unwrap()], None);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(
+        stdout.contains("Checked 2 files, no errors"),
+        "stdout: {stdout}"
+    );
+}
+
+#[test]
+fn check_reads_stdin() {
+    let ok = rlf(&["check", "-"], Some(r#"hello = "Hello";"#));
+    assert!(ok.status.success());
+    assert!(String::from_utf8_lossy(&ok.stdout).contains("<stdin>: OK"));
+
+    let broken = rlf(&["check", "-"], Some(r#"hello = "#));
+    assert!(!broken.status.success());
+    assert!(String::from_utf8_lossy(&broken.stderr).contains("<stdin>"));
+}
+
+#[test]
+fn check_json_lists_each_expanded_file() {
+    let dir = scratch_dir("check_json");
+    fs::write(dir.join("good.rlf"), r#"hello = "Hello";"#).unwrap();
+    fs::write(dir.join("bad.rlf"), r#"hello = "Hello"#).unwrap();
+
+    let pattern = dir.join("*.rlf");
+    let output = rlf(&["check", "--json", pattern.to_str().unwrap()], None);
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(json["total_errors"], 1);
+    assert_eq!(json["files"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn check_project_resolves_references_across_files() {
+    let dir = scratch_dir("check_project");
+    fs::write(dir.join("en.rlf"), r#"draw($n) = "Draw {$n} {card:$n}.";"#).unwrap();
+    fs::write(
+        dir.join("en.cards.rlf"),
+        r#"card = { one: "card", *other: "cards" };"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.join("de.rlf"),
+        r#"draw($n) = "Ziehe {$n} {karte:$n}.";"#,
+    )
+    .unwrap();
+
+    let output = rlf(&["check", "--project", dir.to_str().unwrap()], None);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stdout.contains("en.rlf: OK"), "stdout: {stdout}");
+    assert!(stdout.contains("en.cards.rlf: OK"), "stdout: {stdout}");
+    assert!(
+        stderr.contains("de.rlf: unknown phrase 'karte' referenced by 'draw'"),
+        "stderr: {stderr}"
+    );
+    assert!(
+        stdout.contains("Checked 3 files, 1 failed with 1 error(s)"),
+        "stdout: {stdout}"
+    );
+}
+
+#[test]
+fn coverage_accepts_translation_globs_and_fails_on_broken_file() {
+    let dir = scratch_dir("coverage_glob");
+    let source = dir.join("source.rlf");
+    fs::write(&source, r#"hello = "Hello"; bye = "Bye";"#).unwrap();
+    fs::create_dir_all(dir.join("translations")).unwrap();
+    fs::write(
+        dir.join("translations/de.rlf"),
+        r#"hello = "Hallo"; bye = "Tschüss";"#,
+    )
+    .unwrap();
+    fs::write(dir.join("translations/fr.rlf"), r#"hello = "#).unwrap();
+
+    let pattern = dir.join("translations/*.rlf");
+    let output = rlf(
+        &[
+            "coverage",
+            "--source",
+            source.to_str().unwrap(),
+            pattern.to_str().unwrap(),
+        ],
+        None,
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stdout.is_empty(), "stdout: {stdout}");
+    assert!(stderr.contains("fr.rlf"), "stderr: {stderr}");
+}
+
+#[test]
+fn coverage_reads_stdin_once_for_every_dash() {
+    let output = rlf(
+        &["coverage", "--source", "-", "--json", "-"],
+        Some(r#"hello = "Hello"; bye = "Bye";"#),
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("\"translated\": 2"), "stdout: {stdout}");
+    assert!(stdout.contains("\"missing\": []"), "stdout: {stdout}");
+}
+
+#[test]
+fn coverage_relative_to_reference_language() {
+    let dir = scratch_dir("coverage_reference");
+    let source = dir.join("source.rlf");
+    fs::write(&source, r#"hello = "Hello"; bye = "Bye"; draft = "Draft";"#).unwrap();
+    fs::write(
+        dir.join("en.rlf"),
+        r#"hello = "Hello"; bye = "Bye"; thanks = "Thanks";"#,
+    )
+    .unwrap();
+    fs::write(dir.join("de.rlf"), r#"hello = "Hallo"; draft = "Entwurf";"#).unwrap();
+
+    let output = rlf(
+        &[
+            "coverage",
+            "--source",
+            source.to_str().unwrap(),
+            "--lang",
+            "en,de",
+            "--reference",
+            "en",
+            "--json",
+        ],
+        None,
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let languages = json.as_array().unwrap();
+
+    assert_eq!(languages.len(), 1);
+    assert_eq!(languages[0]["language"], "de");
+    assert_eq!(languages[0]["reference"], "en");
+    assert_eq!(languages[0]["translated"], 1);
+    assert_eq!(languages[0]["total"], 3);
+    assert_eq!(
+        languages[0]["missing"],
+        serde_json::json!(["bye", "thanks"])
+    );
+
+    let table = rlf(
+        &[
+            "coverage",
+            "--source",
+            source.to_str().unwrap(),
+            "--lang",
+            "de",
+            "--reference",
+            "en",
+        ],
+        None,
+    );
+    let stdout = String::from_utf8_lossy(&table.stdout);
+    assert!(stdout.contains("1/3"), "stdout: {stdout}");
+    assert!(
+        stdout.contains("Missing in de (relative to en):"),
+        "stdout: {stdout}"
+    );
+}
+
+#[test]
+fn check_pedantic_warns_on_literal_numbers() {
+    let dir = scratch_dir("check_pedantic");
+    let file = dir.join("en.rlf");
+    fs::write(
+        &file,
+        r#"result = "3rd place"; warn = "<color=#112233>Careful</color>";"#,
+    )
+    .unwrap();
+
+    let plain = rlf(&["check", file.to_str().unwrap()], None);
+    assert!(plain.status.success());
+    assert!(!String::from_utf8_lossy(&plain.stderr).contains("warning"));
+
+    let pedantic = rlf(&["check", "--pedantic", file.to_str().unwrap()], None);
+    let stderr = String::from_utf8_lossy(&pedantic.stderr);
+    assert!(pedantic.status.success());
+    assert!(
+        stderr.contains("phrase 'result' in 'en' contains literal number '3rd'"),
+        "stderr: {stderr}"
+    );
+    assert!(!stderr.contains("'warn'"), "stderr: {stderr}");
+}
+
+#[test]
+fn check_pedantic_warns_on_wrong_language_transform() {
+    let dir = scratch_dir("check_mixed_language");
+    let source = dir.join("en.rlf");
+    fs::write(
+        &source,
+        r#"card = :a "card"; take = "Take {@the card}."; ok = "OK";"#,
+    )
+    .unwrap();
+    let file = dir.join("de.rlf");
+    fs::write(
+        &file,
+        r#"card = :fem "Karte"; take = "Nimm {@the card}."; ok = "OK";"#,
+    )
+    .unwrap();
+
+    let plain = rlf(&["check", file.to_str().unwrap()], None);
+    assert!(!String::from_utf8_lossy(&plain.stderr).contains("warning"));
+
+    let pedantic = rlf(
+        &[
+            "check",
+            "--pedantic",
+            "--strict",
+            source.to_str().unwrap(),
+            file.to_str().unwrap(),
+        ],
+        None,
+    );
+    let stderr = String::from_utf8_lossy(&pedantic.stderr);
+    assert!(pedantic.status.success());
+    assert!(
+        stderr.contains("phrase 'take' in 'de' uses @the, which is only defined for en"),
+        "stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("phrase 'ok' in 'de' is identical to 'en'"),
+        "stderr: {stderr}"
+    );
+}
+
+#[test]
+fn check_warns_on_unreachable_plural_category() {
+    let dir = scratch_dir("check_plural_categories");
+    let file = dir.join("en.rlf");
+    fs::write(
+        &file,
+        r#"card = { one: "card", few: "cards", *other: "cards" };"#,
+    )
+    .unwrap();
+
+    let output = rlf(&["check", file.to_str().unwrap()], None);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success());
+    assert!(
+        stderr.contains("phrase 'card' in 'en' has key 'few'"),
+        "stderr: {stderr}"
+    );
+}
+
+#[test]
+fn check_warns_when_term_exceeds_maxlen() {
+    let dir = scratch_dir("check_maxlen");
+    let file = dir.join("de.rlf");
+    fs::write(
+        &file,
+        r#"
+            @note("Shown on the main menu button")
+            @maxlen(10)
+            start = "<b>Spiel starten</b>";
+            @maxlen(10)
+            quit = "Beenden";
+        "#,
+    )
+    .unwrap();
+
+    let output = rlf(&["check", file.to_str().unwrap()], None);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success());
+    assert!(
+        stderr.contains("phrase 'start' in 'de' is 13 characters long, over its @maxlen(10)"),
+        "stderr: {stderr}"
+    );
+    assert!(!stderr.contains("'quit'"), "stderr: {stderr}");
+}
+
+#[test]
+fn check_rejects_duplicate_variant_key() {
+    let output = rlf(
+        &["check", "-"],
+        Some(r#"card = { one: "a", one: "b", *other: "c" };"#),
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("duplicate key 'one'"));
+}
+
+#[test]
+fn check_reports_every_syntax_error_in_a_file() {
+    let source = "hello = \"Hello\";\nBad = \"x\";\nbye = \"Bye\";\ndraw($n) = \"Draw {$n}\"\nend = \"End\";\n";
+    let output = rlf(&["check", "--json", "-"], Some(source));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(json["total_errors"], 2);
+    let errors = json["files"][0]["errors"].as_array().unwrap();
+    let lines: Vec<u64> = errors.iter().map(|e| e["line"].as_u64().unwrap()).collect();
+    assert_eq!(lines, vec![2, 5]);
+}
@@ -0,0 +1,82 @@
+//! Integration tests for `rlf po`.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn rlf_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rlf"))
+        .args(["--color", "never"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut input = child.stdin.take().unwrap();
+    input.write_all(stdin.as_bytes()).unwrap();
+    drop(input);
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn po_emits_notes_as_extracted_comments() {
+    let output = rlf_stdin(
+        &["po", "-"],
+        r#"
+        @note("Label on the main menu button")
+        @maxlen(16)
+        start_game = "Start game";
+    "#,
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(
+        stdout.contains(
+            "#. Label on the main menu button\n#. Max length: 16\nmsgctxt \"start_game\"\nmsgid \"Start game\"\nmsgstr \"\"\n"
+        ),
+        "stdout: {stdout}"
+    );
+}
+
+#[test]
+fn po_writes_one_entry_per_variant_in_rlf_syntax() {
+    let output = rlf_stdin(
+        &["po", "-"],
+        r#"
+        card = { one: "card", *other: "cards" };
+        draw($n) = :match($n) {
+            1: "Draw {@a card}.",
+            *other: "Draw {$n} {card:$n} {{fast}}{?$n: " \"now\""}",
+        };
+    "#,
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(
+        stdout.contains("msgctxt \"card:one\"\nmsgid \"card\"\n"),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains("msgctxt \"card:other\"\nmsgid \"cards\"\n"),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains("msgctxt \"draw:1\"\nmsgid \"Draw {@a card}.\"\n"),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains(
+            r#"msgctxt "draw:other"
+msgid "Draw {$n} {card:$n} {{fast}}{?$n: \" \\\"now\\\"\"}"
+"#
+        ),
+        "stdout: {stdout}"
+    );
+}
+
+#[test]
+fn po_reports_parse_errors() {
+    let output = rlf_stdin(&["po", "-"], r#"hello = "Hello"#);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("<stdin>"));
+}
@@ -0,0 +1,61 @@
+//! Integration tests for `rlf stats`.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use serde_json::Value;
+
+const FILE: &str = r#"
+    karte = :fem { nom: "Karte", acc: "Karte", *other: "Karten" };
+    draw($c) = "Ziehe {@der:acc $c}.";
+    play($c) = "{@cap @der $c} ausspielen";
+    title = "{@cap karte}";
+"#;
+
+fn rlf_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rlf"))
+        .args(["--color", "never"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut input = child.stdin.take().unwrap();
+    input.write_all(stdin.as_bytes()).unwrap();
+    drop(input);
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn stats_json_counts_transform_usage() {
+    let output = rlf_stdin(&["stats", "--json", "-"], FILE);
+    assert!(output.status.success());
+    let stats: Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(stats["transforms"]["cap"], 2);
+    assert_eq!(stats["transforms"]["der"], 2);
+    assert_eq!(stats["phrases"], 4);
+    assert_eq!(stats["templates"], 6);
+    assert_eq!(stats["tagged"], 1);
+    assert_eq!(stats["with_variants"], 1);
+    assert_eq!(stats["max_length"], 11);
+    assert_eq!(stats["longest"][0]["name"], "play");
+}
+
+#[test]
+fn stats_text_output_lists_histogram() {
+    let output = rlf_stdin(&["stats", "-"], FILE);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains("Phrases: 4"), "stdout: {stdout}");
+    assert!(stdout.contains("@cap"), "stdout: {stdout}");
+    assert!(stdout.contains("@der"), "stdout: {stdout}");
+}
+
+#[test]
+fn stats_reports_parse_errors() {
+    let output = rlf_stdin(&["stats", "-"], r#"hello = "Hello"#);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("<stdin>"));
+}
@@ -10,8 +10,9 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
 use crate::input::{
-    DefinitionKind, Interpolation, MacroInput, MatchBranch, MatchKey, PhraseBody, PhraseDefinition,
-    Reference, Segment, Selector, Template, TransformContext, TransformRef, VariantEntryBody,
+    DefinitionKind, Interpolation, MacroInput, MatchBranch, MatchKey, ParamType, PhraseAttribute,
+    PhraseBody, PhraseDefinition, Reference, Segment, Selector, Template, TransformContext,
+    TransformRef, VariantEntryBody,
 };
 
 /// Main code generation entry point.
@@ -58,15 +59,53 @@ fn generate_function(phrase: &PhraseDefinition) -> TokenStream {
     }
 }
 
-/// Generate a phrase function that takes an explicit `locale` parameter.
-fn generate_function_explicit(phrase: &PhraseDefinition) -> TokenStream {
-    let fn_name = format_ident!("{}", phrase.name.name);
-    let phrase_name = &phrase.name.name;
+/// Generate parameter declarations and their conversions to `Value`.
+///
+/// Untyped parameters accept `impl Into<Value>`. Typed parameters narrow the
+/// accepted types so mismatches fail to compile: `num` takes
+/// `impl Into<NumValue>`, `text` takes `impl Into<TextValue>`, and `phrase`
+/// takes `impl Into<Phrase>`.
+fn generate_params(phrase: &PhraseDefinition) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    phrase
+        .parameters
+        .iter()
+        .zip(&phrase.parameter_types)
+        .map(|(param, param_type)| {
+            let name = format_ident!("{}", param.name);
+            let ty = match param_type {
+                None => quote! { ::rlf::Value },
+                Some(ParamType::Num) => quote! { ::rlf::NumValue },
+                Some(ParamType::Text) => quote! { ::rlf::TextValue },
+                Some(ParamType::Phrase) => quote! { ::rlf::Phrase },
+            };
+            let decl = quote! { #name: impl Into<#ty> };
+            let conversion = quote! { ::rlf::Value::from(Into::<#ty>::into(#name)) };
+            (decl, conversion)
+        })
+        .unzip()
+}
+
+/// Doc comment for a generated phrase function, including any `@note` text.
+fn function_doc(phrase: &PhraseDefinition) -> String {
     let kind_label = match phrase.kind {
         DefinitionKind::Term => "term",
         DefinitionKind::Phrase => "phrase",
     };
-    let doc = format!("Returns the \"{phrase_name}\" {kind_label}.");
+    let mut doc = format!("Returns the \"{}\" {kind_label}.", phrase.name.name);
+    for attribute in &phrase.attributes {
+        if let PhraseAttribute::Note(note) = attribute {
+            doc.push_str("\n\n");
+            doc.push_str(note);
+        }
+    }
+    doc
+}
+
+/// Generate a phrase function that takes an explicit `locale` parameter.
+fn generate_function_explicit(phrase: &PhraseDefinition) -> TokenStream {
+    let fn_name = format_ident!("{}", phrase.name.name);
+    let phrase_name = &phrase.name.name;
+    let doc = function_doc(phrase);
 
     if phrase.parameters.is_empty() {
         quote! {
@@ -77,21 +116,7 @@ fn generate_function_explicit(phrase: &PhraseDefinition) -> TokenStream {
             }
         }
     } else {
-        let param_names: Vec<_> = phrase
-            .parameters
-            .iter()
-            .map(|p| format_ident!("{}", p.name))
-            .collect();
-
-        let param_decls: Vec<TokenStream> = param_names
-            .iter()
-            .map(|name| quote! { #name: impl Into<::rlf::Value> })
-            .collect();
-
-        let param_conversions: Vec<TokenStream> = param_names
-            .iter()
-            .map(|name| quote! { #name.into() })
-            .collect();
+        let (param_decls, param_conversions) = generate_params(phrase);
 
         quote! {
             #[doc = #doc]
@@ -107,11 +132,7 @@ fn generate_function_explicit(phrase: &PhraseDefinition) -> TokenStream {
 fn generate_function_global(phrase: &PhraseDefinition) -> TokenStream {
     let fn_name = format_ident!("{}", phrase.name.name);
     let phrase_name = &phrase.name.name;
-    let kind_label = match phrase.kind {
-        DefinitionKind::Term => "term",
-        DefinitionKind::Phrase => "phrase",
-    };
-    let doc = format!("Returns the \"{phrase_name}\" {kind_label}.");
+    let doc = function_doc(phrase);
 
     if phrase.parameters.is_empty() {
         quote! {
@@ -130,21 +151,7 @@ fn generate_function_global(phrase: &PhraseDefinition) -> TokenStream {
             }
         }
     } else {
-        let param_names: Vec<_> = phrase
-            .parameters
-            .iter()
-            .map(|p| format_ident!("{}", p.name))
-            .collect();
-
-        let param_decls: Vec<TokenStream> = param_names
-            .iter()
-            .map(|name| quote! { #name: impl Into<::rlf::Value> })
-            .collect();
-
-        let param_conversions: Vec<TokenStream> = param_names
-            .iter()
-            .map(|name| quote! { #name.into() })
-            .collect();
+        let (param_decls, param_conversions) = generate_params(phrase);
 
         quote! {
             #[doc = #doc]
@@ -225,6 +232,17 @@ fn reconstruct_source(input: &MacroInput) -> String {
     for phrase in &input.phrases {
         let mut line = String::new();
 
+        // Attributes
+        for attribute in &phrase.attributes {
+            match attribute {
+                PhraseAttribute::Note(note) => {
+                    let escaped = note.replace('\\', "\\\\").replace('"', "\\\"");
+                    line.push_str(&format!("@note(\"{escaped}\") "));
+                }
+                PhraseAttribute::MaxLength(max) => line.push_str(&format!("@maxlen({max}) ")),
+            }
+        }
+
         // Name
         line.push_str(&phrase.name.name);
 
@@ -234,7 +252,11 @@ fn reconstruct_source(input: &MacroInput) -> String {
             let params: Vec<_> = phrase
                 .parameters
                 .iter()
-                .map(|p| format!("${}", p.name))
+                .zip(&phrase.parameter_types)
+                .map(|(p, param_type)| match param_type {
+                    Some(param_type) => format!("${}: {}", p.name, param_type.name()),
+                    None => format!("${}", p.name),
+                })
                 .collect();
             line.push_str(&params.join(", "));
             line.push(')');
@@ -357,9 +379,14 @@ fn reconstruct_match_key(key: &MatchKey) -> String {
 
 /// Reconstruct a template string from Template AST.
 fn reconstruct_template(template: &Template) -> String {
+    reconstruct_segments(&template.segments)
+}
+
+/// Reconstruct template text from a list of segments.
+fn reconstruct_segments(segments: &[Segment]) -> String {
     let mut result = String::new();
 
-    for segment in &template.segments {
+    for segment in segments {
         match segment {
             Segment::Literal(text) => {
                 // Only braces need escaping in template text.
@@ -374,6 +401,13 @@ fn reconstruct_template(template: &Template) -> String {
             Segment::Interpolation(interp) => {
                 result.push_str(&reconstruct_interpolation(interp));
             }
+            Segment::Conditional(conditional) => {
+                result.push_str("{?$");
+                result.push_str(&conditional.parameter.name);
+                result.push_str(": \"");
+                result.push_str(&reconstruct_segments(&conditional.body));
+                result.push_str("\"}");
+            }
         }
     }
 
@@ -401,6 +435,10 @@ fn reconstruct_interpolation(interp: &Interpolation) -> String {
                 result.push('$');
                 result.push_str(&ident.name);
             }
+            Selector::Ordinal(ident) => {
+                result.push_str("#$");
+                result.push_str(&ident.name);
+            }
             Selector::Default => result.push('*'),
         }
     }
@@ -453,6 +491,14 @@ fn reconstruct_reference(reference: &Reference) -> String {
             let escaped = s.replace('\\', "\\\\").replace('"', "\\\"");
             format!("\"{escaped}\"")
         }
+        Reference::Coalesce(alternatives) => {
+            let alternative_strs: Vec<String> =
+                alternatives.iter().map(reconstruct_reference).collect();
+            alternative_strs.join(" || ")
+        }
+        Reference::Named { name, value } => {
+            format!("{}: {}", name.name, reconstruct_reference(value))
+        }
     }
 }
 
@@ -467,6 +513,11 @@ fn generate_phrase_ids_module(input: &MacroInput) -> TokenStream {
         .iter()
         .map(generate_phrase_id_constant)
         .collect();
+    let phrase_name_enum = if cfg!(feature = "phrase-name-enum") {
+        generate_phrase_name_enum(input)
+    } else {
+        TokenStream::new()
+    };
 
     quote! {
         /// PhraseId constants for all defined phrases.
@@ -480,6 +531,52 @@ fn generate_phrase_ids_module(input: &MacroInput) -> TokenStream {
         /// ```
         pub mod phrase_ids {
             #(#constants)*
+
+            #phrase_name_enum
+        }
+    }
+}
+
+/// Generate the `PhraseName` enum with one variant per phrase, with the
+/// `phrase-name-enum` feature.
+///
+/// Matching on it is checked for exhaustiveness, so adding or removing a
+/// phrase breaks every `match` that has not been updated.
+fn generate_phrase_name_enum(input: &MacroInput) -> TokenStream {
+    let variants: Vec<_> = input
+        .phrases
+        .iter()
+        .map(|phrase| format_ident!("{}", to_upper_camel_case(&phrase.name.name)))
+        .collect();
+    let docs = input
+        .phrases
+        .iter()
+        .map(|phrase| format!("The \"{}\" definition.", phrase.name.name));
+    let constants = input
+        .phrases
+        .iter()
+        .map(|phrase| format_ident!("{}", to_screaming_case(&phrase.name.name)));
+
+    quote! {
+        /// Every phrase and term defined by this `rlf!` block.
+        ///
+        /// Converts into a [`PhraseId`](::rlf::PhraseId) with `into()`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum PhraseName {
+            #(#[doc = #docs] #variants,)*
+        }
+
+        impl PhraseName {
+            /// All phrase names, in definition order.
+            pub const ALL: &[PhraseName] = &[#(PhraseName::#variants),*];
+        }
+
+        impl ::core::convert::From<PhraseName> for ::rlf::PhraseId {
+            fn from(name: PhraseName) -> Self {
+                match name {
+                    #(PhraseName::#variants => #constants,)*
+                }
+            }
         }
     }
 }
@@ -523,6 +620,25 @@ fn to_screaming_case(s: &str) -> String {
     s.to_uppercase()
 }
 
+/// Convert snake_case to UpperCamelCase.
+///
+/// Examples:
+/// - "card" -> "Card"
+/// - "fire_elemental" -> "FireElemental"
+/// - "item1" -> "Item1"
+pub(crate) fn to_upper_camel_case(s: &str) -> String {
+    s.split('_')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -562,6 +678,13 @@ mod tests {
         assert_eq!(to_screaming_case("item1"), "ITEM1");
     }
 
+    #[test]
+    fn test_upper_camel_case() {
+        assert_eq!(to_upper_camel_case("card"), "Card");
+        assert_eq!(to_upper_camel_case("fire_elemental"), "FireElemental");
+        assert_eq!(to_upper_camel_case("item1"), "Item1");
+    }
+
     // =========================================================================
     // reconstruct_template tests
     // =========================================================================
@@ -586,6 +709,16 @@ mod tests {
         assert!(source.contains("\\\""));
     }
 
+    #[test]
+    fn test_reconstruct_attributes() {
+        let input = parse_input(parse_quote! {
+            @note("the \"start\" button") @maxlen(10)
+            start = "Start";
+        });
+        let source = reconstruct_source(&input);
+        assert!(source.starts_with(r#"@note("the \"start\" button") @maxlen(10) start = "#));
+    }
+
     #[test]
     fn test_reconstruct_parameter_interpolation() {
         let input = parse_input(parse_quote! {
@@ -799,6 +932,11 @@ mod tests {
         assert!(output.contains("mod phrase_ids"));
         // Should produce HELLO constant
         assert!(output.contains("HELLO"));
+        // Should produce PhraseName enum only with phrase-name-enum
+        assert_eq!(
+            output.contains("enum PhraseName"),
+            cfg!(feature = "phrase-name-enum")
+        );
     }
 
     #[test]
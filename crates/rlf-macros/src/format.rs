@@ -0,0 +1,229 @@
+//! The `rlf_format!` macro: evaluate a one-off template with named arguments.
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Ident, LitStr, Token};
+
+use crate::input::{Reference, Segment, Selector, SpannedIdent, TransformContext};
+use crate::parse::parse_template_string;
+
+/// Parsed `rlf_format!(locale, "template", name = value, ...)` input.
+pub struct FormatInput {
+    locale: Expr,
+    template: LitStr,
+    args: Vec<(Ident, Expr)>,
+}
+
+impl Parse for FormatInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let locale: Expr = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let template: LitStr = input.parse()?;
+        let mut args = Vec::new();
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let name: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let value: Expr = input.parse()?;
+            args.push((name, value));
+        }
+        if !input.is_empty() {
+            return Err(input.error("expected ', name = value' after the template"));
+        }
+        Ok(Self {
+            locale,
+            template,
+            args,
+        })
+    }
+}
+
+/// Validate the template against the named arguments and generate the call.
+pub fn expand(input: FormatInput) -> syn::Result<TokenStream> {
+    validate(&input)?;
+
+    let FormatInput {
+        locale,
+        template,
+        args,
+    } = input;
+    let inserts = args.iter().map(|(name, value)| {
+        let key = name.to_string();
+        quote! {
+            params.insert(
+                ::std::string::String::from(#key),
+                ::std::convert::Into::<::rlf::Value>::into(#value),
+            );
+        }
+    });
+    Ok(quote! {
+        {
+            #[allow(unused_mut)]
+            let mut params =
+                ::std::collections::HashMap::<::std::string::String, ::rlf::Value>::new();
+            #(#inserts)*
+            (#locale).eval_str(#template, params)
+        }
+    })
+}
+
+/// Every `$param` in the template must be supplied, and every argument used.
+fn validate(input: &FormatInput) -> syn::Result<()> {
+    let segments = parse_template_string(&input.template.value(), input.template.span())?;
+    let mut used = Vec::new();
+    collect_parameters(&segments, &[], &mut used);
+
+    let mut supplied = HashSet::new();
+    for (name, _) in &input.args {
+        if !supplied.insert(name.to_string()) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("duplicate argument '{name}'"),
+            ));
+        }
+    }
+
+    for UsedParameter { param, optional } in &used {
+        if !optional && !supplied.contains(&param.name) {
+            return Err(syn::Error::new(
+                param.span,
+                format!(
+                    "template uses '${0}' but no argument '{0}' was supplied\nhelp: add '{0} = ...' to the arguments",
+                    param.name
+                ),
+            ));
+        }
+    }
+
+    let used_names: HashSet<&str> = used.iter().map(|used| used.param.name.as_str()).collect();
+    for (name, _) in &input.args {
+        if !used_names.contains(name.to_string().as_str()) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("argument '{name}' is never used in the template"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// A `$param` referenced by the template.
+struct UsedParameter<'a> {
+    param: &'a SpannedIdent,
+    /// Whether the reference is only evaluated when the parameter is present:
+    /// it is the parameter of a `{?$param: ...}` conditional, or appears in
+    /// the body of one.
+    optional: bool,
+}
+
+/// Collect every `$param` referenced by template segments, in source order.
+///
+/// `guards` are the parameters of the enclosing conditionals.
+fn collect_parameters<'a>(
+    segments: &'a [Segment],
+    guards: &[&str],
+    used: &mut Vec<UsedParameter<'a>>,
+) {
+    for segment in segments {
+        match segment {
+            Segment::Literal(_) => {}
+            Segment::Interpolation(interp) => {
+                let mut params = Vec::new();
+                for transform in &interp.transforms {
+                    match &transform.context {
+                        TransformContext::Dynamic(ident) | TransformContext::Both(_, ident) => {
+                            params.push(ident);
+                        }
+                        TransformContext::None | TransformContext::Static(_) => {}
+                    }
+                }
+                collect_reference_parameters(&interp.reference, &mut params);
+                for selector in &interp.selectors {
+                    if let Selector::Parameter(ident) | Selector::Ordinal(ident) = selector {
+                        params.push(ident);
+                    }
+                }
+                used.extend(params.into_iter().map(|param| UsedParameter {
+                    param,
+                    optional: guards.contains(&param.name.as_str()),
+                }));
+            }
+            Segment::Conditional(conditional) => {
+                used.push(UsedParameter {
+                    param: &conditional.parameter,
+                    optional: true,
+                });
+                let mut inner = guards.to_vec();
+                inner.push(&conditional.parameter.name);
+                collect_parameters(&conditional.body, &inner, used);
+            }
+        }
+    }
+}
+
+fn collect_reference_parameters<'a>(reference: &'a Reference, params: &mut Vec<&'a SpannedIdent>) {
+    match reference {
+        Reference::Parameter(ident) => params.push(ident),
+        Reference::Call { args, .. } | Reference::Coalesce(args) => {
+            for arg in args {
+                collect_reference_parameters(arg, params);
+            }
+        }
+        Reference::Named { value, .. } => collect_reference_parameters(value, params),
+        Reference::Identifier(_) | Reference::NumberLiteral(..) | Reference::StringLiteral(..) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn validate_tokens(tokens: TokenStream) -> syn::Result<()> {
+        let input: FormatInput = syn::parse2(tokens).unwrap();
+        validate(&input)
+    }
+
+    #[test]
+    fn accepts_supplied_parameters() {
+        validate_tokens(parse_quote! {
+            locale, "Draw {$n} {card:$n}{?$bonus: \" (+{$bonus})\"}.", n = 3, bonus = 1,
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn accepts_omitted_conditional_parameter() {
+        validate_tokens(parse_quote! {
+            locale, "Draw {$n}{?$bonus: \" (+{$bonus})\"}.", n = 3,
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn rejects_missing_parameter_inside_conditional() {
+        let err = validate_tokens(parse_quote! {
+            locale, "Draw{?$bonus: \" {$n}\"}.", bonus = 1,
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("no argument 'n' was supplied"));
+    }
+
+    #[test]
+    fn rejects_missing_parameter() {
+        let err = validate_tokens(parse_quote! { locale, "{@count($n) card}" }).unwrap_err();
+        assert!(err.to_string().contains("no argument 'n' was supplied"));
+    }
+
+    #[test]
+    fn rejects_unused_argument() {
+        let err = validate_tokens(parse_quote! { locale, "Pass.", n = 3 }).unwrap_err();
+        assert!(err.to_string().contains("argument 'n' is never used"));
+    }
+}
@@ -8,6 +8,7 @@
 #![allow(dead_code)]
 
 use proc_macro2::Span;
+pub use rlf_semantics::ParamType;
 use syn::Ident;
 
 /// Top-level macro input containing all phrase definitions.
@@ -33,11 +34,22 @@ pub struct PhraseDefinition {
     pub kind: DefinitionKind,
     pub name: SpannedIdent,
     pub parameters: Vec<SpannedIdent>,
+    /// Declared parameter types, parallel to `parameters` (`None` if untyped).
+    pub parameter_types: Vec<Option<ParamType>>,
     pub tags: Vec<SpannedIdent>,
     pub from_param: Option<SpannedIdent>,
     /// :match parameter names (empty if no :match).
     pub match_params: Vec<SpannedIdent>,
     pub body: PhraseBody,
+    /// Attributes written before the name (`@note("...")`, `@maxlen(N)`).
+    pub attributes: Vec<PhraseAttribute>,
+}
+
+/// Metadata written before a definition: `@note("...")` or `@maxlen(N)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhraseAttribute {
+    Note(String),
+    MaxLength(usize),
 }
 
 /// Wrapper for identifiers that preserves span information.
@@ -123,11 +135,19 @@ pub struct Template {
     pub span: Span,
 }
 
-/// A segment of a template: literal text or interpolation.
+/// A segment of a template: literal text, interpolation, or conditional.
 #[derive(Debug)]
 pub enum Segment {
     Literal(String),
     Interpolation(Interpolation),
+    Conditional(Conditional),
+}
+
+/// A conditional: `{?$param: "body"}`
+#[derive(Debug)]
+pub struct Conditional {
+    pub parameter: SpannedIdent,
+    pub body: Vec<Segment>,
 }
 
 /// An interpolation: `{@transform name:selector}`
@@ -178,6 +198,14 @@ pub enum Reference {
     NumberLiteral(i64, Span),
     /// Literal string argument in a phrase call: `trigger("Attack")`
     StringLiteral(String, Span),
+    /// First present, non-empty alternative: `{$nick || $name || "Player"}`
+    Coalesce(Vec<Reference>),
+    /// Argument bound by parameter name in a phrase call: `draw(n: 3)`.
+    /// Only appears inside `Call` args.
+    Named {
+        name: SpannedIdent,
+        value: Box<Reference>,
+    },
 }
 
 /// A selector for variant selection.
@@ -188,6 +216,8 @@ pub enum Selector {
     /// Parameterized selector with `$` prefix: `:$n`, `:$entity`
     /// The SpannedIdent stores the name without the `$` prefix.
     Parameter(SpannedIdent),
+    /// Ordinal selector on a parameter: `:#$n`
+    Ordinal(SpannedIdent),
     /// Explicit default selector: `:*`
     Default,
 }
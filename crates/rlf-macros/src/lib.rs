@@ -2,6 +2,7 @@ use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 
 mod codegen;
+mod format;
 mod input;
 mod parse;
 mod validate;
@@ -43,6 +44,28 @@ pub fn rlf(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Evaluate a one-off template against a locale with named arguments.
+///
+/// Expands to `locale.eval_str(template, params)` and returns its
+/// `Result<Phrase, EvalError>`. Each `name = value` argument is converted with
+/// `Into<Value>`. At compile time, every `$name` the template uses must be
+/// supplied and every argument must be used.
+///
+/// # Example
+///
+/// ```ignore
+/// let text = rlf_format!(locale, "Draw {$n} {card:$n}.", n = 3)?;
+/// ```
+#[proc_macro]
+pub fn rlf_format(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as format::FormatInput);
+
+    match format::expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
 fn expand(input: input::MacroInput) -> syn::Result<TokenStream2> {
     // Step 1: Validate
     validate::validate(&input)?;
@@ -6,14 +6,14 @@ use std::collections::HashSet;
 use std::mem;
 
 use crate::input::{
-    DefinitionKind, Interpolation, MacroInput, MatchBranch, MatchKey, PhraseBody, PhraseDefinition,
-    Reference, Segment, Selector, SpannedIdent, Template, TransformContext, TransformRef,
-    VariantEntry, VariantEntryBody,
+    Conditional, DefinitionKind, Interpolation, MacroInput, MatchBranch, MatchKey, ParamType,
+    PhraseAttribute, PhraseBody, PhraseDefinition, Reference, Segment, Selector, SpannedIdent,
+    Template, TransformContext, TransformRef, VariantEntry, VariantEntryBody,
 };
 use proc_macro2::Span;
 use syn::parse::{Parse, ParseStream};
 use syn::token::{Brace, Paren};
-use syn::{Ident, LitStr, Token};
+use syn::{Ident, LitInt, LitStr, Token};
 
 impl Parse for MacroInput {
     fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -102,17 +102,41 @@ fn parse_tags_from_match(input: ParseStream) -> syn::Result<TagsFromMatch> {
     })
 }
 
+/// Parse an optional `: type` annotation after a parameter name.
+fn parse_param_type(input: ParseStream) -> syn::Result<Option<ParamType>> {
+    if !input.peek(Token![:]) {
+        return Ok(None);
+    }
+    input.parse::<Token![:]>()?;
+    let ident: Ident = input.parse()?;
+    ParamType::from_name(&ident.to_string())
+        .map(Some)
+        .ok_or_else(|| {
+            syn::Error::new(
+                ident.span(),
+                format!("unknown parameter type '{ident}' — expected num, text, or phrase"),
+            )
+        })
+}
+
 impl Parse for PhraseDefinition {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Parse attributes before the name
+        let mut attributes = Vec::new();
+        while input.peek(Token![@]) {
+            attributes.push(parse_attribute(input)?);
+        }
+
         // Parse phrase name
         let name_ident: Ident = input.parse()?;
         let name = SpannedIdent::new(&name_ident);
 
         // Parse optional parameters ($-prefixed)
-        let parameters = if input.peek(Paren) {
+        let (parameters, parameter_types) = if input.peek(Paren) {
             let content;
             let paren = syn::parenthesized!(content in input);
             let mut params = Vec::new();
+            let mut types = Vec::new();
             while !content.is_empty() {
                 // Require $ prefix
                 if content.peek(Token![$]) {
@@ -125,6 +149,7 @@ impl Parse for PhraseDefinition {
                 }
                 let ident: Ident = content.parse()?;
                 params.push(SpannedIdent::new(&ident));
+                types.push(parse_param_type(&content)?);
                 if content.peek(Token![,]) {
                     content.parse::<Token![,]>()?;
                 }
@@ -138,9 +163,9 @@ impl Parse for PhraseDefinition {
                 ));
             }
 
-            params
+            (params, types)
         } else {
-            Vec::new()
+            (Vec::new(), Vec::new())
         };
 
         // Parse =
@@ -222,14 +247,38 @@ impl Parse for PhraseDefinition {
             kind,
             name,
             parameters,
+            parameter_types,
             tags,
             from_param,
             match_params,
             body,
+            attributes,
         })
     }
 }
 
+/// Parse one `@note("...")` or `@maxlen(N)` attribute.
+fn parse_attribute(input: ParseStream) -> syn::Result<PhraseAttribute> {
+    input.parse::<Token![@]>()?;
+    let name: Ident = input.parse()?;
+    let content;
+    syn::parenthesized!(content in input);
+    let attribute = match name.to_string().as_str() {
+        "note" => PhraseAttribute::Note(content.parse::<LitStr>()?.value()),
+        "maxlen" => PhraseAttribute::MaxLength(content.parse::<LitInt>()?.base10_parse()?),
+        other => {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("unknown attribute '@{other}', expected '@note' or '@maxlen'"),
+            ));
+        }
+    };
+    if !content.is_empty() {
+        return Err(content.error("unexpected tokens in attribute"));
+    }
+    Ok(attribute)
+}
+
 impl Parse for PhraseBody {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         // If starts with { it's a variant block, otherwise simple template
@@ -271,16 +320,15 @@ impl Parse for VariantEntry {
         // Parse keys (comma-separated identifiers, possibly with dots)
         // Format: key1, key2: "template"
         // Or: nom.one, nom.few: "template"
+        // Or numeric buckets: 0, 1..3, 4..: "template"
         let mut keys = Vec::new();
 
         loop {
             // Parse a key which may be dotted (e.g., nom.one)
-            let first: Ident = input.parse()?;
-            let mut key_str = first.to_string();
-            let key_span = first.span();
+            let (mut key_str, key_span) = parse_match_key_component(input)?;
 
             // Check for dot-separated parts
-            while input.peek(Token![.]) {
+            while input.peek(Token![.]) && !input.peek(Token![..]) {
                 input.parse::<Token![.]>()?;
                 let part: Ident = input.parse()?;
                 key_str.push('.');
@@ -294,7 +342,7 @@ impl Parse for VariantEntry {
                 // More keys coming, but need to be careful about trailing comma before string
                 let fork = input.fork();
                 fork.parse::<Token![,]>().ok();
-                if fork.peek(Ident) {
+                if fork.peek(Ident) || fork.peek(syn::LitInt) || fork.peek(Token![..]) {
                     input.parse::<Token![,]>()?;
                     continue;
                 }
@@ -405,7 +453,11 @@ fn parse_match_entry(input: ParseStream, num_params: usize) -> syn::Result<Match
             fork.parse::<Token![,]>().ok();
             // Look ahead: if the next token after comma could start a key (ident,
             // number, or *), consume the comma and continue.
-            if fork.peek(Ident) || fork.peek(syn::LitInt) || fork.peek(Token![*]) {
+            if fork.peek(Ident)
+                || fork.peek(syn::LitInt)
+                || fork.peek(Token![*])
+                || fork.peek(Token![..])
+            {
                 input.parse::<Token![,]>()?;
                 continue;
             }
@@ -429,7 +481,7 @@ fn parse_match_entry(input: ParseStream, num_params: usize) -> syn::Result<Match
 
 /// Parse a single match key, possibly with dot notation and `*` defaults per dimension.
 ///
-/// Examples: `1`, `other`, `*other`, `1.masc`, `*other.*neut`
+/// Examples: `1`, `other`, `*other`, `1.masc`, `*other.*neut`, `1..3`
 fn parse_match_key(input: ParseStream, num_params: usize) -> syn::Result<MatchKey> {
     let mut value_parts = Vec::new();
     let mut default_dims = Vec::new();
@@ -447,7 +499,7 @@ fn parse_match_key(input: ParseStream, num_params: usize) -> syn::Result<MatchKe
     value_parts.push(part);
 
     // Parse additional dimensions (dot-separated)
-    while input.peek(Token![.]) {
+    while input.peek(Token![.]) && !input.peek(Token![..]) {
         input.parse::<Token![.]>()?;
         let is_default = if input.peek(Token![*]) {
             input.parse::<Token![*]>()?;
@@ -485,10 +537,24 @@ fn parse_match_key(input: ParseStream, num_params: usize) -> syn::Result<MatchKe
     })
 }
 
-/// Parse a single component of a match key: an identifier or integer literal.
+/// Parse a single component of a match key: an identifier, an integer
+/// literal, or a numeric range (`1..3`, `4..`, `..2`).
 fn parse_match_key_component(input: ParseStream) -> syn::Result<(String, Span)> {
-    if input.peek(syn::LitInt) {
+    if input.peek(Token![..]) {
+        let dots: Token![..] = input.parse()?;
+        let hi: syn::LitInt = input.parse()?;
+        Ok((format!("..{hi}"), dots.spans[0]))
+    } else if input.peek(syn::LitInt) {
         let lit: syn::LitInt = input.parse()?;
+        if input.peek(Token![..]) {
+            input.parse::<Token![..]>()?;
+            let hi = if input.peek(syn::LitInt) {
+                input.parse::<syn::LitInt>()?.to_string()
+            } else {
+                String::new()
+            };
+            return Ok((format!("{lit}..{hi}"), lit.span()));
+        }
         Ok((lit.to_string(), lit.span()))
     } else {
         let ident: Ident = input.parse()?;
@@ -560,6 +626,7 @@ impl Parse for Template {
 /// - Literal text
 /// - Escaped braces: {{ and }}
 /// - Interpolations: {reference}, {@transform reference}, {reference:selector}
+/// - Conditionals: {?$param: "body"}
 pub(crate) fn parse_template_string(s: &str, span: Span) -> syn::Result<Vec<Segment>> {
     let mut segments = Vec::new();
     let mut current_literal = String::new();
@@ -606,8 +673,13 @@ pub(crate) fn parse_template_string(s: &str, span: Span) -> syn::Result<Vec<Segm
                         return Err(syn::Error::new(span, "empty interpolation"));
                     }
 
-                    let interpolation = parse_interpolation(&interp_content, span)?;
-                    segments.push(Segment::Interpolation(interpolation));
+                    if interp_content.trim_start().starts_with('?') {
+                        let conditional = parse_conditional(&interp_content, span)?;
+                        segments.push(Segment::Conditional(conditional));
+                    } else {
+                        let interpolation = parse_interpolation(&interp_content, span)?;
+                        segments.push(Segment::Interpolation(interpolation));
+                    }
                 }
             }
             '}' => {
@@ -634,6 +706,49 @@ pub(crate) fn parse_template_string(s: &str, span: Span) -> syn::Result<Vec<Segm
     Ok(segments)
 }
 
+/// Parse the content of a conditional: ?$param: "body"
+fn parse_conditional(content: &str, span: Span) -> syn::Result<Conditional> {
+    let rest = content.trim().trim_start_matches('?').trim_start();
+    let Some(rest) = rest.strip_prefix('$') else {
+        return Err(syn::Error::new(
+            span,
+            "conditional must name a parameter: {?$param: \"...\"}",
+        ));
+    };
+    let end = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    if end == 0 {
+        return Err(syn::Error::new(span, "empty parameter name in conditional"));
+    }
+    let parameter = SpannedIdent::from_str(&rest[..end], span);
+    let rest = rest[end..].trim_start();
+    let Some(rest) = rest.strip_prefix(':') else {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "expected ':' after conditional parameter '${}'",
+                parameter.name
+            ),
+        ));
+    };
+    let Some(body) = rest
+        .trim()
+        .strip_prefix('"')
+        .and_then(|body| body.strip_suffix('"'))
+    else {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "conditional body for '${}' must be a quoted string",
+                parameter.name
+            ),
+        ));
+    };
+    let body = parse_template_string(body, span)?;
+    Ok(Conditional { parameter, body })
+}
+
 /// Parse the content of an interpolation: @transforms reference :selectors
 fn parse_interpolation(content: &str, span: Span) -> syn::Result<Interpolation> {
     let content = content.trim();
@@ -656,8 +771,27 @@ fn parse_interpolation(content: &str, span: Span) -> syn::Result<Interpolation>
         let transform_name = &rest[..end];
         rest = &rest[end..];
 
+        // Parse optional parameter context (:$param)
+        let mut colon_param = None;
+        if let Some(after_dollar) = rest.strip_prefix(":$") {
+            let param_end = after_dollar
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(after_dollar.len());
+            if param_end == 0 {
+                return Err(syn::Error::new(
+                    span,
+                    "expected parameter name after '$' in transform context",
+                ));
+            }
+            colon_param = Some(SpannedIdent::from_str(&after_dollar[..param_end], span));
+            rest = &after_dollar[param_end..];
+        }
+
         // Parse optional static context (:literal)
-        let static_ctx = if rest.starts_with(':') && !rest[1..].starts_with(char::is_whitespace) {
+        let static_ctx = if colon_param.is_none()
+            && rest.starts_with(':')
+            && !rest[1..].starts_with(char::is_whitespace)
+        {
             let after_colon = &rest[1..];
             let ctx_end = after_colon
                 .find(|c: char| c.is_whitespace() || c == ':' || c == '(')
@@ -675,7 +809,9 @@ fn parse_interpolation(content: &str, span: Span) -> syn::Result<Interpolation>
         };
 
         // Parse optional dynamic context ($param)
-        let dynamic_ctx = if rest.starts_with('(') {
+        let dynamic_ctx = if colon_param.is_some() {
+            colon_param
+        } else if rest.starts_with('(') {
             let after_paren = &rest[1..];
             let trimmed = after_paren.trim_start();
             if !trimmed.starts_with('$') {
@@ -723,8 +859,25 @@ fn parse_interpolation(content: &str, span: Span) -> syn::Result<Interpolation>
         });
     }
 
-    // Parse reference (identifier or call)
-    let (reference, remaining, auto_cap) = parse_reference(rest, span)?;
+    // Parse reference (identifier or call), or a `||` coalesce of several
+    let (mut reference, mut remaining, auto_cap) = parse_reference(rest, span)?;
+    if let Some(after) = strip_coalesce_separator(&remaining) {
+        let mut alternatives = vec![coalesce_alternative(reference, auto_cap, span)?];
+        let mut after = after.to_string();
+        loop {
+            let (alternative, rest, auto_cap) = parse_reference(&after, span)?;
+            alternatives.push(coalesce_alternative(alternative, auto_cap, span)?);
+            match strip_coalesce_separator(&rest) {
+                Some(next) => after = next.to_string(),
+                None => {
+                    remaining = rest;
+                    break;
+                }
+            }
+        }
+        reference = Reference::Coalesce(alternatives);
+    }
+    let auto_cap = auto_cap && !matches!(reference, Reference::Coalesce(_));
 
     // If auto-capitalization was triggered, prepend @cap transform
     if auto_cap {
@@ -748,6 +901,28 @@ fn parse_interpolation(content: &str, span: Span) -> syn::Result<Interpolation>
     })
 }
 
+/// The text after a leading `||` or `|` coalesce separator, if there is one.
+fn strip_coalesce_separator(text: &str) -> Option<&str> {
+    let text = text.trim_start();
+    text.strip_prefix("||").or_else(|| text.strip_prefix('|'))
+}
+
+/// Check one alternative of a `||` coalesce, which must not be
+/// auto-capitalized.
+fn coalesce_alternative(
+    reference: Reference,
+    auto_cap: bool,
+    span: Span,
+) -> syn::Result<Reference> {
+    if auto_cap {
+        return Err(syn::Error::new(
+            span,
+            "coalesce alternatives cannot be auto-capitalized — apply @cap to the whole interpolation",
+        ));
+    }
+    Ok(reference)
+}
+
 /// Parse a reference from a string, returning the Reference, auto-cap flag, and remaining content.
 ///
 /// `$name` -> Parameter reference. Bare `name` -> Identifier (term) reference.
@@ -898,7 +1073,8 @@ fn parse_reference(content: &str, span: Span) -> syn::Result<(Reference, String,
         let mut args = Vec::new();
         if !args_str.is_empty() {
             for arg in split_args(args_str) {
-                let (arg_ref, remaining, _) = parse_reference(arg.trim(), span)?;
+                let (arg_name, arg) = split_named_arg(arg.trim());
+                let (arg_ref, remaining, _) = parse_reference(arg, span)?;
                 if !remaining.trim().is_empty() {
                     return Err(syn::Error::new(
                         span,
@@ -907,7 +1083,13 @@ fn parse_reference(content: &str, span: Span) -> syn::Result<(Reference, String,
                         ),
                     ));
                 }
-                args.push(arg_ref);
+                args.push(match arg_name {
+                    Some(arg_name) => Reference::Named {
+                        name: SpannedIdent::from_str(arg_name, span),
+                        value: Box::new(arg_ref),
+                    },
+                    None => arg_ref,
+                });
             }
         }
 
@@ -928,6 +1110,23 @@ fn parse_reference(content: &str, span: Span) -> syn::Result<(Reference, String,
     }
 }
 
+/// Split a `name: value` call argument into its parameter name and value.
+///
+/// The `:` must be followed by whitespace, so that `card:one` stays a
+/// selector expression rather than a name.
+fn split_named_arg(arg: &str) -> (Option<&str>, &str) {
+    let name_len = arg
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(arg.len());
+    let starts_ident = arg.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_');
+    match arg[name_len..].trim_start().strip_prefix(':') {
+        Some(value) if starts_ident && value.starts_with(char::is_whitespace) => {
+            (Some(&arg[..name_len]), value.trim_start())
+        }
+        _ => (None, arg),
+    }
+}
+
 /// Split argument string by commas, respecting string literals.
 fn split_args(s: &str) -> Vec<&str> {
     let mut args = Vec::new();
@@ -999,7 +1198,8 @@ fn parse_string_literal_arg(content: &str, span: Span) -> syn::Result<(String, S
 
 /// Extract selectors from the remaining content after a reference.
 ///
-/// After `:`, if `$` -> `Selector::Parameter`, otherwise -> `Selector::Literal`.
+/// After `:`, if `$` -> `Selector::Parameter`, if `#$` -> `Selector::Ordinal`,
+/// otherwise -> `Selector::Literal`.
 fn extract_selectors(content: &str, span: Span) -> syn::Result<Vec<Selector>> {
     let mut selectors = Vec::new();
     let mut rest = content.trim();
@@ -1012,9 +1212,10 @@ fn extract_selectors(content: &str, span: Span) -> syn::Result<Vec<Selector>> {
             rest = &rest[1..]; // Skip *
             selectors.push(Selector::Default);
             rest = rest.trim_start();
-        } else if rest.starts_with('$') {
-            // Check for $ prefix (parameterized selector)
-            rest = &rest[1..]; // Skip $
+        } else if rest.starts_with('$') || rest.starts_with("#$") {
+            // Check for $ prefix (parameterized selector), or #$ (ordinal)
+            let ordinal = rest.starts_with('#');
+            rest = &rest[if ordinal { 2 } else { 1 }..]; // Skip $ or #$
             let end = rest
                 .find(|c: char| c.is_whitespace() || c == ':')
                 .unwrap_or(rest.len());
@@ -1023,11 +1224,12 @@ fn extract_selectors(content: &str, span: Span) -> syn::Result<Vec<Selector>> {
                 return Err(syn::Error::new(span, "expected parameter name after ':$'"));
             }
 
-            let selector_name = &rest[..end];
-            selectors.push(Selector::Parameter(SpannedIdent::from_str(
-                selector_name,
-                span,
-            )));
+            let ident = SpannedIdent::from_str(&rest[..end], span);
+            selectors.push(if ordinal {
+                Selector::Ordinal(ident)
+            } else {
+                Selector::Parameter(ident)
+            });
             rest = rest[end..].trim_start();
         } else {
             // Literal selector
@@ -1089,14 +1291,18 @@ mod tests {
     fn get_literal(segment: &Segment) -> &str {
         match segment {
             Segment::Literal(s) => s,
-            Segment::Interpolation(_) => panic!("expected Literal segment"),
+            Segment::Interpolation(_) | Segment::Conditional(_) => {
+                panic!("expected Literal segment")
+            }
         }
     }
 
     fn get_interpolation(segment: &Segment) -> &Interpolation {
         match segment {
             Segment::Interpolation(i) => i,
-            Segment::Literal(_) => panic!("expected Interpolation segment"),
+            Segment::Literal(_) | Segment::Conditional(_) => {
+                panic!("expected Interpolation segment")
+            }
         }
     }
 
@@ -1104,7 +1310,7 @@ mod tests {
     fn selector_name(sel: &Selector) -> &str {
         match sel {
             Selector::Literal(ident) => &ident.name,
-            Selector::Parameter(ident) => &ident.name,
+            Selector::Parameter(ident) | Selector::Ordinal(ident) => &ident.name,
             Selector::Default => "*",
         }
     }
@@ -1374,6 +1580,17 @@ mod tests {
         assert!(matches!(&interp.reference, Reference::Identifier(ident) if ident.name == "card"));
     }
 
+    #[test]
+    fn test_transform_with_colon_parameter_context() {
+        let segments = parse_ok("{@der:$case karte}");
+        let interp = get_interpolation(&segments[0]);
+        assert_eq!(interp.transforms[0].name.name, "der");
+        assert!(
+            matches!(&interp.transforms[0].context, TransformContext::Dynamic(ident) if ident.name == "case")
+        );
+        assert!(matches!(&interp.reference, Reference::Identifier(ident) if ident.name == "karte"));
+    }
+
     #[test]
     fn test_transform_with_both_contexts() {
         let segments = parse_ok("{@transform:lit($param) ref}");
@@ -1562,6 +1779,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_phrase_call_named_args() {
+        let segments = parse_ok("{draw(target: $p, n: 3)}");
+        let interp = get_interpolation(&segments[0]);
+        let Reference::Call { args, .. } = &interp.reference else {
+            panic!("expected Call reference");
+        };
+        match &args[..] {
+            [
+                Reference::Named {
+                    name: first,
+                    value: first_value,
+                },
+                Reference::Named {
+                    name: second,
+                    value: second_value,
+                },
+            ] => {
+                assert_eq!(first.name, "target");
+                assert!(matches!(&**first_value, Reference::Parameter(p) if p.name == "p"));
+                assert_eq!(second.name, "n");
+                assert!(matches!(**second_value, Reference::NumberLiteral(3, _)));
+            }
+            _ => panic!("expected two named arguments"),
+        }
+    }
+
     // =========================================================================
     // Error cases
     // =========================================================================
@@ -1691,6 +1935,14 @@ mod tests {
         assert!(matches!(&interp.selectors[0], Selector::Default));
     }
 
+    #[test]
+    fn test_ordinal_selector() {
+        let segments = parse_ok("{nth:#$n}");
+        let interp = get_interpolation(&segments[0]);
+        assert_eq!(interp.selectors.len(), 1);
+        assert!(matches!(&interp.selectors[0], Selector::Ordinal(ident) if ident.name == "n"));
+    }
+
     #[test]
     fn test_default_selector_with_literal_selector() {
         let segments = parse_ok("{card:nom:*}");
@@ -13,18 +13,25 @@
 //! 10. Nested phrase calls not supported as arguments
 //! 11. `$name` referencing a term instead of a parameter
 //! 12. Numeric keys in term variant blocks
+//! 13. Duplicate keys in a variant or match block
 //!
 //! Also provides typo suggestions (MACRO-17) using Levenshtein distance.
 
 use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
 
 use proc_macro2::Span;
-use rlf_semantics::{accepted_transform_names, resolve_transform};
+use rlf_semantics::{
+    TransformId, accepted_transform_names, is_case_language, languages_with_transform,
+    resolve_transform,
+};
 use strsim::levenshtein;
 
+use crate::codegen::to_upper_camel_case;
 use crate::input::{
-    DefinitionKind, Interpolation, MacroInput, PhraseBody, PhraseDefinition, Reference, Segment,
-    Selector, Template, TransformContext, VariantEntryBody,
+    DefinitionKind, Interpolation, MacroInput, MatchBranch, PhraseBody, PhraseDefinition,
+    Reference, Segment, Selector, SpannedIdent, Template, TransformContext, VariantEntry,
+    VariantEntryBody,
 };
 
 /// Validation context built from MacroInput.
@@ -38,8 +45,8 @@ pub struct ValidationContext {
     pub phrase_tags: HashMap<String, HashSet<String>>,
     /// Phrase name -> DefinitionKind (Term or Phrase).
     pub phrase_kinds: HashMap<String, DefinitionKind>,
-    /// Phrase name -> parameter count (0 for terms).
-    pub phrase_param_counts: HashMap<String, usize>,
+    /// Phrase name -> declared parameter names, in order (empty for terms).
+    pub phrase_params: HashMap<String, Vec<String>>,
 }
 
 impl ValidationContext {
@@ -49,13 +56,16 @@ impl ValidationContext {
         let mut phrase_variants = HashMap::new();
         let mut phrase_tags = HashMap::new();
         let mut phrase_kinds = HashMap::new();
-        let mut phrase_param_counts = HashMap::new();
+        let mut phrase_params = HashMap::new();
 
         for phrase in &input.phrases {
             let name = phrase.name.name.clone();
             phrases.insert(name.clone());
             phrase_kinds.insert(name.clone(), phrase.kind);
-            phrase_param_counts.insert(name.clone(), phrase.parameters.len());
+            phrase_params.insert(
+                name.clone(),
+                phrase.parameters.iter().map(|p| p.name.clone()).collect(),
+            );
 
             // Collect variant keys
             match &phrase.body {
@@ -94,7 +104,7 @@ impl ValidationContext {
             phrase_variants,
             phrase_tags,
             phrase_kinds,
-            phrase_param_counts,
+            phrase_params,
         }
     }
 }
@@ -115,6 +125,30 @@ pub fn validate(input: &MacroInput) -> syn::Result<()> {
     // Check for cycles (separate pass after all phrases validated)
     detect_cycles(input, &ctx)?;
 
+    if cfg!(feature = "phrase-name-enum") {
+        check_phrase_name_variants(input)?;
+    }
+
+    Ok(())
+}
+
+/// Reject two definitions whose `PhraseName` variants would share a name,
+/// such as `card_2` and `card2`, which both become `Card2`.
+fn check_phrase_name_variants(input: &MacroInput) -> syn::Result<()> {
+    let mut variants: HashMap<String, &SpannedIdent> = HashMap::new();
+    for phrase in &input.phrases {
+        let variant = to_upper_camel_case(&phrase.name.name);
+        if let Some(existing) = variants.get(&variant) {
+            return Err(syn::Error::new(
+                phrase.name.span,
+                format!(
+                    "phrases '{}' and '{}' both become the PhraseName variant '{}'\nhelp: rename one of them",
+                    existing.name, phrase.name.name, variant
+                ),
+            ));
+        }
+        variants.insert(variant, &phrase.name);
+    }
     Ok(())
 }
 
@@ -135,18 +169,28 @@ fn validate_phrase(phrase: &PhraseDefinition, ctx: &ValidationContext) -> syn::R
         }
     }
 
-    // Validate: numeric keys in term variant blocks are not allowed
+    // Validate: numeric keys in term variant blocks are only allowed as
+    // buckets in a block with range keys
     if phrase.kind == DefinitionKind::Term
         && let PhraseBody::Variants(variants) = &phrase.body
     {
+        let has_ranges = variants
+            .iter()
+            .flat_map(|v| &v.keys)
+            .any(|key| numeric_range(&key.name).is_some());
         for variant in variants {
             for key in &variant.keys {
+                let is_bucket =
+                    key.name.parse::<i64>().is_ok() || numeric_range(&key.name).is_some();
+                if has_ranges && is_bucket {
+                    continue;
+                }
                 for component in key.name.split('.') {
                     if component.chars().next().is_some_and(|c| c.is_ascii_digit()) {
                         return Err(syn::Error::new(
                             key.span,
                             format!(
-                                "term variant keys must be named identifiers — use ':match' for numeric branching (found '{}')",
+                                "term variant keys must be named identifiers — use ':match' or range keys like '1..3' for numeric branching (found '{}')",
                                 key.name
                             ),
                         ));
@@ -156,6 +200,29 @@ fn validate_phrase(phrase: &PhraseDefinition, ctx: &ValidationContext) -> syn::R
         }
     }
 
+    // Validate: each key appears at most once per variant or match block
+    match &phrase.body {
+        PhraseBody::Simple(_) => {}
+        PhraseBody::Variants(variants) => {
+            check_unique_keys(variants.iter().flat_map(|v| &v.keys), "variant")?;
+            check_variant_range_keys(variants)?;
+            for variant in variants {
+                if let VariantEntryBody::Match {
+                    match_params,
+                    branches,
+                } = &variant.body
+                {
+                    check_unique_keys(match_keys(branches), "match")?;
+                    check_match_range_keys(branches, match_params.len())?;
+                }
+            }
+        }
+        PhraseBody::Match(branches) => {
+            check_unique_keys(match_keys(branches), "match")?;
+            check_match_range_keys(branches, phrase.match_params.len())?;
+        }
+    }
+
     // Validate body references
     match &phrase.body {
         PhraseBody::Simple(template) => {
@@ -176,6 +243,116 @@ fn validate_phrase(phrase: &PhraseDefinition, ctx: &ValidationContext) -> syn::R
     Ok(())
 }
 
+/// Reject the second occurrence of any key in a block.
+fn check_unique_keys<'a>(
+    keys: impl IntoIterator<Item = &'a SpannedIdent>,
+    block: &str,
+) -> syn::Result<()> {
+    let mut seen = HashSet::new();
+    for key in keys {
+        if !seen.insert(key.name.as_str()) {
+            return Err(syn::Error::new(
+                key.span,
+                format!("duplicate key '{}' in {block} block", key.name),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Reject numeric range keys that appear in a multi-parameter match block.
+fn check_match_range_keys(branches: &[MatchBranch], num_params: usize) -> syn::Result<()> {
+    if num_params != 1
+        && let Some(key) = match_keys(branches).find(|key| numeric_range(&key.name).is_some())
+    {
+        return Err(syn::Error::new(
+            key.span,
+            format!("range key '{}' needs a single :match parameter", key.name),
+        ));
+    }
+    check_range_keys(match_keys(branches), "match")
+}
+
+/// Reject numeric range keys in a variant block without an `other` variant
+/// for the numbers they do not cover.
+fn check_variant_range_keys(variants: &[VariantEntry]) -> syn::Result<()> {
+    let keys = || variants.iter().flat_map(|v| &v.keys);
+    if let Some(key) = keys().find(|key| numeric_range(&key.name).is_some())
+        && !keys().any(|key| key.name == "other")
+    {
+        return Err(syn::Error::new(
+            key.span,
+            format!(
+                "range key '{}' needs an 'other' variant for the numbers no key covers",
+                key.name
+            ),
+        ));
+    }
+    check_range_keys(keys(), "variant")
+}
+
+/// Reject numeric range keys that are empty or overlap another range key.
+///
+/// Exact numeric keys take precedence over ranges, so an exact key inside a
+/// range is not an overlap.
+fn check_range_keys<'a>(
+    keys: impl IntoIterator<Item = &'a SpannedIdent>,
+    block: &str,
+) -> syn::Result<()> {
+    let mut seen: Vec<(&SpannedIdent, RangeInclusive<i64>)> = Vec::new();
+    for key in keys {
+        let Some(range) = numeric_range(&key.name) else {
+            continue;
+        };
+        if range.is_empty() {
+            return Err(syn::Error::new(
+                key.span,
+                format!(
+                    "empty range key '{}' — the lower bound is above the upper bound",
+                    key.name
+                ),
+            ));
+        }
+        if let Some((other, _)) = seen
+            .iter()
+            .find(|(_, prev)| prev.start() <= range.end() && range.start() <= prev.end())
+        {
+            return Err(syn::Error::new(
+                key.span,
+                format!(
+                    "overlapping keys '{}' and '{}' in {block} block",
+                    other.name, key.name
+                ),
+            ));
+        }
+        seen.push((key, range));
+    }
+    Ok(())
+}
+
+/// Parse a range key (`1..3`, `4..`, `..2`) into an inclusive range.
+fn numeric_range(key: &str) -> Option<RangeInclusive<i64>> {
+    let (lo, hi) = key.split_once("..")?;
+    let lo = if lo.is_empty() {
+        i64::MIN
+    } else {
+        lo.parse().ok()?
+    };
+    let hi = if hi.is_empty() {
+        i64::MAX
+    } else {
+        hi.parse().ok()?
+    };
+    Some(lo..=hi)
+}
+
+/// The keys of every branch in a match block, in source order.
+fn match_keys(branches: &[MatchBranch]) -> impl Iterator<Item = &SpannedIdent> {
+    branches
+        .iter()
+        .flat_map(|branch| branch.keys.iter().map(|key| &key.value))
+}
+
 /// Validate a template and all its interpolations.
 fn validate_template(
     template: &Template,
@@ -183,9 +360,31 @@ fn validate_template(
     ctx: &ValidationContext,
     _current_phrase: &str,
 ) -> syn::Result<()> {
-    for segment in &template.segments {
-        if let Segment::Interpolation(interp) = segment {
-            validate_interpolation(interp, params, ctx)?;
+    validate_segments(&template.segments, params, ctx)
+}
+
+/// Validate template segments, recursing into conditional bodies.
+fn validate_segments(
+    segments: &[Segment],
+    params: &HashSet<String>,
+    ctx: &ValidationContext,
+) -> syn::Result<()> {
+    for segment in segments {
+        match segment {
+            Segment::Literal(_) => {}
+            Segment::Interpolation(interp) => validate_interpolation(interp, params, ctx)?,
+            Segment::Conditional(conditional) => {
+                if !params.contains(&conditional.parameter.name) {
+                    return Err(syn::Error::new(
+                        conditional.parameter.span,
+                        format!(
+                            "undefined parameter '${0}' in conditional\nhelp: declare it as a parameter: name(${0})",
+                            conditional.parameter.name
+                        ),
+                    ));
+                }
+                validate_segments(&conditional.body, params, ctx)?;
+            }
         }
     }
     Ok(())
@@ -217,11 +416,37 @@ fn validate_interpolation(
     params: &HashSet<String>,
     ctx: &ValidationContext,
 ) -> syn::Result<()> {
-    let source_transform_names = accepted_transform_names("en");
+    // `@lang:xx` checks the transforms to its right against language xx; after
+    // a dynamic `@lang($l)` any language's transform is accepted
+    let mut transform_lang = Some(("en".to_string(), "source language"));
 
     // Validate transforms exist (MACRO-11)
     for transform in &interp.transforms {
-        if resolve_transform(&transform.name.name, "en").is_none() {
+        let known = match &transform_lang {
+            Some((lang, _)) => resolve_transform(&transform.name.name, lang).is_some(),
+            None => {
+                resolve_transform(&transform.name.name, "").is_some()
+                    || !languages_with_transform(&transform.name.name).is_empty()
+            }
+        };
+        let (lang, lang_label) = match &transform_lang {
+            Some((lang, label)) => (lang.as_str(), *label),
+            None => ("en", "source language"),
+        };
+        let source_transform_names = accepted_transform_names(lang);
+        if !known {
+            let available_in = languages_with_transform(&transform.name.name);
+            if !available_in.is_empty() {
+                return Err(syn::Error::new(
+                    transform.name.span,
+                    format!(
+                        "transform '@{}' is not available for {lang_label} '{lang}'\nnote: '@{}' is defined for: {}",
+                        transform.name.name,
+                        transform.name.name,
+                        available_in.join(", ")
+                    ),
+                ));
+            }
             let suggestions = compute_suggestions_str(&transform.name.name, source_transform_names);
             let mut msg = format!("unknown transform '@{}'", transform.name.name);
             if !suggestions.is_empty() {
@@ -234,6 +459,52 @@ fn validate_interpolation(
             }
             return Err(syn::Error::new(transform.name.span, msg));
         }
+        if resolve_transform(&transform.name.name, "") == Some(TransformId::Lang) {
+            transform_lang = match &transform.context {
+                TransformContext::Static(code) => {
+                    let primary = code.name.split(['-', '_']).next().unwrap_or_default();
+                    Some((primary.to_string(), "language"))
+                }
+                TransformContext::None
+                | TransformContext::Dynamic(_)
+                | TransformContext::Both(..) => None,
+            };
+        }
+
+        // Validate static language codes on @lang and case transforms
+        // (`@title:keepcaps` is an option, not a language)
+        let transform_id = resolve_transform(&transform.name.name, "");
+        if transform_id == Some(TransformId::Lang)
+            && matches!(transform.context, TransformContext::None)
+        {
+            return Err(syn::Error::new(
+                transform.name.span,
+                "'@lang' needs a language code, as in '@lang:en'",
+            ));
+        }
+        if let TransformContext::Static(code) | TransformContext::Both(code, _) = &transform.context
+            && !(code.name == "keepcaps" && transform_id == Some(TransformId::Title))
+            && matches!(
+                transform_id,
+                Some(
+                    TransformId::Lang
+                        | TransformId::Cap
+                        | TransformId::Upper
+                        | TransformId::Lower
+                        | TransformId::Sentence
+                        | TransformId::Title
+                )
+            )
+            && !is_case_language(&code.name)
+        {
+            return Err(syn::Error::new(
+                code.span,
+                format!(
+                    "unknown language code '{}' in '@{}' context\nhelp: use a language code such as 'tr' or 'en_GB'",
+                    code.name, transform.name.name
+                ),
+            ));
+        }
 
         // Transform tag validation (MACRO-12)
         // Note: Tag requirements are enforced at runtime by the transform itself.
@@ -286,6 +557,7 @@ fn validate_interpolation(
                         .map(|s| match s {
                             Selector::Literal(i) => i.name.clone(),
                             Selector::Parameter(i) => format!("${}", i.name),
+                            Selector::Ordinal(i) => format!("#${}", i.name),
                             Selector::Default => "*".to_string(),
                         })
                         .collect::<Vec<_>>()
@@ -299,9 +571,11 @@ fn validate_interpolation(
     // Determine if reference is a literal phrase (not a parameter)
     let is_literal_phrase = match &interp.reference {
         Reference::Identifier(ident) => ctx.phrases.contains(&ident.name),
-        Reference::Parameter(_) | Reference::NumberLiteral(..) | Reference::StringLiteral(..) => {
-            false
-        }
+        Reference::Parameter(_)
+        | Reference::NumberLiteral(..)
+        | Reference::StringLiteral(..)
+        | Reference::Coalesce(_)
+        | Reference::Named { .. } => false,
         Reference::Call { name, .. } => ctx.phrases.contains(&name.name),
     };
 
@@ -311,7 +585,9 @@ fn validate_interpolation(
             Reference::Call { name, .. } => &name.name,
             Reference::Parameter(_)
             | Reference::NumberLiteral(..)
-            | Reference::StringLiteral(..) => unreachable!(),
+            | Reference::StringLiteral(..)
+            | Reference::Coalesce(_)
+            | Reference::Named { .. } => unreachable!(),
         };
 
         // Get phrase variants for literal selector validation
@@ -334,7 +610,7 @@ fn validate_interpolation(
                 .iter()
                 .filter_map(|selector| match selector {
                     Selector::Literal(ident) => Some(ident.name.clone()),
-                    Selector::Parameter(_) | Selector::Default => None,
+                    Selector::Parameter(_) | Selector::Ordinal(_) | Selector::Default => None,
                 })
                 .collect::<Vec<_>>()
                 .join(".");
@@ -357,7 +633,7 @@ fn validate_interpolation(
 
     // Validate parameter selectors reference declared parameters
     for selector in &interp.selectors {
-        if let Selector::Parameter(ident) = selector
+        if let Selector::Parameter(ident) | Selector::Ordinal(ident) = selector
             && !params.contains(&ident.name)
         {
             return Err(syn::Error::new(
@@ -473,23 +749,29 @@ fn validate_reference(
             }
 
             // Validate argument count
-            if let Some(&expected) = ctx.phrase_param_counts.get(&name.name)
-                && args.len() != expected
-            {
-                return Err(syn::Error::new(
-                    name.span,
-                    format!(
-                        "phrase '{}' expects {} parameter{}, got {}",
-                        name.name,
-                        expected,
-                        if expected == 1 { "" } else { "s" },
-                        args.len()
-                    ),
-                ));
+            if let Some(declared) = ctx.phrase_params.get(&name.name) {
+                let expected = declared.len();
+                if args.len() != expected {
+                    return Err(syn::Error::new(
+                        name.span,
+                        format!(
+                            "phrase '{}' expects {} parameter{}, got {}",
+                            name.name,
+                            expected,
+                            if expected == 1 { "" } else { "s" },
+                            args.len()
+                        ),
+                    ));
+                }
+                validate_named_args(&name.name, declared, args)?;
             }
 
             // Validate arguments recursively, checking for nested calls
             for arg in args {
+                let arg = match arg {
+                    Reference::Named { value, .. } => value,
+                    _ => arg,
+                };
                 if let Reference::Call {
                     name: inner_name, ..
                 } = arg
@@ -505,6 +787,46 @@ fn validate_reference(
         Reference::NumberLiteral(..) | Reference::StringLiteral(..) => {
             // Literal values are always valid
         }
+        Reference::Coalesce(alternatives) => {
+            for alternative in alternatives {
+                validate_reference(alternative, params, ctx)?;
+            }
+        }
+        Reference::Named { name, .. } => {
+            return Err(syn::Error::new(
+                name.span,
+                "named arguments are only allowed inside phrase calls",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check that named call arguments match a declared parameter of the callee
+/// and that no parameter is bound twice.
+fn validate_named_args(phrase: &str, declared: &[String], args: &[Reference]) -> syn::Result<()> {
+    let mut seen = HashSet::new();
+    for arg in args {
+        let Reference::Named { name, .. } = arg else {
+            continue;
+        };
+        if !declared.contains(&name.name) {
+            let mut msg = format!("phrase '{phrase}' has no parameter '${}'", name.name);
+            let suggestions = compute_suggestions(&name.name, declared.iter());
+            if !suggestions.is_empty() {
+                msg.push_str(&format!("\nhelp: did you mean '{}'?", suggestions[0]));
+            }
+            return Err(syn::Error::new(name.span, msg));
+        }
+        if !seen.insert(&name.name) {
+            return Err(syn::Error::new(
+                name.span,
+                format!(
+                    "argument '${}' passed twice to phrase '{phrase}'",
+                    name.name
+                ),
+            ));
+        }
     }
     Ok(())
 }
@@ -697,9 +1019,24 @@ fn collect_template_refs(
     ctx: &ValidationContext,
     refs: &mut Vec<(String, Span)>,
 ) {
-    for segment in &template.segments {
-        if let Segment::Interpolation(interp) = segment {
-            collect_reference_refs(&interp.reference, params, ctx, refs);
+    collect_segment_refs(&template.segments, params, ctx, refs);
+}
+
+fn collect_segment_refs(
+    segments: &[Segment],
+    params: &HashSet<String>,
+    ctx: &ValidationContext,
+    refs: &mut Vec<(String, Span)>,
+) {
+    for segment in segments {
+        match segment {
+            Segment::Literal(_) => {}
+            Segment::Interpolation(interp) => {
+                collect_reference_refs(&interp.reference, params, ctx, refs);
+            }
+            Segment::Conditional(conditional) => {
+                collect_segment_refs(&conditional.body, params, ctx, refs);
+            }
         }
     }
 }
@@ -732,6 +1069,12 @@ fn collect_reference_refs(
         Reference::NumberLiteral(..) | Reference::StringLiteral(..) => {
             // Literal values are not phrase references
         }
+        Reference::Coalesce(alternatives) => {
+            for alternative in alternatives {
+                collect_reference_refs(alternative, params, ctx, refs);
+            }
+        }
+        Reference::Named { value, .. } => collect_reference_refs(value, params, ctx, refs),
     }
 }
 
@@ -745,6 +1088,25 @@ mod tests {
         syn::parse2(tokens).expect("should parse")
     }
 
+    #[test]
+    fn test_phrase_name_variant_collision() {
+        let input = parse_input(parse_quote! {
+            card_2 = "a";
+            card2 = "b";
+        });
+        let err = check_phrase_name_variants(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "phrases 'card_2' and 'card2' both become the PhraseName variant 'Card2'\nhelp: rename one of them"
+        );
+
+        let input = parse_input(parse_quote! {
+            card = "a";
+            card_two = "b";
+        });
+        assert!(check_phrase_name_variants(&input).is_ok());
+    }
+
     // =========================================================================
     // ValidationContext::from_input tests
     // =========================================================================
@@ -943,6 +1305,58 @@ mod tests {
         assert!(err.contains("available variants"));
     }
 
+    #[test]
+    fn test_validate_duplicate_variant_key() {
+        let input = parse_input(parse_quote! {
+            card = { one: "a", one: "b", other: "c" };
+        });
+        let err = validate(&input).unwrap_err().to_string();
+        assert!(err.contains("duplicate key 'one' in variant block"));
+    }
+
+    #[test]
+    fn test_validate_duplicate_match_key() {
+        let input = parse_input(parse_quote! {
+            cards($n) = :match($n) { 1: "a card", 1, *other: "{$n} cards" };
+        });
+        let err = validate(&input).unwrap_err().to_string();
+        assert!(err.contains("duplicate key '1' in match block"));
+    }
+
+    #[test]
+    fn test_validate_overlapping_range_keys() {
+        let input = parse_input(parse_quote! {
+            threat($n) = :match($n) { 0: "calm", 1..3: "tense", 3..: "critical", *other: "?" };
+        });
+        let err = validate(&input).unwrap_err().to_string();
+        assert!(err.contains("overlapping keys '1..3' and '3..' in match block"));
+
+        let input = parse_input(parse_quote! {
+            threat($n) = :match($n) { ..0: "calm", 2: "two", 1..3: "tense", 4..: "critical", *other: "?" };
+        });
+        assert!(validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_validate_term_range_keys() {
+        let input = parse_input(parse_quote! {
+            mood = { 0: "calm", 1..3: "tense", 3..: "critical", other: "?" };
+        });
+        let err = validate(&input).unwrap_err().to_string();
+        assert!(err.contains("overlapping keys '1..3' and '3..' in variant block"));
+
+        let input = parse_input(parse_quote! {
+            mood = { 0: "calm", 1..3: "tense" };
+        });
+        let err = validate(&input).unwrap_err().to_string();
+        assert!(err.contains("needs an 'other' variant"));
+
+        let input = parse_input(parse_quote! {
+            mood = { 0: "calm", 1..3: "tense", 4..: "critical", other: "?" };
+        });
+        assert!(validate(&input).is_ok());
+    }
+
     #[test]
     fn test_validate_static_selector_requires_resolvable_full_key() {
         let input = parse_input(parse_quote! {
@@ -1009,6 +1423,22 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_lang_override_checks_transforms_in_that_language() {
+        let input = parse_input(parse_quote! {
+            karte = "Karte";
+            good = "{@lang:de @der:acc karte}";
+        });
+        assert!(validate(&input).is_ok());
+
+        let input = parse_input(parse_quote! {
+            karte = "Karte";
+            bad = "{@lang:de @the karte}";
+        });
+        let err = validate(&input).unwrap_err().to_string();
+        assert!(err.contains("transform '@the' is not available for language 'de'"));
+    }
+
     // =========================================================================
     // detect_cycles tests
     // =========================================================================
@@ -0,0 +1,7 @@
+use rlf::rlf;
+
+rlf! {
+    draw($n) = "Draw {$n} cards{?$suffix: \" {$suffix}\"}.";
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rlf::rlf;
+
+rlf! {
+    card = { one: "card", other: "cards" };
+    draw($n, $target) = "Draw {$n} {card:$n} for {$target}.";
+    bad($p) = "{draw(n: 3, n: $p)}";
+}
+
+fn main() {}
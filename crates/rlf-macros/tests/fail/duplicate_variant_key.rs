@@ -0,0 +1,7 @@
+use rlf::rlf;
+
+rlf! {
+    card = { one: "a", one: "b", other: "c" };
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use rlf::{rlf_format, Locale};
+
+fn main() {
+    let locale = Locale::new();
+    let _ = rlf_format!(locale, "Draw {$n} cards.", count = 3);
+}
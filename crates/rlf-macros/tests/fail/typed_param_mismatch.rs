@@ -0,0 +1,10 @@
+use rlf::{rlf, Locale};
+
+rlf! {
+    draw($n: num) = "Draw {$n}.";
+}
+
+fn main() {
+    let locale = Locale::new();
+    let _ = draw(&locale, "three");
+}
@@ -0,0 +1,8 @@
+use rlf::rlf;
+
+rlf! {
+    city = "istanbul";
+    bad = "{@upper:foo city}";
+}
+
+fn main() {}
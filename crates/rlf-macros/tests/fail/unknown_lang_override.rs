@@ -0,0 +1,8 @@
+use rlf::rlf;
+
+rlf! {
+    card = :a "card";
+    bad = "{@lang:xx @a card}";
+}
+
+fn main() {}
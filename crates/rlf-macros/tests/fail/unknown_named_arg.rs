@@ -0,0 +1,9 @@
+use rlf::rlf;
+
+rlf! {
+    card = { one: "card", other: "cards" };
+    draw($n, $target) = "Draw {$n} {card:$n} for {$target}.";
+    bad($p) = "{draw(count: 3, target: $p)}";
+}
+
+fn main() {}
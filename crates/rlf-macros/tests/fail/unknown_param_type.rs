@@ -0,0 +1,7 @@
+use rlf::rlf;
+
+rlf! {
+    draw($n: count) = "Draw {$n}.";
+}
+
+fn main() {}
@@ -4,3 +4,11 @@ fn compile_tests() {
     t.pass("tests/pass/*.rs");
     t.compile_fail("tests/fail/*.rs");
 }
+
+#[cfg(feature = "phrase-name-enum")]
+#[test]
+fn phrase_name_enum_compile_tests() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/phrase_name_enum/pass/*.rs");
+    t.compile_fail("tests/phrase_name_enum/fail/*.rs");
+}
@@ -0,0 +1,23 @@
+// Test @note and @maxlen attributes before definitions
+use rlf::{rlf, Locale};
+
+rlf! {
+    @note("Label on the \"Start\" button")
+    @maxlen(12)
+    start = "Start";
+    @note("Shown after drawing")
+    draw($n) = "Draw {$n}.";
+}
+
+fn main() {
+    let mut locale = Locale::new();
+    register_source_phrases(&mut locale);
+
+    assert_eq!(start(&locale).to_string(), "Start");
+    assert_eq!(draw(&locale, 2).to_string(), "Draw 2.");
+
+    let info = locale.phrase_info("start").unwrap();
+    assert_eq!(info.note().as_deref(), Some("Label on the \"Start\" button"));
+    assert_eq!(info.max_length(), Some(12));
+    assert_eq!(locale.phrase_info("draw").unwrap().max_length(), None);
+}
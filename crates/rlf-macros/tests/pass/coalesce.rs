@@ -0,0 +1,21 @@
+// Test {$a || $b || "fallback"} coalesce references
+use rlf::{rlf, Locale};
+
+rlf! {
+    player = "Player";
+    greet($nick, $name) = "Hello, {$nick || $name || player}!";
+    tag($nick) = "[{@upper $nick || \"anon\"}]";
+    cards($n) = "{$n} cards";
+    draw($nick, $n) = "{$nick | \"You\"} drew {cards($n) || \"nothing\"}.";
+}
+
+fn main() {
+    let mut locale = Locale::new();
+    register_source_phrases(&mut locale);
+
+    assert_eq!(greet(&locale, "Ace", "Ana").to_string(), "Hello, Ace!");
+    assert_eq!(greet(&locale, "", "Ana").to_string(), "Hello, Ana!");
+    assert_eq!(greet(&locale, "", "").to_string(), "Hello, Player!");
+    assert_eq!(tag(&locale, "").to_string(), "[ANON]");
+    assert_eq!(draw(&locale, "", 2).to_string(), "You drew 2 cards.");
+}
@@ -0,0 +1,15 @@
+// Test {?$param: "..."} conditional segments
+use rlf::{rlf, Locale};
+
+rlf! {
+    card = { one: "card", other: "cards" };
+    draw($n, $suffix) = "Draw {$n} {card:$n}{?$suffix: \" {$suffix}\"}.";
+}
+
+fn main() {
+    let mut locale = Locale::new();
+    register_source_phrases(&mut locale);
+
+    assert_eq!(draw(&locale, 3, "now").to_string(), "Draw 3 cards now.");
+    assert_eq!(draw(&locale, 1, "").to_string(), "Draw 1 card.");
+}
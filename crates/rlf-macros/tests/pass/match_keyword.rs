@@ -34,6 +34,18 @@ rlf! {
         *other: "{$n} {$s}",
     };
 
+    // Match with numeric range keys
+    threat($n) = :match($n) {
+        0: "calm",
+        1..3: "tense",
+        4..: "critical",
+        *other: "unknown",
+    };
+
+    // Term with numeric range keys, selected by a parameter
+    mood = { 0: "calm", 1..3: "tense", 4..: "critical", other: "unknown" };
+    status($n) = "{mood:$n}";
+
     // Match with tags
     items($n) = :a :match($n) {
         1: "an item",
@@ -53,4 +65,9 @@ fn main() {
     let _ = count_cards_a(&locale, 1, c.clone());
     let _ = count_cards_b(&locale, 3, c);
     let _ = items(&locale, 1);
+    assert_eq!(threat(&locale, 2).to_string(), "tense");
+    assert_eq!(threat(&locale, 9).to_string(), "critical");
+    assert_eq!(status(&locale, 0).to_string(), "calm");
+    assert_eq!(status(&locale, 2).to_string(), "tense");
+    assert_eq!(status(&locale, -1).to_string(), "unknown");
 }
@@ -0,0 +1,23 @@
+// Test named arguments in phrase calls
+use rlf::{rlf, Locale};
+
+rlf! {
+    card = { one: "card", other: "cards" };
+    draw($n, $target) = "Draw {$n} {card:$n} for {$target}.";
+
+    // Named arguments in declaration order, reversed, and mixed with positional
+    draw_three($p) = "{draw(n: 3, target: $p)}";
+    draw_three_reversed($p) = "{draw(target: $p, n: 3)}";
+    draw_one($p) = "{draw(1, target: $p)}";
+}
+
+fn main() {
+    let mut locale = Locale::new();
+    register_source_phrases(&mut locale);
+
+    assert_eq!(
+        draw_three(&locale, "Ana").to_string(),
+        draw_three_reversed(&locale, "Ana").to_string()
+    );
+    let _ = draw_one(&locale, "Ana");
+}
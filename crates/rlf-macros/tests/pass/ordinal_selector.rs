@@ -0,0 +1,18 @@
+// Test :#$n ordinal selector syntax
+use rlf::{rlf, Locale};
+
+rlf! {
+    nth = { one: "st", two: "nd", few: "rd", other: "th" };
+
+    // :#$n selects by ordinal category instead of cardinal plural category
+    place($n) = "{$n}{nth:#$n} place";
+}
+
+fn main() {
+    let mut locale = Locale::new();
+    register_source_phrases(&mut locale);
+
+    assert_eq!(place(&locale, 1).to_string(), "1st place");
+    assert_eq!(place(&locale, 22).to_string(), "22nd place");
+    assert_eq!(place(&locale, 13).to_string(), "13th place");
+}
@@ -0,0 +1,25 @@
+// Test rlf_format! for one-off templates
+use rlf::{rlf, rlf_format, Locale};
+
+rlf! {
+    card = :a { one: "card", other: "cards" };
+}
+
+fn main() {
+    let mut locale = Locale::new();
+    register_source_phrases(&mut locale);
+
+    let simple = rlf_format!(locale, "Draw {$n} {card:$n}.", n = 3).unwrap();
+    assert_eq!(simple.to_string(), "Draw 3 cards.");
+
+    let transformed = rlf_format!(&locale, "{@cap @a card} for {$who}!", who = "Ann",).unwrap();
+    assert_eq!(transformed.to_string(), "A card for Ann!");
+
+    let with_bonus = rlf_format!(locale, "Draw {$n}{?$bonus: \" (+{$bonus})\"}.", n = 3, bonus = 1);
+    assert_eq!(with_bonus.unwrap().to_string(), "Draw 3 (+1).");
+    let without_bonus = rlf_format!(locale, "Draw {$n}{?$bonus: \" (+{$bonus})\"}.", n = 3);
+    assert_eq!(without_bonus.unwrap().to_string(), "Draw 3.");
+
+    let literal = rlf_format!(locale, "Pass.").unwrap();
+    assert_eq!(literal.to_string(), "Pass.");
+}
@@ -7,6 +7,11 @@ rlf! {
     upper_test = "{@upper hello}";
     lower_test = "{@lower hello}";
     multi_transform = "{@upper @cap hello}";
+    city = "istanbul";
+    turkish_upper = "{@upper:tr city}";
+    keep_caps = "{@title:keepcaps hello}";
+    card = :a "card";
+    british = "{@lang:en_GB @a card}";
 }
 
 fn main() {
@@ -18,4 +23,7 @@ fn main() {
     let _ = upper_test(&locale);
     let _ = lower_test(&locale);
     let _ = multi_transform(&locale);
+    assert_eq!(turkish_upper(&locale).to_string(), "İSTANBUL");
+    let _ = keep_caps(&locale);
+    assert_eq!(british(&locale).to_string(), "a card");
 }
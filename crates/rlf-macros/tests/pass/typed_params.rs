@@ -0,0 +1,23 @@
+// Test typed phrase parameters
+use rlf::{rlf, Locale, Phrase};
+
+rlf! {
+    card = { one: "card", other: "cards" };
+    draw($n: num) = "Draw {$n} {card:$n}.";
+    greet($name: text) = "Hello, {$name}!";
+    show($p: phrase) = "Showing {$p}.";
+    mixed($n: num, $who) = "{$who} draws {$n}.";
+}
+
+fn main() {
+    let mut locale = Locale::new();
+    register_source_phrases(&mut locale);
+
+    assert_eq!(draw(&locale, 3).to_string(), "Draw 3 cards.");
+    assert_eq!(draw(&locale, 2.5).to_string(), "Draw 2.5 cards.");
+    assert_eq!(greet(&locale, "World").to_string(), "Hello, World!");
+    assert_eq!(greet(&locale, String::from("Ann")).to_string(), "Hello, Ann!");
+    let p: Phrase = card(&locale);
+    assert_eq!(show(&locale, p).to_string(), "Showing card.");
+    assert_eq!(mixed(&locale, 1, "Ann").to_string(), "Ann draws 1.");
+}
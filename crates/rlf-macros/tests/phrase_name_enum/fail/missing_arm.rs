@@ -0,0 +1,19 @@
+// Test that a match on PhraseName missing a phrase is a compile error
+use rlf::rlf;
+
+rlf! {
+    hello = "Hello, world!";
+    card = { one: "card", other: "cards" };
+    draw_cards($n) = "Draw {$n} {card:$n}.";
+}
+
+fn describe(name: phrase_ids::PhraseName) -> &'static str {
+    match name {
+        phrase_ids::PhraseName::Hello => "greeting",
+        phrase_ids::PhraseName::Card => "term",
+    }
+}
+
+fn main() {
+    let _ = describe(phrase_ids::PhraseName::Hello);
+}
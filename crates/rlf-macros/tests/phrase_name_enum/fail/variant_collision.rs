@@ -0,0 +1,9 @@
+// Test that two phrases mapping to the same PhraseName variant are rejected
+use rlf::rlf;
+
+rlf! {
+    card_2 = "first";
+    card2 = "second";
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Test the generated PhraseName enum matches exhaustively and converts to PhraseId
+use rlf::{rlf, PhraseId};
+
+rlf! {
+    hello = "Hello, world!";
+    card = { one: "card", other: "cards" };
+    draw_cards($n) = "Draw {$n} {card:$n}.";
+}
+
+fn describe(name: phrase_ids::PhraseName) -> &'static str {
+    match name {
+        phrase_ids::PhraseName::Hello => "greeting",
+        phrase_ids::PhraseName::Card => "term",
+        phrase_ids::PhraseName::DrawCards => "action",
+    }
+}
+
+fn main() {
+    let id: PhraseId = phrase_ids::PhraseName::DrawCards.into();
+    assert_eq!(id, phrase_ids::DRAW_CARDS);
+    assert_eq!(phrase_ids::PhraseName::ALL.len(), 3);
+    let _ = describe(phrase_ids::PhraseName::Hello);
+}
@@ -1,7 +1,12 @@
 //! Shared RLF semantic tables used by both runtime and macro validation.
 //!
-//! This crate centralizes transform name/alias resolution to avoid drift between
-//! compile-time (`rlf-macros`) and runtime (`rlf`) behavior.
+//! This crate centralizes transform name/alias resolution and parameter type
+//! names to avoid drift between compile-time (`rlf-macros`) and runtime (`rlf`)
+//! behavior.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
 
 /// Canonical transform identifiers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -9,11 +14,44 @@ pub enum TransformId {
     Cap,
     Upper,
     Lower,
+    Date,
+    Time,
+    Spell,
+    Currency,
+    Num,
+    Sentence,
+    List,
+    Title,
+    Lang,
+    TruncateWords,
+    Duration,
+    CaseFold,
+    Quote,
+    Map,
+    AutoCap,
+    StripMarkup,
+    EscapeMarkup,
+    Formal,
+    Informal,
+    Super,
+    Sub,
+    NoOp,
+    CompoundBreak,
+    Scientific,
+    CountNoun,
+    Month,
+    Weekday,
     EnglishA,
     EnglishThe,
     EnglishPlural,
+    EnglishPossessive,
+    EnglishGenitive,
+    EnglishCount,
+    EnglishPronoun,
     GermanDer,
     GermanEin,
+    GermanKein,
+    GermanGenitive,
     DutchDe,
     DutchEen,
     SpanishEl,
@@ -22,11 +60,13 @@ pub enum TransformId {
     PortugueseUm,
     PortugueseDe,
     PortugueseEm,
+    PortuguesePor,
     FrenchLe,
     FrenchUn,
     FrenchDe,
     FrenchAu,
     FrenchLiaison,
+    FrenchPunct,
     ItalianIl,
     ItalianUn,
     ItalianDi,
@@ -35,19 +75,27 @@ pub enum TransformId {
     GreekEnas,
     RomanianDef,
     ArabicAl,
+    ArabicConnect,
     PersianEzafe,
+    RussianNeg,
     ChineseCount,
     JapaneseCount,
     KoreanCount,
     VietnameseCount,
     ThaiCount,
+    ThaiWordBreak,
     BengaliCount,
     IndonesianPlural,
+    MalayCount,
     KoreanParticle,
     TurkishInflect,
     FinnishInflect,
     HungarianInflect,
+    BasqueInflect,
+    SwahiliAgree,
+    IrishMutate,
     JapaneseParticle,
+    JapaneseRuby,
     HindiKa,
     HindiKo,
     HindiSe,
@@ -56,28 +104,324 @@ pub enum TransformId {
     HindiNe,
 }
 
+impl TransformId {
+    /// The canonical name this transform is written as (e.g. `"der"`).
+    pub fn canonical_name(self) -> &'static str {
+        match self {
+            TransformId::Cap => "cap",
+            TransformId::Upper => "upper",
+            TransformId::Lower => "lower",
+            TransformId::Date => "date",
+            TransformId::Time => "time",
+            TransformId::Spell => "spell",
+            TransformId::Currency => "currency",
+            TransformId::Num => "num",
+            TransformId::Sentence => "sentence",
+            TransformId::List => "list",
+            TransformId::Title => "title",
+            TransformId::Lang => "lang",
+            TransformId::TruncateWords => "truncate_words",
+            TransformId::Duration => "duration",
+            TransformId::CaseFold => "case_fold",
+            TransformId::Quote => "quote",
+            TransformId::Map => "map",
+            TransformId::AutoCap => "autocap",
+            TransformId::StripMarkup => "strip_markup",
+            TransformId::EscapeMarkup => "escape_markup",
+            TransformId::Formal => "formal",
+            TransformId::Informal => "informal",
+            TransformId::Super => "superscript",
+            TransformId::Sub => "subscript",
+            TransformId::NoOp => "noop",
+            TransformId::CompoundBreak => "break",
+            TransformId::Scientific => "scientific",
+            TransformId::CountNoun => "count_noun",
+            TransformId::Month => "month",
+            TransformId::Weekday => "weekday",
+            TransformId::EnglishA => "a",
+            TransformId::EnglishThe => "the",
+            TransformId::EnglishPlural => "plural",
+            TransformId::EnglishPossessive => "poss",
+            TransformId::EnglishGenitive => "genitive",
+            TransformId::EnglishCount => "count",
+            TransformId::EnglishPronoun => "pronoun",
+            TransformId::GermanDer => "der",
+            TransformId::GermanEin => "ein",
+            TransformId::GermanKein => "kein",
+            TransformId::GermanGenitive => "genitive",
+            TransformId::DutchDe => "de",
+            TransformId::DutchEen => "een",
+            TransformId::SpanishEl => "el",
+            TransformId::SpanishUn => "un",
+            TransformId::PortugueseO => "o",
+            TransformId::PortugueseUm => "um",
+            TransformId::PortugueseDe => "de",
+            TransformId::PortugueseEm => "em",
+            TransformId::PortuguesePor => "por",
+            TransformId::FrenchLe => "le",
+            TransformId::FrenchUn => "un",
+            TransformId::FrenchDe => "de",
+            TransformId::FrenchAu => "au",
+            TransformId::FrenchLiaison => "liaison",
+            TransformId::FrenchPunct => "punct",
+            TransformId::ItalianIl => "il",
+            TransformId::ItalianUn => "un",
+            TransformId::ItalianDi => "di",
+            TransformId::ItalianA => "a",
+            TransformId::GreekO => "o",
+            TransformId::GreekEnas => "enas",
+            TransformId::RomanianDef => "def",
+            TransformId::ArabicAl => "al",
+            TransformId::ArabicConnect => "connect",
+            TransformId::PersianEzafe => "ezafe",
+            TransformId::RussianNeg => "neg",
+            TransformId::ChineseCount => "count",
+            TransformId::JapaneseCount => "count",
+            TransformId::KoreanCount => "count",
+            TransformId::VietnameseCount => "count",
+            TransformId::ThaiCount => "count",
+            TransformId::ThaiWordBreak => "wordbreak",
+            TransformId::BengaliCount => "count",
+            TransformId::IndonesianPlural => "plural",
+            TransformId::MalayCount => "count",
+            TransformId::KoreanParticle => "particle",
+            TransformId::TurkishInflect => "inflect",
+            TransformId::FinnishInflect => "inflect",
+            TransformId::HungarianInflect => "inflect",
+            TransformId::BasqueInflect => "inflect",
+            TransformId::SwahiliAgree => "agree",
+            TransformId::IrishMutate => "mutate",
+            TransformId::JapaneseParticle => "particle",
+            TransformId::JapaneseRuby => "ruby",
+            TransformId::HindiKa => "ka",
+            TransformId::HindiKo => "ko",
+            TransformId::HindiSe => "se",
+            TransformId::HindiMe => "me",
+            TransformId::HindiPar => "par",
+            TransformId::HindiNe => "ne",
+        }
+    }
+
+    /// Alternate names that resolve to this transform (e.g. `["die", "das"]`).
+    pub fn aliases(self) -> &'static [&'static str] {
+        match self {
+            TransformId::EnglishA => &["an"],
+            TransformId::EnglishPossessive => &["possessive"],
+            TransformId::NoOp => &["id", "raw"],
+            TransformId::GermanDer => &["die", "das"],
+            TransformId::GermanEin => &["eine"],
+            TransformId::GermanKein => &["keine"],
+            TransformId::DutchDe => &["het"],
+            TransformId::SpanishEl => &["la"],
+            TransformId::SpanishUn => &["una"],
+            TransformId::PortugueseO => &["a"],
+            TransformId::PortugueseUm => &["uma"],
+            TransformId::FrenchLe => &["la"],
+            TransformId::FrenchUn => &["une"],
+            TransformId::ItalianIl => &["lo", "la"],
+            TransformId::ItalianUn => &["uno", "una"],
+            TransformId::GreekO => &["i", "to"],
+            TransformId::GreekEnas => &["mia", "ena"],
+            TransformId::HindiKa => &["ki", "ke"],
+            _ => &[],
+        }
+    }
+
+    /// Languages this transform is defined for; empty for universal transforms.
+    pub fn languages(self) -> &'static [&'static str] {
+        match self {
+            TransformId::EnglishA => &["en"],
+            TransformId::EnglishThe => &["en"],
+            TransformId::EnglishPlural => &["en"],
+            TransformId::EnglishPossessive => &["en"],
+            TransformId::EnglishGenitive => &["en"],
+            TransformId::EnglishCount => &["en"],
+            TransformId::EnglishPronoun => &["en"],
+            TransformId::GermanDer => &["de"],
+            TransformId::GermanEin => &["de"],
+            TransformId::GermanKein => &["de"],
+            TransformId::GermanGenitive => &["de"],
+            TransformId::DutchDe => &["nl"],
+            TransformId::DutchEen => &["nl"],
+            TransformId::SpanishEl => &["es"],
+            TransformId::SpanishUn => &["es"],
+            TransformId::PortugueseO => &["pt"],
+            TransformId::PortugueseUm => &["pt"],
+            TransformId::PortugueseDe => &["pt"],
+            TransformId::PortugueseEm => &["pt"],
+            TransformId::PortuguesePor => &["pt"],
+            TransformId::FrenchLe => &["fr"],
+            TransformId::FrenchUn => &["fr"],
+            TransformId::FrenchDe => &["fr"],
+            TransformId::FrenchAu => &["fr"],
+            TransformId::FrenchLiaison => &["fr"],
+            TransformId::FrenchPunct => &["fr"],
+            TransformId::ItalianIl => &["it"],
+            TransformId::ItalianUn => &["it"],
+            TransformId::ItalianDi => &["it"],
+            TransformId::ItalianA => &["it"],
+            TransformId::GreekO => &["el"],
+            TransformId::GreekEnas => &["el"],
+            TransformId::RomanianDef => &["ro"],
+            TransformId::ArabicAl => &["ar"],
+            TransformId::ArabicConnect => &["ar", "fa"],
+            TransformId::PersianEzafe => &["fa"],
+            TransformId::RussianNeg => &["ru"],
+            TransformId::ChineseCount => &["zh"],
+            TransformId::JapaneseCount => &["ja"],
+            TransformId::KoreanCount => &["ko"],
+            TransformId::VietnameseCount => &["vi"],
+            TransformId::ThaiCount => &["th"],
+            TransformId::ThaiWordBreak => &["th"],
+            TransformId::BengaliCount => &["bn"],
+            TransformId::IndonesianPlural => &["id"],
+            TransformId::MalayCount => &["ms"],
+            TransformId::KoreanParticle => &["ko"],
+            TransformId::TurkishInflect => &["tr"],
+            TransformId::FinnishInflect => &["fi"],
+            TransformId::HungarianInflect => &["hu"],
+            TransformId::BasqueInflect => &["eu"],
+            TransformId::SwahiliAgree => &["sw"],
+            TransformId::IrishMutate => &["ga"],
+            TransformId::JapaneseParticle => &["ja"],
+            TransformId::JapaneseRuby => &["ja"],
+            TransformId::HindiKa => &["hi"],
+            TransformId::HindiKo => &["hi"],
+            TransformId::HindiSe => &["hi"],
+            TransformId::HindiMe => &["hi"],
+            TransformId::HindiPar => &["hi"],
+            TransformId::HindiNe => &["hi"],
+            _ => &[],
+        }
+    }
+}
+
+impl Display for TransformId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.canonical_name())
+    }
+}
+
+/// Parses a canonical transform name (`"cap"`, `"der"`).
+///
+/// Language-specific aliases (like `"an"` or `"die"`) are not accepted, but
+/// the universal aliases `"id"` and `"raw"` parse as [`TransformId::NoOp`].
+/// Names that several languages define (like `"count"` or `"de"`) are
+/// ambiguous; use [`resolve_transform`] with a language for those.
+impl FromStr for TransformId {
+    type Err = ParseTransformIdError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let mut found: Vec<(&'static str, TransformId)> = Vec::new();
+        for &lang in TRANSFORM_LANGUAGES {
+            if let Some(id) = resolve_canonical(name, lang)
+                && !found.iter().any(|(_, f)| *f == id)
+            {
+                found.push((lang, id));
+            }
+        }
+        match found.as_slice() {
+            [] => Err(ParseTransformIdError::Unknown(name.to_string())),
+            [(_, id)] => Ok(*id),
+            _ => Err(ParseTransformIdError::Ambiguous {
+                name: name.to_string(),
+                languages: found.iter().map(|(lang, _)| *lang).collect(),
+            }),
+        }
+    }
+}
+
+/// Error returned when a string is not the canonical name of one transform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseTransformIdError {
+    /// No transform has this canonical name.
+    Unknown(String),
+    /// Several languages define a transform with this name.
+    Ambiguous {
+        name: String,
+        languages: Vec<&'static str>,
+    },
+}
+
+impl Display for ParseTransformIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ParseTransformIdError::Unknown(name) => write!(f, "unknown transform '@{name}'"),
+            ParseTransformIdError::Ambiguous { name, languages } => write!(
+                f,
+                "transform '@{name}' is defined for several languages ({}); resolve it with a language",
+                languages.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for ParseTransformIdError {}
+
 /// Resolve a transform name for a language to a canonical transform id.
 ///
 /// Resolution order:
-/// 1. Alias canonicalization (language-aware where needed)
+/// 1. Alias canonicalization (each alias folds only in its own language)
 /// 2. Universal transforms
 /// 3. Language-specific transforms
 pub fn resolve_transform(name: &str, lang: &str) -> Option<TransformId> {
-    let canonical = canonicalize_alias(name, lang);
+    resolve_canonical(canonicalize_alias(name, lang), lang)
+}
 
+/// Resolve a name with no language-specific alias to fold.
+///
+/// The universal aliases of `noop` apply in every language, so they resolve
+/// here rather than in [`canonicalize_alias`].
+fn resolve_canonical(canonical: &str, lang: &str) -> Option<TransformId> {
     match canonical {
         "cap" => return Some(TransformId::Cap),
         "upper" => return Some(TransformId::Upper),
         "lower" => return Some(TransformId::Lower),
+        "date" => return Some(TransformId::Date),
+        "time" => return Some(TransformId::Time),
+        "spell" => return Some(TransformId::Spell),
+        "currency" => return Some(TransformId::Currency),
+        "num" => return Some(TransformId::Num),
+        "sentence" => return Some(TransformId::Sentence),
+        "list" => return Some(TransformId::List),
+        "title" => return Some(TransformId::Title),
+        "lang" => return Some(TransformId::Lang),
+        "truncate_words" => return Some(TransformId::TruncateWords),
+        "duration" => return Some(TransformId::Duration),
+        "case_fold" => return Some(TransformId::CaseFold),
+        "quote" => return Some(TransformId::Quote),
+        "map" => return Some(TransformId::Map),
+        "autocap" => return Some(TransformId::AutoCap),
+        "strip_markup" => return Some(TransformId::StripMarkup),
+        "escape_markup" => return Some(TransformId::EscapeMarkup),
+        "formal" => return Some(TransformId::Formal),
+        "informal" => return Some(TransformId::Informal),
+        "superscript" => return Some(TransformId::Super),
+        "subscript" => return Some(TransformId::Sub),
+        "break" => return Some(TransformId::CompoundBreak),
+        "scientific" => return Some(TransformId::Scientific),
+        "count_noun" => return Some(TransformId::CountNoun),
+        "month" => return Some(TransformId::Month),
+        "weekday" => return Some(TransformId::Weekday),
+        "noop" | "id" | "raw" => return Some(TransformId::NoOp),
         _ => {}
     }
 
+    if !is_language_enabled(lang) {
+        return None;
+    }
     match (lang, canonical) {
         ("en", "a") => Some(TransformId::EnglishA),
         ("en", "the") => Some(TransformId::EnglishThe),
         ("en", "plural") => Some(TransformId::EnglishPlural),
+        ("en", "poss") => Some(TransformId::EnglishPossessive),
+        ("en", "genitive") => Some(TransformId::EnglishGenitive),
+        ("en", "count") => Some(TransformId::EnglishCount),
+        ("en", "pronoun") => Some(TransformId::EnglishPronoun),
         ("de", "der") => Some(TransformId::GermanDer),
         ("de", "ein") => Some(TransformId::GermanEin),
+        ("de", "kein") => Some(TransformId::GermanKein),
+        ("de", "genitive") => Some(TransformId::GermanGenitive),
         ("nl", "de") => Some(TransformId::DutchDe),
         ("nl", "een") => Some(TransformId::DutchEen),
         ("es", "el") => Some(TransformId::SpanishEl),
@@ -86,11 +430,13 @@ pub fn resolve_transform(name: &str, lang: &str) -> Option<TransformId> {
         ("pt", "um") => Some(TransformId::PortugueseUm),
         ("pt", "de") => Some(TransformId::PortugueseDe),
         ("pt", "em") => Some(TransformId::PortugueseEm),
+        ("pt", "por") => Some(TransformId::PortuguesePor),
         ("fr", "le") => Some(TransformId::FrenchLe),
         ("fr", "un") => Some(TransformId::FrenchUn),
         ("fr", "de") => Some(TransformId::FrenchDe),
         ("fr", "au") => Some(TransformId::FrenchAu),
         ("fr", "liaison") => Some(TransformId::FrenchLiaison),
+        ("fr", "punct") => Some(TransformId::FrenchPunct),
         ("it", "il") => Some(TransformId::ItalianIl),
         ("it", "un") => Some(TransformId::ItalianUn),
         ("it", "di") => Some(TransformId::ItalianDi),
@@ -99,19 +445,27 @@ pub fn resolve_transform(name: &str, lang: &str) -> Option<TransformId> {
         ("el", "enas") => Some(TransformId::GreekEnas),
         ("ro", "def") => Some(TransformId::RomanianDef),
         ("ar", "al") => Some(TransformId::ArabicAl),
+        ("ar" | "fa", "connect") => Some(TransformId::ArabicConnect),
         ("fa", "ezafe") => Some(TransformId::PersianEzafe),
+        ("ru", "neg") => Some(TransformId::RussianNeg),
         ("zh", "count") => Some(TransformId::ChineseCount),
         ("ja", "count") => Some(TransformId::JapaneseCount),
         ("ko", "count") => Some(TransformId::KoreanCount),
         ("vi", "count") => Some(TransformId::VietnameseCount),
         ("th", "count") => Some(TransformId::ThaiCount),
+        ("th", "wordbreak") => Some(TransformId::ThaiWordBreak),
         ("bn", "count") => Some(TransformId::BengaliCount),
         ("id", "plural") => Some(TransformId::IndonesianPlural),
+        ("ms", "count") => Some(TransformId::MalayCount),
         ("ko", "particle") => Some(TransformId::KoreanParticle),
         ("ja", "particle") => Some(TransformId::JapaneseParticle),
+        ("ja", "ruby") => Some(TransformId::JapaneseRuby),
         ("tr", "inflect") => Some(TransformId::TurkishInflect),
         ("fi", "inflect") => Some(TransformId::FinnishInflect),
         ("hu", "inflect") => Some(TransformId::HungarianInflect),
+        ("eu", "inflect") => Some(TransformId::BasqueInflect),
+        ("sw", "agree") => Some(TransformId::SwahiliAgree),
+        ("ga", "mutate") => Some(TransformId::IrishMutate),
         ("hi", "ka") => Some(TransformId::HindiKa),
         ("hi", "ko") => Some(TransformId::HindiKo),
         ("hi", "se") => Some(TransformId::HindiSe),
@@ -126,6 +480,9 @@ pub fn resolve_transform(name: &str, lang: &str) -> Option<TransformId> {
 ///
 /// Used for diagnostics and typo suggestions.
 pub fn accepted_transform_names(lang: &str) -> &'static [&'static str] {
+    if !is_language_enabled(lang) {
+        return UNIVERSAL_NAMES;
+    }
     match lang {
         "en" => EN_NAMES,
         "de" => DE_NAMES,
@@ -138,6 +495,7 @@ pub fn accepted_transform_names(lang: &str) -> &'static [&'static str] {
         "ro" => RO_NAMES,
         "ar" => AR_NAMES,
         "fa" => FA_NAMES,
+        "ru" => RU_NAMES,
         "zh" => ZH_NAMES,
         "ja" => JA_NAMES,
         "ko" => KO_NAMES,
@@ -145,24 +503,251 @@ pub fn accepted_transform_names(lang: &str) -> &'static [&'static str] {
         "th" => TH_NAMES,
         "bn" => BN_NAMES,
         "id" => ID_NAMES,
+        "ms" => MS_NAMES,
         "tr" => TR_NAMES,
         "fi" => FI_NAMES,
         "hu" => HU_NAMES,
+        "eu" => EU_NAMES,
+        "sw" => SW_NAMES,
+        "ga" => GA_NAMES,
         "hi" => HI_NAMES,
         _ => UNIVERSAL_NAMES,
     }
 }
 
+/// Languages whose language-specific transforms accept `name`, in table order.
+///
+/// Used to explain an unknown transform that is defined for another language
+/// (e.g. `@der` used in an English file). Universal transforms and names that
+/// no language defines return an empty list.
+pub fn languages_with_transform(name: &str) -> Vec<&'static str> {
+    if resolve_transform(name, "").is_some() {
+        return Vec::new();
+    }
+    TRANSFORM_LANGUAGES
+        .iter()
+        .copied()
+        .filter(|lang| resolve_transform(name, lang).is_some())
+        .collect()
+}
+
+/// Languages that define language-specific transforms, in table order.
+///
+/// Only languages enabled by a `lang-*` feature are listed.
+pub fn transform_languages() -> Vec<&'static str> {
+    TRANSFORM_LANGUAGES
+        .iter()
+        .copied()
+        .filter(|lang| is_language_enabled(lang))
+        .collect()
+}
+
+/// Whether the `lang-*` feature for `lang`'s transform table is enabled.
+///
+/// Every language is enabled by default (the `all-languages` feature). With a
+/// language disabled, its transforms resolve to `None` and only universal
+/// transforms are accepted for it.
+pub fn is_language_enabled(lang: &str) -> bool {
+    ENABLED_LANGUAGES
+        .iter()
+        .any(|&(code, enabled)| enabled && code == lang)
+}
+
+/// Languages with built-in CLDR plural rules, sorted.
+pub fn plural_languages() -> &'static [&'static str] {
+    PLURAL_LANGUAGES
+}
+
+/// Whether `code` names a language a case transform or `@lang` can switch
+/// to, as in `{@upper:tr $x}`.
+///
+/// Accepted are languages with plural rules or enabled transforms, plus
+/// languages with only their own case mapping rules. Region subtags are
+/// ignored, so `tr_TR` and `en-GB` are accepted.
+pub fn is_case_language(code: &str) -> bool {
+    let primary = code.split(['-', '_']).next().unwrap_or(code);
+    PLURAL_LANGUAGES.contains(&primary)
+        || CASE_ONLY_LANGUAGES.contains(&primary)
+        || is_language_enabled(primary)
+}
+
+/// Languages with built-in CLDR plural rules.
+const PLURAL_LANGUAGES: &[&str] = &[
+    "ar", "be", "bn", "bs", "cs", "de", "el", "en", "es", "fa", "fr", "ga", "he", "hi", "hr", "id",
+    "it", "ja", "ko", "ms", "nl", "pl", "pt", "ro", "ru", "sk", "sl", "sr", "th", "tr", "uk", "vi",
+    "zh",
+];
+
+/// Languages with their own case mapping rules but no plural rules or
+/// language-specific transforms.
+const CASE_ONLY_LANGUAGES: &[&str] = &["az", "lt"];
+
+/// Each transform language with whether its `lang-*` feature is enabled.
+const ENABLED_LANGUAGES: &[(&str, bool)] = &[
+    ("en", cfg!(feature = "lang-en")),
+    ("de", cfg!(feature = "lang-de")),
+    ("nl", cfg!(feature = "lang-nl")),
+    ("es", cfg!(feature = "lang-es")),
+    ("pt", cfg!(feature = "lang-pt")),
+    ("fr", cfg!(feature = "lang-fr")),
+    ("it", cfg!(feature = "lang-it")),
+    ("el", cfg!(feature = "lang-el")),
+    ("ro", cfg!(feature = "lang-ro")),
+    ("ar", cfg!(feature = "lang-ar")),
+    ("fa", cfg!(feature = "lang-fa")),
+    ("ru", cfg!(feature = "lang-ru")),
+    ("zh", cfg!(feature = "lang-zh")),
+    ("ja", cfg!(feature = "lang-ja")),
+    ("ko", cfg!(feature = "lang-ko")),
+    ("vi", cfg!(feature = "lang-vi")),
+    ("th", cfg!(feature = "lang-th")),
+    ("bn", cfg!(feature = "lang-bn")),
+    ("id", cfg!(feature = "lang-id")),
+    ("ms", cfg!(feature = "lang-ms")),
+    ("tr", cfg!(feature = "lang-tr")),
+    ("fi", cfg!(feature = "lang-fi")),
+    ("hu", cfg!(feature = "lang-hu")),
+    ("eu", cfg!(feature = "lang-eu")),
+    ("sw", cfg!(feature = "lang-sw")),
+    ("ga", cfg!(feature = "lang-ga")),
+    ("hi", cfg!(feature = "lang-hi")),
+];
+
+/// A suspicious overlap between transform names, reported by [`audit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Collision {
+    /// An alias rewrites `name` even though `name` is already a distinct
+    /// transform in `lang`, so the original transform is unreachable.
+    Shadowed {
+        name: &'static str,
+        lang: &'static str,
+        alias_of: TransformId,
+        shadowed: TransformId,
+    },
+    /// `name` resolves in `lang` but is missing from that language's accepted
+    /// names, usually because an alias applies to every language.
+    Unlisted {
+        name: &'static str,
+        lang: &'static str,
+        resolved: TransformId,
+    },
+    /// `name` is an accepted name for `lang` but does not resolve.
+    Unresolved {
+        name: &'static str,
+        lang: &'static str,
+    },
+    /// `name` resolves to different transforms in different languages.
+    ///
+    /// This is expected for short article and preposition names like `@de`
+    /// or `@a`; it is reported so that new overlaps are reviewed.
+    Shared {
+        name: &'static str,
+        resolutions: Vec<(&'static str, TransformId)>,
+    },
+}
+
+/// Check the name tables for collisions between aliases and transforms.
+///
+/// Every name accepted by any language is resolved against every language.
+/// A consistent table produces only [`Collision::Shared`] entries.
+pub fn audit() -> Vec<Collision> {
+    let mut names: Vec<&'static str> = Vec::new();
+    for lang in TRANSFORM_LANGUAGES {
+        for name in accepted_transform_names(lang) {
+            if !names.contains(name) {
+                names.push(name);
+            }
+        }
+    }
+
+    let mut collisions = Vec::new();
+    for &name in &names {
+        let mut resolutions: Vec<(&'static str, TransformId)> = Vec::new();
+        for &lang in TRANSFORM_LANGUAGES {
+            let listed = accepted_transform_names(lang).contains(&name);
+            let Some(resolved) = resolve_transform(name, lang) else {
+                if listed {
+                    collisions.push(Collision::Unresolved { name, lang });
+                }
+                continue;
+            };
+            if !listed {
+                collisions.push(Collision::Unlisted {
+                    name,
+                    lang,
+                    resolved,
+                });
+            }
+            if let Some(shadowed) = resolve_canonical(name, lang)
+                && shadowed != resolved
+            {
+                collisions.push(Collision::Shadowed {
+                    name,
+                    lang,
+                    alias_of: resolved,
+                    shadowed,
+                });
+            }
+            if resolved.languages().contains(&lang) {
+                resolutions.push((lang, resolved));
+            }
+        }
+        if resolutions.iter().any(|(_, id)| *id != resolutions[0].1) {
+            collisions.push(Collision::Shared { name, resolutions });
+        }
+    }
+    collisions
+}
+
+/// Declared type of a phrase parameter (`$n: num`, `$name: text`, `$p: phrase`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamType {
+    /// An integer or float (`num`).
+    Num,
+    /// A plain string (`text`).
+    Text,
+    /// A phrase with variants and tags (`phrase`).
+    Phrase,
+}
+
+impl ParamType {
+    /// Parse a type annotation name, returning `None` for unknown names.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "num" => Some(ParamType::Num),
+            "text" => Some(ParamType::Text),
+            "phrase" => Some(ParamType::Phrase),
+            _ => None,
+        }
+    }
+
+    /// The annotation name as written in source.
+    pub fn name(self) -> &'static str {
+        match self {
+            ParamType::Num => "num",
+            ParamType::Text => "text",
+            ParamType::Phrase => "phrase",
+        }
+    }
+}
+
+/// Fold an alias to its canonical name within `lang`.
+///
+/// Every rule names the language it belongs to, so an alias never leaks into
+/// another language: `@het` folds to `@de` in Dutch but stays `@het` (and is
+/// unknown) everywhere else. New aliases must follow the same pattern.
 fn canonicalize_alias<'a>(name: &'a str, lang: &str) -> &'a str {
     match (name, lang) {
-        ("an", _) => "a",
-        ("die" | "das", _) => "der",
-        ("eine", _) => "ein",
-        ("het", _) => "de",
+        ("an", "en") => "a",
+        ("possessive", "en") => "poss",
+        ("die" | "das", "de") => "der",
+        ("eine", "de") => "ein",
+        ("keine", "de") => "kein",
+        ("het", "nl") => "de",
         ("la", "es") => "el",
         ("una", "es") => "un",
         ("a", "pt") => "o",
-        ("uma", _) => "um",
+        ("uma", "pt") => "um",
         ("la", "fr") => "le",
         ("une", "fr") => "un",
         ("lo" | "la", "it") => "il",
@@ -174,34 +759,57 @@ fn canonicalize_alias<'a>(name: &'a str, lang: &str) -> &'a str {
     }
 }
 
-const UNIVERSAL_NAMES: &[&str] = &["cap", "upper", "lower"];
-const EN_NAMES: &[&str] = &["cap", "upper", "lower", "a", "an", "the", "plural"];
-const DE_NAMES: &[&str] = &["cap", "upper", "lower", "der", "die", "das", "ein", "eine"];
-const NL_NAMES: &[&str] = &["cap", "upper", "lower", "de", "het", "een"];
-const ES_NAMES: &[&str] = &["cap", "upper", "lower", "el", "la", "un", "una"];
-const PT_NAMES: &[&str] = &["cap", "upper", "lower", "o", "a", "um", "uma", "de", "em"];
-const FR_NAMES: &[&str] = &[
-    "cap", "upper", "lower", "le", "la", "un", "une", "de", "au", "liaison",
-];
-const IT_NAMES: &[&str] = &[
-    "cap", "upper", "lower", "il", "lo", "la", "un", "uno", "una", "di", "a",
-];
-const EL_NAMES: &[&str] = &[
-    "cap", "upper", "lower", "o", "i", "to", "enas", "mia", "ena",
-];
-const RO_NAMES: &[&str] = &["cap", "upper", "lower", "def"];
-const AR_NAMES: &[&str] = &["cap", "upper", "lower", "al"];
-const FA_NAMES: &[&str] = &["cap", "upper", "lower", "ezafe"];
-const ZH_NAMES: &[&str] = &["cap", "upper", "lower", "count"];
-const JA_NAMES: &[&str] = &["cap", "upper", "lower", "count", "particle"];
-const KO_NAMES: &[&str] = &["cap", "upper", "lower", "count", "particle"];
-const VI_NAMES: &[&str] = &["cap", "upper", "lower", "count"];
-const TH_NAMES: &[&str] = &["cap", "upper", "lower", "count"];
-const BN_NAMES: &[&str] = &["cap", "upper", "lower", "count"];
-const ID_NAMES: &[&str] = &["cap", "upper", "lower", "plural"];
-const TR_NAMES: &[&str] = &["cap", "upper", "lower", "inflect"];
-const FI_NAMES: &[&str] = &["cap", "upper", "lower", "inflect"];
-const HU_NAMES: &[&str] = &["cap", "upper", "lower", "inflect"];
-const HI_NAMES: &[&str] = &[
-    "cap", "upper", "lower", "ka", "ki", "ke", "ko", "se", "me", "par", "ne",
+const TRANSFORM_LANGUAGES: &[&str] = &[
+    "en", "de", "nl", "es", "pt", "fr", "it", "el", "ro", "ar", "fa", "ru", "zh", "ja", "ko", "vi",
+    "th", "bn", "id", "ms", "tr", "fi", "hu", "eu", "sw", "ga", "hi",
 ];
+
+/// Expands to a name table: universal transform names followed by the given
+/// language-specific names.
+macro_rules! with_universal_names {
+    ($($name:literal),* $(,)?) => {
+        &["cap", "upper", "lower", "date", "time", "spell", "currency", "num", "sentence", "list", "title", "lang", "truncate_words", "duration", "case_fold", "quote", "map", "autocap", "strip_markup", "escape_markup", "formal", "informal", "superscript", "subscript", "noop", "id", "raw", "break", "scientific", "count_noun", "month", "weekday", $($name),*]
+    };
+}
+
+const UNIVERSAL_NAMES: &[&str] = with_universal_names!();
+const EN_NAMES: &[&str] = with_universal_names!(
+    "a",
+    "an",
+    "the",
+    "plural",
+    "poss",
+    "possessive",
+    "genitive",
+    "count",
+    "pronoun"
+);
+const DE_NAMES: &[&str] = with_universal_names!(
+    "der", "die", "das", "ein", "eine", "kein", "keine", "genitive"
+);
+const NL_NAMES: &[&str] = with_universal_names!("de", "het", "een");
+const ES_NAMES: &[&str] = with_universal_names!("el", "la", "un", "una");
+const PT_NAMES: &[&str] = with_universal_names!("o", "a", "um", "uma", "de", "em", "por");
+const FR_NAMES: &[&str] =
+    with_universal_names!("le", "la", "un", "une", "de", "au", "liaison", "punct");
+const IT_NAMES: &[&str] = with_universal_names!("il", "lo", "la", "un", "uno", "una", "di", "a");
+const EL_NAMES: &[&str] = with_universal_names!("o", "i", "to", "enas", "mia", "ena");
+const RO_NAMES: &[&str] = with_universal_names!("def");
+const AR_NAMES: &[&str] = with_universal_names!("al", "connect");
+const FA_NAMES: &[&str] = with_universal_names!("ezafe", "connect");
+const RU_NAMES: &[&str] = with_universal_names!("neg");
+const ZH_NAMES: &[&str] = with_universal_names!("count");
+const JA_NAMES: &[&str] = with_universal_names!("count", "particle", "ruby");
+const KO_NAMES: &[&str] = with_universal_names!("count", "particle");
+const VI_NAMES: &[&str] = with_universal_names!("count");
+const TH_NAMES: &[&str] = with_universal_names!("count", "wordbreak");
+const BN_NAMES: &[&str] = with_universal_names!("count");
+const ID_NAMES: &[&str] = with_universal_names!("plural");
+const MS_NAMES: &[&str] = with_universal_names!("count");
+const TR_NAMES: &[&str] = with_universal_names!("inflect");
+const FI_NAMES: &[&str] = with_universal_names!("inflect");
+const HU_NAMES: &[&str] = with_universal_names!("inflect");
+const EU_NAMES: &[&str] = with_universal_names!("inflect");
+const SW_NAMES: &[&str] = with_universal_names!("agree");
+const GA_NAMES: &[&str] = with_universal_names!("mutate");
+const HI_NAMES: &[&str] = with_universal_names!("ka", "ki", "ke", "ko", "se", "me", "par", "ne");
@@ -0,0 +1,58 @@
+//! Tests for the `lang-*` features. They hold for any feature set; run them
+//! restricted with e.g. `cargo test -p rlf-semantics --no-default-features
+//! --features lang-en,lang-de`.
+
+use rlf_semantics::{
+    TransformId, accepted_transform_names, is_language_enabled, languages_with_transform,
+    resolve_transform, transform_languages,
+};
+
+#[test]
+fn disabled_languages_resolve_only_universal_transforms() {
+    let samples = [
+        ("en", "a"),
+        ("de", "der"),
+        ("fr", "le"),
+        ("ja", "particle"),
+        ("hi", "ka"),
+    ];
+    for (lang, name) in samples {
+        let enabled = is_language_enabled(lang);
+        assert_eq!(resolve_transform(name, lang).is_some(), enabled, "{lang}");
+        assert_eq!(
+            accepted_transform_names(lang).contains(&name),
+            enabled,
+            "{lang}"
+        );
+        assert_eq!(
+            languages_with_transform(name).contains(&lang),
+            enabled,
+            "{lang}"
+        );
+        assert_eq!(transform_languages().contains(&lang), enabled, "{lang}");
+        assert_eq!(resolve_transform("cap", lang), Some(TransformId::Cap));
+    }
+}
+
+#[test]
+fn enabled_languages_follow_features() {
+    assert_eq!(is_language_enabled("de"), cfg!(feature = "lang-de"));
+    assert_eq!(is_language_enabled("fr"), cfg!(feature = "lang-fr"));
+    assert!(!is_language_enabled("tlh"));
+}
+
+#[cfg(not(feature = "lang-fr"))]
+#[test]
+fn french_transforms_are_absent_without_the_feature() {
+    assert_eq!(resolve_transform("le", "fr"), None);
+    assert_eq!(resolve_transform("une", "fr"), None);
+    assert!(!languages_with_transform("liaison").contains(&"fr"));
+    assert!(!transform_languages().contains(&"fr"));
+}
+
+#[cfg(feature = "all-languages")]
+#[test]
+fn all_languages_enables_every_table() {
+    assert_eq!(transform_languages().len(), 27);
+    assert_eq!(resolve_transform("le", "fr"), Some(TransformId::FrenchLe));
+}
@@ -1,11 +1,25 @@
+// These tests cover the full tables; see language_features.rs for builds with
+// only some languages enabled.
+#![cfg(feature = "all-languages")]
+
 use std::collections::HashSet;
 
-use rlf_semantics::{TransformId, accepted_transform_names, resolve_transform};
+use rlf_semantics::{
+    Collision, ParseTransformIdError, TransformId, accepted_transform_names, audit,
+    languages_with_transform, resolve_transform,
+};
 
 #[test]
 fn alias_resolution_matrix() {
     assert_eq!(resolve_transform("an", "en"), Some(TransformId::EnglishA));
     assert_eq!(resolve_transform("an", "pt"), None);
+    assert_eq!(resolve_transform("an", "it"), None);
+    assert_eq!(resolve_transform("het", "fr"), None);
+    assert_eq!(resolve_transform("uma", "it"), None);
+    assert_eq!(
+        resolve_transform("possessive", "en"),
+        Some(TransformId::EnglishPossessive)
+    );
     assert_eq!(resolve_transform("die", "de"), Some(TransformId::GermanDer));
     assert_eq!(resolve_transform("das", "de"), Some(TransformId::GermanDer));
     assert_eq!(
@@ -36,11 +50,44 @@ fn canonical_resolution_covers_all_transform_ids() {
         ("en", "cap", TransformId::Cap),
         ("en", "upper", TransformId::Upper),
         ("en", "lower", TransformId::Lower),
+        ("en", "date", TransformId::Date),
+        ("en", "time", TransformId::Time),
+        ("en", "spell", TransformId::Spell),
+        ("en", "currency", TransformId::Currency),
+        ("en", "num", TransformId::Num),
+        ("en", "sentence", TransformId::Sentence),
+        ("en", "list", TransformId::List),
+        ("en", "title", TransformId::Title),
+        ("en", "lang", TransformId::Lang),
+        ("en", "truncate_words", TransformId::TruncateWords),
+        ("en", "duration", TransformId::Duration),
+        ("en", "case_fold", TransformId::CaseFold),
+        ("en", "quote", TransformId::Quote),
+        ("en", "map", TransformId::Map),
+        ("en", "autocap", TransformId::AutoCap),
+        ("en", "strip_markup", TransformId::StripMarkup),
+        ("en", "escape_markup", TransformId::EscapeMarkup),
+        ("en", "formal", TransformId::Formal),
+        ("en", "informal", TransformId::Informal),
+        ("en", "superscript", TransformId::Super),
+        ("en", "subscript", TransformId::Sub),
+        ("en", "noop", TransformId::NoOp),
+        ("en", "break", TransformId::CompoundBreak),
+        ("en", "scientific", TransformId::Scientific),
+        ("en", "count_noun", TransformId::CountNoun),
+        ("en", "month", TransformId::Month),
+        ("en", "weekday", TransformId::Weekday),
         ("en", "a", TransformId::EnglishA),
         ("en", "the", TransformId::EnglishThe),
         ("en", "plural", TransformId::EnglishPlural),
+        ("en", "poss", TransformId::EnglishPossessive),
+        ("en", "genitive", TransformId::EnglishGenitive),
+        ("en", "count", TransformId::EnglishCount),
+        ("en", "pronoun", TransformId::EnglishPronoun),
         ("de", "der", TransformId::GermanDer),
         ("de", "ein", TransformId::GermanEin),
+        ("de", "kein", TransformId::GermanKein),
+        ("de", "genitive", TransformId::GermanGenitive),
         ("nl", "de", TransformId::DutchDe),
         ("nl", "een", TransformId::DutchEen),
         ("es", "el", TransformId::SpanishEl),
@@ -49,11 +96,13 @@ fn canonical_resolution_covers_all_transform_ids() {
         ("pt", "um", TransformId::PortugueseUm),
         ("pt", "de", TransformId::PortugueseDe),
         ("pt", "em", TransformId::PortugueseEm),
+        ("pt", "por", TransformId::PortuguesePor),
         ("fr", "le", TransformId::FrenchLe),
         ("fr", "un", TransformId::FrenchUn),
         ("fr", "de", TransformId::FrenchDe),
         ("fr", "au", TransformId::FrenchAu),
         ("fr", "liaison", TransformId::FrenchLiaison),
+        ("fr", "punct", TransformId::FrenchPunct),
         ("it", "il", TransformId::ItalianIl),
         ("it", "un", TransformId::ItalianUn),
         ("it", "di", TransformId::ItalianDi),
@@ -62,19 +111,28 @@ fn canonical_resolution_covers_all_transform_ids() {
         ("el", "enas", TransformId::GreekEnas),
         ("ro", "def", TransformId::RomanianDef),
         ("ar", "al", TransformId::ArabicAl),
+        ("ar", "connect", TransformId::ArabicConnect),
+        ("fa", "connect", TransformId::ArabicConnect),
         ("fa", "ezafe", TransformId::PersianEzafe),
+        ("ru", "neg", TransformId::RussianNeg),
         ("zh", "count", TransformId::ChineseCount),
         ("ja", "count", TransformId::JapaneseCount),
         ("ko", "count", TransformId::KoreanCount),
         ("vi", "count", TransformId::VietnameseCount),
         ("th", "count", TransformId::ThaiCount),
+        ("th", "wordbreak", TransformId::ThaiWordBreak),
         ("bn", "count", TransformId::BengaliCount),
         ("id", "plural", TransformId::IndonesianPlural),
+        ("ms", "count", TransformId::MalayCount),
         ("ko", "particle", TransformId::KoreanParticle),
         ("tr", "inflect", TransformId::TurkishInflect),
         ("fi", "inflect", TransformId::FinnishInflect),
         ("hu", "inflect", TransformId::HungarianInflect),
+        ("eu", "inflect", TransformId::BasqueInflect),
+        ("sw", "agree", TransformId::SwahiliAgree),
+        ("ga", "mutate", TransformId::IrishMutate),
         ("ja", "particle", TransformId::JapaneseParticle),
+        ("ja", "ruby", TransformId::JapaneseRuby),
         ("hi", "ka", TransformId::HindiKa),
         ("hi", "ko", TransformId::HindiKo),
         ("hi", "se", TransformId::HindiSe),
@@ -91,10 +149,88 @@ fn canonical_resolution_covers_all_transform_ids() {
             Some(expected),
             "expected {name} in {lang} to resolve to {expected:?}"
         );
+        assert_eq!(expected.canonical_name(), name);
         covered.insert(expected);
     }
 
-    assert_eq!(covered.len(), 48);
+    assert_eq!(covered.len(), 91);
+}
+
+#[test]
+fn universal_aliases_resolve_in_every_language() {
+    for lang in ["en", "fr", "ja", "xx"] {
+        assert_eq!(resolve_transform("id", lang), Some(TransformId::NoOp));
+        assert_eq!(resolve_transform("raw", lang), Some(TransformId::NoOp));
+    }
+    assert_eq!(TransformId::NoOp.aliases(), &["id", "raw"]);
+}
+
+#[test]
+fn reverse_mapping_for_german_der() {
+    let der = TransformId::GermanDer;
+    assert_eq!(der.canonical_name(), "der");
+    assert!(der.aliases().contains(&"die"));
+    assert!(der.aliases().contains(&"das"));
+    assert_eq!(der.languages(), &["de"]);
+}
+
+#[test]
+fn reverse_mapping_round_trips_through_resolution() {
+    for lang in [
+        "en", "de", "nl", "es", "pt", "fr", "it", "el", "hi", "ja", "ko",
+    ] {
+        for name in accepted_transform_names(lang) {
+            let id = resolve_transform(name, lang).unwrap();
+            if id.languages().is_empty() {
+                assert!(id.canonical_name() == *name || id.aliases().contains(name));
+                continue;
+            }
+            assert_eq!(id.languages(), &[lang], "{name} in {lang}");
+            assert!(
+                id.canonical_name() == *name || id.aliases().contains(name),
+                "{name} in {lang} missing from {id:?} reverse mapping"
+            );
+            for alias in id.aliases() {
+                assert_eq!(resolve_transform(alias, lang), Some(id));
+            }
+        }
+    }
+}
+
+#[test]
+fn dutch_het_alias_only_folds_in_dutch() {
+    assert_eq!(resolve_transform("het", "nl"), Some(TransformId::DutchDe));
+    assert_eq!(languages_with_transform("het"), vec!["nl"]);
+    for lang in ["de", "en", "pt", "fr", "", "nl-BE"] {
+        assert_eq!(resolve_transform("het", lang), None, "het in {lang}");
+    }
+}
+
+#[test]
+fn aliases_never_fold_outside_their_language() {
+    for lang in [
+        "en", "de", "nl", "es", "pt", "fr", "it", "el", "hi", "ja", "ko",
+    ] {
+        for name in accepted_transform_names(lang) {
+            let id = resolve_transform(name, lang).unwrap();
+            for alias in id.aliases() {
+                for other_lang in languages_with_transform(alias) {
+                    let other = resolve_transform(alias, other_lang).unwrap();
+                    assert!(
+                        other.canonical_name() == *alias || other.languages().contains(&other_lang),
+                        "@{alias} folds to {other:?} in {other_lang}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn universal_transforms_have_no_languages_or_aliases() {
+    assert_eq!(TransformId::Cap.canonical_name(), "cap");
+    assert!(TransformId::Cap.languages().is_empty());
+    assert!(TransformId::Cap.aliases().is_empty());
 }
 
 #[test]
@@ -107,8 +243,102 @@ fn accepted_name_discovery_includes_english_alias_and_plural() {
 
 #[test]
 fn unknown_language_accepts_universal_only() {
-    let universal = &["cap", "upper", "lower"];
+    let universal = &[
+        "cap",
+        "upper",
+        "lower",
+        "date",
+        "time",
+        "spell",
+        "currency",
+        "num",
+        "sentence",
+        "list",
+        "title",
+        "lang",
+        "truncate_words",
+        "duration",
+        "case_fold",
+        "quote",
+        "map",
+        "autocap",
+        "strip_markup",
+        "escape_markup",
+        "formal",
+        "informal",
+        "superscript",
+        "subscript",
+        "noop",
+        "id",
+        "raw",
+        "break",
+        "scientific",
+        "count_noun",
+        "month",
+        "weekday",
+    ];
     assert_eq!(accepted_transform_names("xx"), universal);
     assert_eq!(resolve_transform("cap", "xx"), Some(TransformId::Cap));
     assert_eq!(resolve_transform("a", "xx"), None);
 }
+
+#[test]
+fn reverse_lookup_finds_languages_defining_a_transform() {
+    assert_eq!(languages_with_transform("der"), ["de"]);
+    assert_eq!(languages_with_transform("la"), ["es", "fr", "it"]);
+    assert!(languages_with_transform("cap").is_empty());
+    assert!(languages_with_transform("bogus").is_empty());
+}
+
+#[test]
+fn audit_reports_only_intentional_shared_names() {
+    let collisions = audit();
+    let mut shared = Vec::new();
+    for collision in &collisions {
+        match collision {
+            Collision::Shared { name, .. } => shared.push(*name),
+            other => panic!("unexpected collision: {other:?}"),
+        }
+    }
+    assert_eq!(
+        shared,
+        [
+            "a", "plural", "genitive", "count", "de", "la", "un", "una", "o", "particle",
+            "inflect",
+        ]
+    );
+}
+
+#[test]
+fn transform_id_display_and_parse() {
+    assert_eq!(TransformId::Cap.to_string(), "cap");
+    assert_eq!("cap".parse::<TransformId>(), Ok(TransformId::Cap));
+    assert_eq!(TransformId::GermanDer.to_string(), "der");
+    assert_eq!("der".parse::<TransformId>(), Ok(TransformId::GermanDer));
+    assert_eq!(
+        "die".parse::<TransformId>(),
+        Err(ParseTransformIdError::Unknown("die".to_string()))
+    );
+    assert_eq!("raw".parse::<TransformId>(), Ok(TransformId::NoOp));
+    assert_eq!(
+        "de".parse::<TransformId>(),
+        Err(ParseTransformIdError::Ambiguous {
+            name: "de".to_string(),
+            languages: vec!["nl", "pt", "fr"],
+        })
+    );
+}
+
+#[test]
+fn unambiguous_canonical_names_round_trip() {
+    for lang in ["en", "de", "fr", "ro", "ar", "fa", "ru", "sw", "hi"] {
+        for name in accepted_transform_names(lang) {
+            let Some(id) = resolve_transform(name, lang) else {
+                continue;
+            };
+            if languages_with_transform(id.canonical_name()).len() <= 1 {
+                assert_eq!(id.to_string().parse::<TransformId>(), Ok(id));
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Benchmark for `@cap` on ASCII and non-ASCII input.
+//!
+//! Run with `cargo bench -p rlf --bench cap`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use rlf::Value;
+use rlf::interpreter::TransformKind;
+
+const ITERATIONS: u32 = 200_000;
+
+fn bench(name: &str, text: &str, lang: &str) {
+    let value = Value::from(text);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let result = TransformKind::Cap.execute(black_box(&value), None, black_box(lang));
+        black_box(result.unwrap());
+    }
+    let per_call = start.elapsed() / ITERATIONS;
+    println!("{name:<24} {per_call:>10.2?}/call");
+}
+
+fn main() {
+    bench("ascii", "card", "en");
+    bench("ascii markup", "<b>card</b>", "en");
+    bench("ascii turkish", "card", "tr");
+    bench("non-ascii", "élan", "fr");
+}
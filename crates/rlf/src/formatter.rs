@@ -1,8 +1,10 @@
-/// Formats RLF definition files and definition bodies within Rust files.
-///
-/// Uses text-level processing rather than AST parsing because `.rs` files
-/// contain Rust escape sequences (e.g., `\u{25CF}`) that the RLF parser
-/// would misinterpret, and comments need to be preserved.
+//! Formats RLF definition files and definition bodies within Rust files.
+//!
+//! Uses text-level processing rather than AST parsing because `.rs` files
+//! contain Rust escape sequences (e.g., `\u{25CF}`) that the RLF parser
+//! would misinterpret, and comments need to be preserved.
+
+use crate::parser::is_attribute_line;
 
 /// Formats a complete `.rlf` file, wrapping lines to `max_width` characters.
 pub fn format_file(input: &str, max_width: usize) -> String {
@@ -63,8 +65,8 @@ fn split_into_chunks(input: &str, base_indent: &str) -> Vec<Chunk> {
 
         if trimmed.is_empty() {
             chunks.push(Chunk::BlankLine);
-        } else if trimmed.starts_with("//") {
-            // Preserve comment with proper indentation
+        } else if trimmed.starts_with("//") || is_attribute_line(trimmed) {
+            // Preserve comment or attribute line with proper indentation
             let comment = if base_indent.is_empty() {
                 trimmed.to_string()
             } else {
@@ -282,11 +284,11 @@ fn try_expand_blocks(definition: &str, base_indent: &str, max_width: usize) -> O
 
         // Check if this entry itself contains a nested block that needs expansion
         let entry_line = format!("{indent}{trimmed},");
-        if entry_line.len() > max_width {
-            if let Some(expanded) = try_expand_nested_entry(trimmed, &indent, max_width) {
-                formatted_entries.push(expanded);
-                continue;
-            }
+        if entry_line.len() > max_width
+            && let Some(expanded) = try_expand_nested_entry(trimmed, &indent, max_width)
+        {
+            formatted_entries.push(expanded);
+            continue;
         }
         formatted_entries.push(format!("{indent}{trimmed},"));
     }
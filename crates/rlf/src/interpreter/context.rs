@@ -5,7 +5,7 @@ use std::mem;
 
 use crate::interpreter::EvalError;
 use crate::interpreter::error::EvalWarning;
-use crate::types::Value;
+use crate::types::{Value, VariantKey};
 
 /// Evaluation context carrying state through recursive evaluation.
 ///
@@ -41,6 +41,12 @@ pub struct EvalContext<'a> {
     /// the "missing selector" lint because `:from` binds the correct
     /// variant automatically.
     from_context: HashSet<String>,
+    /// Whether the template being evaluated starts a sentence in the output,
+    /// read by `@autocap`.
+    sentence_start: bool,
+    /// Key of the first variant a parameter selector picked in the phrase
+    /// being evaluated, reported by [`Phrase::selected_variant`](crate::Phrase::selected_variant).
+    selected_variant: Option<VariantKey>,
 }
 
 impl<'a> EvalContext<'a> {
@@ -54,6 +60,8 @@ impl<'a> EvalContext<'a> {
             string_context: None,
             warnings: Vec::new(),
             from_context: HashSet::new(),
+            sentence_start: true,
+            selected_variant: None,
         }
     }
 
@@ -67,6 +75,8 @@ impl<'a> EvalContext<'a> {
             string_context: None,
             warnings: Vec::new(),
             from_context: HashSet::new(),
+            sentence_start: true,
+            selected_variant: None,
         }
     }
 
@@ -83,6 +93,8 @@ impl<'a> EvalContext<'a> {
             string_context,
             warnings: Vec::new(),
             from_context: HashSet::new(),
+            sentence_start: true,
+            selected_variant: None,
         }
     }
 
@@ -171,4 +183,30 @@ impl<'a> EvalContext<'a> {
     pub fn is_in_from_context(&self, param: &str) -> bool {
         self.from_context.contains(param)
     }
+
+    /// Whether the text being evaluated starts a sentence in the final output.
+    pub(crate) fn at_sentence_start(&self) -> bool {
+        self.sentence_start
+    }
+
+    /// Record whether the text about to be evaluated starts a sentence.
+    pub(crate) fn set_sentence_start(&mut self, sentence_start: bool) {
+        self.sentence_start = sentence_start;
+    }
+
+    /// Record the key of a variant picked by a parameter selector, unless
+    /// the phrase being evaluated has already picked one.
+    pub(crate) fn record_selected_variant(&mut self, key: &VariantKey) {
+        if self.selected_variant.is_none() {
+            self.selected_variant = Some(key.clone());
+        }
+    }
+
+    /// Replace the recorded variant key, returning the previous one.
+    pub(crate) fn replace_selected_variant(
+        &mut self,
+        key: Option<VariantKey>,
+    ) -> Option<VariantKey> {
+        mem::replace(&mut self.selected_variant, key)
+    }
 }
@@ -1,12 +1,17 @@
 //! Error types for the RLF interpreter.
 
+use std::collections::BTreeMap;
+use std::error::Error;
 use std::fmt;
 use std::io;
+use std::ops::Range;
 use std::path::PathBuf;
 
 use strsim::levenshtein;
 use thiserror::Error;
 
+use crate::parser::ParseError;
+
 /// Compute "did you mean" suggestions for a key using Levenshtein distance.
 ///
 /// Returns up to 3 suggestions with edit distance <= 2 (or <= 1 for short keys).
@@ -56,6 +61,9 @@ pub enum LoadError {
         line: usize,
         column: usize,
         message: String,
+        /// The underlying `ParseError` or `EvalError`, if any.
+        #[source]
+        source: Option<Box<dyn Error + Send + Sync>>,
     },
 
     /// Attempted to reload translations that were loaded from a string.
@@ -63,6 +71,106 @@ pub enum LoadError {
     NoPathForReload { language: String },
 }
 
+impl LoadError {
+    /// Attach a file path to a parse error.
+    pub(crate) fn from_parse_error(error: ParseError, path: PathBuf) -> Self {
+        let (line, column, message) = match &error {
+            ParseError::Syntax {
+                line,
+                column,
+                message,
+            } => (*line, *column, message.clone()),
+            ParseError::UnexpectedEof { line, column } => {
+                (*line, *column, "unexpected end of file".to_string())
+            }
+            ParseError::InvalidUtf8 => (0, 0, "invalid UTF-8".to_string()),
+        };
+        LoadError::Parse {
+            path,
+            line,
+            column,
+            message,
+            source: Some(Box::new(error)),
+        }
+    }
+
+    /// Attach a file path to an error from registering a parsed definition.
+    pub(crate) fn from_registry_error(error: EvalError, path: PathBuf) -> Self {
+        LoadError::Parse {
+            path,
+            line: 0,
+            column: 0,
+            message: error.to_string(),
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+/// Errors reported by `verify_translations()`.
+#[derive(Debug, Error)]
+pub enum VerifyError {
+    /// The source or a required translation file could not be read or parsed.
+    #[error(transparent)]
+    Load(#[from] LoadError),
+
+    /// Required languages are missing phrases defined in the source language.
+    #[error("missing translations:{}", format_missing(missing))]
+    MissingPhrases {
+        /// Missing phrase names, sorted, keyed by language.
+        missing: BTreeMap<String, Vec<String>>,
+    },
+}
+
+/// A problem found by `validate_template()`.
+///
+/// Every variant carries the byte range of the offending text in the template
+/// source, for highlighting in editors.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TemplateError {
+    /// The template is malformed, e.g. an unclosed `{` or an empty reference.
+    #[error("syntax error at byte {}: {message}", span.start)]
+    Syntax { span: Range<usize>, message: String },
+
+    /// No language defines a transform with this name.
+    #[error("unknown transform '@{name}'{}", format_suggestions(suggestions))]
+    UnknownTransform {
+        name: String,
+        span: Range<usize>,
+        /// Similar transform names accepted for the language.
+        suggestions: Vec<String>,
+    },
+
+    /// The transform exists, but only for other languages.
+    #[error(
+        "transform '@{name}' is not available for '{language}'; it is defined for: {}",
+        available_in.join(", ")
+    )]
+    WrongLanguageTransform {
+        name: String,
+        language: String,
+        span: Range<usize>,
+        available_in: Vec<String>,
+    },
+}
+
+impl TemplateError {
+    /// Byte range of the problem in the template source.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            TemplateError::Syntax { span, .. }
+            | TemplateError::UnknownTransform { span, .. }
+            | TemplateError::WrongLanguageTransform { span, .. } => span.clone(),
+        }
+    }
+}
+
+fn format_missing(missing: &BTreeMap<String, Vec<String>>) -> String {
+    missing
+        .iter()
+        .map(|(language, names)| format!("\n  {language}: {}", names.join(", ")))
+        .collect()
+}
+
 /// A warning produced during translation linting.
 ///
 /// Warnings indicate potential issues in translation files that do not prevent
@@ -104,6 +212,65 @@ pub enum LoadWarning {
         /// Language code of the translation.
         language: String,
     },
+    /// Template text contains a hardcoded ordinal or grouped number.
+    ///
+    /// Produced only by the pedantic `lint_literal_numbers()` pass.
+    LiteralNumber {
+        /// Name of the phrase.
+        name: String,
+        /// Language code of the translation.
+        language: String,
+        /// The literal text that was flagged (e.g. `3rd` or `1,000`).
+        text: String,
+        /// The transform that should format the number instead.
+        suggestion: String,
+    },
+    /// Variant or match key names a plural category the language never uses.
+    UnreachablePluralCategory {
+        /// Name of the phrase.
+        name: String,
+        /// Language code of the translation.
+        language: String,
+        /// The unreachable key (e.g. `few` in English).
+        key: String,
+    },
+
+    /// Phrase text is longer than its `@maxlen` attribute allows.
+    ExceedsMaxLength {
+        /// Name of the phrase.
+        name: String,
+        /// Language code of the translation.
+        language: String,
+        /// Visible characters in the rendered text.
+        length: usize,
+        /// The declared `@maxlen` limit.
+        max: usize,
+    },
+    /// Template uses a transform that only exists in other languages.
+    ///
+    /// Produced only by the pedantic `lint_mixed_language()` pass.
+    WrongLanguageTransform {
+        /// Name of the phrase.
+        name: String,
+        /// Language code of the translation.
+        language: String,
+        /// The transform name, without `@`.
+        transform: String,
+        /// Languages that define the transform.
+        available_in: Vec<String>,
+    },
+    /// Phrase text is identical to the reference language's, so it was
+    /// probably never translated.
+    ///
+    /// Produced only by the pedantic `lint_mixed_language()` pass.
+    UntranslatedText {
+        /// Name of the phrase.
+        name: String,
+        /// Language code of the translation.
+        language: String,
+        /// Language code of the reference it matches.
+        reference: String,
+    },
 }
 
 impl fmt::Display for LoadWarning {
@@ -142,10 +309,66 @@ impl fmt::Display for LoadWarning {
                     "warning: phrase '{name}' in '{language}' uses ':from($p) \"{{$p}}\"'; use body-less ':from($p);' instead"
                 )
             }
+            LoadWarning::LiteralNumber {
+                name,
+                language,
+                text,
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "warning: phrase '{name}' in '{language}' contains literal number '{text}'; pass it as a parameter and format it with @{suggestion}"
+                )
+            }
+            LoadWarning::UnreachablePluralCategory {
+                name,
+                language,
+                key,
+            } => {
+                write!(
+                    f,
+                    "warning: phrase '{name}' in '{language}' has key '{key}', which '{language}' plural rules never select"
+                )
+            }
+            LoadWarning::ExceedsMaxLength {
+                name,
+                language,
+                length,
+                max,
+            } => {
+                write!(
+                    f,
+                    "warning: phrase '{name}' in '{language}' is {length} characters long, over its @maxlen({max})"
+                )
+            }
+            LoadWarning::WrongLanguageTransform {
+                name,
+                language,
+                transform,
+                available_in,
+            } => {
+                write!(
+                    f,
+                    "warning: phrase '{name}' in '{language}' uses @{transform}, which is only defined for {}; it may have been copied from another language",
+                    available_in.join(", ")
+                )
+            }
+            LoadWarning::UntranslatedText {
+                name,
+                language,
+                reference,
+            } => {
+                write!(
+                    f,
+                    "warning: phrase '{name}' in '{language}' is identical to '{reference}'; it may be untranslated"
+                )
+            }
         }
     }
 }
 
+impl Error for LoadWarning {}
+
 /// A warning produced during phrase evaluation.
 ///
 /// Runtime warnings indicate potential issues detected during evaluation
@@ -228,6 +451,15 @@ pub enum EvalError {
         phrase: String,
     },
 
+    /// `@count` found another language's classifier tag instead of one of
+    /// this language's.
+    #[error("classifier tag ':{tag}' is not a classifier in '{lang}', known: {}", known.join(", "))]
+    UnknownClassifier {
+        lang: String,
+        tag: String,
+        known: Vec<String>,
+    },
+
     /// Wrong number of arguments passed to phrase call.
     #[error("phrase '{phrase}' expects {expected} arguments, got {got}")]
     ArgumentCount {
@@ -236,6 +468,23 @@ pub enum EvalError {
         got: usize,
     },
 
+    /// Argument does not match the declared parameter type (`$n: num`).
+    #[error("phrase '{phrase}' parameter '${parameter}' expects {expected}, got '{value}'")]
+    TypeMismatch {
+        phrase: String,
+        parameter: String,
+        expected: String,
+        value: String,
+    },
+
+    /// Named argument does not match any parameter of the called phrase.
+    #[error("phrase '{phrase}' has no parameter '${name}'")]
+    UnknownArgument { phrase: String, name: String },
+
+    /// Named argument given for a parameter that already has a value.
+    #[error("argument '${name}' passed twice to phrase '{phrase}'")]
+    DuplicateArgument { phrase: String, name: String },
+
     /// Cyclic reference detected during evaluation.
     #[error("cyclic reference detected: {}", chain.join(" -> "))]
     CyclicReference { chain: Vec<String> },
@@ -248,6 +497,16 @@ pub enum EvalError {
     #[error("unknown transform '@{name}'")]
     UnknownTransform { name: String },
 
+    /// Transform exists, but only for other languages.
+    #[error(
+        "transform '@{name}' is not available for this language; it is defined for: {}",
+        available_in.join(", ")
+    )]
+    WrongLanguageTransform {
+        name: String,
+        available_in: Vec<String>,
+    },
+
     /// Term cannot be called with arguments.
     #[error(
         "'{name}' is a term — cannot use () call syntax; use {{{}:variant}} or {{{}:$param}} to select a variant",
@@ -271,4 +530,12 @@ pub enum EvalError {
     /// Parameter not found in scope.
     #[error("unknown parameter '${name}' — not in scope")]
     UnknownParameter { name: String },
+
+    /// Transform received a value it cannot operate on.
+    #[error("transform '@{transform}' expects {expected}, got '{value}'")]
+    InvalidTransformInput {
+        transform: String,
+        expected: String,
+        value: String,
+    },
 }
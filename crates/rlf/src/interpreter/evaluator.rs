@@ -6,13 +6,20 @@
 
 use std::collections::HashMap;
 
+use rlf_semantics::languages_with_transform;
+
 use crate::interpreter::error::{EvalWarning, compute_suggestions};
-use crate::interpreter::plural::plural_category;
-use crate::interpreter::transforms::TransformRegistry;
+use crate::interpreter::marks::{self, PROTECT_TAG};
+use crate::interpreter::plural::ordinal_category;
+use crate::interpreter::pseudo;
+use crate::interpreter::transforms::{
+    LookupEntry, OutputTarget, TransformKind, TransformRegistry, lang_override,
+};
 use crate::interpreter::{EvalContext, EvalError, PhraseRegistry};
 use crate::parser::ast::{
-    DefinitionKind, MatchBranch, PhraseBody, PhraseDefinition, Reference, Segment, Selector,
-    Template, Transform, TransformContext, VariantEntry, VariantEntryBody,
+    DefinitionKind, MatchBranch, ParamType, PhraseBody, PhraseDefinition, Reference, Segment,
+    Selector, Template, Transform, TransformContext, VariantEntry, VariantEntryBody,
+    parse_numeric_range,
 };
 use crate::types::{Phrase, Tag, Value, VariantKey};
 
@@ -44,17 +51,36 @@ pub fn eval_template(
     registry: &PhraseRegistry,
     transform_registry: &TransformRegistry,
     lang: &str,
+) -> Result<String, EvalError> {
+    render_template(template, ctx, registry, transform_registry, lang)
+        .map(|text| marks::strip(&text).into_owned())
+}
+
+/// Evaluate a template, keeping the span markers of [`marks`] in the output
+/// for the enclosing evaluation.
+pub(crate) fn render_template(
+    template: &Template,
+    ctx: &mut EvalContext<'_>,
+    registry: &PhraseRegistry,
+    transform_registry: &TransformRegistry,
+    lang: &str,
 ) -> Result<String, EvalError> {
     let mut output = String::new();
+    let template_starts_sentence = ctx.at_sentence_start();
     for segment in &template.segments {
         match segment {
+            Segment::Literal(s) if transform_registry.pseudo_localize() => {
+                output.push_str(&pseudo::accent(s));
+            }
             Segment::Literal(s) => output.push_str(s),
             Segment::Interpolation {
                 transforms,
                 reference,
                 selectors,
             } => {
-                // 1. Resolve reference to Value
+                // 1. Resolve reference to Value, telling nested phrases and
+                // @autocap whether they start a sentence
+                ctx.set_sentence_start(starts_sentence(&output, template_starts_sentence));
                 let value = resolve_reference(reference, ctx, registry, transform_registry, lang)?;
 
                 // Lint 5: Check for bare parameter reference to Phrase with
@@ -75,24 +101,214 @@ pub fn eval_template(
                 }
 
                 // 2. Apply selectors to get variant/final value (returns Value to preserve tags)
-                let selected = apply_selectors(&value, selectors, ctx, lang)?;
+                let selected = marks::remark(apply_selectors(
+                    &value,
+                    selectors,
+                    ctx,
+                    registry,
+                    transform_registry,
+                    lang,
+                )?);
                 // 3. Apply transforms (right-to-left per DESIGN.md)
                 // Pass Value directly so transforms can access tags on first call
-                let transformed =
-                    apply_transforms(&selected, transforms, transform_registry, ctx, lang)?;
-                output.push_str(&transformed);
+                let transformed = apply_transforms(
+                    &selected,
+                    transforms,
+                    registry,
+                    transform_registry,
+                    ctx,
+                    lang,
+                )?;
+                ctx.set_sentence_start(template_starts_sentence);
+                // 4. Mark :protect phrases so enclosing case transforms skip them
+                let transformed = match &selected {
+                    Value::Phrase(phrase) if phrase.has_tag(PROTECT_TAG) => {
+                        marks::protect(&transformed)
+                    }
+                    _ => transformed,
+                };
+                // 5. Escape caller-supplied values for the output target, and
+                // mark them as plain text that is never markup
+                if is_caller_supplied(reference)
+                    && !is_evaluated_phrase(&value)
+                    && !uses_lookup_table(transforms, transform_registry, lang)
+                {
+                    if transform_registry.output_target() == OutputTarget::Html {
+                        let mut escaped = String::with_capacity(transformed.len());
+                        push_html_escaped(&mut escaped, &transformed);
+                        output.push_str(&marks::plain(&escaped));
+                    } else {
+                        output.push_str(&marks::plain(&transformed));
+                    }
+                } else {
+                    output.push_str(&transformed);
+                }
+            }
+            Segment::Conditional { parameter, body } => {
+                // Render the body only for a bound, non-empty parameter
+                let present = ctx
+                    .get_param(parameter)
+                    .is_some_and(|value| !value.to_string().is_empty());
+                if present {
+                    ctx.set_sentence_start(starts_sentence(&output, template_starts_sentence));
+                    output.push_str(&render_template(
+                        body,
+                        ctx,
+                        registry,
+                        transform_registry,
+                        lang,
+                    )?);
+                    ctx.set_sentence_start(template_starts_sentence);
+                }
             }
         }
     }
     Ok(output)
 }
 
+/// Whether text following `output` starts a sentence.
+///
+/// Trailing whitespace and markup tags are ignored. Empty output starts a
+/// sentence if the enclosing template does; otherwise a line break or
+/// sentence-ending punctuation does.
+fn starts_sentence(output: &str, template_starts_sentence: bool) -> bool {
+    let stripped = marks::strip(output);
+    let mut visible = stripped.as_ref();
+    loop {
+        let trimmed = visible.trim_end();
+        if trimmed.len() < visible.len() && visible[trimmed.len()..].contains('\n') {
+            return true;
+        }
+        match trimmed.strip_suffix('>').and_then(|rest| rest.rfind('<')) {
+            Some(tag_start) => visible = &trimmed[..tag_start],
+            None => {
+                visible = trimmed;
+                break;
+            }
+        }
+    }
+    match visible.chars().last() {
+        None => template_starts_sentence,
+        Some(c) => matches!(c, '.' | '!' | '?' | '…' | '。' | '！' | '？'),
+    }
+}
+
+/// Whether a value is RLF output whose markup is trusted: a phrase, or a list
+/// made only of phrases.
+fn is_evaluated_phrase(value: &Value) -> bool {
+    match value {
+        Value::Phrase(_) => true,
+        Value::List(items) => !items.is_empty() && items.iter().all(is_evaluated_phrase),
+        _ => false,
+    }
+}
+
+/// Whether an interpolation goes through `@map`, whose output comes from a
+/// lookup table rather than the parameter value.
+fn uses_lookup_table(
+    transforms: &[Transform],
+    transform_registry: &TransformRegistry,
+    lang: &str,
+) -> bool {
+    transforms
+        .iter()
+        .any(|transform| transform_registry.get(&transform.name, lang) == Some(TransformKind::Map))
+}
+
+/// Escape the caller-supplied text in a transform context for the HTML
+/// output target.
+///
+/// Transforms such as `@genitive($owner)` write their context into the
+/// output. Strings, and the strings in a list, are escaped; phrases are RLF
+/// output whose markup is trusted, and numbers have nothing to escape.
+fn escape_caller_value(value: Value) -> Value {
+    match value {
+        Value::String(text) => {
+            let mut escaped = String::with_capacity(text.len());
+            push_html_escaped(&mut escaped, &text);
+            Value::String(escaped)
+        }
+        Value::List(items) => Value::List(items.into_iter().map(escape_caller_value).collect()),
+        other => other,
+    }
+}
+
+/// Append `text` with the HTML special characters escaped.
+fn push_html_escaped(output: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            _ => output.push(c),
+        }
+    }
+}
+
+/// Order call arguments to match the parameters of `def`.
+///
+/// Named arguments bind to the parameter with their name; positional
+/// arguments fill the remaining parameters in order. The caller has already
+/// checked that the argument count matches.
+fn bind_arguments<'a>(
+    phrase: &str,
+    def: &PhraseDefinition,
+    args: &'a [Reference],
+) -> Result<Vec<&'a Reference>, EvalError> {
+    let mut bound: Vec<Option<&Reference>> = vec![None; def.parameters.len()];
+    for arg in args {
+        if let Reference::Named { name, value } = arg {
+            let index = def
+                .parameters
+                .iter()
+                .position(|param| param == name)
+                .ok_or_else(|| EvalError::UnknownArgument {
+                    phrase: phrase.to_string(),
+                    name: name.clone(),
+                })?;
+            if bound[index].replace(value).is_some() {
+                return Err(EvalError::DuplicateArgument {
+                    phrase: phrase.to_string(),
+                    name: name.clone(),
+                });
+            }
+        }
+    }
+    let mut positional = args
+        .iter()
+        .filter(|arg| !matches!(arg, Reference::Named { .. }));
+    Ok(bound
+        .into_iter()
+        .map(|slot| {
+            slot.or_else(|| positional.next())
+                .expect("argument count matches parameter count")
+        })
+        .collect())
+}
+
+/// Whether a reference can render a caller-supplied parameter value.
+///
+/// A coalesce counts when any alternative is a parameter, so a literal
+/// fallback next to a parameter is escaped as well.
+fn is_caller_supplied(reference: &Reference) -> bool {
+    match reference {
+        Reference::Parameter(_) => true,
+        Reference::Coalesce(alternatives) => alternatives.iter().any(is_caller_supplied),
+        _ => false,
+    }
+}
+
 /// Resolve a reference to a Value.
 ///
 /// Uses the AST distinction between parameters and identifiers:
 /// - `Reference::Parameter(name)` → look up in current parameter bindings
 /// - `Reference::Identifier(name)` → look up as a term/phrase in the registry
 /// - `Reference::PhraseCall { name, args }` → evaluate phrase call
+/// - `Reference::Coalesce(alternatives)` → first present, non-empty alternative;
+///   unbound parameters and undefined terms and phrases are skipped
+/// - `Reference::Named { value, .. }` → the value (names are bound by the call)
 ///
 /// No implicit fallback: parameters never check the registry, and identifiers
 /// never check parameter bindings.
@@ -124,12 +340,37 @@ fn resolve_reference(
 
             // Evaluate the term
             ctx.push_call(name)?;
-            let result = eval_phrase_def(def, ctx, registry, transform_registry, lang)?;
+            let result = render_phrase_def(def, ctx, registry, transform_registry, lang)?;
             ctx.pop_call();
             Ok(Value::Phrase(result))
         }
         Reference::NumberLiteral(n) => Ok(Value::Number(*n)),
         Reference::StringLiteral(s) => Ok(Value::String(s.clone())),
+        Reference::Coalesce(alternatives) => {
+            let mut last = Value::String(String::new());
+            for alternative in alternatives {
+                let absent = match alternative {
+                    Reference::Parameter(name) => ctx.get_param(name).is_none(),
+                    Reference::Identifier(name) | Reference::PhraseCall { name, .. } => {
+                        registry.get(name).is_none()
+                    }
+                    _ => false,
+                };
+                if absent {
+                    continue;
+                }
+                let value =
+                    resolve_reference(alternative, ctx, registry, transform_registry, lang)?;
+                if !value.to_string().is_empty() {
+                    return Ok(value);
+                }
+                last = value;
+            }
+            Ok(last)
+        }
+        Reference::Named { value, .. } => {
+            resolve_reference(value, ctx, registry, transform_registry, lang)
+        }
         Reference::PhraseCall { name, args } => {
             let def = registry
                 .get(name)
@@ -149,11 +390,12 @@ fn resolve_reference(
                 });
             }
 
-            // Resolve arguments to values
-            let resolved_args: Vec<Value> = args
-                .iter()
+            // Resolve arguments to values, in parameter order
+            let resolved_args: Vec<Value> = bind_arguments(name, def, args)?
+                .into_iter()
                 .map(|arg| resolve_reference(arg, ctx, registry, transform_registry, lang))
                 .collect::<Result<Vec<_>, _>>()?;
+            check_argument_types(name, def, &resolved_args)?;
 
             // Lint 6: Check if phrase without :from receives Phrase arguments
             if def.from_param.is_none() {
@@ -181,8 +423,10 @@ fn resolve_reference(
                 &params,
                 ctx.string_context().map(ToString::to_string),
             );
+            child_ctx.set_sentence_start(ctx.at_sentence_start());
             child_ctx.push_call(name)?;
-            let result = eval_phrase_def(def, &mut child_ctx, registry, transform_registry, lang)?;
+            let result =
+                render_phrase_def(def, &mut child_ctx, registry, transform_registry, lang)?;
             child_ctx.pop_call();
             ctx.merge_warnings_from(&mut child_ctx);
 
@@ -203,6 +447,18 @@ pub fn eval_phrase_def(
     registry: &PhraseRegistry,
     transform_registry: &TransformRegistry,
     lang: &str,
+) -> Result<Phrase, EvalError> {
+    render_phrase_def(def, ctx, registry, transform_registry, lang).map(marks::finish)
+}
+
+/// Evaluate a phrase definition, keeping the span markers of [`marks`] in
+/// its text and variants for the enclosing evaluation.
+pub(crate) fn render_phrase_def(
+    def: &PhraseDefinition,
+    ctx: &mut EvalContext<'_>,
+    registry: &PhraseRegistry,
+    transform_registry: &TransformRegistry,
+    lang: &str,
 ) -> Result<Phrase, EvalError> {
     // Convert definition tags to Phrase tags
     let tags: Vec<Tag> = def.tags.clone();
@@ -214,8 +470,11 @@ pub fn eval_phrase_def(
 
     match &def.body {
         PhraseBody::Simple(template) => {
-            let text = eval_template(template, ctx, registry, transform_registry, lang)?;
-            Ok(Phrase::builder().text(text).tags(tags).build())
+            let outer = ctx.replace_selected_variant(None);
+            let text = render_template(template, ctx, registry, transform_registry, lang)?;
+            let mut phrase = Phrase::builder().text(text).tags(tags).build();
+            phrase.selected_variant = ctx.replace_selected_variant(outer);
+            Ok(phrase)
         }
         PhraseBody::Variants(entries) => {
             let (text, variants) =
@@ -227,7 +486,7 @@ pub fn eval_phrase_def(
                 .build())
         }
         PhraseBody::Match(branches) => {
-            let text = eval_match_branches(
+            let (text, selected) = eval_match_branches(
                 branches,
                 &def.match_params,
                 ctx,
@@ -235,7 +494,9 @@ pub fn eval_phrase_def(
                 transform_registry,
                 lang,
             )?;
-            Ok(Phrase::builder().text(text).tags(tags).build())
+            let mut phrase = Phrase::builder().text(text).tags(tags).build();
+            phrase.selected_variant = Some(selected);
+            Ok(phrase)
         }
     }
 }
@@ -303,7 +564,7 @@ fn eval_with_from_modifier(
         let mut variants = HashMap::new();
 
         // Evaluate for default text first
-        let default_text = eval_template(template, ctx, registry, transform_registry, lang)?;
+        let default_text = render_template(template, ctx, registry, transform_registry, lang)?;
 
         // Evaluate for each variant (sorted keys for deterministic order)
         let mut sorted_keys: Vec<_> = source_variants.keys().collect();
@@ -337,7 +598,7 @@ fn eval_with_from_modifier(
             );
             variant_ctx.add_from_context(from_param);
 
-            let variant_result = eval_template(
+            let variant_result = render_template(
                 template,
                 &mut variant_ctx,
                 registry,
@@ -355,7 +616,7 @@ fn eval_with_from_modifier(
             .build())
     } else {
         // No variants - just evaluate the template
-        let text = eval_template(template, ctx, registry, transform_registry, lang)?;
+        let text = render_template(template, ctx, registry, transform_registry, lang)?;
         Ok(Phrase::builder().text(text).tags(inherited_tags).build())
     }
 }
@@ -496,7 +757,7 @@ fn eval_variant_entry_body(
 ) -> Result<String, EvalError> {
     match body {
         VariantEntryBody::Template(template) => {
-            eval_template(template, ctx, registry, transform_registry, lang)
+            render_template(template, ctx, registry, transform_registry, lang)
         }
         VariantEntryBody::Match {
             match_params,
@@ -508,7 +769,8 @@ fn eval_variant_entry_body(
             registry,
             transform_registry,
             lang,
-        ),
+        )
+        .map(|(text, _)| text),
     }
 }
 
@@ -577,7 +839,7 @@ fn eval_from_with_match(
     ctx.add_from_context(from_param);
 
     // Evaluate match for default text
-    let default_text = eval_match_branches(
+    let (default_text, selected) = eval_match_branches(
         branches,
         &def.match_params,
         ctx,
@@ -620,7 +882,7 @@ fn eval_from_with_match(
                 ctx.string_context().map(ToString::to_string),
             );
             variant_ctx.add_from_context(from_param);
-            let variant_result = eval_match_branches(
+            let (variant_result, _) = eval_match_branches(
                 branches,
                 &def.match_params,
                 &mut variant_ctx,
@@ -632,16 +894,20 @@ fn eval_from_with_match(
             variants.insert(key.clone(), variant_result);
         }
 
-        Ok(Phrase::builder()
+        let mut phrase = Phrase::builder()
             .text(default_text)
             .variants(variants)
             .tags(inherited_tags)
-            .build())
+            .build();
+        phrase.selected_variant = Some(selected);
+        Ok(phrase)
     } else {
-        Ok(Phrase::builder()
+        let mut phrase = Phrase::builder()
             .text(default_text)
             .tags(inherited_tags)
-            .build())
+            .build();
+        phrase.selected_variant = Some(selected);
+        Ok(phrase)
     }
 }
 
@@ -656,10 +922,17 @@ fn eval_from_with_match(
 ///
 /// If no selectors are present, returns the original Value unchanged,
 /// preserving Phrase type with its tags for transform access.
+///
+/// A plain string value whose selectors are all tags declared by some
+/// definition in `registry` (or `:protect`) is treated as a phrase carrying
+/// those tags, so `{@der $name:masc}` works on strings. Any other selector on
+/// a string is a missing variant, so a typo like `{$name:mask}` is an error.
 fn apply_selectors(
     value: &Value,
     selectors: &[Selector],
-    ctx: &EvalContext<'_>,
+    ctx: &mut EvalContext<'_>,
+    registry: &PhraseRegistry,
+    transform_registry: &TransformRegistry,
     lang: &str,
 ) -> Result<Value, EvalError> {
     if selectors.is_empty() {
@@ -680,11 +953,29 @@ fn apply_selectors(
         };
     }
 
+    // Declared tags on a plain string attach inline tags (`{@der $name:masc}`)
+    if let Value::String(text) = value
+        && let Some(tags) = inline_tags(selectors, registry)
+    {
+        return Ok(Value::Phrase(
+            Phrase::builder().text(text.clone()).tags(tags).build(),
+        ));
+    }
+
     // Build candidate key parts from selectors. Each selector position may
     // have multiple candidates (e.g., a Phrase with tags [:masc, :anim]).
     let mut candidate_parts: Vec<Vec<String>> = Vec::new();
     for selector in selectors {
-        let candidates = resolve_selector_candidates(selector, ctx, lang)?;
+        let ranged = match value {
+            Value::Phrase(phrase) => {
+                range_selector_candidates(selector, phrase, ctx, transform_registry, lang)
+            }
+            _ => None,
+        };
+        let candidates = match ranged {
+            Some(candidates) => candidates,
+            None => resolve_selector_candidates(selector, ctx, transform_registry, lang)?,
+        };
         candidate_parts.push(candidates);
     }
 
@@ -695,31 +986,21 @@ fn apply_selectors(
 
     match value {
         Value::Phrase(phrase) => {
-            for key in &compound_keys {
-                if let Ok(variant_text) = variant_lookup(phrase, key) {
-                    // Preserve tags through variant selection so transforms can
-                    // still access metadata (e.g., @a needs :a tag after :n selector)
-                    return Ok(Value::Phrase(
-                        Phrase::builder()
-                            .text(variant_text)
-                            .tags(phrase.tags.clone())
-                            .build(),
-                    ));
-                }
+            let is_dynamic = selectors
+                .iter()
+                .any(|s| matches!(s, Selector::Parameter(_) | Selector::Ordinal(_)));
+            // Try each key in order; on no match, report the first (most specific) key
+            let found = compound_keys
+                .iter()
+                .find_map(|key| variant_lookup(phrase, key).ok());
+            let selected = match found {
+                Some(selected) => selected,
+                None => variant_lookup(phrase, compound_keys.first().map_or("?", String::as_str))?,
+            };
+            if is_dynamic && let Some(key) = &selected.selected_variant {
+                ctx.record_selected_variant(key);
             }
-            // None matched - report error using the first (most specific) key
-            let primary_key = compound_keys
-                .first()
-                .cloned()
-                .unwrap_or_else(|| "?".to_string());
-            variant_lookup(phrase, &primary_key).map(|text| {
-                Value::Phrase(
-                    Phrase::builder()
-                        .text(text)
-                        .tags(phrase.tags.clone())
-                        .build(),
-                )
-            })
+            Ok(Value::Phrase(selected))
         }
         _ => {
             // Non-phrase values don't have variants
@@ -770,11 +1051,13 @@ fn build_compound_keys(parts: &[Vec<String>]) -> Vec<String> {
 /// Uses the AST distinction directly:
 /// - `Selector::Identifier(name)` → use as a literal variant key
 /// - `Selector::Parameter(name)` → look up parameter value, then resolve:
-///   Number → CLDR plural category, Phrase → all tags, String → literal or parsed number
+///   Number → plural category (custom rule or CLDR), Phrase → all tags, String → literal or parsed number
+/// - `Selector::Ordinal(name)` → as `Parameter`, but numbers map to their CLDR ordinal category
 /// - `Selector::Default` → handled before this function is called (short-circuit in apply_selectors)
 fn resolve_selector_candidates(
     selector: &Selector,
     ctx: &EvalContext<'_>,
+    transform_registry: &TransformRegistry,
     lang: &str,
 ) -> Result<Vec<String>, EvalError> {
     match selector {
@@ -787,63 +1070,110 @@ fn resolve_selector_candidates(
             unreachable!("Selector::Default should be handled in apply_selectors")
         }
         Selector::Parameter(name) => {
-            // Parameterized selector: look up parameter value
-            let value = ctx
-                .get_param(name)
-                .ok_or_else(|| EvalError::UnknownParameter { name: name.clone() })?;
-            match value {
-                Value::Number(n) => Ok(vec![plural_category(lang, *n).to_string()]),
-                Value::Float(f) => Ok(vec![plural_category(lang, *f as i64).to_string()]),
-                Value::Phrase(phrase) => {
-                    // Use all tags as candidates, preserving order
-                    let tags: Vec<String> = phrase.tags.iter().map(ToString::to_string).collect();
-                    if tags.is_empty() {
-                        return Err(EvalError::MissingTag {
-                            transform: "selector".to_string(),
-                            expected: vec!["any".to_string()],
-                            phrase: phrase.text.clone(),
-                        });
-                    }
-                    Ok(tags)
-                }
-                Value::String(s) => {
-                    if let Ok(n) = s.parse::<i64>() {
-                        Ok(vec![plural_category(lang, n).to_string()])
-                    } else {
-                        Ok(vec![s.clone()])
-                    }
-                }
+            parameter_candidates(name, ctx, |n| transform_registry.plural_category(lang, n))
+        }
+        Selector::Ordinal(name) => parameter_candidates(name, ctx, |n| ordinal_category(lang, n)),
+    }
+}
+
+/// Candidate keys for the value of parameter `name`, using `category` to map
+/// numbers to a plural category.
+fn parameter_candidates(
+    name: &str,
+    ctx: &EvalContext<'_>,
+    category: impl Fn(i64) -> &'static str,
+) -> Result<Vec<String>, EvalError> {
+    let value = ctx
+        .get_param(name)
+        .ok_or_else(|| EvalError::UnknownParameter {
+            name: name.to_string(),
+        })?;
+    match value {
+        Value::Number(n) => Ok(vec![category(*n).to_string()]),
+        Value::Float(f) => Ok(vec![category(*f as i64).to_string()]),
+        Value::List(items) => Ok(vec![category(items.len() as i64).to_string()]),
+        Value::Phrase(phrase) => {
+            // Use all tags as candidates, preserving order
+            let tags: Vec<String> = phrase.tags.iter().map(ToString::to_string).collect();
+            if tags.is_empty() {
+                return Err(EvalError::MissingTag {
+                    transform: "selector".to_string(),
+                    expected: vec!["any".to_string()],
+                    phrase: marks::strip(&phrase.text).into_owned(),
+                });
             }
+            Ok(tags)
         }
+        Value::String(s) => {
+            if let Ok(n) = s.parse::<i64>() {
+                Ok(vec![category(n).to_string()])
+            } else {
+                Ok(vec![s.clone()])
+            }
+        }
+        Value::DateTime(_) => Ok(vec![value.to_string()]),
     }
 }
 
+/// Interpret selectors as inline tags when every selector is a static
+/// identifier naming `:protect` or a tag declared in `registry`.
+fn inline_tags(selectors: &[Selector], registry: &PhraseRegistry) -> Option<Vec<Tag>> {
+    selectors
+        .iter()
+        .map(|selector| match selector {
+            Selector::Identifier(name) if name == PROTECT_TAG || registry.declares_tag(name) => {
+                Some(Tag::new(name))
+            }
+            Selector::Identifier(_)
+            | Selector::Parameter(_)
+            | Selector::Ordinal(_)
+            | Selector::Default => None,
+        })
+        .collect()
+}
+
 /// Look up a variant with fallback resolution.
 ///
 /// Resolution order:
 /// 1. Try exact key
 /// 2. Progressively strip trailing ".segment"
 ///
+/// Returns the variant as a phrase keeping the tags of `phrase`, so
+/// transforms can still read them (e.g. `@a` needs `:a` after a `:n`
+/// selector), with the matched key as its selected variant.
+///
 /// Returns MissingVariant error if no match found.
-fn variant_lookup(phrase: &Phrase, key: &str) -> Result<String, EvalError> {
+fn variant_lookup(phrase: &Phrase, key: &str) -> Result<Phrase, EvalError> {
+    let select = |key: &str, text: &String| {
+        let mut selected = Phrase::builder()
+            .text(text.clone())
+            .tags(phrase.tags.clone())
+            .build();
+        selected.selected_variant = Some(VariantKey::new(key));
+        selected
+    };
+
     // Try exact match
-    if let Some(v) = phrase.variants.get(&VariantKey::new(key)) {
-        return Ok(v.clone());
+    if let Some(v) = phrase.variants.get(key) {
+        return Ok(select(key, v));
     }
 
     // Try progressively shorter keys (fallback resolution)
     let mut current = key;
     while let Some(dot_pos) = current.rfind('.') {
         current = &current[..dot_pos];
-        if let Some(v) = phrase.variants.get(&VariantKey::new(current)) {
-            return Ok(v.clone());
+        if let Some(v) = phrase.variants.get(current) {
+            return Ok(select(current, v));
         }
     }
 
     // If no variants exist but we have a key, this might be a simple phrase
     // being used with a selector - return the default text
     if phrase.variants.is_empty() {
-        return Ok(phrase.text.clone());
+        return Ok(Phrase::builder()
+            .text(phrase.text.clone())
+            .tags(phrase.tags.clone())
+            .build());
     }
 
     // No match found - return error with available variants
@@ -851,13 +1181,47 @@ fn variant_lookup(phrase: &Phrase, key: &str) -> Result<String, EvalError> {
     available.sort();
     let suggestions = compute_suggestions(key, &available);
     Err(EvalError::MissingVariant {
-        phrase: phrase.text.clone(),
+        phrase: marks::strip(&phrase.text).into_owned(),
         key: key.to_string(),
         suggestions,
         available,
     })
 }
 
+/// Check arguments against the definition's declared parameter types.
+///
+/// Untyped parameters accept any value.
+pub(crate) fn check_argument_types(
+    name: &str,
+    def: &PhraseDefinition,
+    args: &[Value],
+) -> Result<(), EvalError> {
+    let typed = def.parameters.iter().zip(&def.parameter_types).zip(args);
+    for ((parameter, param_type), value) in typed {
+        let Some(param_type) = param_type else {
+            continue;
+        };
+        if !accepts(*param_type, value) {
+            return Err(EvalError::TypeMismatch {
+                phrase: name.to_string(),
+                parameter: parameter.clone(),
+                expected: param_type.name().to_string(),
+                value: marks::strip(&value.to_string()).into_owned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Whether a runtime value satisfies a declared parameter type.
+fn accepts(param_type: ParamType, value: &Value) -> bool {
+    match param_type {
+        ParamType::Num => matches!(value, Value::Number(_) | Value::Float(_)),
+        ParamType::Text => matches!(value, Value::String(_)),
+        ParamType::Phrase => matches!(value, Value::Phrase(_)),
+    }
+}
+
 /// Apply transforms to a Value, executing right-to-left.
 ///
 /// Per DESIGN.md: `{@cap @a card}` executes @a first, then @cap.
@@ -866,30 +1230,82 @@ fn variant_lookup(phrase: &Phrase, key: &str) -> Result<String, EvalError> {
 fn apply_transforms(
     initial_value: &Value,
     transforms: &[Transform],
+    registry: &PhraseRegistry,
     transform_registry: &TransformRegistry,
-    ctx: &EvalContext<'_>,
+    ctx: &mut EvalContext<'_>,
     lang: &str,
 ) -> Result<String, EvalError> {
     if transforms.is_empty() {
         return Ok(initial_value.to_string());
     }
 
+    // Resolve each transform and its context left-to-right, so that `@lang`
+    // can switch the language of the transforms to its right
+    let mut resolved = Vec::with_capacity(transforms.len());
+    let mut transform_lang = lang.to_string();
+    for transform in transforms {
+        let transform_kind = transform_registry
+            .get(&transform.name, &transform_lang)
+            .ok_or_else(|| unknown_transform_error(&transform.name))?;
+        let caller_context = !matches!(transform.context, TransformContext::Static(_));
+        let context_value = resolve_transform_context(&transform.context, ctx)?.map(|value| {
+            if transform_registry.output_target() == OutputTarget::Html && caller_context {
+                escape_caller_value(value)
+            } else {
+                value
+            }
+        });
+        let current_lang = transform_lang.clone();
+        if transform_kind == TransformKind::Lang {
+            transform_lang = lang_override(context_value.as_ref())?;
+        }
+        resolved.push((transform_kind, context_value, caller_context, current_lang));
+    }
+
     // Start with the initial Value (preserves Phrase type with tags for first transform)
     let mut current = initial_value.clone();
 
     // Process right-to-left (reverse iteration)
-    for transform in transforms.iter().rev() {
-        let transform_kind = transform_registry
-            .get(&transform.name, lang)
-            .ok_or_else(|| EvalError::UnknownTransform {
-                name: transform.name.clone(),
-            })?;
-
-        // Resolve transform context
-        let context_value = resolve_transform_context(&transform.context, ctx)?;
-
-        // Pass full Value to transform so it can read tags (on first iteration)
-        let result = transform_kind.execute(&current, context_value.as_ref(), lang)?;
+    for (transform_kind, context_value, caller_context, lang) in resolved.into_iter().rev() {
+        // @map resolves to a term or text, keeping the term's tags for the
+        // transforms to its left
+        if transform_kind == TransformKind::Map {
+            current = map_lookup(
+                &current,
+                context_value.as_ref(),
+                registry,
+                transform_registry,
+                ctx,
+                &lang,
+            )?;
+            continue;
+        }
+        // @autocap needs the sentence position, which only the evaluator knows
+        if transform_kind == TransformKind::AutoCap {
+            if transform_registry.auto_sentence_cap() && ctx.at_sentence_start() {
+                let capitalized =
+                    transform_registry.execute(TransformKind::Cap, &current, None, &lang)?;
+                current = Value::String(capitalized);
+            }
+            continue;
+        }
+        // Pass full Value to transform so it can read tags (on first iteration);
+        // only case transforms see the span markers
+        let mut result = if is_case_transform(transform_kind) {
+            transform_registry.execute(transform_kind, &current, context_value.as_ref(), &lang)?
+        } else {
+            let unmarked = marks::strip_value(&current);
+            let result = transform_registry.execute(
+                transform_kind,
+                &unmarked,
+                context_value.as_ref(),
+                &lang,
+            )?;
+            marks::restore(&current, result)
+        };
+        if caller_context && let Some(Value::String(context)) = &context_value {
+            result = marks::mark_plain_occurrence(result, context);
+        }
         // After transform, result is String - wrap for next iteration
         current = Value::String(result);
     }
@@ -897,6 +1313,82 @@ fn apply_transforms(
     Ok(current.to_string())
 }
 
+/// Whether a transform changes letter case, and so must read the protected
+/// spans of [`marks`] in its input.
+fn is_case_transform(kind: TransformKind) -> bool {
+    matches!(
+        kind,
+        TransformKind::Cap
+            | TransformKind::Upper
+            | TransformKind::Lower
+            | TransformKind::Sentence
+            | TransformKind::Title
+    )
+}
+
+/// Resolve `{@map:table value}` through a lookup table registered on the
+/// transform registry.
+///
+/// The value's text is the key. Term entries are evaluated in `lang` like a
+/// bare term reference; text entries are used as-is. Keys missing from a
+/// table without a fallback are an error listing the known keys.
+fn map_lookup(
+    value: &Value,
+    context: Option<&Value>,
+    registry: &PhraseRegistry,
+    transform_registry: &TransformRegistry,
+    ctx: &mut EvalContext<'_>,
+    lang: &str,
+) -> Result<Value, EvalError> {
+    let key = marks::strip(&value.to_string()).into_owned();
+    let Some(Value::String(name)) = context else {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "map".to_string(),
+            expected: "a table name context such as ':rarity'".to_string(),
+            value: key,
+        });
+    };
+    let table =
+        transform_registry
+            .lookup_table(name)
+            .ok_or_else(|| EvalError::InvalidTransformInput {
+                transform: "map".to_string(),
+                expected: format!("a lookup table named '{name}' registered on the Locale"),
+                value: key.clone(),
+            })?;
+    match table.get(&key) {
+        Some(LookupEntry::Text(text)) => Ok(Value::String(text.clone())),
+        Some(LookupEntry::Term(term)) => resolve_reference(
+            &Reference::Identifier(term.clone()),
+            ctx,
+            registry,
+            transform_registry,
+            lang,
+        ),
+        None => Err(EvalError::InvalidTransformInput {
+            transform: "map".to_string(),
+            expected: format!("a key of map '{name}' ({})", table.keys().join(", ")),
+            value: key,
+        }),
+    }
+}
+
+/// Build the error for a transform name that doesn't resolve in the current
+/// language, pointing at the languages that do define it.
+fn unknown_transform_error(name: &str) -> EvalError {
+    let available_in = languages_with_transform(name);
+    if available_in.is_empty() {
+        EvalError::UnknownTransform {
+            name: name.to_string(),
+        }
+    } else {
+        EvalError::WrongLanguageTransform {
+            name: name.to_string(),
+            available_in: available_in.into_iter().map(str::to_string).collect(),
+        }
+    }
+}
+
 /// Resolve a transform context to an optional Value.
 ///
 /// Static context becomes a literal string value. Dynamic context looks up
@@ -934,6 +1426,8 @@ fn resolve_transform_context(
 
 /// Evaluate match branches, selecting the best-matching branch template.
 ///
+/// Returns the evaluated text and the key of the selected branch.
+///
 /// Resolution per dimension:
 /// 1. Exact numeric key (for Number values)
 /// 2. CLDR plural category (for Number values)
@@ -946,7 +1440,7 @@ fn eval_match_branches(
     registry: &PhraseRegistry,
     transform_registry: &TransformRegistry,
     lang: &str,
-) -> Result<String, EvalError> {
+) -> Result<(String, VariantKey), EvalError> {
     // Resolve each match parameter to its current value
     let mut resolved_keys: Vec<Vec<String>> = Vec::new();
     for param_name in match_params {
@@ -957,14 +1451,7 @@ fn eval_match_branches(
             })?;
         match value {
             Value::Number(n) => {
-                // For numbers, try exact match first, then CLDR
-                let exact = n.to_string();
-                let cldr = plural_category(lang, *n).to_string();
-                if exact == cldr {
-                    resolved_keys.push(vec![exact]);
-                } else {
-                    resolved_keys.push(vec![exact, cldr]);
-                }
+                resolved_keys.push(numeric_match_keys(*n, branches, transform_registry, lang));
             }
             Value::Phrase(phrase) => {
                 let tags: Vec<String> = phrase.tags.iter().map(ToString::to_string).collect();
@@ -972,19 +1459,22 @@ fn eval_match_branches(
             }
             Value::String(s) => {
                 if let Ok(n) = s.parse::<i64>() {
-                    let exact = n.to_string();
-                    let cldr = plural_category(lang, n).to_string();
-                    if exact == cldr {
-                        resolved_keys.push(vec![exact]);
-                    } else {
-                        resolved_keys.push(vec![exact, cldr]);
-                    }
+                    resolved_keys.push(numeric_match_keys(n, branches, transform_registry, lang));
                 } else {
                     resolved_keys.push(vec![s.clone()]);
                 }
             }
             Value::Float(f) => {
-                let cldr = plural_category(lang, *f as i64).to_string();
+                let cldr = transform_registry
+                    .plural_category(lang, *f as i64)
+                    .to_string();
+                resolved_keys.push(vec![cldr]);
+            }
+            Value::DateTime(_) => resolved_keys.push(vec![value.to_string()]),
+            Value::List(items) => {
+                let cldr = transform_registry
+                    .plural_category(lang, items.len() as i64)
+                    .to_string();
                 resolved_keys.push(vec![cldr]);
             }
         }
@@ -992,18 +1482,90 @@ fn eval_match_branches(
 
     // Try to find a matching branch, considering all candidate key combinations
     // and falling back to * default branches.
-    let selected_template = select_match_branch(branches, &resolved_keys, match_params.len())?;
-    eval_template(selected_template, ctx, registry, transform_registry, lang)
+    let (selected_template, selected_key) =
+        select_match_branch(branches, &resolved_keys, match_params.len())?;
+    let text = render_template(selected_template, ctx, registry, transform_registry, lang)?;
+    Ok((text, VariantKey::new(selected_key)))
+}
+
+/// Candidate keys for a number in a match block, most specific first: the
+/// exact value, then the range key containing it, then its CLDR category.
+fn numeric_match_keys(
+    n: i64,
+    branches: &[MatchBranch],
+    transform_registry: &TransformRegistry,
+    lang: &str,
+) -> Vec<String> {
+    let keys = branches
+        .iter()
+        .flat_map(|branch| &branch.keys)
+        .map(|key| key.value.as_str());
+    numeric_keys(n, keys, transform_registry, lang)
+}
+
+/// Candidate keys for `n` given the keys of a block, most specific first:
+/// the exact value, then the range key containing it, then its CLDR category.
+fn numeric_keys<'a>(
+    n: i64,
+    block_keys: impl IntoIterator<Item = &'a str>,
+    transform_registry: &TransformRegistry,
+    lang: &str,
+) -> Vec<String> {
+    let mut keys = vec![n.to_string()];
+    let range = block_keys
+        .into_iter()
+        .find(|key| parse_numeric_range(key).is_some_and(|range| range.contains(&n)));
+    if let Some(key) = range {
+        keys.push(key.to_string());
+    }
+    let cldr = transform_registry.plural_category(lang, n).to_string();
+    if !keys.contains(&cldr) {
+        keys.push(cldr);
+    }
+    keys
+}
+
+/// Candidate keys for a parameter selector on a phrase with numeric range
+/// keys, when the parameter is a number: exact and range keys come before
+/// the CLDR category, and `other` is the catch-all.
+fn range_selector_candidates(
+    selector: &Selector,
+    phrase: &Phrase,
+    ctx: &EvalContext<'_>,
+    transform_registry: &TransformRegistry,
+    lang: &str,
+) -> Option<Vec<String>> {
+    let Selector::Parameter(name) = selector else {
+        return None;
+    };
+    let n = match ctx.get_param(name)? {
+        Value::Number(n) => *n,
+        Value::String(s) => s.parse().ok()?,
+        _ => return None,
+    };
+    let block_keys: Vec<&str> = phrase.variants.keys().map(VariantKey::as_str).collect();
+    if !block_keys
+        .iter()
+        .any(|key| parse_numeric_range(key).is_some())
+    {
+        return None;
+    }
+    let mut keys = numeric_keys(n, block_keys, transform_registry, lang);
+    if !keys.iter().any(|key| key == "other") {
+        keys.push("other".to_string());
+    }
+    Some(keys)
 }
 
 /// Select the best matching branch template from match branches.
 ///
 /// Tries all combinations of resolved keys, then falls back to default branches.
+/// Returns the branch template along with the branch key that matched.
 fn select_match_branch<'a>(
     branches: &'a [MatchBranch],
     resolved_keys: &[Vec<String>],
     num_dims: usize,
-) -> Result<&'a Template, EvalError> {
+) -> Result<(&'a Template, &'a str), EvalError> {
     // Build candidate compound keys from resolved keys via cartesian product
     let compound_keys = build_compound_keys(resolved_keys);
 
@@ -1012,7 +1574,7 @@ fn select_match_branch<'a>(
         for branch in branches {
             for key in &branch.keys {
                 if key.value == *candidate {
-                    return Ok(&branch.template);
+                    return Ok((&branch.template, &key.value));
                 }
             }
         }
@@ -1030,7 +1592,7 @@ fn select_match_branch<'a>(
             // Compound tag key: more parts than dimensions. Check that each
             // part exists in the resolved tags for this compound's dimension.
             if compound_tag_matches(&key_parts, resolved_keys, num_dims) {
-                return Ok(&branch.template);
+                return Ok((&branch.template, &key.value));
             }
         }
     }
@@ -1055,7 +1617,7 @@ fn select_match_branch<'a>(
                             || key.default_dimensions.get(dim).copied().unwrap_or(false)
                     });
                 if matches {
-                    return Ok(&branch.template);
+                    return Ok((&branch.template, &key.value));
                 }
             }
         }
@@ -1066,7 +1628,7 @@ fn select_match_branch<'a>(
         for key in &branch.keys {
             if key.default_dimensions.len() == num_dims && key.default_dimensions.iter().all(|d| *d)
             {
-                return Ok(&branch.template);
+                return Ok((&branch.template, &key.value));
             }
         }
     }
@@ -1122,8 +1684,9 @@ fn has_multi_dimensional_variants(phrase: &Phrase) -> bool {
 /// Evaluates each variant template and populates the variants HashMap.
 /// Default text priority:
 /// 1. String context match (if set)
-/// 2. `*`-marked default variant
-/// 3. First entry's text (backward compatibility)
+/// 2. Variant keyed by the locale's register (`formal` or `informal`)
+/// 3. `*`-marked default variant
+/// 4. First entry's text (backward compatibility)
 fn build_phrase_from_variants(
     entries: &[VariantEntry],
     ctx: &mut EvalContext<'_>,
@@ -1135,6 +1698,8 @@ fn build_phrase_from_variants(
     let mut first_text = String::new();
     let mut default_text: Option<String> = None;
     let mut context_text: Option<String> = None;
+    let mut register_text: Option<String> = None;
+    let register = transform_registry.register().key();
 
     for (i, entry) in entries.iter().enumerate() {
         let text = eval_variant_entry_body(&entry.body, ctx, registry, transform_registry, lang)?;
@@ -1161,14 +1726,21 @@ fn build_phrase_from_variants(
             }
         }
 
+        if register_text.is_none() && entry.keys.iter().any(|key| key == register) {
+            register_text = Some(text.clone());
+        }
+
         // Add to variants map for each key
         for key in &entry.keys {
             variants.insert(VariantKey::new(key.clone()), text.clone());
         }
     }
 
-    // Priority: context match > *-marked default > first entry
-    let result_text = context_text.or(default_text).unwrap_or(first_text);
+    // Priority: context match > register variant > *-marked default > first entry
+    let result_text = context_text
+        .or(register_text)
+        .or(default_text)
+        .unwrap_or(first_text);
 
     Ok((result_text, variants))
 }
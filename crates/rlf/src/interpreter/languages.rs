@@ -0,0 +1,67 @@
+//! Languages with built-in plural rules or transforms.
+
+use std::sync::LazyLock;
+
+use rlf_semantics::{plural_languages, transform_languages};
+
+/// Every supported language code, sorted, built from the plural rule and
+/// transform tables so the list cannot drift from them.
+static SUPPORTED_LANGUAGES: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
+    let mut languages: Vec<&'static str> = plural_languages()
+        .iter()
+        .copied()
+        .chain(transform_languages())
+        .collect();
+    languages.sort_unstable();
+    languages.dedup();
+    languages
+});
+
+/// What RLF has built in for one language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LanguageSupport {
+    /// The primary language code, e.g. `"de"`.
+    pub code: &'static str,
+    /// CLDR plural rules, used for numeric variant selection.
+    pub plural_rules: bool,
+    /// Language-specific transforms such as articles or case suffixes.
+    pub transforms: bool,
+}
+
+/// Language codes with built-in plural rules or language-specific
+/// transforms, sorted.
+///
+/// Universal transforms like `@cap` work in any language, so a language
+/// missing from this list can still be loaded; it just falls back to English
+/// plural rules.
+///
+/// ```
+/// let languages = rlf::supported_languages();
+/// assert!(languages.contains(&"de"));
+/// assert!(!languages.contains(&"tlh"));
+/// ```
+pub fn supported_languages() -> &'static [&'static str] {
+    &SUPPORTED_LANGUAGES
+}
+
+/// The built-in features for `lang`, or `None` if it has none.
+///
+/// Region and script subtags are ignored, so `pt-BR` reports `pt`.
+///
+/// ```
+/// let finnish = rlf::language_support("fi").unwrap();
+/// assert!(finnish.transforms);
+/// assert!(!finnish.plural_rules);
+/// ```
+pub fn language_support(lang: &str) -> Option<LanguageSupport> {
+    let primary = lang.split(['-', '_']).next().unwrap_or(lang);
+    let code = supported_languages()
+        .iter()
+        .copied()
+        .find(|&code| code == primary)?;
+    Some(LanguageSupport {
+        code,
+        plural_rules: plural_languages().contains(&code),
+        transforms: transform_languages().contains(&code),
+    })
+}
@@ -3,14 +3,28 @@
 //! Analyzes parsed AST to detect verbose patterns, missing `:from` annotations,
 //! and other issues that may cause silent metadata loss or unnecessary verbosity.
 
+use std::iter;
+use std::process;
+
+use rlf_semantics::languages_with_transform;
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::interpreter::error::LoadWarning;
+use crate::interpreter::languages::language_support;
 use crate::interpreter::locale::Locale;
+use crate::interpreter::plural::plural_categories;
+use crate::interpreter::registry::{PhraseInfo, PhraseRegistry};
+use crate::interpreter::transforms::{TransformRegistry, markup_tag_len, strip_markup};
 use crate::parser::ast::{
-    DefinitionKind, PhraseBody, PhraseDefinition, Reference, Segment, Selector, Template,
-    VariantEntryBody,
+    DefinitionKind, MatchBranch, PhraseBody, PhraseDefinition, Reference, Segment, Selector,
+    Template, Transform, VariantEntryBody,
 };
+use crate::parser::{TemplateVisitor, walk_definition};
 use crate::types::Value;
 
+/// The CLDR plural category names.
+const PLURAL_CATEGORIES: &[&str] = &["zero", "one", "two", "few", "many", "other"];
+
 /// Runs static lint rules over parsed phrase definitions, returning warnings.
 ///
 /// Operates purely on the parsed AST without evaluating phrases. Pass the
@@ -26,6 +40,184 @@ pub fn lint_definitions(defs: &[PhraseDefinition], language: &str) -> Vec<LoadWa
     warnings
 }
 
+/// Flags variant and match keys naming a plural category that the language
+/// never selects, such as `few` in English.
+///
+/// Each dot-separated component of a key is checked against the CLDR
+/// categories of `language`. Languages without plural rule support produce no
+/// warnings.
+pub fn lint_plural_categories(defs: &[PhraseDefinition], language: &str) -> Vec<LoadWarning> {
+    let Some(categories) = plural_categories(language) else {
+        return Vec::new();
+    };
+    let mut warnings = Vec::new();
+    for def in defs {
+        for key in definition_keys(def) {
+            let unreachable = key.split('.').any(|component| {
+                PLURAL_CATEGORIES.contains(&component) && !categories.contains(&component)
+            });
+            if unreachable {
+                warnings.push(LoadWarning::UnreachablePluralCategory {
+                    name: def.name.clone(),
+                    language: language.to_string(),
+                    key: key.to_string(),
+                });
+            }
+        }
+    }
+    warnings
+}
+
+/// Runs the pedantic literal-number lint over parsed phrase definitions.
+///
+/// Flags hardcoded ordinals (`3rd`, `21st`) and comma-grouped numbers
+/// (`1,000`) in template text, which will not localize, suggesting `@ordinal`
+/// or `@num` instead. This is a best-effort heuristic and is not part of
+/// `lint_definitions()`; digits inside markup tags such as `<color=#112233>`
+/// are ignored.
+///
+/// The suffixes and separator are English, so only English files are
+/// checked; elsewhere "1,000" may be a decimal and "1er" a word.
+pub fn lint_literal_numbers(defs: &[PhraseDefinition], language: &str) -> Vec<LoadWarning> {
+    let mut warnings = Vec::new();
+    if language.split(['-', '_']).next() != Some("en") {
+        return warnings;
+    }
+    for def in defs {
+        for template in definition_templates(def) {
+            for (text, suggestion) in find_literal_numbers(&template_text(template)) {
+                warnings.push(LoadWarning::LiteralNumber {
+                    name: def.name.clone(),
+                    language: language.to_string(),
+                    text,
+                    suggestion: suggestion.to_string(),
+                });
+            }
+        }
+    }
+    warnings
+}
+
+/// Runs the pedantic mixed-language lint over parsed phrase definitions.
+///
+/// Flags transforms that `language` does not define but other languages do,
+/// such as `@the` in a German file, which usually means a phrase was copied
+/// from another translation. With `reference` definitions and their language,
+/// also flags phrases whose body is identical to the reference's and contains
+/// letters, which are likely untranslated. Unknown languages are skipped.
+pub fn lint_mixed_language(
+    defs: &[PhraseDefinition],
+    language: &str,
+    reference: Option<(&[PhraseDefinition], &str)>,
+) -> Vec<LoadWarning> {
+    if language_support(language).is_none() {
+        return Vec::new();
+    }
+    let mut warnings = Vec::new();
+    for def in defs {
+        let mut collector = TransformNames::default();
+        walk_definition(def, &mut collector);
+        for transform in collector.names {
+            if TransformRegistry::builtin().has_transform(&transform, language) {
+                continue;
+            }
+            let available_in = languages_with_transform(&transform);
+            if !available_in.is_empty() {
+                warnings.push(LoadWarning::WrongLanguageTransform {
+                    name: def.name.clone(),
+                    language: language.to_string(),
+                    transform,
+                    available_in: available_in.into_iter().map(str::to_string).collect(),
+                });
+            }
+        }
+    }
+    if let Some((reference_defs, reference)) = reference
+        && reference != language
+    {
+        for def in defs {
+            let untranslated = reference_defs.iter().any(|source| {
+                source.name == def.name
+                    && source.body == def.body
+                    && definition_templates(def)
+                        .into_iter()
+                        .any(|template| template_text(template).chars().any(char::is_alphabetic))
+            });
+            if untranslated {
+                warnings.push(LoadWarning::UntranslatedText {
+                    name: def.name.clone(),
+                    language: language.to_string(),
+                    reference: reference.to_string(),
+                });
+            }
+        }
+    }
+    warnings
+}
+
+/// Collects the distinct transform names used in a definition, in source order.
+#[derive(Default)]
+struct TransformNames {
+    names: Vec<String>,
+}
+
+impl TemplateVisitor for TransformNames {
+    fn visit_transform(&mut self, transform: &Transform) {
+        if !self.names.contains(&transform.name) {
+            self.names.push(transform.name.clone());
+        }
+    }
+}
+
+/// Flags definitions in `registry` whose text is longer than their `@maxlen`
+/// attribute.
+///
+/// Terms are evaluated with `language`'s rules and measured by their longest
+/// form, the default text or any variant. Phrases cannot be rendered without
+/// arguments, so the literal text of their longest template is measured,
+/// which gives a lower bound. Lengths count visible graphemes, ignoring
+/// `<...>` markup. Terms that fail to evaluate are skipped; other lints and
+/// `rlf check` report those errors. Warnings are ordered by phrase name.
+pub fn lint_max_length(registry: &PhraseRegistry, language: &str) -> Vec<LoadWarning> {
+    let mut names: Vec<&str> = registry.phrase_names().collect();
+    names.sort_unstable();
+    let mut warnings = Vec::new();
+    for name in names {
+        let Some(def) = registry.get(name) else {
+            continue;
+        };
+        let Some(max) = PhraseInfo::new(def).max_length() else {
+            continue;
+        };
+        let texts = match def.kind {
+            DefinitionKind::Term => match registry.get_phrase(language, name) {
+                Ok(phrase) => iter::once(phrase.text)
+                    .chain(phrase.variants.into_values())
+                    .collect(),
+                Err(_) => continue,
+            },
+            DefinitionKind::Phrase => definition_templates(def)
+                .into_iter()
+                .map(template_text)
+                .collect::<Vec<_>>(),
+        };
+        let length = texts
+            .iter()
+            .map(|text| strip_markup(text).graphemes(true).count())
+            .max()
+            .unwrap_or(0);
+        if length > max {
+            warnings.push(LoadWarning::ExceedsMaxLength {
+                name: name.to_string(),
+                language: language.to_string(),
+                length,
+                max,
+            });
+        }
+    }
+    warnings
+}
+
 /// Runs all static and runtime lint checks over registered phrases, prints any
 /// warnings to stdout, and exits with status 0 (no warnings) or 1 (warnings
 /// found).
@@ -42,7 +234,7 @@ pub fn lint_definitions(defs: &[PhraseDefinition], language: &str) -> Vec<LoadWa
 pub fn run_lints(locale: &Locale) -> ! {
     let Some(registry) = locale.registry() else {
         eprintln!("No phrase registry found for current language");
-        std::process::exit(1);
+        process::exit(1);
     };
 
     let mut all_warnings: Vec<String> = Vec::new();
@@ -72,13 +264,13 @@ pub fn run_lints(locale: &Locale) -> ! {
 
     if all_warnings.is_empty() {
         println!("RLF lint passed: no warnings found");
-        std::process::exit(0);
+        process::exit(0);
     } else {
         println!("RLF lint found {} warning(s):\n", all_warnings.len());
         for warning in &all_warnings {
             println!("  {warning}");
         }
-        std::process::exit(1);
+        process::exit(1);
     }
 }
 
@@ -289,6 +481,139 @@ fn lint_verbose_transparent_wrapper(
     }
 }
 
+/// Collects every template in a definition body, in source order.
+fn definition_templates(def: &PhraseDefinition) -> Vec<&Template> {
+    match &def.body {
+        PhraseBody::Simple(template) => vec![template],
+        PhraseBody::Match(branches) => branches.iter().map(|b| &b.template).collect(),
+        PhraseBody::Variants(entries) => entries
+            .iter()
+            .flat_map(|entry| match &entry.body {
+                VariantEntryBody::Template(template) => vec![template],
+                VariantEntryBody::Match { branches, .. } => {
+                    branches.iter().map(|b| &b.template).collect()
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Collects every variant and match key in a definition body, in source order.
+fn definition_keys(def: &PhraseDefinition) -> Vec<&str> {
+    fn branch_keys(branches: &[MatchBranch]) -> Vec<&str> {
+        branches
+            .iter()
+            .flat_map(|b| b.keys.iter().map(|k| k.value.as_str()))
+            .collect()
+    }
+    match &def.body {
+        PhraseBody::Simple(_) => Vec::new(),
+        PhraseBody::Match(branches) => branch_keys(branches),
+        PhraseBody::Variants(entries) => entries
+            .iter()
+            .flat_map(|entry| {
+                let mut keys: Vec<&str> = entry.keys.iter().map(String::as_str).collect();
+                if let VariantEntryBody::Match { branches, .. } = &entry.body {
+                    keys.extend(branch_keys(branches));
+                }
+                keys
+            })
+            .collect(),
+    }
+}
+
+/// Joins a template's literal text, replacing each interpolation with a space
+/// so that digits on either side of it are never read as one number.
+/// Conditional bodies are included, set off by spaces.
+fn template_text(template: &Template) -> String {
+    let mut text = String::new();
+    for segment in &template.segments {
+        match segment {
+            Segment::Literal(literal) => text.push_str(literal),
+            Segment::Interpolation { .. } => text.push(' '),
+            Segment::Conditional { body, .. } => {
+                text.push(' ');
+                text.push_str(&template_text(body));
+                text.push(' ');
+            }
+        }
+    }
+    text
+}
+
+/// Scans text outside markup tags for English literal ordinals and grouped
+/// numbers.
+///
+/// Returns each flagged token with the name of the transform to suggest.
+fn find_literal_numbers(text: &str) -> Vec<(String, &'static str)> {
+    let (offsets, chars): (Vec<usize>, Vec<char>) = text.char_indices().unzip();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '<'
+            && let Some(len) = markup_tag_len(&text[offsets[i]..])
+        {
+            let end = offsets[i] + len;
+            while i < chars.len() && offsets[i] < end {
+                i += 1;
+            }
+            continue;
+        }
+        let attached = i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '#');
+        if !c.is_ascii_digit() || attached {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+        let mut grouped = false;
+        if i - start <= 3 {
+            while let Some(next) = digit_group_end(&chars, i) {
+                grouped = true;
+                i = next;
+            }
+        }
+
+        let suggestion = if has_ordinal_suffix(&chars, i) {
+            i += 2;
+            Some("ordinal")
+        } else if grouped && !chars.get(i).is_some_and(|c| c.is_alphanumeric()) {
+            Some("num")
+        } else {
+            None
+        };
+        if let Some(suggestion) = suggestion {
+            found.push((chars[start..i].iter().collect(), suggestion));
+        }
+        while i < chars.len() && chars[i].is_alphanumeric() {
+            i += 1;
+        }
+    }
+    found
+}
+
+/// Returns the index after a `,ddd` thousands group starting at `i`, if any.
+fn digit_group_end(chars: &[char], i: usize) -> Option<usize> {
+    let group = chars.get(i..i + 4)?;
+    let is_group = group[0] == ',' && group[1..].iter().all(char::is_ascii_digit);
+    let followed_by_digit = chars.get(i + 4).is_some_and(char::is_ascii_digit);
+    (is_group && !followed_by_digit).then_some(i + 4)
+}
+
+/// Whether an English ordinal suffix (`st`, `nd`, `rd`, `th`) ends a word at `i`.
+fn has_ordinal_suffix(chars: &[char], i: usize) -> bool {
+    let Some(suffix) = chars.get(i..i + 2) else {
+        return false;
+    };
+    let suffix = suffix.iter().collect::<String>().to_lowercase();
+    let ends_word = !chars.get(i + 2).is_some_and(|c| c.is_alphanumeric());
+    matches!(suffix.as_str(), "st" | "nd" | "rd" | "th") && ends_word
+}
+
 /// A normalized template segment for passthrough comparison.
 ///
 /// Replaces the `:from` parameter interpolation with a placeholder so that
@@ -341,6 +666,9 @@ fn normalize_template_for_passthrough(
                 // Some other interpolation (other params, transforms, etc.)
                 normalized.push(NormalizedSegment::OtherInterpolation);
             }
+            Segment::Conditional { .. } => {
+                normalized.push(NormalizedSegment::OtherInterpolation);
+            }
         }
     }
 
@@ -380,7 +708,10 @@ fn find_parameter_with_selector(template: &Template) -> Option<String> {
 fn extract_first_parameter(reference: &Reference) -> Option<String> {
     match reference {
         Reference::Parameter(name) => Some(name.clone()),
-        Reference::PhraseCall { args, .. } => args.iter().find_map(extract_first_parameter),
+        Reference::PhraseCall { args, .. } | Reference::Coalesce(args) => {
+            args.iter().find_map(extract_first_parameter)
+        }
+        Reference::Named { value, .. } => extract_first_parameter(value),
         Reference::Identifier(_) | Reference::NumberLiteral(_) | Reference::StringLiteral(_) => {
             None
         }
@@ -3,19 +3,24 @@
 //! The Locale struct provides the user-facing API for managing language selection,
 //! loading translations, and accessing phrases.
 
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 
 use bon::Builder;
 
-use crate::interpreter::error::{EvalWarning, LoadError};
-use crate::interpreter::registry::PhraseRegistry;
-use crate::interpreter::transforms::TransformRegistry;
-use crate::interpreter::{EvalContext, EvalError, eval_phrase_def, eval_template};
-use crate::parser::ast::Template;
-use crate::parser::{ParseError, parse_file, parse_template};
+use crate::interpreter::EvalError;
+use crate::interpreter::error::{EvalWarning, LoadError, LoadWarning};
+use crate::interpreter::lint::{lint_definitions, lint_max_length, lint_plural_categories};
+use crate::interpreter::registry::{PhraseInfo, PhraseRegistry};
+use crate::interpreter::transforms::{
+    ApostropheStyle, DigitStyle, LookupTable, OutputTarget, Register, SharpSStyle,
+    TransformRegistry,
+};
+use crate::parser::ast::{PhraseBody, PhraseDefinition, Segment, Template};
+use crate::parser::{parse_file, parse_template};
 use crate::types::{Phrase, Value};
 
 /// User-facing locale management for RLF translations.
@@ -56,6 +61,15 @@ pub struct Locale {
     /// `"<b>X</b>"` as its default text.
     string_context: Option<String>,
 
+    /// Language whose declarations give the parameter types checked when a
+    /// phrase is called, so an untyped translation cannot skip the check.
+    ///
+    /// Defaults to "en", the language the `rlf!` macro loads its source
+    /// phrases as. A phrase the source language lacks is checked against its
+    /// own declaration.
+    #[builder(default = "en".to_string())]
+    source_language: String,
+
     /// Per-language phrase registries.
     /// Each language has its own PhraseRegistry, enabling:
     /// - Clean "replace" semantics when reloading a language
@@ -88,6 +102,29 @@ impl Default for Locale {
     }
 }
 
+impl<S: locale_builder::IsComplete> LocaleBuilder<S> {
+    /// Build the locale and load every `*.rlf` file in `dir` into it.
+    ///
+    /// See [`Locale::load_dir`]; the lint warnings are returned alongside the
+    /// locale.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rlf::Locale;
+    ///
+    /// let (locale, warnings) = Locale::builder()
+    ///     .language("de")
+    ///     .load_dir("assets/localization")?;
+    /// # Ok::<(), rlf::LoadError>(())
+    /// ```
+    pub fn load_dir(self, dir: impl AsRef<Path>) -> Result<(Locale, Vec<LoadWarning>), LoadError> {
+        let mut locale = self.build();
+        let warnings = locale.load_dir(dir)?;
+        Ok((locale, warnings))
+    }
+}
+
 impl Locale {
     /// Create a new Locale with default settings (English).
     pub fn new() -> Self {
@@ -149,6 +186,215 @@ impl Locale {
         self.string_context = context.map(Into::into);
     }
 
+    /// Get the apostrophe written by elided articles.
+    pub fn apostrophe_style(&self) -> ApostropheStyle {
+        self.transforms.apostrophe_style()
+    }
+
+    /// Set the apostrophe written by elided articles.
+    ///
+    /// Defaults to [`ApostropheStyle::Ascii`]. Use [`ApostropheStyle::Curly`]
+    /// for typographic text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::{ApostropheStyle, Locale};
+    ///
+    /// let mut locale = Locale::with_language("fr");
+    /// locale.load_translations_str("fr", r#"
+    ///     ami = :masc :vowel "ami";
+    ///     the_friend = "{@le ami}";
+    /// "#).unwrap();
+    ///
+    /// locale.set_apostrophe_style(ApostropheStyle::Curly);
+    /// assert_eq!(locale.get_phrase("the_friend").unwrap().to_string(), "l\u{2019}ami");
+    /// ```
+    pub fn set_apostrophe_style(&mut self, style: ApostropheStyle) {
+        self.transforms.set_apostrophe_style(style);
+    }
+
+    /// Get the digit set written by `@num` and `@count`.
+    pub fn digit_style(&self) -> DigitStyle {
+        self.transforms.digit_style()
+    }
+
+    /// Set the digit set written by `@num` and `@count`.
+    ///
+    /// Defaults to [`DigitStyle::Latin`]. [`DigitStyle::Native`] writes
+    /// Persian, Arabic, Bengali, and Thai numbers in their own digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::{DigitStyle, Locale};
+    ///
+    /// let mut locale = Locale::with_language("fa");
+    /// locale.load_translations_str("fa", r#"score($n) = "{@num $n}";"#).unwrap();
+    ///
+    /// locale.set_digit_style(DigitStyle::Native);
+    /// let score = locale.call_phrase("score", &[1234.into()]).unwrap();
+    /// assert_eq!(score.to_string(), "۱٬۲۳۴");
+    /// ```
+    pub fn set_digit_style(&mut self, style: DigitStyle) {
+        self.transforms.set_digit_style(style);
+    }
+
+    /// Get how `@upper` and `@cap` write German "ß".
+    pub fn sharp_s_style(&self) -> SharpSStyle {
+        self.transforms.sharp_s_style()
+    }
+
+    /// Set how `@upper` and `@cap` write German "ß".
+    ///
+    /// Defaults to [`SharpSStyle::Double`] ("STRASSE"). [`SharpSStyle::Capital`]
+    /// writes the capital sharp s instead. `@lower` always turns "ẞ" into "ß".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::{Locale, SharpSStyle};
+    ///
+    /// let mut locale = Locale::with_language("de");
+    /// locale.load_translations_str("de", r#"
+    ///     street = "Straße";
+    ///     sign = "{@upper street}";
+    /// "#).unwrap();
+    ///
+    /// locale.set_sharp_s_style(SharpSStyle::Capital);
+    /// assert_eq!(locale.get_phrase("sign").unwrap().to_string(), "STRA\u{1E9E}E");
+    /// ```
+    pub fn set_sharp_s_style(&mut self, style: SharpSStyle) {
+        self.transforms.set_sharp_s_style(style);
+    }
+
+    /// Get whether `@autocap` capitalizes values that start a sentence.
+    pub fn auto_sentence_cap(&self) -> bool {
+        self.transforms.auto_sentence_cap()
+    }
+
+    /// Set whether `@autocap` capitalizes values that start a sentence.
+    ///
+    /// Disabled by default, which makes `@autocap` a no-op. When enabled,
+    /// `{@autocap ...}` capitalizes its first letter (by the `@cap` rule) if
+    /// nothing but markup precedes it in the final output, or it follows
+    /// sentence-ending punctuation or a line break. A phrase embedded
+    /// mid-sentence in another phrase is not at a sentence start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::Locale;
+    ///
+    /// let mut locale = Locale::new();
+    /// locale.load_translations_str("en", r#"
+    ///     enemy = "an ogre";
+    ///     appears = "{@autocap enemy} appears.";
+    ///     warning = "Look out, {appears}";
+    /// "#).unwrap();
+    ///
+    /// locale.set_auto_sentence_cap(true);
+    /// assert_eq!(locale.get_phrase("appears").unwrap().to_string(), "An ogre appears.");
+    /// assert_eq!(locale.get_phrase("warning").unwrap().to_string(), "Look out, an ogre appears.");
+    /// ```
+    pub fn set_auto_sentence_cap(&mut self, enabled: bool) {
+        self.transforms.set_auto_sentence_cap(enabled);
+    }
+
+    /// Get the register that definitions with `formal`/`informal` variants
+    /// default to.
+    pub fn register(&self) -> Register {
+        self.transforms.register()
+    }
+
+    /// Set the register that definitions with `formal`/`informal` variants
+    /// default to.
+    ///
+    /// Defaults to [`Register::Formal`]. A reference without a selector, such
+    /// as `{you}`, uses the variant named by the register when the definition
+    /// has one, ahead of its `*` default. `@formal` and `@informal` pick a
+    /// register explicitly, whatever this setting is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::{Locale, Register};
+    ///
+    /// let mut locale = Locale::with_language("de");
+    /// locale.load_translations_str("de", r#"
+    ///     you = { formal: "Sie", informal: "du" };
+    ///     ready = "Sind {you} bereit?";
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(locale.get_phrase("ready").unwrap().to_string(), "Sind Sie bereit?");
+    /// locale.set_register(Register::Informal);
+    /// assert_eq!(locale.get_phrase("ready").unwrap().to_string(), "Sind du bereit?");
+    /// ```
+    pub fn set_register(&mut self, register: Register) {
+        self.transforms.set_register(register);
+    }
+
+    /// Get the format that parameter values are escaped for.
+    pub fn output_target(&self) -> OutputTarget {
+        self.transforms.output_target()
+    }
+
+    /// Set the format that parameter values are escaped for.
+    ///
+    /// Defaults to [`OutputTarget::Plain`]. With [`OutputTarget::Html`], string,
+    /// number, and list parameters are HTML-escaped where they are substituted,
+    /// so user input cannot inject tags. Markup written in templates, and
+    /// [`Phrase`] arguments (already-evaluated RLF output), pass through.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::{Locale, OutputTarget};
+    ///
+    /// let mut locale = Locale::new();
+    /// locale.load_translations_str("en", r#"greet($name) = "Hello, <b>{$name}</b>!";"#).unwrap();
+    ///
+    /// locale.set_output_target(OutputTarget::Html);
+    /// let greeting = locale.call_phrase("greet", &["<i>Ann</i>".into()]).unwrap();
+    /// assert_eq!(greeting.to_string(), "Hello, <b>&lt;i&gt;Ann&lt;/i&gt;</b>!");
+    /// ```
+    pub fn set_output_target(&mut self, target: OutputTarget) {
+        self.transforms.set_output_target(target);
+    }
+
+    /// Get whether phrases are pseudo-localized.
+    pub fn pseudo_localize(&self) -> bool {
+        self.transforms.pseudo_localize()
+    }
+
+    /// Set whether phrases are pseudo-localized, for testing layouts before
+    /// translations exist.
+    ///
+    /// When enabled, letters in template text are replaced with accented
+    /// look-alikes, and each phrase returned by [`get_phrase`](Self::get_phrase),
+    /// [`call_phrase`](Self::call_phrase) and [`eval_str`](Self::eval_str) is
+    /// bracketed and padded with `~` to 30% more visible characters. Strings
+    /// that are clipped, concatenated, or not localized at all stand out.
+    /// Parameter values and markup tags are left as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::Locale;
+    ///
+    /// let mut locale = Locale::new();
+    /// locale.load_translations_str("en", r#"
+    ///     draw($n) = "Draw <b>{$n}</b> cards";
+    /// "#).unwrap();
+    ///
+    /// locale.set_pseudo_localize(true);
+    /// let text = locale.call_phrase("draw", &[3.into()]).unwrap();
+    /// assert_eq!(text.to_string(), "[Ðŕåŵ <b>3</b> çåŕðš~~~~]");
+    /// ```
+    pub fn set_pseudo_localize(&mut self, enabled: bool) {
+        self.transforms.set_pseudo_localize(enabled);
+    }
+
     // =========================================================================
     // Registry Access
     // =========================================================================
@@ -160,6 +406,36 @@ impl Locale {
         self.registries.get(language)
     }
 
+    /// A stable hash of the translations loaded for `language`.
+    ///
+    /// Returns None if no translations have been loaded for that language.
+    /// See [`PhraseRegistry::content_hash`].
+    ///
+    /// ```
+    /// use rlf::Locale;
+    ///
+    /// let mut locale = Locale::new();
+    /// locale.load_translations_str("en", r#"hello = "Hello!";"#).unwrap();
+    /// let before = locale.content_hash("en").unwrap();
+    ///
+    /// locale.load_translations_str("en", r#"hello = "Hi!";"#).unwrap();
+    /// assert_ne!(locale.content_hash("en"), Some(before));
+    /// assert_eq!(locale.content_hash("fr"), None);
+    /// ```
+    pub fn content_hash(&self, language: &str) -> Option<u64> {
+        self.registries
+            .get(language)
+            .map(PhraseRegistry::content_hash)
+    }
+
+    /// Get the declared metadata for a phrase in the current language.
+    ///
+    /// Returns None if the current language has no translations loaded or
+    /// does not define the phrase.
+    pub fn phrase_info(&self, name: &str) -> Option<PhraseInfo<'_>> {
+        self.registry()?.phrase_info(name)
+    }
+
     /// Get the phrase registry for the current language (read-only).
     pub fn registry(&self) -> Option<&PhraseRegistry> {
         self.registries.get(&self.language)
@@ -175,16 +451,103 @@ impl Locale {
         &mut self.transforms
     }
 
-    /// Get or create the phrase registry for a language (mutable).
-    fn registry_for_mut(&mut self, language: &str) -> &mut PhraseRegistry {
-        self.registries.entry(language.to_string()).or_default()
+    /// Register a plural rule for a language, consulted before the built-in
+    /// CLDR rules when selecting variants by number.
+    ///
+    /// This supports languages without built-in rules, or game-specific
+    /// pluralization. The returned category is matched against variant and
+    /// `:match` keys. See [`TransformRegistry::set_plural_rule`].
+    pub fn register_plural_rule(
+        &mut self,
+        language: impl Into<String>,
+        rule: impl Fn(i64) -> &'static str + Send + Sync + 'static,
+    ) {
+        self.transforms.set_plural_rule(language, rule);
+    }
+
+    /// Register the segmenter that finds Thai word boundaries for
+    /// `@wordbreak` and `@truncate_words`.
+    ///
+    /// Without one, a small bundled word list is used. The segmenter receives
+    /// a run of Thai text and returns the byte offsets where words start.
+    ///
+    /// ```
+    /// use rlf::Locale;
+    ///
+    /// let mut locale = Locale::with_language("th");
+    /// locale.load_translations_str("th", r#"hint($t) = "{@wordbreak $t}";"#).unwrap();
+    /// locale.register_word_segmenter(|run| vec![run.len() / 2]);
+    ///
+    /// let text = locale.call_phrase("hint", &["กกขข".into()]).unwrap();
+    /// assert_eq!(text.to_string(), "กก\u{200B}ขข");
+    /// ```
+    pub fn register_word_segmenter(
+        &mut self,
+        segmenter: impl Fn(&str) -> Vec<usize> + Send + Sync + 'static,
+    ) {
+        self.transforms.set_word_segmenter(segmenter);
+    }
+
+    /// Register the splitter that finds compound boundaries in `language`
+    /// words for `@break`.
+    ///
+    /// `@break` inserts a soft hyphen (U+00AD) at each boundary, letting
+    /// renderers hyphenate long German or Finnish compounds in narrow layouts.
+    /// The splitter receives one word and returns the byte offsets where its
+    /// parts meet. Without a splitter, `@break` leaves text unchanged.
+    ///
+    /// ```
+    /// use rlf::Locale;
+    ///
+    /// let mut locale = Locale::with_language("de");
+    /// locale.load_translations_str("de", r#"label($t) = "{@break $t}";"#).unwrap();
+    /// locale.register_compound_splitter("de", |word| {
+    ///     word.find("versicherung").into_iter().collect()
+    /// });
+    ///
+    /// let text = locale.call_phrase("label", &["Lebensversicherung".into()]).unwrap();
+    /// assert_eq!(text.to_string(), "Lebens\u{AD}versicherung");
+    /// ```
+    pub fn register_compound_splitter(
+        &mut self,
+        language: impl Into<String>,
+        splitter: impl Fn(&str) -> Vec<usize> + Send + Sync + 'static,
+    ) {
+        self.transforms.set_compound_splitter(language, splitter);
     }
 
-    /// Clear all phrases for a specific language.
+    /// Register a lookup table for `{@map:name $key}`, replacing any table
+    /// with the same name.
     ///
-    /// This is called internally before loading to implement "replace" semantics.
-    fn clear_language(&mut self, language: &str) {
-        self.registries.remove(language);
+    /// Tables are shared by every language; term entries are evaluated in the
+    /// language of the phrase using them. See [`LookupTable`].
+    pub fn register_map(&mut self, name: impl Into<String>, table: LookupTable) {
+        self.transforms.set_lookup_table(name, table);
+    }
+
+    /// Unload every phrase for `language`, releasing its registry.
+    ///
+    /// Returns whether the language had translations loaded. Cached
+    /// `eval_str` templates are cleared. A file path recorded by
+    /// [`Self::load_translations`] is kept, so [`Self::reload_translations`]
+    /// can load the language again. Loading a language calls this first, which
+    /// is what gives loading its "replace" semantics; long-running servers can
+    /// also call it directly to drop a language they no longer serve.
+    ///
+    /// ```
+    /// use rlf::Locale;
+    ///
+    /// let mut locale = Locale::new();
+    /// locale.load_translations_str("en", r#"hello = "Hello!";"#).unwrap();
+    ///
+    /// assert!(locale.clear_language("en"));
+    /// assert!(locale.registry_for("en").is_none());
+    /// assert!(locale.get_phrase("hello").is_err());
+    /// ```
+    pub fn clear_language(&mut self, language: &str) -> bool {
+        let removed = self.registries.remove(language).is_some();
+        self.clear_template_cache();
+        removed
     }
 
     // =========================================================================
@@ -227,6 +590,65 @@ impl Locale {
         Ok(count)
     }
 
+    /// Load every `*.rlf` file in a directory, using each file stem as the
+    /// language code (`de.rlf` loads `"de"`, `pt-BR.rlf` loads `"pt-BR"`).
+    ///
+    /// Each file is loaded as with [`Self::load_translations`], so it replaces
+    /// the language's phrases and can be reloaded later. Files are loaded in
+    /// path order; subdirectories and other files are ignored. Every file is
+    /// loaded before any language is replaced, so if one fails, no phrases
+    /// are changed. Returns the [`lint_definitions`],
+    /// [`lint_plural_categories`], and [`lint_max_length`] warnings for all
+    /// files.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rlf::Locale;
+    ///
+    /// let mut locale = Locale::new();
+    /// let warnings = locale.load_dir("assets/localization")?;
+    /// # Ok::<(), rlf::LoadError>(())
+    /// ```
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Result<Vec<LoadWarning>, LoadError> {
+        let dir = dir.as_ref();
+        let io_error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| LoadError::Io { path, source }
+        };
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir).map_err(io_error(dir))? {
+            let path = entry.map_err(io_error(dir))?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "rlf") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut warnings = Vec::new();
+        let mut staged = Vec::with_capacity(paths.len());
+        for path in paths {
+            let Some(language) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let language = language.to_string();
+            let content = fs::read_to_string(&path).map_err(io_error(&path))?;
+            let definitions =
+                parse_file(&content).map_err(|e| LoadError::from_parse_error(e, path.clone()))?;
+            warnings.extend(load_warnings(&definitions, &language));
+            let mut registry = PhraseRegistry::new();
+            insert_definitions(&mut registry, &language, definitions, Some(&path))?;
+            warnings.extend(lint_max_length(&registry, &language));
+            staged.push((language, registry, path));
+        }
+
+        for (language, registry, path) in staged {
+            self.commit_registry(&language, registry);
+            self.loaded_paths.insert(language, path);
+        }
+        Ok(warnings)
+    }
+
     /// Load translations from a string for a specific language.
     ///
     /// Translations loaded this way cannot be reloaded via `reload_translations()`.
@@ -258,6 +680,174 @@ impl Locale {
         self.load_translations_str_internal(language, content, None)
     }
 
+    /// Load translations from a string, returning the load-time lint warnings.
+    ///
+    /// Behaves like [`Self::load_translations_str`], which discards warnings,
+    /// but returns the [`lint_definitions`], [`lint_plural_categories`], and
+    /// [`lint_max_length`] warnings so applications can log them or treat
+    /// them as failures. If the content
+    /// fails to parse, no phrases are changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::{LoadWarning, Locale};
+    ///
+    /// let mut locale = Locale::new();
+    /// let warnings = locale.load_translations_str_with_warnings("en", r#"
+    ///     card = { one: "card", few: "cards", other: "cards" };
+    /// "#).unwrap();
+    /// assert!(matches!(
+    ///     &warnings[..],
+    ///     [LoadWarning::UnreachablePluralCategory { key, .. }] if key == "few"
+    /// ));
+    /// ```
+    pub fn load_translations_str_with_warnings(
+        &mut self,
+        language: &str,
+        content: &str,
+    ) -> Result<Vec<LoadWarning>, LoadError> {
+        let path = PathBuf::from(format!("<{language}>"));
+        let definitions = parse_file(content).map_err(|e| LoadError::from_parse_error(e, path))?;
+        let mut warnings = load_warnings(&definitions, language);
+
+        let mut registry = PhraseRegistry::new();
+        insert_definitions(&mut registry, language, definitions, None)?;
+
+        self.loaded_paths.remove(language);
+        self.commit_registry(language, registry);
+        warnings.extend(self.max_length_warnings(language));
+        Ok(warnings)
+    }
+
+    /// Overlay translations from a string onto a language's existing phrases.
+    ///
+    /// Unlike [`Self::load_translations_str`], existing phrases are kept. A
+    /// definition in the overlay replaces any existing definition with the same
+    /// name; other definitions are added. Returns the names of the replaced
+    /// definitions in overlay order. Use this to layer optional translation
+    /// packs (e.g. DLC) over a base locale; later overlays win.
+    ///
+    /// If the overlay fails to load, no phrases are changed.
+    /// [`Self::reload_translations`] reloads only the base file and discards
+    /// overlays.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::Locale;
+    ///
+    /// let mut locale = Locale::new();
+    /// locale.load_translations_str("en", r#"
+    ///     hello = "Hello!";
+    ///     card = "card";
+    /// "#).unwrap();
+    ///
+    /// let overridden = locale.overlay_translations_str("en", r#"
+    ///     card = "relic";
+    ///     dragon = "dragon";
+    /// "#).unwrap();
+    /// assert_eq!(overridden, vec!["card"]);
+    /// assert_eq!(locale.get_phrase("hello").unwrap().to_string(), "Hello!");
+    /// assert_eq!(locale.get_phrase("card").unwrap().to_string(), "relic");
+    /// ```
+    pub fn overlay_translations_str(
+        &mut self,
+        language: &str,
+        content: &str,
+    ) -> Result<Vec<String>, LoadError> {
+        self.overlay_translations_str_with_warnings(language, content)
+            .map(|(overridden, _)| overridden)
+    }
+
+    /// Overlay translations from a string, returning the replaced definition
+    /// names and the load-time lint warnings.
+    ///
+    /// Behaves like [`Self::overlay_translations_str`], which discards
+    /// warnings. The [`lint_definitions`], [`lint_plural_categories`], and
+    /// [`lint_max_length`] warnings cover only the overlay's definitions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rlf::{LoadWarning, Locale};
+    ///
+    /// let mut locale = Locale::new();
+    /// locale.load_translations_str("en", r#"card = "card";"#).unwrap();
+    ///
+    /// let (overridden, warnings) = locale.overlay_translations_str_with_warnings("en", r#"
+    ///     @maxlen(4)
+    ///     card = "relic";
+    /// "#).unwrap();
+    /// assert_eq!(overridden, vec!["card"]);
+    /// assert!(matches!(
+    ///     &warnings[..],
+    ///     [LoadWarning::ExceedsMaxLength { name, .. }] if name == "card"
+    /// ));
+    /// ```
+    pub fn overlay_translations_str_with_warnings(
+        &mut self,
+        language: &str,
+        content: &str,
+    ) -> Result<(Vec<String>, Vec<LoadWarning>), LoadError> {
+        let path = PathBuf::from(format!("<{language}>"));
+        let definitions = parse_file(content).map_err(|e| LoadError::from_parse_error(e, path))?;
+        let mut warnings = load_warnings(&definitions, language);
+        let names: HashSet<String> = definitions.iter().map(|def| def.name.clone()).collect();
+
+        let mut registry = self
+            .registries
+            .get(language)
+            .map(PhraseRegistry::copy_phrases)
+            .unwrap_or_default();
+        let overridden = definitions
+            .iter()
+            .filter(|def| registry.get(&def.name).is_some())
+            .map(|def| def.name.clone())
+            .collect();
+        insert_definitions(&mut registry, language, definitions, None)?;
+
+        self.commit_registry(language, registry);
+        warnings.extend(
+            self.max_length_warnings(language)
+                .into_iter()
+                .filter(|warning| {
+                    matches!(warning, LoadWarning::ExceedsMaxLength { name, .. } if names.contains(name))
+                }),
+        );
+        Ok((overridden, warnings))
+    }
+
+    /// Remove one phrase, by PhraseId hash, from `language`'s translations.
+    ///
+    /// Returns whether the phrase was loaded. Afterwards, looking the phrase up
+    /// in that language fails with `PhraseNotFound`, as does evaluating any
+    /// phrase that references it. Cached `eval_str` templates are cleared. See
+    /// [`PhraseRegistry::remove`] for memory behavior.
+    ///
+    /// ```
+    /// use rlf::{Locale, PhraseId};
+    ///
+    /// let mut locale = Locale::new();
+    /// locale.load_translations_str("en", r#"old = "Stale"; new = "Fresh";"#).unwrap();
+    ///
+    /// let id = PhraseId::from_name("old").as_u128();
+    /// assert!(locale.remove_phrase("en", id));
+    /// assert!(locale.get_phrase("old").is_err());
+    /// assert_eq!(locale.get_phrase("new").unwrap().to_string(), "Fresh");
+    /// ```
+    pub fn remove_phrase(&mut self, language: &str, id: u128) -> bool {
+        let removed = self
+            .registries
+            .get_mut(language)
+            .and_then(|registry| registry.remove_by_id(id))
+            .is_some();
+        if removed {
+            self.clear_template_cache();
+        }
+        removed
+    }
+
     /// Hot-reload translations from the original file path.
     ///
     /// Returns an error if the translations were loaded from a string
@@ -294,54 +884,21 @@ impl Locale {
         // Parse the content
         let definitions = parse_file(content).map_err(|e| {
             let default_path = PathBuf::from(format!("<{language}>"));
-            let path_buf = path.map(Path::to_path_buf).unwrap_or(default_path);
-
-            match e {
-                ParseError::Syntax {
-                    line,
-                    column,
-                    message,
-                } => LoadError::Parse {
-                    path: path_buf,
-                    line,
-                    column,
-                    message,
-                },
-                ParseError::UnexpectedEof { line, column } => LoadError::Parse {
-                    path: path_buf,
-                    line,
-                    column,
-                    message: "unexpected end of file".to_string(),
-                },
-                ParseError::InvalidUtf8 => LoadError::Parse {
-                    path: path_buf,
-                    line: 0,
-                    column: 0,
-                    message: "invalid UTF-8".to_string(),
-                },
-            }
+            LoadError::from_parse_error(e, path.map(Path::to_path_buf).unwrap_or(default_path))
         })?;
 
-        // Get or create registry for this language
-        let registry = self.registry_for_mut(language);
-
-        // Insert phrases (registry handles collision detection)
-        let count = definitions.len();
-        for def in definitions {
-            registry.insert(def).map_err(|e| {
-                let default_path = PathBuf::from(format!("<{language}>"));
-                LoadError::Parse {
-                    path: path.map(Path::to_path_buf).unwrap_or(default_path),
-                    line: 0,
-                    column: 0,
-                    message: format!("{e}"),
-                }
-            })?;
-        }
-
+        let mut registry = PhraseRegistry::new();
+        let count = insert_definitions(&mut registry, language, definitions, path)?;
+        self.commit_registry(language, registry);
         Ok(count)
     }
 
+    /// Make `registry` the language's registry, replacing any loaded phrases.
+    fn commit_registry(&mut self, language: &str, registry: PhraseRegistry) {
+        self.registries.insert(language.to_string(), registry);
+        self.clear_template_cache();
+    }
+
     // =========================================================================
     // Phrase Evaluation
     // =========================================================================
@@ -372,12 +929,34 @@ impl Locale {
             });
         }
 
-        let params = HashMap::new();
-        let mut ctx = EvalContext::with_string_context(&params, self.string_context.clone());
-        ctx.push_call(name)?;
-        let result = eval_phrase_def(def, &mut ctx, registry, &self.transforms, &self.language)?;
-        ctx.pop_call();
-        Ok(result)
+        registry
+            .evaluate_definition(
+                &self.transforms,
+                &self.language,
+                def,
+                &HashMap::new(),
+                self.string_context.clone(),
+            )
+            .map(|(phrase, _)| phrase)
+    }
+
+    /// Get the text of a parameterless phrase without allocating when possible.
+    ///
+    /// Phrases whose body is plain constant text (no interpolations, variants,
+    /// or `:from`) are borrowed straight from the loaded definition, which
+    /// avoids an allocation for static UI labels. Anything else is evaluated
+    /// as [`get_phrase`](Self::get_phrase) would and returned owned.
+    pub fn get_phrase_text(&self, name: &str) -> Result<Cow<'_, str>, EvalError> {
+        let literal = self
+            .registries
+            .get(&self.language)
+            .and_then(|registry| registry.get(name))
+            .and_then(literal_text)
+            .filter(|_| !self.transforms.pseudo_localize());
+        match literal {
+            Some(text) => Ok(Cow::Borrowed(text)),
+            None => Ok(Cow::Owned(self.get_phrase(name)?.text)),
+        }
     }
 
     /// Call a phrase with arguments in the current language.
@@ -385,39 +964,8 @@ impl Locale {
     /// Returns an error if the phrase is not found. Missing translations are
     /// treated as errors to be caught during development or by CI tooling.
     pub fn call_phrase(&self, name: &str, args: &[Value]) -> Result<Phrase, EvalError> {
-        let registry =
-            self.registries
-                .get(&self.language)
-                .ok_or_else(|| EvalError::PhraseNotFound {
-                    name: name.to_string(),
-                })?;
-
-        let def = registry
-            .get(name)
-            .ok_or_else(|| EvalError::PhraseNotFound {
-                name: name.to_string(),
-            })?;
-
-        if def.parameters.len() != args.len() {
-            return Err(EvalError::ArgumentCount {
-                phrase: name.to_string(),
-                expected: def.parameters.len(),
-                got: args.len(),
-            });
-        }
-
-        let params: HashMap<String, Value> = def
-            .parameters
-            .iter()
-            .zip(args.iter())
-            .map(|(name, value)| (name.clone(), value.clone()))
-            .collect();
-
-        let mut ctx = EvalContext::with_string_context(&params, self.string_context.clone());
-        ctx.push_call(name)?;
-        let result = eval_phrase_def(def, &mut ctx, registry, &self.transforms, &self.language)?;
-        ctx.pop_call();
-        Ok(result)
+        self.call_phrase_with_warnings(name, args)
+            .map(|(phrase, _)| phrase)
     }
 
     /// Call a phrase with arguments, also returning runtime warnings.
@@ -436,34 +984,18 @@ impl Locale {
                 .ok_or_else(|| EvalError::PhraseNotFound {
                     name: name.to_string(),
                 })?;
-
-        let def = registry
-            .get(name)
-            .ok_or_else(|| EvalError::PhraseNotFound {
-                name: name.to_string(),
-            })?;
-
-        if def.parameters.len() != args.len() {
-            return Err(EvalError::ArgumentCount {
-                phrase: name.to_string(),
-                expected: def.parameters.len(),
-                got: args.len(),
-            });
-        }
-
-        let params: HashMap<String, Value> = def
-            .parameters
-            .iter()
-            .zip(args.iter())
-            .map(|(name, value)| (name.clone(), value.clone()))
-            .collect();
-
-        let mut ctx = EvalContext::with_string_context(&params, self.string_context.clone());
-        ctx.push_call(name)?;
-        let result = eval_phrase_def(def, &mut ctx, registry, &self.transforms, &self.language)?;
-        ctx.pop_call();
-        let warnings = ctx.take_warnings();
-        Ok((result, warnings))
+        let declaration = self
+            .registries
+            .get(&self.source_language)
+            .and_then(|source| source.get(name));
+        let (def, params) = registry.bind_call(name, args, declaration)?;
+        registry.evaluate_definition(
+            &self.transforms,
+            &self.language,
+            def,
+            &params,
+            self.string_context.clone(),
+        )
     }
 
     /// Get a parameterless phrase by PhraseId in the current language.
@@ -534,15 +1066,13 @@ impl Locale {
                 })?;
 
         let template = self.cached_template(template_str)?;
-        let mut ctx = EvalContext::with_string_context(&params, self.string_context.clone());
-        let text = eval_template(
-            &template,
-            &mut ctx,
-            registry,
+        registry.evaluate_template(
             &self.transforms,
+            &template,
             &self.language,
-        )?;
-        Ok(Phrase::builder().text(text).build())
+            &params,
+            self.string_context.clone(),
+        )
     }
 
     /// Clear the template cache.
@@ -557,6 +1087,14 @@ impl Locale {
         self.template_cache.read().expect("lock poisoned").len()
     }
 
+    /// The [`lint_max_length`] warnings for the phrases loaded in `language`.
+    fn max_length_warnings(&self, language: &str) -> Vec<LoadWarning> {
+        self.registries
+            .get(language)
+            .map(|registry| lint_max_length(registry, language))
+            .unwrap_or_default()
+    }
+
     /// Look up or parse and cache a template string.
     fn cached_template(&self, template_str: &str) -> Result<Template, EvalError> {
         {
@@ -575,3 +1113,43 @@ impl Locale {
         Ok(template)
     }
 }
+
+/// Insert parsed definitions into a registry that is not yet committed, so a
+/// definition that fails to insert leaves the locale's phrases unchanged.
+fn insert_definitions(
+    registry: &mut PhraseRegistry,
+    language: &str,
+    definitions: Vec<PhraseDefinition>,
+    path: Option<&Path>,
+) -> Result<usize, LoadError> {
+    let count = definitions.len();
+    for def in definitions {
+        registry.insert(def).map_err(|e| {
+            let default_path = PathBuf::from(format!("<{language}>"));
+            LoadError::from_registry_error(e, path.map(Path::to_path_buf).unwrap_or(default_path))
+        })?;
+    }
+    Ok(count)
+}
+
+/// The definition lint warnings reported when loading a translation file.
+fn load_warnings(definitions: &[PhraseDefinition], language: &str) -> Vec<LoadWarning> {
+    let mut warnings = lint_definitions(definitions, language);
+    warnings.extend(lint_plural_categories(definitions, language));
+    warnings
+}
+
+/// The text of a definition that is a single constant literal, if it is one.
+fn literal_text(def: &PhraseDefinition) -> Option<&str> {
+    if !def.parameters.is_empty() || def.from_param.is_some() {
+        return None;
+    }
+    let PhraseBody::Simple(template) = &def.body else {
+        return None;
+    };
+    match template.segments.as_slice() {
+        [] => Some(""),
+        [Segment::Literal(text)] => Some(text),
+        _ => None,
+    }
+}
@@ -0,0 +1,238 @@
+//! Marked spans inside text that is still being evaluated.
+//!
+//! The evaluator tracks two kinds of span in the text it builds:
+//! - protected spans, from phrases tagged `:protect`, whose casing case
+//!   transforms keep;
+//! - plain spans, from caller-supplied values, whose `<...>` is never markup.
+//!
+//! Spans are delimited by Unicode noncharacters, which never appear in
+//! interchanged text. Only the case transforms read the markers. Every other
+//! transform is given its input without them ([`strip_value`]), and the
+//! markers are put back around the part of its output that repeats the input
+//! ([`restore`]). Before the [`Locale`](crate::Locale) or
+//! [`PhraseRegistry`](crate::PhraseRegistry) returns a phrase, the markers are
+//! removed and the plain spans are recorded on the phrase as byte ranges
+//! ([`finish`]), which [`Phrase::to_rich`] reads.
+
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::iter;
+use std::mem;
+
+use crate::types::{Phrase, Value};
+
+/// Tag marking a phrase whose casing case transforms must keep.
+pub(crate) const PROTECT_TAG: &str = "protect";
+
+/// Marker opening a protected span.
+pub(crate) const PROTECT_START: char = '\u{FDD0}';
+
+/// Marker closing a protected span.
+pub(crate) const PROTECT_END: char = '\u{FDD1}';
+
+/// Marker opening a plain span.
+const PLAIN_START: char = '\u{FDD2}';
+
+/// Marker closing a plain span.
+const PLAIN_END: char = '\u{FDD3}';
+
+const MARKERS: [char; 4] = [PROTECT_START, PROTECT_END, PLAIN_START, PLAIN_END];
+
+/// Wrap `text` in protection markers.
+pub(crate) fn protect(text: &str) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    format!("{PROTECT_START}{text}{PROTECT_END}")
+}
+
+/// Wrap `text` in plain-span markers.
+pub(crate) fn plain(text: &str) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    format!("{PLAIN_START}{text}{PLAIN_END}")
+}
+
+/// Whether `c` is one of the span markers.
+pub(crate) fn is_marker(c: char) -> bool {
+    MARKERS.contains(&c)
+}
+
+/// `text` without its span markers.
+pub(crate) fn strip(text: &str) -> Cow<'_, str> {
+    if text.contains(MARKERS) {
+        Cow::Owned(text.replace(MARKERS, ""))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// `value` without span markers in its text, variants, or list items.
+pub(crate) fn strip_value(value: &Value) -> Cow<'_, Value> {
+    match value {
+        Value::String(text) if text.contains(MARKERS) => {
+            Cow::Owned(Value::String(strip(text).into_owned()))
+        }
+        Value::Phrase(phrase) if phrase_texts(phrase).any(|text| text.contains(MARKERS)) => {
+            Cow::Owned(Value::Phrase(unmark(phrase.clone())))
+        }
+        Value::List(items)
+            if items
+                .iter()
+                .any(|item| matches!(strip_value(item), Cow::Owned(_))) =>
+        {
+            Cow::Owned(Value::List(
+                items
+                    .iter()
+                    .map(|item| strip_value(item).into_owned())
+                    .collect(),
+            ))
+        }
+        _ => Cow::Borrowed(value),
+    }
+}
+
+/// Apply `f` to the parts of `text` outside protected spans, copying the
+/// protected spans (markers included) unchanged.
+pub(crate) fn map_unprotected(text: &str, mut f: impl FnMut(&str) -> String) -> String {
+    if !text.contains(PROTECT_START) {
+        return f(text);
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(PROTECT_START) {
+        result.push_str(&f(&rest[..start]));
+        let end = rest[start..]
+            .find(PROTECT_END)
+            .map_or(rest.len(), |end| start + end + PROTECT_END.len_utf8());
+        result.push_str(&rest[start..end]);
+        rest = &rest[end..];
+    }
+    result.push_str(&f(rest));
+    result
+}
+
+/// Put the markers of `input` back into `output`, the result of a transform
+/// that was given `input` without them.
+///
+/// The text of each input item (the phrase text or one of its variants) is
+/// looked for in order in the output, and the first occurrence is replaced
+/// with the marked text. If an item with plain spans does not appear, the
+/// whole output becomes one plain span, since it may hold caller text.
+pub(crate) fn restore(input: &Value, output: String) -> String {
+    let items: Vec<Vec<&str>> = match input {
+        Value::List(items) => items.iter().map(item_texts).collect(),
+        other => vec![item_texts(other)],
+    };
+    let mut result = String::with_capacity(output.len());
+    let mut rest = output.as_str();
+    for mut texts in items {
+        texts.retain(|text| text.contains(MARKERS));
+        texts.sort_by_key(|text| Reverse(text.len()));
+        let found = texts.iter().find_map(|marked| {
+            let stripped = strip(marked);
+            let start = rest.find(&*stripped).filter(|_| !stripped.is_empty())?;
+            Some((*marked, start, stripped.len()))
+        });
+        match found {
+            Some((marked, start, len)) => {
+                result.push_str(&rest[..start]);
+                result.push_str(marked);
+                rest = &rest[start + len..];
+            }
+            None if texts.iter().any(|text| text.contains(PLAIN_START)) => {
+                return plain(&output);
+            }
+            None => {}
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Mark the first occurrence of caller-supplied `text` in `output` as plain.
+pub(crate) fn mark_plain_occurrence(output: String, text: &str) -> String {
+    match output.find(text).filter(|_| !text.is_empty()) {
+        Some(start) => format!(
+            "{}{}{}",
+            &output[..start],
+            plain(text),
+            &output[start + text.len()..]
+        ),
+        None => output,
+    }
+}
+
+/// Re-insert the markers for the plain spans recorded on a returned phrase,
+/// so a phrase passed back in as an argument keeps them.
+pub(crate) fn remark(value: Value) -> Value {
+    match value {
+        Value::Phrase(mut phrase) if !phrase.plain.is_empty() => {
+            let mut text = String::with_capacity(phrase.text.len());
+            let mut last = 0;
+            for range in mem::take(&mut phrase.plain) {
+                text.push_str(&phrase.text[last..range.start]);
+                text.push_str(&plain(&phrase.text[range.clone()]));
+                last = range.end;
+            }
+            text.push_str(&phrase.text[last..]);
+            phrase.text = text;
+            Value::Phrase(phrase)
+        }
+        Value::List(items) => Value::List(items.into_iter().map(remark).collect()),
+        other => other,
+    }
+}
+
+/// Remove the markers from a finished phrase, recording its plain spans.
+pub(crate) fn finish(mut phrase: Phrase) -> Phrase {
+    let mut text = String::with_capacity(phrase.text.len());
+    let mut depth = 0usize;
+    let mut start = 0;
+    for c in phrase.text.chars() {
+        match c {
+            PLAIN_START => {
+                if depth == 0 {
+                    start = text.len();
+                }
+                depth += 1;
+            }
+            PLAIN_END => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 && text.len() > start {
+                    phrase.plain.push(start..text.len());
+                }
+            }
+            PROTECT_START | PROTECT_END => {}
+            _ => text.push(c),
+        }
+    }
+    phrase.text = text;
+    unmark(phrase)
+}
+
+/// Remove the markers from the text and variants of a phrase.
+fn unmark(mut phrase: Phrase) -> Phrase {
+    let strip_in_place = |text: &mut String| {
+        if text.contains(MARKERS) {
+            *text = text.replace(MARKERS, "");
+        }
+    };
+    strip_in_place(&mut phrase.text);
+    phrase.variants.values_mut().for_each(strip_in_place);
+    phrase
+}
+
+/// The texts a value can contribute to a transform's output.
+fn item_texts(value: &Value) -> Vec<&str> {
+    match value {
+        Value::String(text) => vec![text],
+        Value::Phrase(phrase) => phrase_texts(phrase).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn phrase_texts(phrase: &Phrase) -> impl Iterator<Item = &str> {
+    iter::once(phrase.text.as_str()).chain(phrase.variants.values().map(String::as_str))
+}
@@ -7,17 +7,34 @@
 mod context;
 mod error;
 mod evaluator;
+mod languages;
 pub mod lint;
 mod locale;
+mod marks;
 mod plural;
+mod pseudo;
 mod registry;
 mod transforms;
+mod validate;
+mod verify;
 
 pub use context::EvalContext;
-pub use error::{EvalError, EvalWarning, LoadError, LoadWarning, compute_suggestions};
+pub use error::{
+    EvalError, EvalWarning, LoadError, LoadWarning, TemplateError, VerifyError, compute_suggestions,
+};
 pub use evaluator::{eval_phrase_def, eval_template};
-pub use lint::{lint_definitions, run_lints};
+pub use languages::{LanguageSupport, language_support, supported_languages};
+pub use lint::{
+    lint_definitions, lint_literal_numbers, lint_max_length, lint_mixed_language,
+    lint_plural_categories, run_lints,
+};
 pub use locale::Locale;
-pub use plural::plural_category;
-pub use registry::PhraseRegistry;
-pub use transforms::{TransformKind, TransformRegistry};
+pub use plural::{ordinal_category, plural_categories, plural_category};
+pub use registry::{PhraseInfo, PhraseRegistry};
+pub use transforms::{
+    ApostropheStyle, CompoundSplitter, DateFormatter, DateStyle, DateTimeComponent, DigitStyle,
+    LookupEntry, LookupTable, OutputTarget, PluralRule, Register, SharpSStyle, TransformKind,
+    TransformRegistry, WordSegmenter,
+};
+pub use validate::validate_template;
+pub use verify::verify_translations;
@@ -3,7 +3,12 @@
 //! This module provides plural category resolution following CLDR rules.
 //! Different languages have different plural rules - English has "one" and "other",
 //! while Russian has "one", "few", "many", and "other", and Arabic uses all six
-//! categories: "zero", "one", "two", "few", "many", "other".
+//! categories: "zero", "one", "two", "few", "many", "other". Slavic languages
+//! (Russian, Ukrainian, Belarusian, Polish, Czech, Slovak, Slovenian, and the
+//! Serbo-Croatian variants) use their full CLDR category sets.
+//!
+//! Ordinal categories ("1st", "2nd", "3rd", "4th" in English) come from the
+//! separate CLDR ordinal rules; see [`ordinal_category`].
 //!
 //! Plural rules are cached per thread per language to avoid re-creating
 //! `PluralRules` instances on every call. The cache is initialized lazily
@@ -13,35 +18,40 @@ use std::cell::RefCell;
 
 use icu_locale_core::locale;
 use icu_plurals::{PluralCategory, PluralRuleType, PluralRules};
-
-/// Supported language codes for plural rule resolution.
-const SUPPORTED_LANGUAGES: &[&str] = &[
-    "ar", "bn", "de", "el", "en", "es", "fa", "fr", "he", "hi", "id", "it", "ja", "ko", "nl", "pl",
-    "pt", "ro", "ru", "th", "tr", "uk", "vi", "zh",
-];
+use rlf_semantics::plural_languages;
 
 thread_local! {
-    /// Per-thread cache of `PluralRules` keyed by language code.
-    static PLURAL_RULES_CACHE: RefCell<Vec<(&'static str, PluralRules)>> = const { RefCell::new(Vec::new()) };
+    /// Per-thread cache of `PluralRules` keyed by language code and rule type.
+    static PLURAL_RULES_CACHE: RefCell<Vec<(&'static str, PluralRuleType, PluralRules)>> = const { RefCell::new(Vec::new()) };
 }
 
 /// Normalize a language code to a supported static string reference.
 ///
-/// Returns the canonical `&'static str` for the language, or `"en"` for
-/// unrecognized codes.
+/// Region and script subtags are ignored (`ru-RU` and `sr_Latn` resolve to
+/// `ru` and `sr`). Returns `"en"` for unrecognized codes.
 fn normalize_lang(lang: &str) -> &'static str {
-    SUPPORTED_LANGUAGES
+    let primary = lang.split(['-', '_']).next().unwrap_or(lang);
+    plural_languages()
         .iter()
-        .find(|&&code| code == lang)
+        .find(|&&code| code == primary)
         .copied()
         .unwrap_or("en")
 }
 
-/// Build `PluralRules` for a normalized language code.
-fn build_rules(lang: &'static str) -> PluralRules {
+/// Build cardinal or ordinal `PluralRules` for a normalized language code.
+fn build_rules(lang: &'static str, rule_type: PluralRuleType) -> PluralRules {
     let loc = match lang {
         "en" => locale!("en"),
         "ru" => locale!("ru"),
+        "uk" => locale!("uk"),
+        "be" => locale!("be"),
+        "pl" => locale!("pl"),
+        "cs" => locale!("cs"),
+        "sk" => locale!("sk"),
+        "sl" => locale!("sl"),
+        "hr" => locale!("hr"),
+        "bs" => locale!("bs"),
+        "sr" => locale!("sr"),
         "ar" => locale!("ar"),
         "de" => locale!("de"),
         "es" => locale!("es"),
@@ -52,12 +62,12 @@ fn build_rules(lang: &'static str) -> PluralRules {
         "zh" => locale!("zh"),
         "ko" => locale!("ko"),
         "nl" => locale!("nl"),
-        "pl" => locale!("pl"),
         "tr" => locale!("tr"),
-        "uk" => locale!("uk"),
         "vi" => locale!("vi"),
         "th" => locale!("th"),
         "id" => locale!("id"),
+        "ms" => locale!("ms"),
+        "ga" => locale!("ga"),
         "el" => locale!("el"),
         "ro" => locale!("ro"),
         "fa" => locale!("fa"),
@@ -66,8 +76,7 @@ fn build_rules(lang: &'static str) -> PluralRules {
         "he" => locale!("he"),
         _ => locale!("en"),
     };
-    PluralRules::try_new(loc.into(), PluralRuleType::Cardinal.into())
-        .expect("locale should be supported")
+    PluralRules::try_new(loc.into(), rule_type.into()).expect("locale should be supported")
 }
 
 /// Translate a `PluralCategory` enum to its string representation.
@@ -108,14 +117,73 @@ fn category_str(category: PluralCategory) -> &'static str {
 /// assert_eq!(plural_category("ru", 5), "many");
 /// ```
 pub fn plural_category(lang: &str, n: i64) -> &'static str {
-    let lang = normalize_lang(lang);
+    with_rules(normalize_lang(lang), PluralRuleType::Cardinal, |rules| {
+        category_str(rules.category_for(n))
+    })
+}
+
+/// Get CLDR ordinal category for a number in a given language.
+///
+/// Ordinal rules decide forms like "1st", "2nd", "3rd" and "4th", and are
+/// separate from the cardinal rules used by [`plural_category`]. English
+/// distinguishes "one", "two", "few", and "other"; many languages use "other"
+/// for every number.
+///
+/// ```
+/// use rlf::interpreter::ordinal_category;
+///
+/// assert_eq!(ordinal_category("en", 1), "one");
+/// assert_eq!(ordinal_category("en", 2), "two");
+/// assert_eq!(ordinal_category("en", 3), "few");
+/// assert_eq!(ordinal_category("en", 11), "other");
+/// assert_eq!(ordinal_category("de", 1), "other");
+/// ```
+pub fn ordinal_category(lang: &str, n: i64) -> &'static str {
+    with_rules(normalize_lang(lang), PluralRuleType::Ordinal, |rules| {
+        category_str(rules.category_for(n))
+    })
+}
+
+/// Get the CLDR plural categories a language distinguishes.
+///
+/// Returns `None` for languages without plural rule support, rather than
+/// falling back to English as [`plural_category`] does.
+///
+/// ```
+/// use rlf::interpreter::plural_categories;
+///
+/// assert_eq!(plural_categories("en"), Some(vec!["one", "other"]));
+/// assert_eq!(plural_categories("ru"), Some(vec!["one", "few", "many", "other"]));
+/// assert_eq!(plural_categories("tlh"), None);
+/// ```
+pub fn plural_categories(lang: &str) -> Option<Vec<&'static str>> {
+    let primary = lang.split(['-', '_']).next().unwrap_or(lang);
+    if !plural_languages().contains(&primary) {
+        return None;
+    }
+    Some(with_rules(
+        normalize_lang(primary),
+        PluralRuleType::Cardinal,
+        |rules| rules.categories().map(category_str).collect(),
+    ))
+}
+
+/// Run `f` with the cached rules of `rule_type` for a normalized language code.
+fn with_rules<T>(
+    lang: &'static str,
+    rule_type: PluralRuleType,
+    f: impl FnOnce(&PluralRules) -> T,
+) -> T {
     PLURAL_RULES_CACHE.with_borrow_mut(|cache| {
-        if let Some(entry) = cache.iter().find(|(code, _)| *code == lang) {
-            return category_str(entry.1.category_for(n));
+        if let Some(entry) = cache
+            .iter()
+            .find(|(code, ty, _)| *code == lang && *ty == rule_type)
+        {
+            return f(&entry.2);
         }
-        let rules = build_rules(lang);
-        let category = category_str(rules.category_for(n));
-        cache.push((lang, rules));
-        category
+        let rules = build_rules(lang, rule_type);
+        let result = f(&rules);
+        cache.push((lang, rule_type, rules));
+        result
     })
 }
@@ -0,0 +1,60 @@
+//! Pseudo-localization for testing layouts before real translations exist.
+
+use crate::interpreter::marks::is_marker;
+use crate::interpreter::transforms::markup_tag_len;
+
+/// Accented forms of `A`-`Z`.
+const UPPER: [char; 26] = [
+    'Å', 'Ɓ', 'Ç', 'Ð', 'É', 'Ƒ', 'Ĝ', 'Ĥ', 'Î', 'Ĵ', 'Ķ', 'Ļ', 'Ṁ', 'Ñ', 'Ö', 'Þ', 'Ǫ', 'Ŕ', 'Š',
+    'Ţ', 'Û', 'Ṽ', 'Ŵ', 'Ẋ', 'Ý', 'Ž',
+];
+
+/// Accented forms of `a`-`z`.
+const LOWER: [char; 26] = [
+    'å', 'ƀ', 'ç', 'ð', 'é', 'ƒ', 'ĝ', 'ĥ', 'î', 'ĵ', 'ķ', 'ļ', 'ɱ', 'ñ', 'ö', 'þ', 'ǫ', 'ŕ', 'š',
+    'ţ', 'û', 'ṽ', 'ŵ', 'ẋ', 'ý', 'ž',
+];
+
+/// Extra length added to pseudo-localized text, as a percentage of its
+/// visible characters. Translations commonly run about this much longer
+/// than English.
+const EXPANSION_PERCENT: usize = 30;
+
+/// Replace ASCII letters in `text` with accented look-alikes, leaving markup
+/// tags untouched so they still render.
+pub(crate) fn accent(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if let Some(len) = markup_tag_len(rest) {
+            result.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+        result.push(match c {
+            'A'..='Z' => UPPER[(c as u8 - b'A') as usize],
+            'a'..='z' => LOWER[(c as u8 - b'a') as usize],
+            _ => c,
+        });
+        rest = &rest[c.len_utf8()..];
+    }
+    result
+}
+
+/// Bracket `text` and pad it by [`EXPANSION_PERCENT`] of its visible length, so
+/// truncated or clipped strings are easy to spot.
+pub(crate) fn expand(text: &str) -> String {
+    let mut visible = 0;
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let len = markup_tag_len(rest).unwrap_or_else(|| {
+            if !is_marker(c) {
+                visible += 1;
+            }
+            c.len_utf8()
+        });
+        rest = &rest[len..];
+    }
+    let padding = (visible * EXPANSION_PERCENT).div_ceil(100);
+    format!("[{text}{}]", "~".repeat(padding))
+}
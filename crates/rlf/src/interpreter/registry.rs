@@ -1,13 +1,20 @@
 //! Phrase registry for storing and looking up phrase definitions.
 
 use std::collections::HashMap;
+use std::io::Read;
+use std::path::PathBuf;
 use std::sync::RwLock;
 
+use const_fnv1a_hash::fnv1a_hash_str_64;
+
+use crate::interpreter::evaluator::{check_argument_types, render_phrase_def, render_template};
+use crate::interpreter::marks;
+use crate::interpreter::pseudo;
 use crate::interpreter::transforms::TransformRegistry;
-use crate::interpreter::{EvalContext, EvalError, eval_phrase_def, eval_template};
-use crate::parser::ast::{DefinitionKind, PhraseDefinition, Template};
-use crate::parser::{ParseError, parse_file, parse_template};
-use crate::types::{Phrase, PhraseId, Value};
+use crate::interpreter::{EvalContext, EvalError, EvalWarning, LoadError};
+use crate::parser::ast::{DefinitionKind, PhraseAttribute, PhraseDefinition, Template};
+use crate::parser::{AstDebug, ParseError, parse_file, parse_template};
+use crate::types::{Phrase, PhraseId, Tag, Value};
 
 /// A registry for storing and looking up phrase definitions.
 ///
@@ -19,6 +26,9 @@ pub struct PhraseRegistry {
     phrases: HashMap<String, PhraseDefinition>,
     /// Maps PhraseId hash to phrase name for id-based lookup.
     id_to_name: HashMap<u128, String>,
+    /// How many definitions declare each tag, kept in step with `phrases` so
+    /// inline tag lookups do not scan every definition.
+    declared_tags: HashMap<String, usize>,
     /// Cache of parsed template ASTs for `eval_str()`.
     ///
     /// Uses `RwLock` for interior mutability so `eval_str` can remain `&self`
@@ -26,12 +36,99 @@ pub struct PhraseRegistry {
     template_cache: RwLock<HashMap<String, Template>>,
 }
 
+/// Declared metadata for a registered phrase.
+///
+/// Returned by [`PhraseRegistry::phrase_info`] for tooling that needs the
+/// translator notes and length limits written as `@note` and `@maxlen`.
+#[derive(Debug, Clone, Copy)]
+pub struct PhraseInfo<'a> {
+    definition: &'a PhraseDefinition,
+}
+
+impl<'a> PhraseInfo<'a> {
+    pub(crate) fn new(definition: &'a PhraseDefinition) -> Self {
+        Self { definition }
+    }
+
+    /// The phrase name.
+    pub fn name(&self) -> &'a str {
+        &self.definition.name
+    }
+
+    /// Whether the definition is a term or a phrase.
+    pub fn kind(&self) -> DefinitionKind {
+        self.definition.kind
+    }
+
+    /// Declared parameter names, without the `$` prefix.
+    pub fn parameters(&self) -> &'a [String] {
+        &self.definition.parameters
+    }
+
+    /// Metadata tags declared on the definition.
+    pub fn tags(&self) -> &'a [Tag] {
+        &self.definition.tags
+    }
+
+    /// All attributes, in source order.
+    pub fn attributes(&self) -> &'a [PhraseAttribute] {
+        &self.definition.attributes
+    }
+
+    /// The `@note` text, if any. Multiple notes are joined with newlines.
+    pub fn note(&self) -> Option<String> {
+        let notes: Vec<&str> = self
+            .attributes()
+            .iter()
+            .filter_map(|attribute| match attribute {
+                PhraseAttribute::Note(text) => Some(text.as_str()),
+                PhraseAttribute::MaxLength(_) => None,
+            })
+            .collect();
+        (!notes.is_empty()).then(|| notes.join("\n"))
+    }
+
+    /// The `@maxlen` limit, if any.
+    pub fn max_length(&self) -> Option<usize> {
+        self.attributes()
+            .iter()
+            .find_map(|attribute| match attribute {
+                PhraseAttribute::MaxLength(max) => Some(*max),
+                PhraseAttribute::Note(_) => None,
+            })
+    }
+}
+
 impl PhraseRegistry {
     /// Create a new empty registry.
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Create an empty registry with room for `capacity` phrases before it
+    /// reallocates.
+    ///
+    /// Useful when the size of a translation pack is known up front, such as
+    /// when reloading a language that was loaded before.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            phrases: HashMap::with_capacity(capacity),
+            id_to_name: HashMap::with_capacity(capacity),
+            declared_tags: HashMap::new(),
+            template_cache: RwLock::default(),
+        }
+    }
+
+    /// A registry with the same phrases and an empty template cache.
+    pub(crate) fn copy_phrases(&self) -> Self {
+        Self {
+            phrases: self.phrases.clone(),
+            id_to_name: self.id_to_name.clone(),
+            declared_tags: self.declared_tags.clone(),
+            template_cache: RwLock::default(),
+        }
+    }
+
     /// Get a phrase definition by name.
     pub fn get(&self, name: &str) -> Option<&PhraseDefinition> {
         self.phrases.get(name)
@@ -67,10 +164,60 @@ impl PhraseRegistry {
         }
 
         self.id_to_name.insert(hash, name.clone());
-        self.phrases.insert(name, def);
+        self.count_tags(&def, true);
+        if let Some(replaced) = self.phrases.insert(name, def) {
+            self.count_tags(&replaced, false);
+        }
         Ok(())
     }
 
+    /// Remove a phrase by name, returning its definition if it was registered.
+    ///
+    /// Later lookups of the phrase, and evaluation of any phrase or
+    /// [`Self::eval_str`] template that references it, fail with
+    /// `PhraseNotFound`. The template cache is cleared so no template parsed
+    /// while the phrase existed outlives it. Removing entries does not shrink
+    /// the registry's allocation; use [`Self::clear`] or drop the registry to
+    /// release it.
+    pub fn remove(&mut self, name: &str) -> Option<PhraseDefinition> {
+        let def = self.phrases.remove(name)?;
+        self.id_to_name.remove(&PhraseId::from_name(name).as_u128());
+        self.count_tags(&def, false);
+        self.clear_template_cache();
+        Some(def)
+    }
+
+    /// Remove a phrase by PhraseId hash. See [`Self::remove`].
+    pub fn remove_by_id(&mut self, id: u128) -> Option<PhraseDefinition> {
+        let name = self.id_to_name.get(&id)?.clone();
+        self.remove(&name)
+    }
+
+    /// Remove every phrase and cached template, releasing their memory.
+    pub fn clear(&mut self) {
+        self.phrases = HashMap::new();
+        self.id_to_name = HashMap::new();
+        self.declared_tags = HashMap::new();
+        self.template_cache = RwLock::default();
+    }
+
+    /// Add or remove `def`'s tags from the declared tag counts.
+    fn count_tags(&mut self, def: &PhraseDefinition, added: bool) {
+        for tag in &def.tags {
+            if added {
+                *self
+                    .declared_tags
+                    .entry(tag.as_str().to_string())
+                    .or_default() += 1;
+            } else if let Some(count) = self.declared_tags.get_mut(tag.as_str()) {
+                *count -= 1;
+                if *count == 0 {
+                    self.declared_tags.remove(tag.as_str());
+                }
+            }
+        }
+    }
+
     /// Load phrases from a string containing .rlf format.
     ///
     /// Returns the number of phrases loaded.
@@ -101,6 +248,41 @@ impl PhraseRegistry {
         Ok(count)
     }
 
+    /// Load phrases in .rlf format from a reader.
+    ///
+    /// Accepts any `Read`, such as an open file or a decompression stream. The
+    /// input is buffered in full before parsing, so error line and column
+    /// numbers refer to positions in the complete stream. Errors report the
+    /// path `<reader>`; invalid UTF-8 is a parse error.
+    ///
+    /// Returns the number of phrases loaded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    ///
+    /// use rlf::PhraseRegistry;
+    ///
+    /// let mut registry = PhraseRegistry::new();
+    /// let reader = Cursor::new(r#"hello = "Hello, world!";"#);
+    /// assert_eq!(registry.load_phrases_from_reader(reader).unwrap(), 1);
+    /// ```
+    pub fn load_phrases_from_reader(&mut self, mut reader: impl Read) -> Result<usize, LoadError> {
+        let path = PathBuf::from("<reader>");
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .map_err(|source| LoadError::Io {
+                path: path.clone(),
+                source,
+            })?;
+        let content = String::from_utf8(bytes)
+            .map_err(|_| LoadError::from_parse_error(ParseError::InvalidUtf8, path.clone()))?;
+        self.load_phrases(&content)
+            .map_err(|e| LoadError::from_parse_error(e, path))
+    }
+
     // =========================================================================
     // Public Evaluation API
     // =========================================================================
@@ -136,12 +318,43 @@ impl PhraseRegistry {
         template_str: &str,
         lang: &str,
         params: HashMap<String, Value>,
+    ) -> Result<Phrase, EvalError> {
+        self.eval_str_with_transforms(TransformRegistry::builtin(), template_str, lang, params)
+    }
+
+    /// Evaluate a template string with the settings of `transforms`.
+    ///
+    /// [`eval_str`](Self::eval_str) uses [`TransformRegistry::builtin`], which
+    /// has every option at its default. Pass a [`Locale`](crate::Locale)'s
+    /// [`transforms`](crate::Locale::transforms) to evaluate as that locale
+    /// would: its output target, digit and apostrophe styles, date formatter,
+    /// sentence capitalization, and pseudo-localization all apply.
+    pub fn eval_str_with_transforms(
+        &self,
+        transforms: &TransformRegistry,
+        template_str: &str,
+        lang: &str,
+        params: HashMap<String, Value>,
     ) -> Result<Phrase, EvalError> {
         let template = self.cached_template(template_str)?;
-        let transform_registry = TransformRegistry::new();
-        let mut ctx = EvalContext::new(&params);
-        let text = eval_template(&template, &mut ctx, self, &transform_registry, lang)?;
-        Ok(Phrase::builder().text(text).build())
+        self.evaluate_template(transforms, &template, lang, &params, None)
+    }
+
+    /// Evaluate a parsed template, finishing the text for `transforms`.
+    pub(crate) fn evaluate_template(
+        &self,
+        transforms: &TransformRegistry,
+        template: &Template,
+        lang: &str,
+        params: &HashMap<String, Value>,
+        string_context: Option<String>,
+    ) -> Result<Phrase, EvalError> {
+        let mut ctx = EvalContext::with_string_context(params, string_context);
+        let text = render_template(template, &mut ctx, self, transforms, lang)?;
+        Ok(finish_output(
+            transforms,
+            Phrase::builder().text(text).build(),
+        ))
     }
 
     /// Clear the template cache.
@@ -197,17 +410,43 @@ impl PhraseRegistry {
     /// assert_eq!(result.to_string(), "Hello, World!");
     /// ```
     pub fn call_phrase(&self, lang: &str, name: &str, args: &[Value]) -> Result<Phrase, EvalError> {
+        self.call_phrase_with_transforms(TransformRegistry::builtin(), lang, name, args)
+    }
+
+    /// Call a phrase with the settings of `transforms`.
+    ///
+    /// See [`eval_str_with_transforms`](Self::eval_str_with_transforms).
+    pub fn call_phrase_with_transforms(
+        &self,
+        transforms: &TransformRegistry,
+        lang: &str,
+        name: &str,
+        args: &[Value],
+    ) -> Result<Phrase, EvalError> {
+        let (def, params) = self.bind_call(name, args, None)?;
+        self.evaluate_definition(transforms, lang, def, &params, None)
+            .map(|(phrase, _)| phrase)
+    }
+
+    /// Look up a phrase for a call and bind `args` to its parameters.
+    ///
+    /// Checks that the definition takes arguments, that their number matches,
+    /// and that they satisfy the parameter types of `declaration`, or of the
+    /// definition itself when there is no separate declaration.
+    pub(crate) fn bind_call(
+        &self,
+        name: &str,
+        args: &[Value],
+        declaration: Option<&PhraseDefinition>,
+    ) -> Result<(&PhraseDefinition, HashMap<String, Value>), EvalError> {
         let def = self.get(name).ok_or_else(|| EvalError::PhraseNotFound {
             name: name.to_string(),
         })?;
-
         if def.kind == DefinitionKind::Term && !args.is_empty() {
             return Err(EvalError::ArgumentsToTerm {
                 name: name.to_string(),
             });
         }
-
-        // Check argument count
         if def.parameters.len() != args.len() {
             return Err(EvalError::ArgumentCount {
                 phrase: name.to_string(),
@@ -215,21 +454,31 @@ impl PhraseRegistry {
                 got: args.len(),
             });
         }
-
-        // Build param map
-        let params: HashMap<String, Value> = def
+        check_argument_types(name, declaration.unwrap_or(def), args)?;
+        let params = def
             .parameters
             .iter()
             .zip(args.iter())
             .map(|(name, value)| (name.clone(), value.clone()))
             .collect();
+        Ok((def, params))
+    }
 
-        let transform_registry = TransformRegistry::new();
-        let mut ctx = EvalContext::new(&params);
-        ctx.push_call(name)?;
-        let result = eval_phrase_def(def, &mut ctx, self, &transform_registry, lang)?;
+    /// Evaluate a definition with bound parameters, returning the finished
+    /// phrase and the runtime warnings raised while evaluating it.
+    pub(crate) fn evaluate_definition(
+        &self,
+        transforms: &TransformRegistry,
+        lang: &str,
+        def: &PhraseDefinition,
+        params: &HashMap<String, Value>,
+        string_context: Option<String>,
+    ) -> Result<(Phrase, Vec<EvalWarning>), EvalError> {
+        let mut ctx = EvalContext::with_string_context(params, string_context);
+        ctx.push_call(&def.name)?;
+        let result = render_phrase_def(def, &mut ctx, self, transforms, lang)?;
         ctx.pop_call();
-        Ok(result)
+        Ok((finish_output(transforms, result), ctx.take_warnings()))
     }
 
     /// Get a parameterless phrase as a Phrase value.
@@ -254,6 +503,18 @@ impl PhraseRegistry {
     /// assert_eq!(result.to_string(), "Hello, world!");
     /// ```
     pub fn get_phrase(&self, lang: &str, name: &str) -> Result<Phrase, EvalError> {
+        self.get_phrase_with_transforms(TransformRegistry::builtin(), lang, name)
+    }
+
+    /// Get a parameterless phrase with the settings of `transforms`.
+    ///
+    /// See [`eval_str_with_transforms`](Self::eval_str_with_transforms).
+    pub fn get_phrase_with_transforms(
+        &self,
+        transforms: &TransformRegistry,
+        lang: &str,
+        name: &str,
+    ) -> Result<Phrase, EvalError> {
         let def = self.get(name).ok_or_else(|| EvalError::PhraseNotFound {
             name: name.to_string(),
         })?;
@@ -264,13 +525,8 @@ impl PhraseRegistry {
             });
         }
 
-        let transform_registry = TransformRegistry::new();
-        let params = HashMap::new();
-        let mut ctx = EvalContext::new(&params);
-        ctx.push_call(name)?;
-        let result = eval_phrase_def(def, &mut ctx, self, &transform_registry, lang)?;
-        ctx.pop_call();
-        Ok(result)
+        self.evaluate_definition(transforms, lang, def, &HashMap::new(), None)
+            .map(|(phrase, _)| phrase)
     }
 
     /// Call a phrase by PhraseId with arguments.
@@ -339,6 +595,36 @@ impl PhraseRegistry {
         self.phrases.get(name).map(|def| def.kind)
     }
 
+    /// Whether any definition in this registry declares `tag`.
+    pub(crate) fn declares_tag(&self, tag: &str) -> bool {
+        self.declared_tags.contains_key(tag)
+    }
+
+    /// Get the declared metadata for a phrase, including its attributes.
+    pub fn phrase_info(&self, name: &str) -> Option<PhraseInfo<'_>> {
+        self.phrases.get(name).map(PhraseInfo::new)
+    }
+
+    /// A hash of every definition in this registry, stable across runs and
+    /// platforms.
+    ///
+    /// Definitions are hashed in name order from their [`AstDebug`] rendering,
+    /// so registries loaded from equivalent sources hash equally whatever the
+    /// load order, while any change to a phrase's text, variants, tags,
+    /// parameters, or attributes changes the hash. Source formatting and
+    /// comments do not. Render caches can key entries on
+    /// `(phrase id, params, content_hash)` to drop stale text when
+    /// translations change.
+    pub fn content_hash(&self) -> u64 {
+        let mut names: Vec<&String> = self.phrases.keys().collect();
+        names.sort_unstable();
+        let mut rendered = String::new();
+        for name in names {
+            rendered.push_str(&self.phrases[name].to_debug_string());
+        }
+        fnv1a_hash_str_64(&rendered)
+    }
+
     /// Returns the number of phrases in this registry.
     pub fn len(&self) -> usize {
         self.phrases.len()
@@ -355,3 +641,12 @@ impl PhraseRegistry {
         self.id_to_name.insert(id, name);
     }
 }
+
+/// Bracket and pad an evaluated phrase when `transforms` pseudo-localizes,
+/// then remove its span markers.
+fn finish_output(transforms: &TransformRegistry, mut phrase: Phrase) -> Phrase {
+    if transforms.pseudo_localize() {
+        phrase.text = pseudo::expand(&phrase.text);
+    }
+    marks::finish(phrase)
+}
@@ -3,14 +3,20 @@
 //! Transforms are functions that modify values (e.g., @cap, @upper, @lower).
 //! This module provides the registry infrastructure and universal transform implementations.
 
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::slice;
+
 use hangeul::ends_with_jongseong;
 use icu_casemap::CaseMapper;
 use icu_locale_core::{LanguageIdentifier, langid};
-use rlf_semantics::{TransformId, resolve_transform};
+use rlf_semantics::{TransformId, is_case_language, resolve_transform};
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::interpreter::EvalError;
-use crate::types::{Value, VariantKey};
+use crate::interpreter::marks::{PROTECT_END, PROTECT_START, map_unprotected};
+use crate::interpreter::plural::{ordinal_category, plural_category};
+use crate::types::{Value, civil_from_unix_seconds, time_of_day};
 
 /// Transform types for static dispatch.
 ///
@@ -23,6 +29,60 @@ pub enum TransformKind {
     Upper,
     /// @lower - All lowercase
     Lower,
+    /// @date - Calendar date from a timestamp with short/medium/long context
+    Date,
+    /// @time - Time of day from a timestamp with short/medium/long context
+    Time,
+    /// @spell - Read out graphemes separately (NATO phonetic with :nato in English)
+    Spell,
+    /// @currency - Amount with locale grouping and currency symbol from context code
+    Currency,
+    /// @num - Group a number with the language's separators, optionally in native digits
+    Num,
+    /// @sentence - Lowercase, then capitalize the start of each sentence
+    Sentence,
+    /// @list - Join list items with the language's conjunction (:and or :or)
+    List,
+    /// @title - Capitalize each word (:keepcaps leaves all-caps words alone)
+    Title,
+    /// @lang - Evaluate the transforms to its right with another language's rules
+    Lang,
+    /// @truncate_words - Keep the first N words from context, adding an ellipsis
+    TruncateWords,
+    /// @duration - Seconds as "1 hour 5 minutes" (:long) or "1h 5m" (:short)
+    Duration,
+    /// @case_fold - Full Unicode case folding for comparison keys, not display
+    CaseFold,
+    /// @quote - Locale-specific quotation marks
+    Quote,
+    /// @map - Look up the value in a lookup table registered on the Locale
+    Map,
+    /// @autocap - Capitalize at the start of a sentence, when enabled on the Locale
+    AutoCap,
+    /// @strip_markup - Remove `<...>` markup tags, keeping literal `<`
+    StripMarkup,
+    /// @escape_markup - Show markup tags as visible `&lt;...&gt;` literals
+    EscapeMarkup,
+    /// @formal - Select the `formal` register variant
+    Formal,
+    /// @informal - Select the `informal` register variant
+    Informal,
+    /// @superscript - Unicode superscript digits and letters ("2" → "²")
+    Super,
+    /// @subscript - Unicode subscript digits and letters ("2" → "₂")
+    Sub,
+    /// @noop/@id/@raw - Return the text unchanged, for uniform transform chains
+    NoOp,
+    /// @break - Soft hyphens at compound boundaries from a registered splitter
+    CompoundBreak,
+    /// @scientific - Scientific ("1.2e6") or engineering (:eng) notation
+    Scientific,
+    /// @count_noun - "{n} {noun}" with the noun's plural variant for the language
+    CountNoun,
+    /// @month - Month name for 1-12 (:short for the abbreviation)
+    Month,
+    /// @weekday - Weekday name for 0-6 from Sunday (:short for the abbreviation)
+    Weekday,
     // English transforms (Phase 6)
     /// @a/@an - English indefinite article from :a/:an tags
     EnglishA,
@@ -30,11 +90,23 @@ pub enum TransformKind {
     EnglishThe,
     /// @plural - English plural form (selects :other variant)
     EnglishPlural,
+    /// @poss/@possessive - English possessive ('s or ' after a final s)
+    EnglishPossessive,
+    /// @genitive - English "the X of the Y" with the owner as context
+    EnglishGenitive,
+    /// @count - English "{n} cards", or "no cards"/"a card" with :words
+    EnglishCount,
+    /// @pronoun - English pronoun agreeing with a runtime gender
+    EnglishPronoun,
     // German transforms (Phase 6)
     /// @der/@die/@das - German definite article with case context
     GermanDer,
     /// @ein/@eine - German indefinite article with case context
     GermanEin,
+    /// @kein/@keine - German negative article with case context
+    GermanKein,
+    /// @genitive - German "der X des Y" with the owner as context
+    GermanGenitive,
     // Dutch transforms (Phase 6)
     /// @de/@het - Dutch definite article from :de/:het tags
     DutchDe,
@@ -54,6 +126,8 @@ pub enum TransformKind {
     PortugueseDe,
     /// @em - Portuguese "em" + article contraction
     PortugueseEm,
+    /// @por - Portuguese "por" + article contraction
+    PortuguesePor,
     // French transforms (Phase 7)
     /// @le/@la - French definite article with elision
     FrenchLe,
@@ -65,6 +139,8 @@ pub enum TransformKind {
     FrenchAu,
     /// @liaison - French prevocalic form selection
     FrenchLiaison,
+    /// @punct - French narrow no-break spacing around punctuation
+    FrenchPunct,
     // Italian transforms (Phase 7)
     /// @il/@lo/@la - Italian definite article with sound rules
     ItalianIl,
@@ -85,9 +161,14 @@ pub enum TransformKind {
     // Arabic transforms (Phase 8)
     /// @al - Arabic definite article with sun/moon letter assimilation
     ArabicAl,
+    /// @connect - Arabic-script joiners at fragment boundaries
+    ArabicConnect,
     // Persian transforms (Phase 8)
     /// @ezafe - Persian ezafe connector (-e/-ye)
     PersianEzafe,
+    // Russian transforms
+    /// @neg - Russian genitive of negation
+    RussianNeg,
     // CJK transforms (Phase 9)
     /// @count - Chinese count with classifier
     ChineseCount,
@@ -100,10 +181,14 @@ pub enum TransformKind {
     VietnameseCount,
     /// @count - Thai count with classifier
     ThaiCount,
+    /// @wordbreak - Thai zero-width spaces at word boundaries
+    ThaiWordBreak,
     /// @count - Bengali count with classifier
     BengaliCount,
     /// @plural - Indonesian reduplication plural
     IndonesianPlural,
+    /// @count - Malay count with optional classifier
+    MalayCount,
     // Korean particle transform (Phase 9)
     /// @particle - Korean particle selection based on final sound
     KoreanParticle,
@@ -116,9 +201,17 @@ pub enum TransformKind {
     // Hungarian inflection transform
     /// @inflect - Hungarian suffix chain with vowel harmony
     HungarianInflect,
+    /// @inflect - Basque definite case suffixes
+    BasqueInflect,
+    /// @agree - Swahili noun-class adjective agreement
+    SwahiliAgree,
+    /// @mutate - Irish initial mutations (lenition and eclipsis)
+    IrishMutate,
     // Japanese particle transform
     /// @particle - Japanese particle selection based on context
     JapaneseParticle,
+    /// @ruby - Japanese reading annotation (furigana)
+    JapaneseRuby,
     // Hindi transforms
     /// @ka/@ki/@ke - Hindi possessive postposition with gender/number agreement
     HindiKa,
@@ -138,27 +231,103 @@ impl TransformKind {
     /// Execute the transform on a value.
     ///
     /// Context is optional (used by language-specific transforms for case, etc.).
-    /// Lang is used for locale-sensitive case mapping.
+    /// Lang is used for locale-sensitive case mapping. Elided articles use the
+    /// ASCII apostrophe; see [`TransformRegistry::set_apostrophe_style`].
     pub fn execute(
         &self,
         value: &Value,
         context: Option<&Value>,
         lang: &str,
+    ) -> Result<String, EvalError> {
+        self.execute_with_style(
+            value,
+            context,
+            lang,
+            ApostropheStyle::Ascii,
+            DigitStyle::Latin,
+            SharpSStyle::Double,
+        )
+    }
+
+    /// Execute the transform, writing elided articles with `apostrophe`,
+    /// numbers from `@num` and `@count` with `digits`, and German uppercase
+    /// "ß" with `sharp_s`.
+    fn execute_with_style(
+        &self,
+        value: &Value,
+        context: Option<&Value>,
+        lang: &str,
+        apostrophe: ApostropheStyle,
+        digits: DigitStyle,
+        sharp_s: SharpSStyle,
     ) -> Result<String, EvalError> {
         let text = value.to_string();
-        let locale = parse_langid(lang);
 
         match self {
-            TransformKind::Cap => cap_transform(&text, &locale),
-            TransformKind::Upper => upper_transform(&text, &locale),
-            TransformKind::Lower => lower_transform(&text, &locale),
+            // Case transforms map with the context's language code, if given
+            TransformKind::Cap => {
+                let locale = case_locale("cap", context, lang)?;
+                cap_transform(&sharp_s.prepare_cap(&text, &locale), &locale)
+            }
+            TransformKind::Upper => {
+                let locale = case_locale("upper", context, lang)?;
+                upper_transform(&sharp_s.prepare_upper(&text, &locale), &locale)
+            }
+            TransformKind::Lower => lower_transform(&text, &case_locale("lower", context, lang)?),
+            // Date/time transforms read the timestamp and a style context
+            TransformKind::Date => {
+                date_time_transform(value, context, lang, DateTimeComponent::Date, None)
+            }
+            TransformKind::Time => {
+                date_time_transform(value, context, lang, DateTimeComponent::Time, None)
+            }
+            // Currency reads the numeric value and the currency code context
+            TransformKind::Currency => currency_transform(value, context, lang),
+            TransformKind::Num => num_transform(value, context, lang, digits),
+            TransformKind::Spell => spell_transform(&text, context, lang),
+            TransformKind::Sentence => {
+                sentence_transform(&text, &case_locale("sentence", context, lang)?)
+            }
+            TransformKind::List => list_transform(value, context, lang),
+            TransformKind::Title => title_transform(&text, context, lang),
+            TransformKind::Lang => lang_override(context).map(|_| text),
+            TransformKind::TruncateWords => truncate_words_transform(&text, context, lang, None),
+            TransformKind::Duration => duration_transform(value, context, lang),
+            TransformKind::CaseFold => Ok(case_fold_transform(&text, lang)),
+            TransformKind::Quote => quote_transform(&text, context, lang),
+            TransformKind::Map => Err(map_without_locale(&text)),
+            // Sentence position is only known to the evaluator, which
+            // capitalizes there; on its own @autocap leaves text unchanged
+            TransformKind::AutoCap => Ok(text),
+            TransformKind::StripMarkup => Ok(strip_markup(&text)),
+            TransformKind::EscapeMarkup => Ok(escape_markup(&text)),
+            TransformKind::Formal => Ok(Register::Formal.select(value)),
+            TransformKind::Informal => Ok(Register::Informal.select(value)),
+            TransformKind::Super => Ok(map_script(&text, superscript_char)),
+            TransformKind::Sub => Ok(map_script(&text, subscript_char)),
+            TransformKind::NoOp => Ok(text),
+            TransformKind::CompoundBreak => Ok(text),
+            TransformKind::Scientific => scientific_transform(value, context, lang),
+            TransformKind::CountNoun => count_noun_transform(value, context, lang, "count_noun"),
+            TransformKind::Month => {
+                calendar_name_transform(value, context, lang, CalendarName::Month)
+            }
+            TransformKind::Weekday => {
+                calendar_name_transform(value, context, lang, CalendarName::Weekday)
+            }
             // English transforms need full Value to read tags
             TransformKind::EnglishA => english_a_transform(value),
             TransformKind::EnglishThe => english_the_transform(value),
             TransformKind::EnglishPlural => english_plural_transform(value),
+            TransformKind::EnglishPossessive => english_possessive_transform(value, context),
+            TransformKind::EnglishGenitive => english_genitive_transform(value, context),
+            TransformKind::EnglishCount => english_count_transform(value, context),
+            TransformKind::EnglishPronoun => english_pronoun_transform(value, context),
             // German transforms need Value (for tags) and context (for case)
             TransformKind::GermanDer => german_der_transform(value, context),
             TransformKind::GermanEin => german_ein_transform(value, context),
+            TransformKind::GermanKein => german_kein_transform(value, context),
+            TransformKind::GermanGenitive => german_genitive_transform(value, context),
             // Dutch transforms need full Value to read tags
             TransformKind::DutchDe => dutch_de_transform(value),
             TransformKind::DutchEen => dutch_een_transform(value),
@@ -170,36 +339,42 @@ impl TransformKind {
             TransformKind::PortugueseUm => portuguese_um_transform(value),
             TransformKind::PortugueseDe => portuguese_de_transform(value, context),
             TransformKind::PortugueseEm => portuguese_em_transform(value, context),
+            TransformKind::PortuguesePor => portuguese_por_transform(value, context),
             // French transforms need Value (for tags) and context (for plural/vowel)
-            TransformKind::FrenchLe => french_le_transform(value, context),
+            TransformKind::FrenchLe => french_le_transform(value, context, apostrophe),
             TransformKind::FrenchUn => french_un_transform(value),
-            TransformKind::FrenchDe => french_de_transform(value, context),
-            TransformKind::FrenchAu => french_au_transform(value, context),
+            TransformKind::FrenchDe => french_de_transform(value, context, apostrophe),
+            TransformKind::FrenchAu => french_au_transform(value, context, apostrophe),
             TransformKind::FrenchLiaison => french_liaison_transform(value, context),
+            TransformKind::FrenchPunct => french_punct_transform(&text),
             // Italian transforms need Value (for tags) and context (for plural)
-            TransformKind::ItalianIl => italian_il_transform(value, context),
-            TransformKind::ItalianUn => italian_un_transform(value),
-            TransformKind::ItalianDi => italian_di_transform(value, context),
-            TransformKind::ItalianA => italian_a_transform(value, context),
+            TransformKind::ItalianIl => italian_il_transform(value, context, apostrophe),
+            TransformKind::ItalianUn => italian_un_transform(value, apostrophe),
+            TransformKind::ItalianDi => italian_di_transform(value, context, apostrophe),
+            TransformKind::ItalianA => italian_a_transform(value, context, apostrophe),
             // Greek transforms need Value (for tags) and context (for case/plural)
             TransformKind::GreekO => greek_o_transform(value, context),
             TransformKind::GreekEnas => greek_enas_transform(value, context),
             // Romanian transforms need Value (for tags) and context (for plural)
             TransformKind::RomanianDef => romanian_def_transform(value, context),
-            // Arabic transforms need Value (for tags)
+            // Arabic @al needs Value (for tags), @connect the text and context
             TransformKind::ArabicAl => arabic_al_transform(value),
+            TransformKind::ArabicConnect => arabic_connect_transform(&text, context),
             // Persian transforms need Value (for tags)
             TransformKind::PersianEzafe => persian_ezafe_transform(value),
+            TransformKind::RussianNeg => russian_neg_transform(value, context),
             // CJK transforms need Value (for tags) and context (for count)
             TransformKind::ChineseCount => chinese_count_transform(value, context),
             TransformKind::JapaneseCount => japanese_count_transform(value, context),
             TransformKind::KoreanCount => korean_count_transform(value, context),
             // Southeast Asian transforms need Value (for tags) and context (for count)
             TransformKind::VietnameseCount => vietnamese_count_transform(value, context),
-            TransformKind::ThaiCount => thai_count_transform(value, context),
-            TransformKind::BengaliCount => bengali_count_transform(value, context),
+            TransformKind::ThaiCount => thai_count_transform(value, context, digits),
+            TransformKind::ThaiWordBreak => Ok(thai_word_break_transform(&text, None)),
+            TransformKind::BengaliCount => bengali_count_transform(value, context, digits),
             // Indonesian @plural doesn't need context
             TransformKind::IndonesianPlural => indonesian_plural_transform(value),
+            TransformKind::MalayCount => malay_count_transform(value, context),
             // Korean @particle needs Value (for text) and context (for particle type)
             TransformKind::KoreanParticle => korean_particle_transform(value, context),
             // Turkish @inflect needs Value (for tags) and context (for suffix chain)
@@ -208,8 +383,12 @@ impl TransformKind {
             TransformKind::FinnishInflect => finnish_inflect_transform(value, context),
             // Hungarian @inflect needs Value (for tags) and context (for suffix chain)
             TransformKind::HungarianInflect => hungarian_inflect_transform(value, context),
+            TransformKind::BasqueInflect => basque_inflect_transform(value, context),
+            TransformKind::SwahiliAgree => swahili_agree_transform(value, context),
+            TransformKind::IrishMutate => irish_mutate_transform(value, context),
             // Japanese @particle needs context (for particle type)
             TransformKind::JapaneseParticle => japanese_particle_transform(context),
+            TransformKind::JapaneseRuby => japanese_ruby_transform(value, context),
             // Hindi postposition transforms
             TransformKind::HindiKa => hindi_ka_transform(value, context),
             TransformKind::HindiKo => hindi_postposition_transform(value, "को"),
@@ -230,69 +409,1904 @@ fn parse_langid(lang: &str) -> LanguageIdentifier {
     lang.parse().unwrap_or(langid!("und"))
 }
 
-/// Capitalize first grapheme, preserving rest of string.
+/// Locale for a case transform: the language code in `context` if present,
+/// otherwise the ambient language.
 ///
-/// Uses unicode-segmentation for grapheme-aware first character detection.
-/// Handles combining characters correctly (e.g., "e\u{0301}" is one grapheme).
+/// An override must name a language accepted by [`is_case_language`]; region
+/// subtags (`tr_TR`) are allowed. The macro checks static overrides at compile
+/// time with the same function.
+fn case_locale(
+    transform: &str,
+    context: Option<&Value>,
+    lang: &str,
+) -> Result<LanguageIdentifier, EvalError> {
+    let Some(context) = context else {
+        return Ok(parse_langid(lang));
+    };
+    let code = context.to_string();
+    if !is_case_language(&code) {
+        return Err(EvalError::InvalidTransformInput {
+            transform: transform.to_string(),
+            expected: "a known language code".to_string(),
+            value: code,
+        });
+    }
+    Ok(parse_langid(&code.replace('_', "-")))
+}
+
+/// Language named by a `@lang` transform's context.
+///
+/// The code must name a language accepted by [`is_case_language`]; region
+/// subtags (`en_GB`) are allowed but dropped, since transform tables, plural
+/// rules, and case mapping are all chosen by the primary language, so
+/// `en-GB` gives `en`. `@lang` itself leaves the text unchanged; the
+/// evaluator applies the transforms to its right in this language.
+pub(crate) fn lang_override(context: Option<&Value>) -> Result<String, EvalError> {
+    let code = context.map(Value::to_string).unwrap_or_default();
+    if !is_case_language(&code) {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "lang".to_string(),
+            expected: "a known language code".to_string(),
+            value: code,
+        });
+    }
+    let primary = code.split(['-', '_']).next().unwrap_or(&code);
+    Ok(primary.to_string())
+}
+
+/// Capitalize the first letter, preserving the rest of the string.
+///
+/// Leading markup tags, whitespace, and punctuation are skipped, so
+/// `"<b>  «hello»</b>"` becomes `"<b>  «Hello»</b>"`. The first letter or digit
+/// found is uppercased as a whole grapheme (e.g., "e\u{0301}" is one grapheme);
+/// digits and uncased scripts are unchanged, so `"1st"` stays `"1st"`. In
+/// ASCII text outside Turkic locales the first grapheme is one byte whose
+/// uppercase form is the ASCII uppercase, so it is uppercased in place.
 fn cap_transform(text: &str, locale: &LanguageIdentifier) -> Result<String, EvalError> {
-    if text.is_empty() {
+    let Some(pos) = find_cap_target(text) else {
+        return Ok(text.to_string());
+    };
+
+    if text.is_ascii() && !has_turkic_casing(locale) {
+        let mut result = text.to_string();
+        result[pos..=pos].make_ascii_uppercase();
+        return Ok(result);
+    }
+
+    let cm = CaseMapper::new();
+    let prefix = &text[..pos];
+    let rest_from_pos = &text[pos..];
+    let mut graphemes = rest_from_pos.graphemes(true);
+
+    match graphemes.next() {
+        Some(first) => {
+            let suffix: String = graphemes.collect();
+            let capitalized = cm.uppercase_to_string(first, locale);
+            Ok(format!("{prefix}{capitalized}{suffix}"))
+        }
+        None => Ok(text.to_string()),
+    }
+}
+
+/// Whether the locale maps `i` to dotted `İ` when uppercasing.
+fn has_turkic_casing(locale: &LanguageIdentifier) -> bool {
+    matches!(locale.language.as_str(), "tr" | "az")
+}
+
+/// Find the byte offset of the character `@cap` capitalizes.
+///
+/// Skips `<...>` markup tags, whitespace, and punctuation or symbols in any
+/// order, stopping at the first letter or digit. Returns `None` if there is
+/// none, or if a case-protected span comes first.
+fn find_cap_target(text: &str) -> Option<usize> {
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        if rest.starts_with('<') {
+            // Skip the entire tag
+            i += rest.find('>').map_or(rest.len(), |end| end + 1);
+            continue;
+        }
+        if rest.starts_with(PROTECT_START) {
+            return None;
+        }
+        let c = rest.chars().next()?;
+        if c.is_alphanumeric() {
+            return Some(i);
+        }
+        i += c.len_utf8();
+    }
+    None
+}
+
+/// Convert entire string to uppercase, except case-protected spans.
+fn upper_transform(text: &str, locale: &LanguageIdentifier) -> Result<String, EvalError> {
+    let cm = CaseMapper::new();
+    Ok(map_unprotected(text, |part| {
+        cm.uppercase_to_string(part, locale).into_owned()
+    }))
+}
+
+/// Convert entire string to lowercase, except case-protected spans.
+///
+/// Greek text gets an extra final-sigma pass over visible characters only, so
+/// markup tags never count as part of a word.
+fn lower_transform(text: &str, locale: &LanguageIdentifier) -> Result<String, EvalError> {
+    let cm = CaseMapper::new();
+    Ok(map_unprotected(text, |part| {
+        let lowered = cm.lowercase_to_string(part, locale);
+        if locale.language.as_str() == "el" {
+            apply_greek_final_sigma(&lowered)
+        } else {
+            lowered.into_owned()
+        }
+    }))
+}
+
+/// Case-fold text for case-insensitive comparison (@case_fold).
+///
+/// Applies full Unicode case folding, which differs from `@lower`: "ß" folds
+/// to "ss" and Greek final "ς" to "σ", so "STRASSE" and "straße" produce the
+/// same key. Turkish and Azerbaijani fold dotted and dotless I by their own
+/// rules. The result is for matching and deduplication, not display.
+fn case_fold_transform(text: &str, lang: &str) -> String {
+    let cm = CaseMapper::new();
+    let primary = primary_language_subtag(lang).unwrap_or(lang);
+    if matches!(primary, "tr" | "az") {
+        cm.fold_turkic_string(text).into_owned()
+    } else {
+        cm.fold_string(text).into_owned()
+    }
+}
+
+/// `@map` needs the lookup tables and phrases of a [`Locale`](crate::Locale),
+/// so it is resolved by the evaluator and fails when executed directly.
+fn map_without_locale(text: &str) -> EvalError {
+    EvalError::InvalidTransformInput {
+        transform: "map".to_string(),
+        expected: "a lookup table registered on the Locale".to_string(),
+        value: text.to_string(),
+    }
+}
+
+/// Primary and secondary (nested) quotation marks for a language.
+struct QuoteMarks {
+    primary: (&'static str, &'static str),
+    secondary: (&'static str, &'static str),
+}
+
+/// Look up quotation marks by primary language subtag, defaulting to English.
+///
+/// French guillemets carry a narrow no-break space (U+202F) on the inside.
+fn quote_marks(lang: &str) -> QuoteMarks {
+    let (primary, secondary) = match primary_language_subtag(lang).unwrap_or(lang) {
+        "de" | "cs" | "sk" => (("„", "“"), ("‚", "‘")),
+        "fr" => (("«\u{202F}", "\u{202F}»"), ("“", "”")),
+        "es" | "it" | "el" => (("«", "»"), ("“", "”")),
+        "ru" | "uk" | "be" => (("«", "»"), ("„", "“")),
+        "pl" => (("„", "”"), ("«", "»")),
+        "ja" => (("「", "」"), ("『", "』")),
+        _ => (("“", "”"), ("‘", "’")),
+    };
+    QuoteMarks { primary, secondary }
+}
+
+/// Wrap text in the language's quotation marks (@quote).
+///
+/// Uses the primary marks by default ("card" -> “card”, „card“, « card »,
+/// 「card」) and the secondary marks for quotes nested inside another quote
+/// with the `:nested` context.
+fn quote_transform(text: &str, context: Option<&Value>, lang: &str) -> Result<String, EvalError> {
+    let marks = quote_marks(lang);
+    let (open, close) = match context {
+        None => marks.primary,
+        Some(Value::String(s)) if s == "nested" => marks.secondary,
+        Some(other) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "quote".to_string(),
+                expected: "no context or ':nested'".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+    Ok(format!("{open}{text}{close}"))
+}
+
+/// Convert text to sentence case.
+///
+/// Lowercases everything, then uppercases the first letter of the text and the
+/// first letter after `.`, `!`, or `?` followed by whitespace. A period after a
+/// one-letter word ("e.g.", "J. Smith") is treated as an abbreviation, and
+/// requiring whitespace keeps "3.5" from starting a sentence. Markup tags and
+/// case-protected spans are copied unchanged.
+fn sentence_transform(text: &str, locale: &LanguageIdentifier) -> Result<String, EvalError> {
+    let lowered = lower_transform(text, locale)?;
+    let cm = CaseMapper::new();
+    let mut result = String::with_capacity(lowered.len());
+    let mut in_markup = false;
+    let mut in_protected = false;
+    let mut capitalize_next = true;
+    let mut after_terminator = false;
+    let mut word_len = 0;
+    for grapheme in lowered.graphemes(true) {
+        if in_markup || grapheme == "<" {
+            in_markup = grapheme != ">";
+            result.push_str(grapheme);
+            continue;
+        }
+        if in_protected || grapheme.starts_with(PROTECT_START) {
+            // A protected word counts as the sentence's first word
+            in_protected = !grapheme.contains(PROTECT_END);
+            capitalize_next = false;
+            after_terminator = false;
+            word_len = 0;
+            result.push_str(grapheme);
+            continue;
+        }
+        let is_space = grapheme.chars().all(char::is_whitespace);
+        let is_letter = grapheme.chars().any(char::is_alphabetic);
+        if after_terminator && is_space {
+            capitalize_next = true;
+        }
+        after_terminator = match grapheme {
+            "." => word_len != 1,
+            "!" | "?" => true,
+            _ => after_terminator && !is_space && !grapheme.chars().any(char::is_alphanumeric),
+        };
+        word_len = if is_letter { word_len + 1 } else { 0 };
+        if capitalize_next && grapheme.chars().any(char::is_alphanumeric) {
+            capitalize_next = false;
+            if is_letter {
+                result.push_str(&cm.uppercase_to_string(grapheme, locale));
+                continue;
+            }
+        }
+        result.push_str(grapheme);
+    }
+    Ok(result)
+}
+
+/// Title-case transform (@title).
+///
+/// Lowercases each whitespace-separated word and capitalizes its first
+/// letter, skipping markup. With the `:keepcaps` context, words written
+/// entirely in capitals ("XP", "NASA") are left as they are; any other context
+/// is a language override, as for `@cap`.
+fn title_transform(text: &str, context: Option<&Value>, lang: &str) -> Result<String, EvalError> {
+    let keep_caps = context.is_some_and(|c| c.to_string() == "keepcaps");
+    let locale = if keep_caps {
+        parse_langid(lang)
+    } else {
+        case_locale("title", context, lang)?
+    };
+    let mut result = String::with_capacity(text.len());
+    for token in text.split_inclusive(char::is_whitespace) {
+        let word = token.trim_end_matches(char::is_whitespace);
+        let visible = strip_markup(word);
+        let acronym =
+            visible.chars().any(char::is_alphabetic) && !visible.chars().any(char::is_lowercase);
+        if keep_caps && acronym {
+            result.push_str(word);
+        } else {
+            result.push_str(&cap_transform(&lower_transform(word, &locale)?, &locale)?);
+        }
+        result.push_str(&token[word.len()..]);
+    }
+    Ok(result)
+}
+
+/// Languages written without spaces between words.
+const UNSPACED_LANGUAGES: &[&str] = &["zh", "ja", "th", "lo", "km", "my"];
+
+/// Word truncation transform (@truncate_words).
+///
+/// Keeps the first N whitespace-delimited words, where N is the context, and
+/// appends "…" when anything was cut. Markup tags are not words; the tags in
+/// the cut text are kept so that open tags still close. Thai counts the words
+/// found by `segmenter` (or the bundled word list); other languages written
+/// without spaces keep the first N graphemes instead.
+fn truncate_words_transform(
+    text: &str,
+    context: Option<&Value>,
+    lang: &str,
+    segmenter: Option<&WordSegmenter>,
+) -> Result<String, EvalError> {
+    let limit = context.and_then(|c| c.to_string().parse::<usize>().ok());
+    let Some(limit) = limit else {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "truncate_words".to_string(),
+            expected: "a word count context such as ':5'".to_string(),
+            value: context.map(Value::to_string).unwrap_or_default(),
+        });
+    };
+
+    let primary = primary_language_subtag(lang).unwrap_or(lang);
+    let thai_words = if primary == "th" {
+        thai_word_starts(text, segmenter)
+    } else {
+        Vec::new()
+    };
+    let mut in_markup = false;
+    let mut units = 0;
+    let mut after_space = true;
+    let mut cut = None;
+    for (pos, grapheme) in text.grapheme_indices(true) {
+        if in_markup || grapheme == "<" {
+            in_markup = grapheme != ">";
+            continue;
+        }
+        let is_space = grapheme
+            .chars()
+            .all(|c| c.is_whitespace() || c == ZERO_WIDTH_SPACE);
+        let starts_unit = if primary == "th" {
+            !is_space && (after_space || thai_words.contains(&pos))
+        } else if UNSPACED_LANGUAGES.contains(&primary) {
+            true
+        } else {
+            !is_space && after_space
+        };
+        after_space = is_space;
+        if starts_unit {
+            units += 1;
+            if units > limit {
+                cut = Some(pos);
+                break;
+            }
+        }
+    }
+    let Some(cut) = cut else {
+        return Ok(text.to_string());
+    };
+
+    let mut result = text[..cut]
+        .trim_end_matches(|c: char| c.is_whitespace() || c == ZERO_WIDTH_SPACE)
+        .to_string();
+    result.push('…');
+    let mut in_markup = false;
+    for c in text[cut..].chars() {
+        if in_markup || c == '<' {
+            in_markup = c != '>';
+            result.push(c);
+        }
+    }
+    Ok(result)
+}
+
+/// Unit words for `@duration`, largest unit first: days, hours, minutes,
+/// seconds.
+struct DurationUnits {
+    /// Long forms as (one, other).
+    long: [(&'static str, &'static str); 4],
+    /// Short forms, identical for every plural category.
+    short: [&'static str; 4],
+    /// Whether a space separates the number from its short unit ("1 Std.").
+    short_spaced: bool,
+}
+
+const ENGLISH_DURATION_UNITS: DurationUnits = DurationUnits {
+    long: [
+        ("day", "days"),
+        ("hour", "hours"),
+        ("minute", "minutes"),
+        ("second", "seconds"),
+    ],
+    short: ["d", "h", "m", "s"],
+    short_spaced: false,
+};
+
+const GERMAN_DURATION_UNITS: DurationUnits = DurationUnits {
+    long: [
+        ("Tag", "Tage"),
+        ("Stunde", "Stunden"),
+        ("Minute", "Minuten"),
+        ("Sekunde", "Sekunden"),
+    ],
+    short: ["Tg.", "Std.", "Min.", "Sek."],
+    short_spaced: true,
+};
+
+/// Duration transform (@duration).
+///
+/// Splits a non-negative number of seconds into days, hours, minutes, and
+/// seconds, omitting zero components: 3660 is "1 hour 1 minute" with the
+/// default `:long` context and "1h 1m" with `:short`. Long unit words follow
+/// the language's plural rules. English and German have unit tables; other
+/// languages use English.
+fn duration_transform(
+    value: &Value,
+    context: Option<&Value>,
+    lang: &str,
+) -> Result<String, EvalError> {
+    let seconds = match value {
+        Value::Number(n) => Some(*n),
+        Value::String(s) => s.trim().parse::<i64>().ok(),
+        Value::Float(_) | Value::Phrase(_) | Value::DateTime(_) | Value::List(_) => None,
+    };
+    let Some(seconds) = seconds.filter(|n| *n >= 0) else {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "duration".to_string(),
+            expected: "a non-negative number of seconds".to_string(),
+            value: value.to_string(),
+        });
+    };
+    let short = match context.map(Value::to_string).as_deref() {
+        None | Some("long") => false,
+        Some("short") => true,
+        Some(other) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "duration".to_string(),
+                expected: "a ':long' or ':short' context".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+
+    let units = match primary_language_subtag(lang).unwrap_or(lang) {
+        "de" => &GERMAN_DURATION_UNITS,
+        _ => &ENGLISH_DURATION_UNITS,
+    };
+    let amounts = [
+        seconds.div_euclid(86_400),
+        seconds.rem_euclid(86_400).div_euclid(3_600),
+        seconds.rem_euclid(3_600).div_euclid(60),
+        seconds.rem_euclid(60),
+    ];
+    let mut parts: Vec<String> = amounts
+        .iter()
+        .enumerate()
+        .filter(|(_, amount)| **amount > 0)
+        .map(|(unit, amount)| duration_part(*amount, unit, units, short, lang))
+        .collect();
+    if parts.is_empty() {
+        parts.push(duration_part(0, 3, units, short, lang));
+    }
+    Ok(parts.join(" "))
+}
+
+/// Format one `@duration` component, such as "5 minutes" or "5m".
+fn duration_part(
+    amount: i64,
+    unit: usize,
+    units: &DurationUnits,
+    short: bool,
+    lang: &str,
+) -> String {
+    if short {
+        let space = if units.short_spaced { " " } else { "" };
+        return format!("{amount}{space}{}", units.short[unit]);
+    }
+    let (one, other) = units.long[unit];
+    let word = if plural_category(lang, amount) == "one" {
+        one
+    } else {
+        other
+    };
+    format!("{amount} {word}")
+}
+
+/// Rewrite each lowercase sigma as final "ς" or medial "σ".
+///
+/// A sigma is final when it follows a letter and is not followed by one; any
+/// non-letter character ends a word. `<...>` markup is skipped when looking at
+/// neighbouring characters.
+fn apply_greek_final_sigma(text: &str) -> String {
+    let mut in_markup = false;
+    let visible: Vec<(usize, char)> = text
+        .char_indices()
+        .filter(|&(_, c)| {
+            let markup = in_markup || c == '<';
+            in_markup = markup && c != '>';
+            !markup
+        })
+        .collect();
+
+    let mut result = String::with_capacity(text.len());
+    let mut copied = 0;
+    for (i, &(pos, c)) in visible.iter().enumerate() {
+        if c != 'σ' && c != 'ς' {
+            continue;
+        }
+        let after_letter = i > 0 && visible[i - 1].1.is_alphabetic();
+        let before_letter = visible
+            .get(i + 1)
+            .is_some_and(|&(_, next)| next.is_alphabetic());
+        result.push_str(&text[copied..pos]);
+        result.push(if after_letter && !before_letter {
+            'ς'
+        } else {
+            'σ'
+        });
+        copied = pos + c.len_utf8();
+    }
+    result.push_str(&text[copied..]);
+    result
+}
+
+// =============================================================================
+// Date and Time Transforms
+// =============================================================================
+
+/// Which part of a timestamp a date/time transform renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeComponent {
+    /// Calendar date (`@date`).
+    Date,
+    /// Time of day (`@time`).
+    Time,
+}
+
+/// Output length for `@date` and `@time`, selected by the transform context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateStyle {
+    /// Numeric form (`@date:short`), e.g. "3/15/2024".
+    Short,
+    /// Abbreviated month names (`@date` or `@date:medium`), e.g. "Mar 15, 2024".
+    #[default]
+    Medium,
+    /// Full month names (`@date:long`), e.g. "March 15, 2024".
+    Long,
+}
+
+/// User-supplied date/time formatter consulted before the built-in tables.
+///
+/// Receives epoch seconds, the component and style being rendered, and the
+/// language code. Returning `None` falls back to the built-in formatting.
+pub type DateFormatter =
+    Box<dyn Fn(i64, DateTimeComponent, DateStyle, &str) -> Option<String> + Send + Sync>;
+
+/// User-supplied plural rule mapping a count to a category such as `"one"`.
+///
+/// The returned category is matched against variant keys like the built-in
+/// CLDR categories are.
+pub type PluralRule = Box<dyn Fn(i64) -> &'static str + Send + Sync>;
+
+/// User-supplied Thai word segmenter used by `@wordbreak` and `@truncate_words`.
+///
+/// Receives a run of Thai characters and returns the byte offsets within it
+/// where a new word starts. Offsets of `0`, past the end, or off a character
+/// boundary are ignored.
+pub type WordSegmenter = Box<dyn Fn(&str) -> Vec<usize> + Send + Sync>;
+
+/// User-supplied compound splitter used by `@break`.
+///
+/// Receives one word and returns the byte offsets within it where a compound
+/// boundary falls. Offsets of `0`, past the end, or off a character boundary
+/// are ignored.
+pub type CompoundSplitter = Box<dyn Fn(&str) -> Vec<usize> + Send + Sync>;
+
+/// What a key in a [`LookupTable`] resolves to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookupEntry {
+    /// A term in the current language, evaluated with its tags and variants.
+    Term(String),
+    /// Fixed text, used as-is in every language.
+    Text(String),
+}
+
+/// A named table that `@map` uses to turn internal keys into localized text.
+///
+/// Keys are matched against the value's text, so numbers match their decimal
+/// form. Register tables with [`Locale::register_map`](crate::Locale::register_map).
+///
+/// ```
+/// use rlf::{Locale, LookupTable};
+///
+/// let mut locale = Locale::new();
+/// locale.load_translations_str("en", r#"
+///     rarity_common = "Common";
+///     rarity_rare = "Rare";
+///     card_rarity($r) = "{@map:rarity $r}";
+/// "#).unwrap();
+/// locale.register_map(
+///     "rarity",
+///     LookupTable::new()
+///         .term("common", "rarity_common")
+///         .term("rare", "rarity_rare"),
+/// );
+///
+/// let label = locale.call_phrase("card_rarity", &["rare".into()]).unwrap();
+/// assert_eq!(label.to_string(), "Rare");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LookupTable {
+    entries: HashMap<String, LookupEntry>,
+    fallback: Option<LookupEntry>,
+}
+
+impl LookupTable {
+    /// Create an empty table with no fallback.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map `key` to the term named `term`.
+    pub fn term(mut self, key: impl Into<String>, term: impl Into<String>) -> Self {
+        self.entries
+            .insert(key.into(), LookupEntry::Term(term.into()));
+        self
+    }
+
+    /// Map `key` to fixed text.
+    pub fn text(mut self, key: impl Into<String>, text: impl Into<String>) -> Self {
+        self.entries
+            .insert(key.into(), LookupEntry::Text(text.into()));
+        self
+    }
+
+    /// Use `entry` for keys not in the table instead of failing.
+    pub fn fallback(mut self, entry: LookupEntry) -> Self {
+        self.fallback = Some(entry);
+        self
+    }
+
+    /// The entry for `key`, or the fallback when the key is not in the table.
+    pub fn get(&self, key: &str) -> Option<&LookupEntry> {
+        self.entries.get(key).or(self.fallback.as_ref())
+    }
+
+    /// The keys in the table, sorted.
+    pub fn keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self.entries.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        keys
+    }
+}
+
+/// Apostrophe written by elided articles such as French "l'" and Italian "dell'".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApostropheStyle {
+    /// Straight ASCII apostrophe (U+0027), e.g. "l'ami".
+    #[default]
+    Ascii,
+    /// Typographic right single quotation mark (U+2019), e.g. "l’ami".
+    Curly,
+}
+
+impl ApostropheStyle {
+    /// The apostrophe character for this style.
+    pub fn as_char(self) -> char {
+        match self {
+            ApostropheStyle::Ascii => '\'',
+            ApostropheStyle::Curly => '\u{2019}',
+        }
+    }
+}
+
+/// Format that evaluated text is written for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputTarget {
+    /// Text is written as-is.
+    #[default]
+    Plain,
+    /// Text is injected into HTML: parameter values are escaped so that `<`,
+    /// `>`, `&`, and quotes in user input cannot create markup, while markup
+    /// written in templates passes through unchanged.
+    Html,
+}
+
+/// Level of formality for T–V address (German Sie/du, French vous/tu).
+///
+/// Phrases declare register variants as `{ formal: "...", informal: "..." }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Register {
+    /// Polite address, e.g. German "Sie".
+    #[default]
+    Formal,
+    /// Familiar address, e.g. German "du".
+    Informal,
+}
+
+impl Register {
+    /// The variant key this register selects.
+    pub fn key(self) -> &'static str {
+        match self {
+            Register::Formal => "formal",
+            Register::Informal => "informal",
+        }
+    }
+
+    /// Text of this register's variant of `value`, or its default text when
+    /// it has no such variant.
+    fn select(self, value: &Value) -> String {
+        resolve_text_with_context(value, Some(&Value::String(self.key().to_string())))
+    }
+}
+
+/// How `@upper` and `@cap` write German "ß".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SharpSStyle {
+    /// Traditional "SS", e.g. "straße" → "STRASSE".
+    #[default]
+    Double,
+    /// Capital sharp s (U+1E9E), e.g. "straße" → "STRAẞE".
+    Capital,
+}
+
+impl SharpSStyle {
+    /// Rewrite "ß" as "ẞ" before uppercasing German text in the capital style.
+    ///
+    /// The case mapper leaves "ẞ" alone when uppercasing and lowers it back
+    /// to "ß", so only the uppercase direction needs help.
+    fn prepare_upper<'a>(self, text: &'a str, locale: &LanguageIdentifier) -> Cow<'a, str> {
+        if self == SharpSStyle::Capital && locale.language.as_str() == "de" {
+            Cow::Owned(map_unprotected(text, |part| part.replace('ß', "\u{1E9E}")))
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    /// Like [`Self::prepare_upper`], but only for a "ß" that is the letter
+    /// `@cap` changes.
+    fn prepare_cap<'a>(self, text: &'a str, locale: &LanguageIdentifier) -> Cow<'a, str> {
+        match find_cap_target(text) {
+            Some(pos)
+                if self == SharpSStyle::Capital
+                    && locale.language.as_str() == "de"
+                    && text[pos..].starts_with('ß') =>
+            {
+                Cow::Owned(format!("{}\u{1E9E}{}", &text[..pos], &text[pos + 2..]))
+            }
+            _ => Cow::Borrowed(text),
+        }
+    }
+}
+
+/// Digit set used by `@num` and `@count`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigitStyle {
+    /// Western digits 0-9 in every language.
+    #[default]
+    Latin,
+    /// The language's native digits where it has them: Persian ۰-۹, Arabic
+    /// ٠-٩, Bengali ০-৯, and Thai ๐-๙. Other languages keep 0-9.
+    Native,
+}
+
+impl DigitStyle {
+    /// Rewrite ASCII digits in `text` into this style's digits for `lang`.
+    ///
+    /// Persian and Arabic also switch to the Arabic thousands (٬) and decimal
+    /// (٫) separators, so the input should use `,` and `.` for those.
+    pub fn shape(self, text: &str, lang: &str) -> String {
+        let primary = primary_language_subtag(lang).unwrap_or(lang);
+        let zero = match (self, primary) {
+            (DigitStyle::Native, "fa") => '\u{06F0}',
+            (DigitStyle::Native, "ar") => '\u{0660}',
+            (DigitStyle::Native, "bn") => '\u{09E6}',
+            (DigitStyle::Native, "th") => '\u{0E50}',
+            _ => return text.to_string(),
+        };
+        let arabic_separators = matches!(primary, "fa" | "ar");
+        text.chars()
+            .map(|c| match c {
+                '0'..='9' => char::from_u32(zero as u32 + (c as u32 - '0' as u32)).unwrap_or(c),
+                ',' if arabic_separators => '\u{066C}',
+                '.' if arabic_separators => '\u{066B}',
+                _ => c,
+            })
+            .collect()
+    }
+}
+
+/// Attach an article to `text`, directly after an elided form ending in `'`
+/// (written with `apostrophe`) and with a space otherwise.
+fn attach_article(article: &str, text: &str, apostrophe: ApostropheStyle) -> String {
+    match article.strip_suffix('\'') {
+        Some(elided) => format!("{elided}{}{text}", apostrophe.as_char()),
+        None => format!("{article} {text}"),
+    }
+}
+
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const EN_MONTHS_SHORT: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const DE_MONTHS: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+const FR_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+const FR_MONTHS_SHORT: [&str; 12] = [
+    "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+    "déc.",
+];
+const ES_MONTHS: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+const ES_MONTHS_SHORT: [&str; 12] = [
+    "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+];
+const PT_MONTHS: [&str; 12] = [
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+];
+const PT_MONTHS_SHORT: [&str; 12] = [
+    "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez.",
+];
+const IT_MONTHS: [&str; 12] = [
+    "gennaio",
+    "febbraio",
+    "marzo",
+    "aprile",
+    "maggio",
+    "giugno",
+    "luglio",
+    "agosto",
+    "settembre",
+    "ottobre",
+    "novembre",
+    "dicembre",
+];
+const IT_MONTHS_SHORT: [&str; 12] = [
+    "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+];
+const DE_MONTHS_SHORT: [&str; 12] = [
+    "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez.",
+];
+
+const EN_WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const EN_WEEKDAYS_SHORT: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const DE_WEEKDAYS: [&str; 7] = [
+    "Sonntag",
+    "Montag",
+    "Dienstag",
+    "Mittwoch",
+    "Donnerstag",
+    "Freitag",
+    "Samstag",
+];
+const DE_WEEKDAYS_SHORT: [&str; 7] = ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."];
+const FR_WEEKDAYS: [&str; 7] = [
+    "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+];
+const FR_WEEKDAYS_SHORT: [&str; 7] = ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."];
+const ES_WEEKDAYS: [&str; 7] = [
+    "domingo",
+    "lunes",
+    "martes",
+    "miércoles",
+    "jueves",
+    "viernes",
+    "sábado",
+];
+const ES_WEEKDAYS_SHORT: [&str; 7] = ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"];
+const PT_WEEKDAYS: [&str; 7] = [
+    "domingo",
+    "segunda-feira",
+    "terça-feira",
+    "quarta-feira",
+    "quinta-feira",
+    "sexta-feira",
+    "sábado",
+];
+const PT_WEEKDAYS_SHORT: [&str; 7] = ["dom.", "seg.", "ter.", "qua.", "qui.", "sex.", "sáb."];
+const IT_WEEKDAYS: [&str; 7] = [
+    "domenica",
+    "lunedì",
+    "martedì",
+    "mercoledì",
+    "giovedì",
+    "venerdì",
+    "sabato",
+];
+const IT_WEEKDAYS_SHORT: [&str; 7] = ["dom", "lun", "mar", "mer", "gio", "ven", "sab"];
+
+/// Parse the `@date`/`@time` context into a style, defaulting to medium.
+fn parse_date_style(context: Option<&Value>) -> DateStyle {
+    match context.map(ToString::to_string).as_deref() {
+        Some("short") => DateStyle::Short,
+        Some("long") => DateStyle::Long,
+        _ => DateStyle::Medium,
+    }
+}
+
+/// Read epoch seconds from a `DateTime`, number, or numeric string value.
+fn value_to_epoch_seconds(value: &Value, transform: &str) -> Result<i64, EvalError> {
+    let secs = match value {
+        Value::DateTime(secs) | Value::Number(secs) => Some(*secs),
+        Value::String(s) => s.parse().ok(),
+        Value::Float(_) | Value::Phrase(_) | Value::List(_) => None,
+    };
+    secs.ok_or_else(|| EvalError::InvalidTransformInput {
+        transform: transform.to_string(),
+        expected: "a date/time value".to_string(),
+        value: value.to_string(),
+    })
+}
+
+/// Format a timestamp's calendar date using the built-in per-language patterns.
+///
+/// Languages without a pattern table use ISO 8601 (`2024-03-15`) for every style.
+fn format_builtin_date(secs: i64, style: DateStyle, lang: &str) -> String {
+    let (year, month, day) = civil_from_unix_seconds(secs);
+    let index = (month - 1) as usize;
+    let primary = primary_language_subtag(lang).unwrap_or(lang);
+    match (primary, style) {
+        ("en", DateStyle::Short) => format!("{month}/{day}/{year}"),
+        ("en", DateStyle::Medium) => format!("{} {day}, {year}", EN_MONTHS_SHORT[index]),
+        ("en", DateStyle::Long) => format!("{} {day}, {year}", EN_MONTHS[index]),
+        ("de", DateStyle::Short | DateStyle::Medium) => format!("{day:02}.{month:02}.{year}"),
+        ("de", DateStyle::Long) => format!("{day}. {} {year}", DE_MONTHS[index]),
+        ("fr", DateStyle::Short) => format!("{day:02}/{month:02}/{year}"),
+        ("fr", DateStyle::Medium) => format!("{day} {} {year}", FR_MONTHS_SHORT[index]),
+        ("fr", DateStyle::Long) => format!("{day} {} {year}", FR_MONTHS[index]),
+        ("es", DateStyle::Short) => format!("{day}/{month}/{year}"),
+        ("es", DateStyle::Medium) => format!("{day} {} {year}", ES_MONTHS_SHORT[index]),
+        ("es", DateStyle::Long) => format!("{day} de {} de {year}", ES_MONTHS[index]),
+        ("pt", DateStyle::Short) => format!("{day:02}/{month:02}/{year}"),
+        ("pt", DateStyle::Medium) => format!("{day} de {} de {year}", PT_MONTHS_SHORT[index]),
+        ("pt", DateStyle::Long) => format!("{day} de {} de {year}", PT_MONTHS[index]),
+        ("it", DateStyle::Short) => format!("{day:02}/{month:02}/{year}"),
+        ("it", DateStyle::Medium) => format!("{day} {} {year}", IT_MONTHS_SHORT[index]),
+        ("it", DateStyle::Long) => format!("{day} {} {year}", IT_MONTHS[index]),
+        _ => format!("{year:04}-{month:02}-{day:02}"),
+    }
+}
+
+/// Format a timestamp's UTC time of day using the built-in per-language patterns.
+///
+/// English uses a 12-hour clock; every other language uses a 24-hour clock.
+fn format_builtin_time(secs: i64, style: DateStyle, lang: &str) -> String {
+    let (hour, minute, second) = time_of_day(secs);
+    let primary = primary_language_subtag(lang).unwrap_or(lang);
+    if primary == "en" {
+        let period = if hour < 12 { "AM" } else { "PM" };
+        let hour12 = match hour % 12 {
+            0 => 12,
+            h => h,
+        };
+        return match style {
+            DateStyle::Short => format!("{hour12}:{minute:02} {period}"),
+            DateStyle::Medium => format!("{hour12}:{minute:02}:{second:02} {period}"),
+            DateStyle::Long => format!("{hour12}:{minute:02}:{second:02} {period} UTC"),
+        };
+    }
+    match style {
+        DateStyle::Short => format!("{hour:02}:{minute:02}"),
+        DateStyle::Medium => format!("{hour:02}:{minute:02}:{second:02}"),
+        DateStyle::Long => format!("{hour:02}:{minute:02}:{second:02} UTC"),
+    }
+}
+
+/// Format a timestamp, consulting a user formatter before the built-in tables.
+fn date_time_transform(
+    value: &Value,
+    context: Option<&Value>,
+    lang: &str,
+    component: DateTimeComponent,
+    formatter: Option<&DateFormatter>,
+) -> Result<String, EvalError> {
+    let name = match component {
+        DateTimeComponent::Date => "date",
+        DateTimeComponent::Time => "time",
+    };
+    let secs = value_to_epoch_seconds(value, name)?;
+    let style = parse_date_style(context);
+    if let Some(custom) = formatter.and_then(|f| f(secs, component, style, lang)) {
+        return Ok(custom);
+    }
+    Ok(match component {
+        DateTimeComponent::Date => format_builtin_date(secs, style, lang),
+        DateTimeComponent::Time => format_builtin_time(secs, style, lang),
+    })
+}
+
+/// Which calendar names `@month` and `@weekday` look up.
+#[derive(Clone, Copy)]
+enum CalendarName {
+    Month,
+    Weekday,
+}
+
+/// Full and abbreviated month names for a primary language subtag.
+fn month_names(primary: &str) -> (&'static [&'static str], &'static [&'static str]) {
+    match primary {
+        "de" => (&DE_MONTHS, &DE_MONTHS_SHORT),
+        "fr" => (&FR_MONTHS, &FR_MONTHS_SHORT),
+        "es" => (&ES_MONTHS, &ES_MONTHS_SHORT),
+        "pt" => (&PT_MONTHS, &PT_MONTHS_SHORT),
+        "it" => (&IT_MONTHS, &IT_MONTHS_SHORT),
+        _ => (&EN_MONTHS, &EN_MONTHS_SHORT),
+    }
+}
+
+/// Full and abbreviated weekday names, from Sunday, for a primary language
+/// subtag.
+fn weekday_names(primary: &str) -> (&'static [&'static str], &'static [&'static str]) {
+    match primary {
+        "de" => (&DE_WEEKDAYS, &DE_WEEKDAYS_SHORT),
+        "fr" => (&FR_WEEKDAYS, &FR_WEEKDAYS_SHORT),
+        "es" => (&ES_WEEKDAYS, &ES_WEEKDAYS_SHORT),
+        "pt" => (&PT_WEEKDAYS, &PT_WEEKDAYS_SHORT),
+        "it" => (&IT_WEEKDAYS, &IT_WEEKDAYS_SHORT),
+        _ => (&EN_WEEKDAYS, &EN_WEEKDAYS_SHORT),
+    }
+}
+
+/// Month or weekday name transform (@month, @weekday).
+///
+/// `@month` takes 1-12 ("March" for 3) and `@weekday` takes 0-6 counting from
+/// Sunday ("Monday" for 1); the `:short` context picks the abbreviation
+/// ("Mar", "Mon"). Names come from the same tables as `@date`, which cover
+/// en, de, fr, es, pt, and it; other languages use English. Names are in the
+/// form used inside a sentence, so French and Spanish ones are lowercase.
+fn calendar_name_transform(
+    value: &Value,
+    context: Option<&Value>,
+    lang: &str,
+    kind: CalendarName,
+) -> Result<String, EvalError> {
+    let primary = primary_language_subtag(lang).unwrap_or(lang);
+    let (transform, expected, first, (long, short)) = match kind {
+        CalendarName::Month => (
+            "month",
+            "a month number from 1 to 12",
+            1,
+            month_names(primary),
+        ),
+        CalendarName::Weekday => (
+            "weekday",
+            "a weekday number from 0 (Sunday) to 6",
+            0,
+            weekday_names(primary),
+        ),
+    };
+    let names = match context.map(ToString::to_string).as_deref() {
+        None | Some("long") => long,
+        Some("short") => short,
+        Some(other) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: transform.to_string(),
+                expected: "no context, ':long', or ':short'".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+    let number = match value {
+        Value::Number(n) => Some(*n),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    };
+    number
+        .and_then(|n| usize::try_from(n - first).ok())
+        .and_then(|index| names.get(index))
+        .map(|name| (*name).to_string())
+        .ok_or_else(|| EvalError::InvalidTransformInput {
+            transform: transform.to_string(),
+            expected: expected.to_string(),
+            value: value.to_string(),
+        })
+}
+
+// =============================================================================
+// Spell-Out Transform
+// =============================================================================
+
+/// NATO phonetic alphabet words for `a`..=`z`.
+const NATO_LETTERS: [&str; 26] = [
+    "Alpha", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel", "India", "Juliett",
+    "Kilo", "Lima", "Mike", "November", "Oscar", "Papa", "Quebec", "Romeo", "Sierra", "Tango",
+    "Uniform", "Victor", "Whiskey", "X-ray", "Yankee", "Zulu",
+];
+
+/// NATO phonetic words for the digits `0`..=`9`.
+const NATO_DIGITS: [&str; 10] = [
+    "Zero", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine",
+];
+
+/// Remove `<...>` markup tags, keeping only visible text.
+///
+/// A `<` that does not start a tag, as in "a < b" or "<3", is kept.
+pub(crate) fn strip_markup(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    while let Some(offset) = text[i..].find('<') {
+        let start = i + offset;
+        result.push_str(&text[i..start]);
+        match markup_tag_len(&text[start..]) {
+            Some(len) => i = start + len,
+            None => {
+                result.push('<');
+                i = start + 1;
+            }
+        }
+    }
+    result.push_str(&text[i..]);
+    result
+}
+
+/// Render markup tags as visible `&lt;...&gt;` literals (@escape_markup).
+fn escape_markup(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    while let Some(offset) = text[i..].find('<') {
+        let start = i + offset;
+        result.push_str(&text[i..start]);
+        match markup_tag_len(&text[start..]) {
+            Some(len) => {
+                result.push_str("&lt;");
+                result.push_str(&text[start + 1..start + len - 1]);
+                result.push_str("&gt;");
+                i = start + len;
+            }
+            None => {
+                result.push('<');
+                i = start + 1;
+            }
+        }
+    }
+    result.push_str(&text[i..]);
+    result
+}
+
+/// Map each character outside markup tags through `map`, keeping characters
+/// it has no mapping for (@superscript, @subscript).
+fn map_script(text: &str, map: fn(char) -> Option<char>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    while let Some(c) = text[i..].chars().next() {
+        if let Some(len) = markup_tag_len(&text[i..]) {
+            result.push_str(&text[i..i + len]);
+            i += len;
+            continue;
+        }
+        result.push(map(c).unwrap_or(c));
+        i += c.len_utf8();
+    }
+    result
+}
+
+/// Unicode superscript form of a digit, sign, or Latin letter.
+///
+/// Every lowercase letter except "q" has a superscript modifier letter;
+/// uppercase letters are not mapped.
+fn superscript_char(c: char) -> Option<char> {
+    Some(match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        '+' => '⁺',
+        '-' | '−' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        'a' => 'ᵃ',
+        'b' => 'ᵇ',
+        'c' => 'ᶜ',
+        'd' => 'ᵈ',
+        'e' => 'ᵉ',
+        'f' => 'ᶠ',
+        'g' => 'ᵍ',
+        'h' => 'ʰ',
+        'i' => 'ⁱ',
+        'j' => 'ʲ',
+        'k' => 'ᵏ',
+        'l' => 'ˡ',
+        'm' => 'ᵐ',
+        'n' => 'ⁿ',
+        'o' => 'ᵒ',
+        'p' => 'ᵖ',
+        'r' => 'ʳ',
+        's' => 'ˢ',
+        't' => 'ᵗ',
+        'u' => 'ᵘ',
+        'v' => 'ᵛ',
+        'w' => 'ʷ',
+        'x' => 'ˣ',
+        'y' => 'ʸ',
+        'z' => 'ᶻ',
+        _ => return None,
+    })
+}
+
+/// Unicode subscript form of a digit, sign, or Latin letter.
+///
+/// Unicode only has subscripts for some lowercase letters.
+fn subscript_char(c: char) -> Option<char> {
+    Some(match c {
+        '0' => '₀',
+        '1' => '₁',
+        '2' => '₂',
+        '3' => '₃',
+        '4' => '₄',
+        '5' => '₅',
+        '6' => '₆',
+        '7' => '₇',
+        '8' => '₈',
+        '9' => '₉',
+        '+' => '₊',
+        '-' | '−' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'h' => 'ₕ',
+        'i' => 'ᵢ',
+        'j' => 'ⱼ',
+        'k' => 'ₖ',
+        'l' => 'ₗ',
+        'm' => 'ₘ',
+        'n' => 'ₙ',
+        'o' => 'ₒ',
+        'p' => 'ₚ',
+        'r' => 'ᵣ',
+        's' => 'ₛ',
+        't' => 'ₜ',
+        'u' => 'ᵤ',
+        'v' => 'ᵥ',
+        'x' => 'ₓ',
+        _ => return None,
+    })
+}
+
+/// Soft hyphen inserted by `@break` at compound boundaries.
+const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// @break transform.
+///
+/// Inserts a soft hyphen at each compound boundary that `splitter` reports
+/// within a word, so renderers can hyphenate long compounds ("Lebens-
+/// versicherung") in narrow layouts. Words are runs of letters outside markup
+/// tags. Without a splitter the text is returned unchanged, as it is when
+/// `@break` runs through [`TransformKind::execute`] rather than a registry.
+fn compound_break_transform(text: &str, splitter: Option<&CompoundSplitter>) -> String {
+    let Some(splitter) = splitter else {
+        return text.to_string();
+    };
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    while let Some(c) = text[i..].chars().next() {
+        if let Some(len) = markup_tag_len(&text[i..]) {
+            result.push_str(&text[i..i + len]);
+            i += len;
+            continue;
+        }
+        if !c.is_alphabetic() {
+            result.push(c);
+            i += c.len_utf8();
+            continue;
+        }
+        let len = text[i..]
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(text.len() - i);
+        let word = &text[i..i + len];
+        let mut boundaries: Vec<usize> = splitter(word)
+            .into_iter()
+            .filter(|&offset| offset > 0 && offset < word.len() && word.is_char_boundary(offset))
+            .collect();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        let mut last = 0;
+        for boundary in boundaries {
+            result.push_str(&word[last..boundary]);
+            result.push(SOFT_HYPHEN);
+            last = boundary;
+        }
+        result.push_str(&word[last..]);
+        i += len;
+    }
+    result
+}
+
+/// Byte length of the markup tag at the start of `text`, if there is one.
+///
+/// A tag is `<`, an optional `/`, an ASCII letter, then anything up to the
+/// next `>` that does not open another tag.
+pub(crate) fn markup_tag_len(text: &str) -> Option<usize> {
+    let name = text.strip_prefix('<')?;
+    let name = name.strip_prefix('/').unwrap_or(name);
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let end = text[1..].find(['<', '>'])? + 1;
+    (text.as_bytes()[end] == b'>').then_some(end + 1)
+}
+
+/// Look up the NATO phonetic word for an ASCII letter or digit grapheme.
+fn nato_word(grapheme: &str) -> Option<&'static str> {
+    let mut chars = grapheme.chars();
+    let c = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+    if c.is_ascii_alphabetic() {
+        let index = (c.to_ascii_lowercase() as u8 - b'a') as usize;
+        Some(NATO_LETTERS[index])
+    } else if c.is_ascii_digit() {
+        Some(NATO_DIGITS[(c as u8 - b'0') as usize])
+    } else {
+        None
+    }
+}
+
+/// Spell out text one grapheme at a time, skipping markup and whitespace.
+///
+/// Graphemes are joined with "-" ("AB12" becomes "A-B-1-2"). A context of
+/// punctuation picks another separator (`@spell:_` gives "A_B_1_2"), and
+/// `space` separates with spaces. In English the `nato` context
+/// (`@spell:nato`) expands letters and digits to NATO phonetic words
+/// separated by spaces ("Alpha Bravo One Two"); other graphemes are kept
+/// as-is. Other languages spell with "-" under `nato`. Any other context is
+/// an error.
+fn spell_transform(text: &str, context: Option<&Value>, lang: &str) -> Result<String, EvalError> {
+    let visible = strip_markup(text);
+    let graphemes = visible
+        .graphemes(true)
+        .filter(|g| !g.chars().all(char::is_whitespace));
+    let context = context.map(ToString::to_string);
+    let separator = match context.as_deref() {
+        None => "-",
+        Some("nato") if primary_language_subtag(lang).unwrap_or(lang) == "en" => {
+            let words: Vec<&str> = graphemes.map(|g| nato_word(g).unwrap_or(g)).collect();
+            return Ok(words.join(" "));
+        }
+        Some("nato") => "-",
+        Some("space") => " ",
+        Some(separator) if !separator.chars().any(char::is_alphanumeric) => separator,
+        Some(other) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "spell".to_string(),
+                expected: "a separator context such as ':_', ':space', or ':nato'".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+    Ok(graphemes.collect::<Vec<_>>().join(separator))
+}
+
+// =============================================================================
+// Currency Transform
+// =============================================================================
+
+/// Number separators and currency symbol placement for a language.
+struct CurrencyFormat {
+    /// Thousands separator.
+    group: char,
+    /// Decimal separator.
+    decimal: char,
+    /// Whether the symbol precedes the amount ("$5.00") rather than following
+    /// it after a no-break space ("5,00 €").
+    symbol_first: bool,
+    /// Whether a leading symbol is separated by a no-break space ("€ 5,00").
+    symbol_space: bool,
+}
+
+/// Currency formatting conventions for a language, keyed by primary subtag.
+///
+/// Languages not listed use the English conventions.
+fn currency_format(lang: &str) -> CurrencyFormat {
+    let primary = primary_language_subtag(lang).unwrap_or(lang);
+    let (group, decimal, symbol_first, symbol_space) = match primary {
+        "de" | "es" | "it" | "pt" | "ro" | "el" | "tr" | "id" | "vi" => ('.', ',', false, false),
+        "nl" => ('.', ',', true, true),
+        "fr" => ('\u{202F}', ',', false, false),
+        "ru" | "uk" | "pl" | "cs" | "fi" | "hu" | "sv" | "nb" => ('\u{00A0}', ',', false, false),
+        _ => (',', '.', true, false),
+    };
+    CurrencyFormat {
+        group,
+        decimal,
+        symbol_first,
+        symbol_space,
+    }
+}
+
+/// Symbol and number of minor-unit digits for an ISO 4217 currency code.
+///
+/// Codes without a known symbol are displayed as the code itself.
+fn currency_symbol(code: &str) -> (&str, usize) {
+    match code {
+        "USD" => ("$", 2),
+        "EUR" => ("\u{20AC}", 2),
+        "GBP" => ("\u{00A3}", 2),
+        "JPY" => ("\u{00A5}", 0),
+        "CNY" => ("\u{00A5}", 2),
+        "KRW" => ("\u{20A9}", 0),
+        "INR" => ("\u{20B9}", 2),
+        "RUB" => ("\u{20BD}", 2),
+        _ => (code, 2),
+    }
+}
+
+/// Insert `separator` between each group of three integer digits.
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len().div_euclid(3) * 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// Currency transform (@currency).
+///
+/// Formats a numeric amount for the currency code given as context
+/// (`{@currency:USD $price}`), rounding to the currency's minor units and
+/// using the language's separators and symbol placement: "$1,234.50" in
+/// English, "1 234,50 €" in French. A code with no symbol is always
+/// separated from the amount ("CHF 5.00"). Any other value, including a
+/// numeric string, or a non-finite float is an error.
+fn currency_transform(
+    value: &Value,
+    context: Option<&Value>,
+    lang: &str,
+) -> Result<String, EvalError> {
+    let amount = match value {
+        Value::Number(n) => *n as f64,
+        Value::Float(n) => *n,
+        Value::String(_) | Value::Phrase(_) | Value::DateTime(_) | Value::List(_) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "currency".to_string(),
+                expected: "a number".to_string(),
+                value: value.to_string(),
+            });
+        }
+    };
+    if !amount.is_finite() {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "currency".to_string(),
+            expected: "a finite amount".to_string(),
+            value: value.to_string(),
+        });
+    }
+    let Some(code) = context.map(|c| c.to_string().to_ascii_uppercase()) else {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "currency".to_string(),
+            expected: "a currency code context such as ':USD'".to_string(),
+            value: value.to_string(),
+        });
+    };
+
+    let format = currency_format(lang);
+    let (symbol, minor_digits) = currency_symbol(&code);
+    let fixed = format!("{:.*}", minor_digits, amount.abs());
+    let (integer, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+    let mut number = group_digits(integer, format.group);
+    if !fraction.is_empty() {
+        number.push(format.decimal);
+        number.push_str(fraction);
+    }
+    let sign = if amount < 0.0 && fixed.bytes().any(|b| matches!(b, b'1'..=b'9')) {
+        "-"
+    } else {
+        ""
+    };
+
+    Ok(if format.symbol_first {
+        let spaced = format.symbol_space || symbol.chars().count() > 1;
+        let space = if spaced { "\u{00A0}" } else { "" };
+        format!("{sign}{symbol}{space}{number}")
+    } else {
+        format!("{sign}{number}\u{00A0}{symbol}")
+    })
+}
+
+/// Number transform (@num).
+///
+/// Groups the integer part with the language's thousands separator and uses
+/// its decimal separator: "1,234.5" in English, "1.234,5" in German. The
+/// `:native` and `:latn` contexts pick the digit set, overriding the
+/// registry's [`DigitStyle`]. Strings that parse as numbers are accepted;
+/// other values are an error.
+fn num_transform(
+    value: &Value,
+    context: Option<&Value>,
+    lang: &str,
+    digits: DigitStyle,
+) -> Result<String, EvalError> {
+    let text = match value {
+        Value::Number(n) => Some(n.to_string()),
+        Value::Float(n) if n.is_finite() => Some(n.to_string()),
+        Value::String(s) => Some(s.trim().to_string()).filter(|s| s.parse::<f64>().is_ok()),
+        Value::Float(_) | Value::Phrase(_) | Value::DateTime(_) | Value::List(_) => None,
+    };
+    let Some(text) = text.filter(|t| t.bytes().all(|b| b.is_ascii_digit() || b"-.".contains(&b)))
+    else {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "num".to_string(),
+            expected: "a number".to_string(),
+            value: value.to_string(),
+        });
+    };
+    let digits = match context.map(Value::to_string).as_deref() {
+        None => digits,
+        Some("native") => DigitStyle::Native,
+        Some("latn") => DigitStyle::Latin,
+        Some(other) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "num".to_string(),
+                expected: "a ':native' or ':latn' context".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+
+    let (sign, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", text.as_str()),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let primary = primary_language_subtag(lang).unwrap_or(lang);
+    let native_arabic = digits == DigitStyle::Native && matches!(primary, "fa" | "ar");
+    // Persian and Arabic native digits take their own separators in `shape`
+    let format = currency_format(if native_arabic { "en" } else { lang });
+    let mut number = format!("{sign}{}", group_digits(integer, format.group));
+    if !fraction.is_empty() {
+        number.push(format.decimal);
+        number.push_str(fraction);
+    }
+    Ok(digits.shape(&number, lang))
+}
+
+/// Scientific notation transform (@scientific).
+///
+/// Writes a number as a mantissa and power of ten with the language's decimal
+/// separator: "1.2e6" in English, "1,2e6" in German. A numeric context sets
+/// the significant digits (`{@scientific:3 $n}` → "1.23e6"); otherwise the
+/// shortest exact mantissa is used. The `:eng` context switches to
+/// engineering notation, whose exponent is a multiple of three ("12.5e3").
+/// Contexts combine with dots, as in `:eng.3`. Strings that parse as numbers
+/// are accepted; other values are an error.
+fn scientific_transform(
+    value: &Value,
+    context: Option<&Value>,
+    lang: &str,
+) -> Result<String, EvalError> {
+    let number = match value {
+        Value::Number(n) => Some(*n as f64),
+        Value::Float(n) => Some(*n),
+        Value::String(s) => s.trim().parse::<f64>().ok(),
+        Value::Phrase(_) | Value::DateTime(_) | Value::List(_) => None,
+    };
+    let Some(number) = number.filter(|n| n.is_finite()) else {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "scientific".to_string(),
+            expected: "a number".to_string(),
+            value: value.to_string(),
+        });
+    };
+
+    let mut engineering = false;
+    let mut significant = None;
+    let parts = match context {
+        Some(Value::Number(n)) => vec![n.to_string()],
+        Some(context) => context.to_string().split('.').map(str::to_string).collect(),
+        None => Vec::new(),
+    };
+    for part in &parts {
+        match part.as_str() {
+            "eng" => engineering = true,
+            digits => match digits.parse::<usize>() {
+                Ok(n @ 1..=17) => significant = Some(n),
+                _ => {
+                    return Err(EvalError::InvalidTransformInput {
+                        transform: "scientific".to_string(),
+                        expected: "a significant-digit count from 1 to 17 or ':eng'".to_string(),
+                        value: part.clone(),
+                    });
+                }
+            },
+        }
+    }
+
+    let formatted = match significant {
+        Some(n) => format!("{:.*e}", n - 1, number),
+        None => format!("{number:e}"),
+    };
+    let (mantissa, exponent) = formatted
+        .split_once('e')
+        .expect("LowerExp output has an exponent");
+    let mut exponent: i32 = exponent.parse().expect("LowerExp exponent is an integer");
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", mantissa),
+    };
+    let mut digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+    let mut integer_len = 1;
+    if engineering && number != 0.0 {
+        let shift = exponent.rem_euclid(3);
+        exponent -= shift;
+        integer_len += shift as usize;
+        while digits.len() < integer_len {
+            digits.push('0');
+        }
+    }
+
+    let (integer, fraction) = digits.split_at(integer_len);
+    let mut result = format!("{sign}{integer}");
+    if !fraction.is_empty() {
+        result.push(currency_format(lang).decimal);
+        result.push_str(fraction);
+    }
+    Ok(format!("{result}e{exponent}"))
+}
+
+/// List transform (@list).
+///
+/// Joins the items of a list value with commas and the language's
+/// conjunction: "A, B, and C" in English, "A, B y C" in Spanish. The `:or`
+/// context uses the disjunction instead. Spanish "y" becomes "e" before an
+/// i-sound and "o" becomes "u" before an o-sound ("padres e hijos", "siete u
+/// ocho"). Languages without a conjunction join every item with a comma, and
+/// a value that is not a list is a list of one.
+///
+/// Items keep their order (`:raw`) unless the context includes `sorted`
+/// (`:sorted`, `:or.sorted`), which orders them with [`collation_key`]. The
+/// `:enumerate` context writes the items as steps instead; see
+/// [`enumerate_items`].
+fn list_transform(value: &Value, context: Option<&Value>, lang: &str) -> Result<String, EvalError> {
+    let items = match value {
+        Value::List(items) => items.as_slice(),
+        other => slice::from_ref(other),
+    };
+    let context = context.map(Value::to_string);
+    let mut disjunction = false;
+    let mut sorted = false;
+    let mut enumerate = false;
+    for component in context.iter().flat_map(|context| context.split('.')) {
+        match component {
+            "and" => disjunction = false,
+            "or" => disjunction = true,
+            "raw" => sorted = false,
+            "sorted" => sorted = true,
+            "enumerate" => enumerate = true,
+            other => {
+                return Err(EvalError::InvalidTransformInput {
+                    transform: "list".to_string(),
+                    expected: "an ':and', ':or', ':raw', ':sorted', or ':enumerate' context"
+                        .to_string(),
+                    value: other.to_string(),
+                });
+            }
+        }
+    }
+
+    let mut texts: Vec<String> = items.iter().map(Value::to_string).collect();
+    let primary = primary_language_subtag(lang).unwrap_or(lang);
+    if sorted {
+        texts.sort_by_cached_key(|text| (collation_key(text, primary), text.clone()));
+    }
+    if enumerate {
+        return Ok(enumerate_items(&texts, primary));
+    }
+    let Some((last, rest)) = texts.split_last() else {
         return Ok(String::new());
+    };
+    if rest.is_empty() {
+        return Ok(last.clone());
     }
-
-    // Find the first visible (non-markup) text position, skipping <...> tags
-    let first_text_pos = find_first_visible_char(text);
-    let Some(pos) = first_text_pos else {
-        return Ok(text.to_string());
+    let Some(conjunction) = list_conjunction(primary, disjunction) else {
+        return Ok(texts.join(", "));
+    };
+    let conjunction = if primary == "es" {
+        spanish_conjunction(conjunction, last)
+    } else {
+        conjunction
+    };
+    // English puts a serial comma before the conjunction of three or more items
+    let separator = if primary == "en" && rest.len() > 1 {
+        ", "
+    } else {
+        " "
     };
+    Ok(format!(
+        "{}{separator}{conjunction} {last}",
+        rest.join(", ")
+    ))
+}
 
-    let cm = CaseMapper::new();
-    let prefix = &text[..pos];
-    let rest_from_pos = &text[pos..];
-    let mut graphemes = rest_from_pos.graphemes(true);
+/// Ordinal adverbs opening the steps of `@list:enumerate`, for the first ten
+/// steps.
+const EN_STEP_ORDINALS: [&str; 10] = [
+    "First", "Second", "Third", "Fourth", "Fifth", "Sixth", "Seventh", "Eighth", "Ninth", "Tenth",
+];
+const DE_STEP_ORDINALS: [&str; 10] = [
+    "Erstens",
+    "Zweitens",
+    "Drittens",
+    "Viertens",
+    "Fünftens",
+    "Sechstens",
+    "Siebtens",
+    "Achtens",
+    "Neuntens",
+    "Zehntens",
+];
 
-    match graphemes.next() {
-        Some(first) => {
-            let suffix: String = graphemes.collect();
-            let capitalized = cm.uppercase_to_string(first, locale);
-            Ok(format!("{prefix}{capitalized}{suffix}"))
-        }
-        None => Ok(text.to_string()),
+/// Write list items as numbered steps (@list:enumerate).
+///
+/// Each item opens with an ordinal adverb and a comma and ends with a period
+/// unless it already ends a sentence: "First, draw. Second, play. Third,
+/// attack." English and German use words for the first ten steps. Later
+/// steps, and every step in other languages, use a numeric ordinal: "11th"
+/// in English (suffix from the CLDR ordinal category), "11." elsewhere.
+fn enumerate_items(texts: &[String], lang: &str) -> String {
+    let words: &[&str] = match lang {
+        "en" => &EN_STEP_ORDINALS,
+        "de" => &DE_STEP_ORDINALS,
+        _ => &[],
+    };
+    let steps: Vec<String> = texts
+        .iter()
+        .enumerate()
+        .map(|(index, text)| {
+            let opener = match words.get(index) {
+                Some(word) => format!("{word},"),
+                None => numeric_step_ordinal(index as i64 + 1, lang),
+            };
+            let ends_sentence = strip_markup(text)
+                .trim_end()
+                .ends_with(['.', '!', '?', '…']);
+            let period = if ends_sentence { "" } else { "." };
+            format!("{opener} {text}{period}")
+        })
+        .collect();
+    steps.join(" ")
+}
+
+/// Numeric ordinal opening a step: "11th," in English, "11." elsewhere.
+fn numeric_step_ordinal(n: i64, lang: &str) -> String {
+    if lang != "en" {
+        return format!("{n}.");
     }
+    let suffix = match ordinal_category("en", n) {
+        "one" => "st",
+        "two" => "nd",
+        "few" => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix},")
 }
 
-/// Find the byte offset of the first visible (non-markup) character in text.
+/// Sort key for `@list:sorted`: a simple alphabetical collation.
 ///
-/// Skips over `<...>` markup tags to find actual content that should be capitalized.
-/// Returns `None` if the text contains only markup with no visible characters.
-fn find_first_visible_char(text: &str) -> Option<usize> {
-    let mut i = 0;
-    let bytes = text.as_bytes();
-    while i < bytes.len() {
-        if bytes[i] == b'<' {
-            // Skip the entire tag
-            while i < bytes.len() && bytes[i] != b'>' {
-                i += 1;
-            }
-            if i < bytes.len() {
-                i += 1; // skip the '>'
+/// Markup is ignored and letters compare case-insensitively. Accented Latin
+/// letters sort with their base letter ("é" with "e"), except the letters
+/// Nordic alphabets place after "z": å, ä, ö in Swedish and Finnish, and æ,
+/// ø, å in Danish and Norwegian. This is not full Unicode collation; ties are
+/// broken by the original text.
+fn collation_key(text: &str, lang: &str) -> Vec<u32> {
+    // Letters after "z", in alphabet order
+    let after_z: &[&[char]] = match lang {
+        "sv" | "fi" => &[&['å'], &['ä', 'æ'], &['ö', 'ø']],
+        "da" | "nb" | "nn" | "no" => &[&['æ', 'ä'], &['ø', 'ö'], &['å']],
+        _ => &[],
+    };
+    strip_markup(text)
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| {
+            let z = u32::from('z') * 4;
+            if let Some(rank) = after_z.iter().position(|letters| letters.contains(&c)) {
+                return z + 1 + rank as u32;
             }
-        } else {
-            return Some(i);
-        }
+            u32::from(fold_latin_accent(c)) * 4
+        })
+        .collect()
+}
+
+/// The base letter of a lowercase accented Latin letter, or `c` itself.
+fn fold_latin_accent(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'æ' => 'a',
+        'ç' => 'c',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+        'ß' => 's',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        other => other,
     }
-    None
 }
 
-/// Convert entire string to uppercase.
-fn upper_transform(text: &str, locale: &LanguageIdentifier) -> Result<String, EvalError> {
-    let cm = CaseMapper::new();
-    Ok(cm.uppercase_to_string(text, locale).into_owned())
+/// The word joining the last two list items, if the language has one.
+fn list_conjunction(lang: &str, disjunction: bool) -> Option<&'static str> {
+    let (and, or) = match lang {
+        "en" => ("and", "or"),
+        "de" => ("und", "oder"),
+        "nl" => ("en", "of"),
+        "es" => ("y", "o"),
+        "pt" => ("e", "ou"),
+        "fr" => ("et", "ou"),
+        "it" => ("e", "o"),
+        _ => return None,
+    };
+    Some(if disjunction { or } else { and })
 }
 
-/// Convert entire string to lowercase.
-fn lower_transform(text: &str, locale: &LanguageIdentifier) -> Result<String, EvalError> {
-    let cm = CaseMapper::new();
-    Ok(cm.lowercase_to_string(text, locale).into_owned())
+/// Spanish "y" becomes "e" before an i-sound, and "o" becomes "u" before an
+/// o-sound, judged from the visible start of the following item.
+fn spanish_conjunction(conjunction: &'static str, next: &str) -> &'static str {
+    let visible = strip_markup(next).trim_start().to_lowercase();
+    // A silent h does not change the initial sound
+    let sound = visible.strip_prefix('h').unwrap_or(&visible);
+    let mut chars = sound.chars();
+    let (first, second) = (chars.next(), chars.next());
+    match (conjunction, first) {
+        // "hielo" and "hierba" open with a diphthong and keep "y"
+        ("y", Some('i' | 'í')) if !matches!(second, Some('a' | 'e' | 'o' | 'u')) => "e",
+        // Digits read as "ocho…" and "once"
+        ("o", Some('o' | 'ó' | '8')) => "u",
+        ("o", Some('1')) if sound == "11" => "u",
+        _ => conjunction,
+    }
 }
 
 // =============================================================================
@@ -327,6 +2341,147 @@ fn english_the_transform(value: &Value) -> Result<String, EvalError> {
     Ok(format!("the {value}"))
 }
 
+/// English genitive transform (@genitive).
+///
+/// Renders "the X of the Y" with the head noun as the value and the owner
+/// passed as context: `{@genitive($owner) $head}`. A noun tagged `:proper`
+/// takes no article ("the edge of Avalon"). For the "Y's X" form, use `@poss`
+/// on the owner instead.
+fn english_genitive_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let owner = genitive_owner(value, context)?;
+    Ok(format!(
+        "{} of {}",
+        english_definite_noun(value),
+        english_definite_noun(owner)
+    ))
+}
+
+/// A noun with "the", or bare if it is tagged `:proper`.
+fn english_definite_noun(value: &Value) -> String {
+    if value.has_tag("proper") {
+        value.to_string()
+    } else {
+        format!("the {value}")
+    }
+}
+
+/// English count transform (@count).
+///
+/// Renders "{n} {noun}" with the noun's `one` or `other` variant chosen by
+/// English plural rules: `{@count($n) card}` gives "2 cards". With the
+/// `words` flag (`@count:words($n)`), 0 becomes "no cards" and 1 becomes
+/// "a card" or "an event", which requires an `:a` or `:an` tag.
+fn english_count_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    count_noun_transform(value, context, "en", "count")
+}
+
+/// Count-and-noun transform (@count_noun).
+///
+/// Renders "{n} {noun}" with the noun's variant for `n` under the language's
+/// plural rules, saving the `{$n} {noun:$n}` pair: `{@count_noun($n) card}`
+/// gives "2 cards", and "2 карты" for a Russian noun. The English `words`
+/// flag behaves as for English `@count`; other languages reject it, since
+/// their "no" and "a" forms depend on grammar this transform cannot see.
+/// A missing count, or one that is not an integer, is an error.
+/// `transform` names the transform in errors.
+fn count_noun_transform(
+    value: &Value,
+    context: Option<&Value>,
+    lang: &str,
+    transform: &str,
+) -> Result<String, EvalError> {
+    let (words, count) = match context {
+        Some(Value::Number(n)) => (false, *n),
+        Some(Value::String(s)) => match s.strip_prefix("words.") {
+            Some(n) => (true, parse_count(transform, n)?),
+            None => (false, parse_count(transform, s)?),
+        },
+        Some(other) => (false, parse_count(transform, &other.to_string())?),
+        None => (false, parse_count(transform, "")?),
+    };
+    let primary = primary_language_subtag(lang).unwrap_or(lang);
+    if words && primary != "en" {
+        return Err(EvalError::InvalidTransformInput {
+            transform: transform.to_string(),
+            expected: "a count context; ':words' is English-only".to_string(),
+            value: "words".to_string(),
+        });
+    }
+    let noun = resolve_text_with_context(
+        value,
+        Some(&Value::String(plural_category(lang, count).to_string())),
+    );
+    match (words, count) {
+        (true, 0) => Ok(format!("no {noun}")),
+        (true, 1) if value.has_tag("a") => Ok(format!("a {noun}")),
+        (true, 1) if value.has_tag("an") => Ok(format!("an {noun}")),
+        (true, 1) => Err(EvalError::MissingTag {
+            transform: transform.to_string(),
+            expected: vec!["a".to_string(), "an".to_string()],
+            phrase: noun,
+        }),
+        _ => Ok(format!("{count} {noun}")),
+    }
+}
+
+/// Parse the count passed as context to `@count` or `@count_noun`.
+fn parse_count(transform: &str, count: &str) -> Result<i64, EvalError> {
+    count.parse().map_err(|_| EvalError::InvalidTransformInput {
+        transform: transform.to_string(),
+        expected: format!("an integer count as context, e.g. @{transform}($n)"),
+        value: count.to_string(),
+    })
+}
+
+/// English pronoun transform (@pronoun).
+///
+/// The value names a gender: a `male`, `female`, `neuter`, or `other` string,
+/// or a phrase with one of those tags. The context picks the pronoun:
+/// `subj` (he, the default), `obj` (him), `poss` (his card), `poss_pron`
+/// (the card is his), or `refl` (himself). `other` uses singular "they".
+fn english_pronoun_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    const GENDERS: [&str; 4] = ["male", "female", "neuter", "other"];
+    let gender = match value {
+        Value::Phrase(phrase) => GENDERS.iter().position(|gender| phrase.has_tag(gender)),
+        other => {
+            let text = other.to_string();
+            GENDERS.iter().position(|gender| *gender == text)
+        }
+    };
+    let Some(gender) = gender else {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "pronoun".to_string(),
+            expected: "a gender: male, female, neuter, or other".to_string(),
+            value: value.to_string(),
+        });
+    };
+    let kind = context.map(Value::to_string);
+    let forms = match kind.as_deref().unwrap_or("subj") {
+        "subj" => ["he", "she", "it", "they"],
+        "obj" => ["him", "her", "it", "them"],
+        "poss" => ["his", "her", "its", "their"],
+        "poss_pron" => ["his", "hers", "its", "theirs"],
+        "refl" => ["himself", "herself", "itself", "themselves"],
+        other => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "pronoun".to_string(),
+                expected: "a pronoun type: subj, obj, poss, poss_pron, or refl".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+    Ok(forms[gender].to_string())
+}
+
+/// The owner phrase passed as context to `@genitive`.
+fn genitive_owner<'a>(value: &Value, context: Option<&'a Value>) -> Result<&'a Value, EvalError> {
+    context.ok_or_else(|| EvalError::InvalidTransformInput {
+        transform: "genitive".to_string(),
+        expected: "an owner passed as context, e.g. @genitive($owner)".to_string(),
+        value: value.to_string(),
+    })
+}
+
 /// English plural transform (@plural).
 ///
 /// Selects the `:other` variant from a Phrase value.
@@ -337,6 +2492,37 @@ fn english_plural_transform(value: &Value) -> Result<String, EvalError> {
     ))
 }
 
+/// English possessive transform.
+///
+/// Appends `’s`, or only `’` when the visible text already ends in "s"
+/// (e.g., "cards’", "<b>bosses</b>’"). A `:sibilant` tag forces `’s` for
+/// singular words like "boss" or "James". The `ascii` context
+/// (`@poss:ascii`) uses a straight `'` instead of `’`; any other context is
+/// an error.
+fn english_possessive_transform(
+    value: &Value,
+    context: Option<&Value>,
+) -> Result<String, EvalError> {
+    let apostrophe = match context.map(Value::to_string).as_deref() {
+        None => '\u{2019}',
+        Some("ascii") => '\'',
+        Some(other) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "poss".to_string(),
+                expected: "no context, or the ':ascii' context".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+    let text = value.to_string();
+    let ends_in_s = strip_markup(&text).ends_with(['s', 'S']);
+    if ends_in_s && !value.has_tag("sibilant") {
+        Ok(format!("{text}{apostrophe}"))
+    } else {
+        Ok(format!("{text}{apostrophe}s"))
+    }
+}
+
 // =============================================================================
 // German Transforms (Phase 6)
 // =============================================================================
@@ -493,14 +2679,40 @@ fn german_indefinite_article(gender: GermanGender, case: GermanCase) -> &'static
     }
 }
 
+/// German negative article lookup table.
+///
+/// "kein" declines like "ein" in the singular and takes the plural endings
+/// that "ein" lacks: nom/acc keine, dat keinen, gen keiner.
+fn german_negative_article(gender: GermanGender, case: GermanCase, plural: GermanPlural) -> String {
+    if plural == GermanPlural::Other {
+        let ending = match case {
+            GermanCase::Nominative | GermanCase::Accusative => "e",
+            GermanCase::Dative => "en",
+            GermanCase::Genitive => "er",
+        };
+        return format!("kein{ending}");
+    }
+    format!("k{}", german_indefinite_article(gender, case))
+}
+
 /// German definite article transform (@der/@die/@das).
 ///
 /// Reads :masc/:fem/:neut tag from Value to determine gender.
 /// Uses context for case and plural (e.g., "acc", "acc.other", "other").
 fn german_der_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    german_definite_phrase("der", value, context)
+}
+
+/// Prepend the German definite article, reporting a missing gender tag
+/// against `transform`.
+fn german_definite_phrase(
+    transform: &str,
+    value: &Value,
+    context: Option<&Value>,
+) -> Result<String, EvalError> {
     let text = resolve_text_with_context(value, context);
     let gender = parse_german_gender(value).ok_or_else(|| EvalError::MissingTag {
-        transform: "der".to_string(),
+        transform: transform.to_string(),
         expected: vec!["masc".to_string(), "fem".to_string(), "neut".to_string()],
         phrase: text.clone(),
     })?;
@@ -529,6 +2741,54 @@ fn german_ein_transform(value: &Value, context: Option<&Value>) -> Result<String
     Ok(format!("{} {}", article, text))
 }
 
+/// German negative article transform (@kein/@keine).
+///
+/// Reads :masc/:fem/:neut tag from Value to determine gender.
+/// Uses context for case and plural like @der (e.g., "acc", "dat.other").
+fn german_kein_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let text = resolve_text_with_context(value, context);
+    let gender = parse_german_gender(value).ok_or_else(|| EvalError::MissingTag {
+        transform: "kein".to_string(),
+        expected: vec!["masc".to_string(), "fem".to_string(), "neut".to_string()],
+        phrase: text.clone(),
+    })?;
+    let (case, plural) = parse_german_context(context);
+    let article = german_negative_article(gender, case, plural);
+    Ok(format!("{} {}", article, text))
+}
+
+/// German genitive transform (@genitive).
+///
+/// Renders "der Rand des Waldes": the head noun (the value) in the nominative
+/// with its definite article, followed by the owner (the context) in the
+/// genitive. Both need a :masc/:fem/:neut tag; `nom` and `gen` variants are
+/// used when present. A noun whose `other` variant was selected takes the
+/// plural article ("der Rand der Wälder").
+fn german_genitive_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let owner = genitive_owner(value, context)?;
+    let head =
+        german_definite_phrase("genitive", value, Some(&german_genitive_case(value, "nom")))?;
+    let owner =
+        german_definite_phrase("genitive", owner, Some(&german_genitive_case(owner, "gen")))?;
+    Ok(format!("{head} {owner}"))
+}
+
+/// The `@der` context for a noun in `@genitive`: `case`, or `case.other` if
+/// the noun is a selected plural variant.
+fn german_genitive_case(value: &Value, case: &str) -> Value {
+    let plural = matches!(
+        value,
+        Value::Phrase(phrase) if phrase
+            .selected_variant()
+            .is_some_and(|key| key.as_str().split('.').any(|part| part == "other"))
+    );
+    if plural {
+        Value::String(format!("{case}.other"))
+    } else {
+        Value::String(case.to_string())
+    }
+}
+
 // =============================================================================
 // Dutch Transforms (Phase 6)
 // =============================================================================
@@ -617,6 +2877,30 @@ fn parse_romance_plural(context: Option<&Value>) -> RomancePlural {
     }
 }
 
+/// Split a `neg` flag off a transform context.
+///
+/// Returns whether the context is negated along with the remaining context:
+/// `:neg` leaves no context, and `:neg($n)` (combined by the evaluator as
+/// `"neg.{n}"`) leaves `$n` so plural selection still works.
+fn split_negation(context: Option<&Value>) -> (bool, Option<Value>) {
+    let Some(Value::String(ctx)) = context else {
+        return (false, context.cloned());
+    };
+    let Some(rest) = ctx.strip_prefix("neg") else {
+        return (false, context.cloned());
+    };
+    if rest.is_empty() {
+        return (true, None);
+    }
+    let Some(rest) = rest.strip_prefix('.') else {
+        return (false, context.cloned());
+    };
+    let rest = rest
+        .parse()
+        .map_or_else(|_| Value::String(rest.to_string()), Value::Number);
+    (true, Some(rest))
+}
+
 /// Resolve the display text for a Value, using context for variant selection.
 ///
 /// When the value is a Phrase with variants and context provides a variant key
@@ -637,11 +2921,11 @@ fn resolve_text_with_context(value: &Value, context: Option<&Value>) -> String {
     if phrase.variants.is_empty() {
         return value.to_string();
     }
-    if let Some(text) = phrase.variants.get(&VariantKey::new(ctx_key)) {
+    if let Some(text) = phrase.variants.get(ctx_key.as_str()) {
         return text.clone();
     }
     for segment in ctx_key.rsplit('.') {
-        if let Some(text) = phrase.variants.get(&VariantKey::new(segment)) {
+        if let Some(text) = phrase.variants.get(segment) {
             return text.clone();
         }
     }
@@ -738,6 +3022,17 @@ fn portuguese_em_contraction(gender: RomanceGender, plural: RomancePlural) -> &'
     }
 }
 
+/// Portuguese "por" + article contraction lookup table.
+/// por + o = pelo, por + a = pela, por + os = pelos, por + as = pelas
+fn portuguese_por_contraction(gender: RomanceGender, plural: RomancePlural) -> &'static str {
+    match (gender, plural) {
+        (RomanceGender::Masculine, RomancePlural::One) => "pelo",
+        (RomanceGender::Masculine, RomancePlural::Other) => "pelos",
+        (RomanceGender::Feminine, RomancePlural::One) => "pela",
+        (RomanceGender::Feminine, RomancePlural::Other) => "pelas",
+    }
+}
+
 /// Portuguese definite article transform (@o/@a).
 fn portuguese_o_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
     let text = resolve_text_with_context(value, context);
@@ -773,6 +3068,15 @@ fn portuguese_em_transform(value: &Value, context: Option<&Value>) -> Result<Str
     Ok(format!("{} {}", contracted, text))
 }
 
+/// Portuguese "por" + article contraction transform (@por).
+fn portuguese_por_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let text = resolve_text_with_context(value, context);
+    let gender = parse_romance_gender(value, "por")?;
+    let plural = parse_romance_plural(context);
+    let contracted = portuguese_por_contraction(gender, plural);
+    Ok(format!("{} {}", contracted, text))
+}
+
 // =============================================================================
 // French Transforms (Phase 7)
 // =============================================================================
@@ -847,7 +3151,11 @@ fn french_au_contraction(
 
 /// French definite article transform (@le/@la).
 /// Handles elision before vowels via :vowel tag.
-fn french_le_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+fn french_le_transform(
+    value: &Value,
+    context: Option<&Value>,
+    apostrophe: ApostropheStyle,
+) -> Result<String, EvalError> {
     let text = resolve_text_with_context(value, context);
     let gender = parse_romance_gender(value, "le")?;
     let has_vowel = value.has_tag("vowel");
@@ -855,11 +3163,7 @@ fn french_le_transform(value: &Value, context: Option<&Value>) -> Result<String,
     let article = french_definite_article(gender, has_vowel, plural);
 
     // Elided article (l') attaches directly, no space
-    if article.ends_with('\'') {
-        Ok(format!("{}{}", article, text))
-    } else {
-        Ok(format!("{} {}", article, text))
-    }
+    Ok(attach_article(article, &text, apostrophe))
 }
 
 /// French indefinite article transform (@un/@une).
@@ -871,7 +3175,19 @@ fn french_un_transform(value: &Value) -> Result<String, EvalError> {
 }
 
 /// French "de" + article contraction transform (@de).
-fn french_de_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+///
+/// With the `:neg` context the partitive article reduces to bare "de"/"d'"
+/// regardless of gender and number, as in "pas de pain" or "pas d'eau".
+fn french_de_transform(
+    value: &Value,
+    context: Option<&Value>,
+    apostrophe: ApostropheStyle,
+) -> Result<String, EvalError> {
+    if let (true, rest) = split_negation(context) {
+        let text = resolve_text_with_context(value, rest.as_ref());
+        let article = if value.has_tag("vowel") { "d'" } else { "de" };
+        return Ok(attach_article(article, &text, apostrophe));
+    }
     let text = resolve_text_with_context(value, context);
     let gender = parse_romance_gender(value, "de")?;
     let has_vowel = value.has_tag("vowel");
@@ -879,15 +3195,15 @@ fn french_de_transform(value: &Value, context: Option<&Value>) -> Result<String,
     let contracted = french_de_contraction(gender, has_vowel, plural);
 
     // "de l'" has apostrophe - attach directly
-    if contracted.ends_with('\'') {
-        Ok(format!("{}{}", contracted, text))
-    } else {
-        Ok(format!("{} {}", contracted, text))
-    }
+    Ok(attach_article(contracted, &text, apostrophe))
 }
 
 /// French "a" + article contraction transform (@au).
-fn french_au_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+fn french_au_transform(
+    value: &Value,
+    context: Option<&Value>,
+    apostrophe: ApostropheStyle,
+) -> Result<String, EvalError> {
     let text = resolve_text_with_context(value, context);
     let gender = parse_romance_gender(value, "au")?;
     let has_vowel = value.has_tag("vowel");
@@ -895,11 +3211,7 @@ fn french_au_transform(value: &Value, context: Option<&Value>) -> Result<String,
     let contracted = french_au_contraction(gender, has_vowel, plural);
 
     // "a l'" has apostrophe - attach directly
-    if contracted.ends_with('\'') {
-        Ok(format!("{}{}", contracted, text))
-    } else {
-        Ok(format!("{} {}", contracted, text))
-    }
+    Ok(attach_article(contracted, &text, apostrophe))
 }
 
 /// French liaison transform (@liaison).
@@ -918,7 +3230,7 @@ fn french_liaison_transform(value: &Value, context: Option<&Value>) -> Result<St
 
     // Try to get the variant from the value
     if let Value::Phrase(phrase) = value
-        && let Some(variant_text) = phrase.variants.get(&VariantKey::new(variant_key))
+        && let Some(variant_text) = phrase.variants.get(variant_key)
     {
         return Ok(variant_text.clone());
     }
@@ -927,6 +3239,57 @@ fn french_liaison_transform(value: &Value, context: Option<&Value>) -> Result<St
     Ok(value.to_string())
 }
 
+/// Narrow no-break space (U+202F), used by French typography before high
+/// punctuation and inside guillemets.
+const NARROW_NBSP: char = '\u{202F}';
+
+/// French punctuation spacing transform (@punct).
+///
+/// Puts a narrow no-break space before `!`, `?`, `:`, `;`, and `»`, and after
+/// `«`: "Bonjour!" -> "Bonjour\u{202F}!". An ordinary space already in place is
+/// replaced rather than doubled, and an existing no-break space is kept.
+/// Colons and semicolons are only spaced when followed by whitespace or the
+/// end of the text, so times ("10:30") and URLs are untouched; runs like "?!"
+/// get a single space; `<...>` markup is copied as-is.
+fn french_punct_transform(text: &str) -> Result<String, EvalError> {
+    let mut result = String::with_capacity(text.len() + 8);
+    let mut chars = text.chars().peekable();
+    let mut in_markup = false;
+    while let Some(c) = chars.next() {
+        if in_markup || c == '<' {
+            in_markup = c != '>';
+            result.push(c);
+            continue;
+        }
+        let spaced_before = match c {
+            '!' | '?' => !result.ends_with(['!', '?']),
+            ':' | ';' => chars.peek().is_none_or(|next| next.is_whitespace()),
+            '»' => true,
+            _ => false,
+        };
+        if spaced_before && !result.is_empty() {
+            if result.ends_with(' ') {
+                result.pop();
+                result.push(NARROW_NBSP);
+            } else if !result.ends_with(char::is_whitespace) {
+                result.push(NARROW_NBSP);
+            }
+        }
+        result.push(c);
+        if c == '«' {
+            match chars.peek() {
+                Some(' ') => {
+                    chars.next();
+                    result.push(NARROW_NBSP);
+                }
+                Some(next) if !next.is_whitespace() => result.push(NARROW_NBSP),
+                _ => {}
+            }
+        }
+    }
+    Ok(result)
+}
+
 // =============================================================================
 // Italian Transforms (Phase 7)
 // =============================================================================
@@ -1042,7 +3405,11 @@ fn italian_a_contraction(
 }
 
 /// Italian definite article transform (@il/@lo/@la).
-fn italian_il_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+fn italian_il_transform(
+    value: &Value,
+    context: Option<&Value>,
+    apostrophe: ApostropheStyle,
+) -> Result<String, EvalError> {
     let text = resolve_text_with_context(value, context);
     let gender = parse_romance_gender(value, "il")?;
     let sound = parse_italian_sound(value);
@@ -1050,56 +3417,48 @@ fn italian_il_transform(value: &Value, context: Option<&Value>) -> Result<String
     let article = italian_definite_article(gender, sound, plural);
 
     // Apostrophe articles attach directly
-    if article.ends_with('\'') {
-        Ok(format!("{}{}", article, text))
-    } else {
-        Ok(format!("{} {}", article, text))
-    }
+    Ok(attach_article(article, &text, apostrophe))
 }
 
 /// Italian indefinite article transform (@un/@uno/@una).
-fn italian_un_transform(value: &Value) -> Result<String, EvalError> {
+fn italian_un_transform(value: &Value, apostrophe: ApostropheStyle) -> Result<String, EvalError> {
     let text = value.to_string();
     let gender = parse_romance_gender(value, "un")?;
     let sound = parse_italian_sound(value);
     let article = italian_indefinite_article(gender, sound);
 
     // Apostrophe articles attach directly (un'amica)
-    if article.ends_with('\'') {
-        Ok(format!("{}{}", article, text))
-    } else {
-        Ok(format!("{} {}", article, text))
-    }
+    Ok(attach_article(article, &text, apostrophe))
 }
 
 /// Italian "di" + article contraction transform (@di).
-fn italian_di_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+fn italian_di_transform(
+    value: &Value,
+    context: Option<&Value>,
+    apostrophe: ApostropheStyle,
+) -> Result<String, EvalError> {
     let text = resolve_text_with_context(value, context);
     let gender = parse_romance_gender(value, "di")?;
     let sound = parse_italian_sound(value);
     let plural = parse_romance_plural(context);
     let contracted = italian_di_contraction(gender, sound, plural);
 
-    if contracted.ends_with('\'') {
-        Ok(format!("{}{}", contracted, text))
-    } else {
-        Ok(format!("{} {}", contracted, text))
-    }
+    Ok(attach_article(contracted, &text, apostrophe))
 }
 
 /// Italian "a" + article contraction transform (@a).
-fn italian_a_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+fn italian_a_transform(
+    value: &Value,
+    context: Option<&Value>,
+    apostrophe: ApostropheStyle,
+) -> Result<String, EvalError> {
     let text = resolve_text_with_context(value, context);
     let gender = parse_romance_gender(value, "a")?;
     let sound = parse_italian_sound(value);
     let plural = parse_romance_plural(context);
     let contracted = italian_a_contraction(gender, sound, plural);
 
-    if contracted.ends_with('\'') {
-        Ok(format!("{}{}", contracted, text))
-    } else {
-        Ok(format!("{} {}", contracted, text))
-    }
+    Ok(attach_article(contracted, &text, apostrophe))
 }
 
 // =============================================================================
@@ -1120,9 +3479,13 @@ enum GreekCase {
     Nominative,
     Accusative,
     Genitive,
-    Dative, // Archaic in modern Greek, but supported per spec
+    /// Archaic: only reachable through the `katharevousa` context segment.
+    Dative,
 }
 
+/// Context segment that unlocks the archaic dative (`@o:dat.katharevousa`).
+const GREEK_ARCHAIC_MARKER: &str = "katharevousa";
+
 /// Parse gender from Value's tags for Greek.
 /// Returns error if no gender tag is present.
 fn parse_greek_gender(value: &Value, transform: &str) -> Result<GreekGender, EvalError> {
@@ -1141,50 +3504,58 @@ fn parse_greek_gender(value: &Value, transform: &str) -> Result<GreekGender, Eva
     }
 }
 
-/// Match a string segment to a Greek case.
-fn match_greek_case(s: &str) -> Option<GreekCase> {
-    match s {
-        "acc" => Some(GreekCase::Accusative),
-        "gen" => Some(GreekCase::Genitive),
-        "dat" => Some(GreekCase::Dative),
-        "nom" => Some(GreekCase::Nominative),
-        _ => None,
-    }
-}
-
-/// Parse case from context value.
+/// Parse case and plural from a Greek transform context.
 ///
-/// Supports compound context like `"gen.other"` where the first segment
-/// is the case. Also supports single-segment contexts like `"gen"` or
-/// `"acc"`. Defaults to nominative if no case is found.
-fn parse_greek_case(context: Option<&Value>) -> GreekCase {
+/// Supports compound context like `"gen.other"` (case, then plural) as well as
+/// single-segment `"gen"` or `"other"`. Defaults to nominative singular.
+///
+/// Modern Greek has no dative: indirect objects use the genitive or σε with
+/// the accusative ("στην κάρτα"). A bare `dat` is therefore an error rather
+/// than silently producing archaic forms like "ταις". Those forms remain
+/// available for period text by adding a `katharevousa` segment, as in
+/// `@o:dat.katharevousa` or `@o:dat.other.katharevousa`.
+fn parse_greek_context(
+    context: Option<&Value>,
+    transform: &str,
+) -> Result<(GreekCase, RomancePlural), EvalError> {
     let Some(Value::String(s)) = context else {
-        return GreekCase::Nominative;
+        return Ok((GreekCase::Nominative, RomancePlural::One));
     };
-    if let Some((first, _)) = s.split_once('.') {
-        return match_greek_case(first).unwrap_or(GreekCase::Nominative);
+    let mut case = GreekCase::Nominative;
+    let mut plural = RomancePlural::One;
+    let mut archaic = false;
+    for segment in s.split('.') {
+        match segment {
+            "acc" => case = GreekCase::Accusative,
+            "gen" => case = GreekCase::Genitive,
+            "dat" => case = GreekCase::Dative,
+            "other" => plural = RomancePlural::Other,
+            GREEK_ARCHAIC_MARKER => archaic = true,
+            _ => {}
+        }
+    }
+    if matches!(case, GreekCase::Dative) && !archaic {
+        return Err(EvalError::InvalidTransformInput {
+            transform: transform.to_string(),
+            expected: format!(
+                "a Modern Greek case (nom, acc, gen); the dative is archaic, use gen or σε + acc, or 'dat.{GREEK_ARCHAIC_MARKER}' for period text"
+            ),
+            value: s.clone(),
+        });
     }
-    match_greek_case(s).unwrap_or(GreekCase::Nominative)
+    Ok((case, plural))
 }
 
-/// Parse plural category from context value for Greek transforms.
-///
-/// Supports compound context like `"gen.other"` where the second segment
-/// is the plural category. Also supports single-segment `"other"` for
-/// backwards compatibility. Defaults to singular (One).
-fn parse_greek_plural(context: Option<&Value>) -> RomancePlural {
-    let Some(Value::String(s)) = context else {
-        return RomancePlural::One;
-    };
-    if let Some((_, second)) = s.split_once('.') {
-        return match second {
-            "other" => RomancePlural::Other,
-            _ => RomancePlural::One,
-        };
-    }
-    match s.as_str() {
-        "other" => RomancePlural::Other,
-        _ => RomancePlural::One,
+/// Drop the `katharevousa` segment so it does not take part in variant lookup.
+fn without_archaic_marker(context: Option<&Value>) -> Option<Value> {
+    match context {
+        Some(Value::String(s)) => Some(Value::String(
+            s.split('.')
+                .filter(|segment| *segment != GREEK_ARCHAIC_MARKER)
+                .collect::<Vec<_>>()
+                .join("."),
+        )),
+        other => other.cloned(),
     }
 }
 
@@ -1260,10 +3631,9 @@ fn greek_indefinite_article(gender: GreekGender, case: GreekCase) -> &'static st
 /// Uses context for case and plural, supporting compound context like
 /// `@o:gen.other` for combined case + plural specification.
 fn greek_o_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
-    let text = resolve_text_with_context(value, context);
+    let text = resolve_text_with_context(value, without_archaic_marker(context).as_ref());
     let gender = parse_greek_gender(value, "o")?;
-    let case = parse_greek_case(context);
-    let plural = parse_greek_plural(context);
+    let (case, plural) = parse_greek_context(context, "o")?;
 
     let article = if plural == RomancePlural::One {
         greek_definite_article_singular(gender, case)
@@ -1277,12 +3647,16 @@ fn greek_o_transform(value: &Value, context: Option<&Value>) -> Result<String, E
 /// Greek indefinite article transform (@enas/@mia/@ena).
 ///
 /// Reads :masc/:fem/:neut tag from Value to determine gender.
-/// Uses context for case (defaults to nominative).
-/// Note: Greek indefinite articles exist only in singular form.
+/// Uses context for case (defaults to nominative), with the same compound
+/// form and dative rules as `@o`. Greek has no plural indefinite article, so a
+/// plural context (`@enas:other`) leaves the bare noun: "κάρτες".
 fn greek_enas_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
-    let text = resolve_text_with_context(value, context);
+    let text = resolve_text_with_context(value, without_archaic_marker(context).as_ref());
     let gender = parse_greek_gender(value, "enas")?;
-    let case = parse_greek_case(context);
+    let (case, plural) = parse_greek_context(context, "enas")?;
+    if plural == RomancePlural::Other {
+        return Ok(text);
+    }
     let article = greek_indefinite_article(gender, case);
 
     Ok(format!("{} {}", article, text))
@@ -1395,6 +3769,107 @@ fn arabic_al_transform(value: &Value) -> Result<String, EvalError> {
     })
 }
 
+/// Zero-width joiner.
+/// Unicode: U+200D (ZERO WIDTH JOINER)
+/// Makes a letter take its connected form across a fragment boundary.
+const ZWJ: char = '\u{200D}';
+
+/// How an Arabic-script letter connects to its neighbors.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ArabicJoining {
+    /// Connects on both sides (ب, ل, ی).
+    Dual,
+    /// Connects only to the preceding letter (ا, د, ر, و).
+    Right,
+}
+
+/// Whether `c` is an Arabic-script combining mark, which is transparent to
+/// joining (harakat, shadda, superscript alef, Quranic marks).
+fn is_arabic_mark(c: char) -> bool {
+    matches!(c, '\u{064B}'..='\u{065F}' | '\u{0670}' | '\u{06D6}'..='\u{06ED}')
+}
+
+/// The joining type of an Arabic-script letter, or `None` for letters that
+/// never connect (hamza) and for characters outside the script.
+fn arabic_joining(c: char) -> Option<ArabicJoining> {
+    match c {
+        '\u{0622}'..='\u{0625}'
+        | '\u{0627}'
+        | '\u{0629}'
+        | '\u{062F}'..='\u{0632}'
+        | '\u{0648}'
+        | '\u{0671}'..='\u{0673}'
+        | '\u{0675}'..='\u{0677}'
+        | '\u{0688}'..='\u{0699}'
+        | '\u{06C0}'
+        | '\u{06C3}'..='\u{06CB}'
+        | '\u{06CD}'
+        | '\u{06CF}'
+        | '\u{06D2}'..='\u{06D3}'
+        | '\u{06D5}' => Some(ArabicJoining::Right),
+        '\u{0620}'
+        | '\u{0626}'
+        | '\u{0628}'
+        | '\u{062A}'..='\u{062E}'
+        | '\u{0633}'..='\u{063F}'
+        | '\u{0640}'..='\u{0647}'
+        | '\u{0649}'..='\u{064A}'
+        | '\u{066E}'..='\u{066F}'
+        | '\u{0678}'..='\u{0687}'
+        | '\u{069A}'..='\u{06BF}'
+        | '\u{06C1}'..='\u{06C2}'
+        | '\u{06CC}'
+        | '\u{06CE}'
+        | '\u{06D0}'..='\u{06D1}'
+        | '\u{06FA}'..='\u{06FC}'
+        | '\u{06FF}' => Some(ArabicJoining::Dual),
+        _ => None,
+    }
+}
+
+/// Arabic-script fragment joining transform (@connect).
+///
+/// Text inserted as a separate fragment (a styled span, or a definite
+/// article in its own markup) may be shaped without its neighbors, so its
+/// edge letters render in isolated forms. This adds explicit joiners:
+/// - Start: ZWJ before a first letter that connects to a preceding letter
+/// - End: ZWJ after a dual-joining last letter, so it connects forward, or
+///   ZWNJ after a right-joining one, which cannot
+///
+/// Combining marks are skipped when finding the edge letters. The `:start`
+/// and `:end` contexts limit this to one side.
+fn arabic_connect_transform(text: &str, context: Option<&Value>) -> Result<String, EvalError> {
+    let (start, end) = match context.map(Value::to_string).as_deref() {
+        None => (true, true),
+        Some("start") => (true, false),
+        Some("end") => (false, true),
+        Some(other) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "connect".to_string(),
+                expected: "no context, ':start', or ':end'".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+    let mut letters = text.chars().filter(|c| !is_arabic_mark(*c));
+    let first = letters.next().and_then(arabic_joining);
+    let last = letters.next_back().map_or(first, arabic_joining);
+
+    let mut result = String::with_capacity(text.len() + 6);
+    if start && first.is_some() {
+        result.push(ZWJ);
+    }
+    result.push_str(text);
+    if end {
+        match last {
+            Some(ArabicJoining::Dual) => result.push(ZWJ),
+            Some(ArabicJoining::Right) => result.push_str(ZWNJ),
+            None => {}
+        }
+    }
+    Ok(result)
+}
+
 // =============================================================================
 // Persian Transforms (Phase 8)
 // =============================================================================
@@ -1433,6 +3908,68 @@ fn persian_ezafe_transform(value: &Value) -> Result<String, EvalError> {
     }
 }
 
+// =============================================================================
+// Russian Transforms
+// =============================================================================
+
+/// Russian genitive-of-negation transform (@neg).
+///
+/// Negated existence and the objects of negated verbs take the genitive
+/// ("нет карты", "нет карт"). Russian selects case through variants rather
+/// than an inflection transform, so negation is its own transform instead of
+/// a `:neg` flag like French `@de:neg`.
+///
+/// The context is a count or a plural category, and picks `gen.{category}`
+/// by Russian plural rules: `gen.one` for 1 and 21, `gen.few` for 2-4,
+/// `gen.many` for 5-20. Other categories fall back to `gen.many`, then
+/// `gen.other`; every category falls back to `gen`. No context means one.
+fn russian_neg_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let category = match context {
+        None => "one",
+        Some(Value::Number(n)) => plural_category("ru", *n),
+        Some(Value::String(s)) => match s.parse::<i64>() {
+            Ok(n) => plural_category("ru", n),
+            Err(_) => RUSSIAN_PLURAL_CATEGORIES
+                .iter()
+                .find(|category| **category == s)
+                .copied()
+                .ok_or_else(|| EvalError::InvalidTransformInput {
+                    transform: "neg".to_string(),
+                    expected: "a count or plural category context ('one', 'few', 'many', 'other')"
+                        .to_string(),
+                    value: s.clone(),
+                })?,
+        },
+        Some(other) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "neg".to_string(),
+                expected: "a count or plural category context".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+    let exact = format!("gen.{category}");
+    let mut keys = vec![exact.as_str()];
+    if category != "one" {
+        keys.extend(["gen.many", "gen.other"]);
+    }
+    keys.push("gen");
+    let genitive = match value {
+        Value::Phrase(phrase) => keys.iter().find_map(|key| phrase.variants.get(*key)),
+        _ => None,
+    };
+    genitive
+        .cloned()
+        .ok_or_else(|| EvalError::InvalidTransformInput {
+            transform: "neg".to_string(),
+            expected: "a phrase with genitive ('gen') variants".to_string(),
+            value: value.to_string(),
+        })
+}
+
+/// Russian cardinal plural categories, which `@neg` accepts as a context.
+const RUSSIAN_PLURAL_CATEGORIES: [&str; 4] = ["one", "few", "many", "other"];
+
 // =============================================================================
 // CJK Transforms (Phase 9)
 // =============================================================================
@@ -1498,6 +4035,17 @@ const BENGALI_CLASSIFIERS: &[(&str, &str)] = &[
     ("jon", "জন"),   // For people
 ];
 
+/// Malay classifiers.
+/// Tag name -> classifier word.
+const MALAY_CLASSIFIERS: &[(&str, &str)] = &[
+    ("ekor", "ekor"),     // Animals
+    ("buah", "buah"),     // Objects, places, general things
+    ("orang", "orang"),   // People
+    ("biji", "biji"),     // Small round objects
+    ("helai", "helai"),   // Sheets, cloth, hair
+    ("batang", "batang"), // Long objects
+];
+
 /// Extract count value from context.
 fn context_to_count(context: Option<&Value>) -> i64 {
     match context {
@@ -1517,6 +4065,50 @@ fn find_classifier<'a>(value: &Value, classifiers: &'a [(&str, &str)]) -> Option
     None
 }
 
+/// Every language's classifier table, used to recognize a classifier tag
+/// that was written for a different language.
+const ALL_CLASSIFIERS: &[&[(&str, &str)]] = &[
+    CHINESE_CLASSIFIERS,
+    JAPANESE_COUNTERS,
+    KOREAN_COUNTERS,
+    VIETNAMESE_CLASSIFIERS,
+    THAI_CLASSIFIERS,
+    BENGALI_CLASSIFIERS,
+    MALAY_CLASSIFIERS,
+];
+
+/// Find the classifier for `value` in `lang`'s table.
+///
+/// A value without one fails with [`EvalError::UnknownClassifier`] when it
+/// carries another language's classifier tag (such as Japanese `:mai` on a
+/// Chinese noun), and with [`EvalError::MissingTag`] otherwise.
+fn require_classifier<'a>(
+    value: &Value,
+    lang: &str,
+    classifiers: &'a [(&str, &str)],
+) -> Result<&'a str, EvalError> {
+    if let Some(classifier) = find_classifier(value, classifiers) {
+        return Ok(classifier);
+    }
+    let known: Vec<String> = classifiers.iter().map(|(t, _)| t.to_string()).collect();
+    let foreign = ALL_CLASSIFIERS
+        .iter()
+        .flat_map(|table| table.iter())
+        .find(|(tag, _)| value.has_tag(tag));
+    Err(match foreign {
+        Some((tag, _)) => EvalError::UnknownClassifier {
+            lang: lang.to_string(),
+            tag: tag.to_string(),
+            known,
+        },
+        None => EvalError::MissingTag {
+            transform: "count".to_string(),
+            expected: known,
+            phrase: value.to_string(),
+        },
+    })
+}
+
 /// Chinese @count transform.
 ///
 /// Produces "{count}{classifier}{noun}" format.
@@ -1525,15 +4117,7 @@ fn chinese_count_transform(value: &Value, context: Option<&Value>) -> Result<Str
     let text = value.to_string();
     let count = context_to_count(context);
 
-    let classifier =
-        find_classifier(value, CHINESE_CLASSIFIERS).ok_or_else(|| EvalError::MissingTag {
-            transform: "count".to_string(),
-            expected: CHINESE_CLASSIFIERS
-                .iter()
-                .map(|(t, _)| t.to_string())
-                .collect(),
-            phrase: text.clone(),
-        })?;
+    let classifier = require_classifier(value, "zh", CHINESE_CLASSIFIERS)?;
 
     Ok(format!("{}{}{}", count, classifier, text))
 }
@@ -1546,15 +4130,7 @@ fn japanese_count_transform(value: &Value, context: Option<&Value>) -> Result<St
     let text = value.to_string();
     let count = context_to_count(context);
 
-    let counter =
-        find_classifier(value, JAPANESE_COUNTERS).ok_or_else(|| EvalError::MissingTag {
-            transform: "count".to_string(),
-            expected: JAPANESE_COUNTERS
-                .iter()
-                .map(|(t, _)| t.to_string())
-                .collect(),
-            phrase: text.clone(),
-        })?;
+    let counter = require_classifier(value, "ja", JAPANESE_COUNTERS)?;
 
     Ok(format!("{}{}{}", count, counter, text))
 }
@@ -1567,11 +4143,7 @@ fn korean_count_transform(value: &Value, context: Option<&Value>) -> Result<Stri
     let text = value.to_string();
     let count = context_to_count(context);
 
-    let counter = find_classifier(value, KOREAN_COUNTERS).ok_or_else(|| EvalError::MissingTag {
-        transform: "count".to_string(),
-        expected: KOREAN_COUNTERS.iter().map(|(t, _)| t.to_string()).collect(),
-        phrase: text.clone(),
-    })?;
+    let counter = require_classifier(value, "ko", KOREAN_COUNTERS)?;
 
     Ok(format!("{}{}{}", count, counter, text))
 }
@@ -1588,15 +4160,7 @@ fn vietnamese_count_transform(value: &Value, context: Option<&Value>) -> Result<
     let text = value.to_string();
     let count = context_to_count(context);
 
-    let classifier =
-        find_classifier(value, VIETNAMESE_CLASSIFIERS).ok_or_else(|| EvalError::MissingTag {
-            transform: "count".to_string(),
-            expected: VIETNAMESE_CLASSIFIERS
-                .iter()
-                .map(|(t, _)| t.to_string())
-                .collect(),
-            phrase: text.clone(),
-        })?;
+    let classifier = require_classifier(value, "vi", VIETNAMESE_CLASSIFIERS)?;
 
     // Vietnamese uses spaces between elements
     Ok(format!("{} {} {}", count, classifier, text))
@@ -1605,42 +4169,184 @@ fn vietnamese_count_transform(value: &Value, context: Option<&Value>) -> Result<
 /// Thai @count transform.
 ///
 /// Produces "{count}{classifier}{noun}" format (no spaces).
-/// Requires classifier tag (bai, tua, khon, an).
-fn thai_count_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+/// Requires classifier tag (bai, tua, khon, an). The count uses Thai digits
+/// under [`DigitStyle::Native`].
+fn thai_count_transform(
+    value: &Value,
+    context: Option<&Value>,
+    digits: DigitStyle,
+) -> Result<String, EvalError> {
     let text = value.to_string();
-    let count = context_to_count(context);
+    let count = digits.shape(&context_to_count(context).to_string(), "th");
 
-    let classifier =
-        find_classifier(value, THAI_CLASSIFIERS).ok_or_else(|| EvalError::MissingTag {
-            transform: "count".to_string(),
-            expected: THAI_CLASSIFIERS
-                .iter()
-                .map(|(t, _)| t.to_string())
-                .collect(),
-            phrase: text.clone(),
-        })?;
+    let classifier = require_classifier(value, "th", THAI_CLASSIFIERS)?;
 
     // Thai uses no spaces between elements
     Ok(format!("{}{}{}", count, classifier, text))
 }
 
+/// Separator inserted by `@wordbreak`, invisible but breakable.
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Common Thai words used by the bundled segmenter.
+///
+/// This list is deliberately small; install a [`WordSegmenter`] backed by a
+/// full dictionary for general text.
+const THAI_WORDS: &[&str] = &[
+    "การ์ด",
+    "จั่ว",
+    "ใบ",
+    "ตัว",
+    "คน",
+    "อัน",
+    "ผู้เล่น",
+    "เล่น",
+    "ศัตรู",
+    "โจมตี",
+    "พลัง",
+    "ชีวิต",
+    "เทิร์น",
+    "ทั้งหมด",
+    "หนึ่ง",
+    "สอง",
+    "สาม",
+    "สี่",
+    "ห้า",
+    "ของ",
+    "คุณ",
+    "และ",
+    "หรือ",
+    "ที่",
+    "ไม่",
+    "มี",
+    "ได้",
+    "จาก",
+    "ใน",
+    "เป็น",
+    "ให้",
+    "กับ",
+    "นี้",
+    "ทุก",
+    "สร้าง",
+    "ความเสียหาย",
+    "ทำลาย",
+    "มือ",
+    "สำรับ",
+    "ทิ้ง",
+    "เพิ่ม",
+    "ลด",
+    "แต้ม",
+    "ชนะ",
+    "แพ้",
+    "เกม",
+    "รอบ",
+    "สวัสดี",
+    "ภาษา",
+    "ไทย",
+];
+
+/// Whether `c` is in the Thai Unicode block.
+fn is_thai(c: char) -> bool {
+    ('\u{0E00}'..='\u{0E7F}').contains(&c)
+}
+
+/// Word starts in a run of Thai text by greedy longest match against
+/// [`THAI_WORDS`]. Characters not covered by any word are kept together with
+/// the unknown text around them.
+fn segment_thai(run: &str) -> Vec<usize> {
+    let mut starts = Vec::new();
+    let mut pos = 0;
+    let mut in_unknown = false;
+    while pos < run.len() {
+        let rest = &run[pos..];
+        let longest = THAI_WORDS
+            .iter()
+            .filter(|word| rest.starts_with(**word))
+            .map(|word| word.len())
+            .max();
+        match longest {
+            Some(len) => {
+                starts.push(pos);
+                pos += len;
+                in_unknown = false;
+            }
+            None => {
+                if !in_unknown {
+                    starts.push(pos);
+                    in_unknown = true;
+                }
+                pos += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+    starts
+}
+
+/// Byte offsets in `text` where a Thai word starts, excluding the start of
+/// each run of Thai characters.
+fn thai_word_starts(text: &str, segmenter: Option<&WordSegmenter>) -> Vec<usize> {
+    let mut starts = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((run_start, c)) = chars.next() {
+        if !is_thai(c) {
+            continue;
+        }
+        let mut run_end = run_start + c.len_utf8();
+        while let Some(&(i, c)) = chars.peek()
+            && is_thai(c)
+        {
+            run_end = i + c.len_utf8();
+            chars.next();
+        }
+        let run = &text[run_start..run_end];
+        let offsets = match segmenter {
+            Some(segmenter) => segmenter(run),
+            None => segment_thai(run),
+        };
+        starts.extend(
+            offsets
+                .into_iter()
+                .filter(|&offset| offset > 0 && offset < run.len() && run.is_char_boundary(offset))
+                .map(|offset| run_start + offset),
+        );
+    }
+    starts.sort_unstable();
+    starts.dedup();
+    starts
+}
+
+/// Thai @wordbreak transform.
+///
+/// Inserts a zero-width space between adjacent Thai words so renderers can
+/// wrap lines there. Words come from `segmenter`, or the bundled
+/// [`THAI_WORDS`] list when none is installed.
+fn thai_word_break_transform(text: &str, segmenter: Option<&WordSegmenter>) -> String {
+    let starts = thai_word_starts(text, segmenter);
+    let mut result = String::with_capacity(text.len() + starts.len() * 3);
+    let mut last = 0;
+    for start in starts {
+        result.push_str(&text[last..start]);
+        result.push(ZERO_WIDTH_SPACE);
+        last = start;
+    }
+    result.push_str(&text[last..]);
+    result
+}
+
 /// Bengali @count transform.
 ///
 /// Produces "{count}{classifier} {noun}" format (classifier attached to number, space before noun).
-/// Requires classifier tag (ta, ti, khana, jon).
-fn bengali_count_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+/// Requires classifier tag (ta, ti, khana, jon). The count uses Bengali digits
+/// under [`DigitStyle::Native`].
+fn bengali_count_transform(
+    value: &Value,
+    context: Option<&Value>,
+    digits: DigitStyle,
+) -> Result<String, EvalError> {
     let text = value.to_string();
-    let count = context_to_count(context);
+    let count = digits.shape(&context_to_count(context).to_string(), "bn");
 
-    let classifier =
-        find_classifier(value, BENGALI_CLASSIFIERS).ok_or_else(|| EvalError::MissingTag {
-            transform: "count".to_string(),
-            expected: BENGALI_CLASSIFIERS
-                .iter()
-                .map(|(t, _)| t.to_string())
-                .collect(),
-            phrase: text.clone(),
-        })?;
+    let classifier = require_classifier(value, "bn", BENGALI_CLASSIFIERS)?;
 
     // Bengali: classifier immediately after number, then space, then noun
     Ok(format!("{}{} {}", count, classifier, text))
@@ -1655,6 +4361,21 @@ fn indonesian_plural_transform(value: &Value) -> Result<String, EvalError> {
     Ok(format!("{}-{}", text, text))
 }
 
+/// Malay @count transform.
+///
+/// Produces "{count} {classifier} {noun}" format, or "{count} {noun}" when the
+/// noun has no classifier tag (ekor, buah, orang, biji, helai, batang). The noun
+/// stays singular: a number already marks plurality, so counted nouns are not
+/// reduplicated.
+fn malay_count_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let text = value.to_string();
+    let count = context_to_count(context);
+    Ok(match find_classifier(value, MALAY_CLASSIFIERS) {
+        Some(classifier) => format!("{count} {classifier} {text}"),
+        None => format!("{count} {text}"),
+    })
+}
+
 // =============================================================================
 // Korean Particle Transform (Phase 9)
 // =============================================================================
@@ -1783,6 +4504,40 @@ fn japanese_particle_transform(context: Option<&Value>) -> Result<String, EvalEr
     Ok(particle.to_string())
 }
 
+// =============================================================================
+// Japanese Ruby Transform
+// =============================================================================
+
+/// Japanese @ruby transform.
+///
+/// Reads the `base` and `reading` variants of a phrase such as
+/// `kanji = { base: "漢字", reading: "かんじ" };`. With the `:ruby` context it
+/// emits ruby markup (`<ruby>漢字<rt>かんじ</rt></ruby>`); with `:plain` or no
+/// context it emits the base text alone. Phrases without a reading fall back
+/// to their base text in both modes.
+fn japanese_ruby_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let ruby = match context {
+        None => false,
+        Some(Value::String(s)) if s == "plain" => false,
+        Some(Value::String(s)) if s == "ruby" => true,
+        Some(other) => {
+            return Err(EvalError::InvalidTransformInput {
+                transform: "ruby".to_string(),
+                expected: "a ':ruby' or ':plain' context".to_string(),
+                value: other.to_string(),
+            });
+        }
+    };
+    let Value::Phrase(phrase) = value else {
+        return Ok(value.to_string());
+    };
+    let base = phrase.variants.get("base").unwrap_or(&phrase.text);
+    match phrase.variants.get("reading") {
+        Some(reading) if ruby => Ok(format!("<ruby>{base}<rt>{reading}</rt></ruby>")),
+        _ => Ok(base.clone()),
+    }
+}
+
 // =============================================================================
 // Turkish Inflect Transform (Phase 9)
 // =============================================================================
@@ -2170,22 +4925,23 @@ fn finnish_suffix_form(suffix: FinnishSuffix, harmony: FinnishHarmony) -> &'stat
 /// The illative case in Finnish works by lengthening the final vowel: talo -> taloon.
 /// If the word ends in a consonant, falls back to appending -Vn where V is the
 /// last vowel found in the word.
+///
+/// Vowels are matched by grapheme, so a decomposed "a\u{0308}" is duplicated
+/// whole rather than losing its combining mark.
 fn apply_finnish_illative(word: &mut String) {
-    let last_vowel = word.chars().rev().find(|c| "aeiouyäöAEIOUYÄÖ".contains(*c));
+    let last_vowel = word
+        .graphemes(true)
+        .rev()
+        .find(|g| {
+            g.chars()
+                .next()
+                .is_some_and(|c| "aeiouyäöAEIOUYÄÖ".contains(c))
+        })
+        .map(str::to_string);
     if let Some(v) = last_vowel {
-        if word.ends_with(v) {
-            // Word ends in a vowel: duplicate it, then append 'n'
-            word.push(v);
-            word.push('n');
-        } else {
-            // Word ends in consonant: append the last vowel + 'n'
-            word.push(v);
-            word.push('n');
-        }
-    } else {
-        // No vowel found, just append 'n'
-        word.push('n');
+        word.push_str(&v);
     }
+    word.push('n');
 }
 
 /// Finnish @inflect transform.
@@ -2268,11 +5024,11 @@ enum HungarianHarmony {
 /// Hungarian suffix types for @inflect transform.
 #[derive(Clone, Copy)]
 enum HungarianSuffix {
-    /// Plural: -k (with linking vowel -ok/-ek/-ök)
+    /// Plural: -k (with linking vowel -ok/-ak/-ek/-ök)
     Plural,
     /// Nominative: no suffix (unmarked case)
     Nominative,
-    /// Accusative: -t (with linking vowel -ot/-et/-öt)
+    /// Accusative: -t (with linking vowel -ot/-at/-et/-öt)
     Accusative,
     /// Dative: -nak/-nek
     Dative,
@@ -2360,17 +5116,19 @@ fn parse_hungarian_suffix_chain(context: Option<&Value>) -> Vec<HungarianSuffix>
 /// Get the Hungarian suffix text for the given suffix and harmony class.
 ///
 /// Hungarian uses 2-way harmony (back/front) for most suffixes, with 3-way
-/// harmony (back/front-unrounded/front-rounded) for allative, superessive,
-/// plural linking vowel, accusative linking vowel, and possessives.
-fn hungarian_suffix_form(suffix: HungarianSuffix, harmony: HungarianHarmony) -> &'static str {
+/// harmony (back/front-unrounded/front-rounded) for allative and possessives.
+/// Suffixes that take a linking vowel after consonants (see
+/// [`hungarian_linking_vowel`]) are returned without it. `vowel_final` selects
+/// the post-vowel form of the possessives that have one (`-ja`, `-juk`, `-nk`).
+fn hungarian_suffix_form(
+    suffix: HungarianSuffix,
+    harmony: HungarianHarmony,
+    vowel_final: bool,
+) -> &'static str {
     match (suffix, harmony) {
-        (HungarianSuffix::Plural, HungarianHarmony::Back) => "ok",
-        (HungarianSuffix::Plural, HungarianHarmony::Front) => "ek",
-        (HungarianSuffix::Plural, HungarianHarmony::Round) => "\u{00f6}k",
+        (HungarianSuffix::Plural, _) => "k",
         (HungarianSuffix::Nominative, _) => "",
-        (HungarianSuffix::Accusative, HungarianHarmony::Back) => "ot",
-        (HungarianSuffix::Accusative, HungarianHarmony::Front) => "et",
-        (HungarianSuffix::Accusative, HungarianHarmony::Round) => "\u{00f6}t",
+        (HungarianSuffix::Accusative, _) => "t",
         (HungarianSuffix::Dative, HungarianHarmony::Back) => "nak",
         (HungarianSuffix::Dative, HungarianHarmony::Front | HungarianHarmony::Round) => "nek",
         (HungarianSuffix::Inessive, HungarianHarmony::Back) => "ban",
@@ -2381,9 +5139,7 @@ fn hungarian_suffix_form(suffix: HungarianSuffix, harmony: HungarianHarmony) ->
         (HungarianSuffix::Elative, HungarianHarmony::Front | HungarianHarmony::Round) => {
             "b\u{0151}l"
         }
-        (HungarianSuffix::Superessive, HungarianHarmony::Back) => "on",
-        (HungarianSuffix::Superessive, HungarianHarmony::Front) => "en",
-        (HungarianSuffix::Superessive, HungarianHarmony::Round) => "\u{00f6}n",
+        (HungarianSuffix::Superessive, _) => "n",
         (HungarianSuffix::Sublative, HungarianHarmony::Back) => "ra",
         (HungarianSuffix::Sublative, HungarianHarmony::Front | HungarianHarmony::Round) => "re",
         (HungarianSuffix::Delative, HungarianHarmony::Back) => "r\u{00f3}l",
@@ -2410,14 +5166,17 @@ fn hungarian_suffix_form(suffix: HungarianSuffix, harmony: HungarianHarmony) ->
         (HungarianSuffix::CausalFinal, _) => "\u{00e9}rt",
         (HungarianSuffix::Terminative, _) => "ig",
         (HungarianSuffix::EssiveFormal, _) => "k\u{00e9}nt",
-        (HungarianSuffix::Poss1Sg, HungarianHarmony::Back) => "om",
-        (HungarianSuffix::Poss1Sg, HungarianHarmony::Front) => "em",
-        (HungarianSuffix::Poss1Sg, HungarianHarmony::Round) => "\u{00f6}m",
-        (HungarianSuffix::Poss2Sg, HungarianHarmony::Back) => "od",
-        (HungarianSuffix::Poss2Sg, HungarianHarmony::Front) => "ed",
-        (HungarianSuffix::Poss2Sg, HungarianHarmony::Round) => "\u{00f6}d",
+        (HungarianSuffix::Poss1Sg, _) => "m",
+        (HungarianSuffix::Poss2Sg, _) => "d",
+        (HungarianSuffix::Poss3Sg, HungarianHarmony::Back) if vowel_final => "ja",
         (HungarianSuffix::Poss3Sg, HungarianHarmony::Back) => "a",
+        (HungarianSuffix::Poss3Sg, HungarianHarmony::Front | HungarianHarmony::Round)
+            if vowel_final =>
+        {
+            "je"
+        }
         (HungarianSuffix::Poss3Sg, HungarianHarmony::Front | HungarianHarmony::Round) => "e",
+        (HungarianSuffix::Poss1Pl, _) if vowel_final => "nk",
         (HungarianSuffix::Poss1Pl, HungarianHarmony::Back) => "unk",
         (HungarianSuffix::Poss1Pl, HungarianHarmony::Front | HungarianHarmony::Round) => {
             "\u{00fc}nk"
@@ -2425,13 +5184,108 @@ fn hungarian_suffix_form(suffix: HungarianSuffix, harmony: HungarianHarmony) ->
         (HungarianSuffix::Poss2Pl, HungarianHarmony::Back) => "tok",
         (HungarianSuffix::Poss2Pl, HungarianHarmony::Front) => "tek",
         (HungarianSuffix::Poss2Pl, HungarianHarmony::Round) => "t\u{00f6}k",
+        (HungarianSuffix::Poss3Pl, HungarianHarmony::Back) if vowel_final => "juk",
         (HungarianSuffix::Poss3Pl, HungarianHarmony::Back) => "uk",
+        (HungarianSuffix::Poss3Pl, HungarianHarmony::Front | HungarianHarmony::Round)
+            if vowel_final =>
+        {
+            "j\u{00fc}k"
+        }
         (HungarianSuffix::Poss3Pl, HungarianHarmony::Front | HungarianHarmony::Round) => {
             "\u{00fc}k"
         }
     }
 }
 
+/// Linking vowel placed between a consonant-final stem and `suffix`, if any.
+///
+/// Plural, accusative, superessive, and the `-m`/`-d`/`-tok` possessives link
+/// with o/e/ö by harmony. Lowering stems (`lowering`) use a/e instead for all
+/// of these except the superessive: "ház" → "házat", "házak", but "házon".
+/// The accusative takes a bare `-t` after a non-lowering stem ending in j, l,
+/// ly, n, ny, r, s, sz, z, or zs ("asztal" → "asztalt").
+fn hungarian_linking_vowel(
+    stem: &str,
+    suffix: HungarianSuffix,
+    harmony: HungarianHarmony,
+    lowering: bool,
+) -> Option<char> {
+    match suffix {
+        HungarianSuffix::Superessive => {}
+        HungarianSuffix::Accusative if !lowering && hungarian_takes_bare_t(stem) => return None,
+        HungarianSuffix::Plural
+        | HungarianSuffix::Accusative
+        | HungarianSuffix::Poss1Sg
+        | HungarianSuffix::Poss2Sg
+        | HungarianSuffix::Poss2Pl
+            if lowering =>
+        {
+            return Some(match harmony {
+                HungarianHarmony::Back => 'a',
+                HungarianHarmony::Front | HungarianHarmony::Round => 'e',
+            });
+        }
+        HungarianSuffix::Plural
+        | HungarianSuffix::Accusative
+        | HungarianSuffix::Poss1Sg
+        | HungarianSuffix::Poss2Sg
+        | HungarianSuffix::Poss2Pl => {}
+        _ => return None,
+    }
+    Some(match harmony {
+        HungarianHarmony::Back => 'o',
+        HungarianHarmony::Front => 'e',
+        HungarianHarmony::Round => '\u{00f6}',
+    })
+}
+
+/// Whether `c` is a Hungarian vowel letter.
+fn is_hungarian_vowel(c: char) -> bool {
+    matches!(
+        c.to_lowercase().next().unwrap_or(c),
+        'a' | '\u{00e1}'
+            | 'e'
+            | '\u{00e9}'
+            | 'i'
+            | '\u{00ed}'
+            | 'o'
+            | '\u{00f3}'
+            | '\u{00f6}'
+            | '\u{0151}'
+            | 'u'
+            | '\u{00fa}'
+            | '\u{00fc}'
+            | '\u{0171}'
+    )
+}
+
+/// Whether the accusative attaches to `stem` as a bare `-t`.
+///
+/// The affricates cs and dzs end in the same letters as s and zs but still
+/// take a linking vowel ("gyümölcs" → "gyümölcsöt").
+fn hungarian_takes_bare_t(stem: &str) -> bool {
+    ["j", "l", "ly", "n", "ny", "r", "s", "sz", "z", "zs"]
+        .iter()
+        .any(|ending| stem.ends_with(ending))
+        && !stem.ends_with("cs")
+        && !stem.ends_with("dzs")
+}
+
+/// Whether `stem` is a lowering stem, which links with a/e instead of o/ö.
+///
+/// Lowering is lexical, so `:low` marks it explicitly. Without the tag,
+/// monosyllabic back stems whose vowel is a or á are treated as lowering
+/// ("ház", "hal", "fal"), which covers the common textbook cases.
+fn hungarian_is_lowering(value: &Value, stem: &str, harmony: HungarianHarmony) -> bool {
+    if value.has_tag("low") {
+        return true;
+    }
+    let mut vowels = stem.chars().filter(|&c| is_hungarian_vowel(c));
+    matches!(harmony, HungarianHarmony::Back)
+        && matches!(vowels.next(), Some('a' | '\u{00e1}'))
+        && vowels.next().is_none()
+}
+
 /// Hungarian @inflect transform.
 ///
 /// Applies suffix chain with vowel harmony based on `:back`/`:front`/`:round` tags.
@@ -2441,15 +5295,21 @@ fn hungarian_suffix_form(suffix: HungarianSuffix, harmony: HungarianHarmony) ->
 /// - `:front` — front unrounded vowels (e, i): -hez, -ek, -em, etc.
 /// - `:round` — front rounded vowels (ö, ü): -höz, -ök, -öm, etc.
 ///
+/// The stem shape picks the linking vowel (see [`hungarian_linking_vowel`]).
+/// A final a or e lengthens to á or é before every suffix except `-ként`, and
+/// vowel-final stems take suffixes without a linking vowel: "alma" → "almát",
+/// "almák", "almája". Plural and possessive forms are themselves lowering, so
+/// "pl.acc" on "kert" gives "kerteket".
+///
 /// Context specifies suffix chain as dot-separated names:
-/// - "pl" -> Plural (-ok/-ek/-ök)
+/// - "pl" -> Plural (-ok/-ak/-ek/-ök, -k after vowels)
 /// - "nom" -> Nominative (no suffix)
-/// - "acc" -> Accusative (-ot/-et/-öt)
+/// - "acc" -> Accusative (-ot/-at/-et/-öt, -t after vowels and some consonants)
 /// - "dat" -> Dative (-nak/-nek)
 /// - "ine" -> Inessive (-ban/-ben)
 /// - "ill" -> Illative (-ba/-be)
 /// - "ela" -> Elative (-ból/-ből)
-/// - "sup" -> Superessive (-on/-en/-ön)
+/// - "sup" -> Superessive (-on/-en/-ön, -n after vowels)
 /// - "sub" -> Sublative (-ra/-re)
 /// - "del" -> Delative (-ról/-ről)
 /// - "ade" -> Adessive (-nál/-nél)
@@ -2460,12 +5320,12 @@ fn hungarian_suffix_form(suffix: HungarianSuffix, harmony: HungarianHarmony) ->
 /// - "cau" -> Causal-final (-ért)
 /// - "ter" -> Terminative (-ig)
 /// - "ess" -> Essive-formal (-ként)
-/// - "poss1sg" -> 1st person sg possessive (-om/-em/-öm)
-/// - "poss2sg" -> 2nd person sg possessive (-od/-ed/-öd)
-/// - "poss3sg" -> 3rd person sg possessive (-a/-e)
-/// - "poss1pl" -> 1st person pl possessive (-unk/-ünk)
-/// - "poss2pl" -> 2nd person pl possessive (-tok/-tek/-tök)
-/// - "poss3pl" -> 3rd person pl possessive (-uk/-ük)
+/// - "poss1sg" -> 1st person sg possessive (-om/-am/-em/-öm, -m after vowels)
+/// - "poss2sg" -> 2nd person sg possessive (-od/-ad/-ed/-öd, -d after vowels)
+/// - "poss3sg" -> 3rd person sg possessive (-a/-e, -ja/-je after vowels)
+/// - "poss1pl" -> 1st person pl possessive (-unk/-ünk, -nk after vowels)
+/// - "poss2pl" -> 2nd person pl possessive (-otok/-atok/-etek/-ötök)
+/// - "poss3pl" -> 3rd person pl possessive (-uk/-ük, -juk/-jük after vowels)
 ///
 /// Example: "pl.dat" on :back "ház" -> "házaknak"
 fn hungarian_inflect_transform(
@@ -2490,15 +5350,313 @@ fn hungarian_inflect_transform(
 
     let suffixes = parse_hungarian_suffix_chain(context);
 
+    let mut lowering = hungarian_is_lowering(value, &text, harmony);
     let mut result = text;
     for suffix in suffixes {
-        let suffix_text = hungarian_suffix_form(suffix, harmony);
-        result.push_str(suffix_text);
+        if matches!(suffix, HungarianSuffix::Nominative) {
+            continue;
+        }
+        let vowel_final = result.chars().next_back().is_some_and(is_hungarian_vowel);
+        if vowel_final {
+            if !matches!(suffix, HungarianSuffix::EssiveFormal) {
+                if result.ends_with('a') {
+                    result.pop();
+                    result.push('\u{00e1}');
+                } else if result.ends_with('e') {
+                    result.pop();
+                    result.push('\u{00e9}');
+                }
+            }
+        } else if let Some(vowel) = hungarian_linking_vowel(&result, suffix, harmony, lowering) {
+            result.push(vowel);
+        }
+        result.push_str(hungarian_suffix_form(suffix, harmony, vowel_final));
+        lowering |= matches!(
+            suffix,
+            HungarianSuffix::Plural
+                | HungarianSuffix::Poss1Sg
+                | HungarianSuffix::Poss2Sg
+                | HungarianSuffix::Poss1Pl
+                | HungarianSuffix::Poss2Pl
+                | HungarianSuffix::Poss3Pl
+        );
     }
 
     Ok(result)
 }
 
+// =============================================================================
+// Basque Inflect Transform
+// =============================================================================
+
+/// Basque grammatical case for definite noun phrases.
+#[derive(Clone, Copy)]
+enum BasqueCase {
+    /// Absolutive (-a / -ak)
+    Absolutive,
+    /// Ergative (-ak / -ek)
+    Ergative,
+    /// Dative (-ari / -ei)
+    Dative,
+    /// Possessive genitive (-aren / -en)
+    Genitive,
+}
+
+/// Definite suffix for a Basque case and number.
+fn basque_definite_suffix(case: BasqueCase, plural: bool) -> &'static str {
+    match (case, plural) {
+        (BasqueCase::Absolutive, false) => "a",
+        (BasqueCase::Ergative, false) => "ak",
+        (BasqueCase::Dative, false) => "ari",
+        (BasqueCase::Genitive, false) => "aren",
+        (BasqueCase::Absolutive, true) => "ak",
+        (BasqueCase::Ergative, true) => "ek",
+        (BasqueCase::Dative, true) => "ei",
+        (BasqueCase::Genitive, true) => "en",
+    }
+}
+
+/// Parse Basque case and number from a dot-separated context.
+///
+/// Case names are `abs` (default), `erg`, `dat`, and `gen`. Number is singular
+/// unless a part is `pl`/`other` or a count other than 1 (from `($n)`).
+fn parse_basque_context(context: Option<&Value>) -> (BasqueCase, bool) {
+    let mut case = BasqueCase::Absolutive;
+    let mut plural = false;
+    let parts: Vec<String> = match context {
+        Some(Value::String(s)) => s.split('.').map(ToString::to_string).collect(),
+        Some(Value::Number(n)) => vec![n.to_string()],
+        _ => Vec::new(),
+    };
+    for part in &parts {
+        match part.as_str() {
+            "abs" => case = BasqueCase::Absolutive,
+            "erg" => case = BasqueCase::Ergative,
+            "dat" => case = BasqueCase::Dative,
+            "gen" => case = BasqueCase::Genitive,
+            "pl" | "other" => plural = true,
+            "sg" | "one" => plural = false,
+            other => {
+                if let Ok(n) = other.parse::<i64>() {
+                    plural = n != 1;
+                }
+            }
+        }
+    }
+    (case, plural)
+}
+
+/// Basque @inflect transform.
+///
+/// Basque has no articles; definiteness, number, and case are suffixed to the
+/// last word of the noun phrase. Consonant- and vowel-final stems take the
+/// same definite suffixes ("lagun" -> "laguna", "etxe" -> "etxea"), while a
+/// stem-final -a merges with the suffix vowel ("neska" -> "neska", "neskek").
+///
+/// Context selects case and number, e.g. `erg`, `abs.pl`, or `erg($n)`.
+/// Stems ending in a strong -r that doubles ("txakur" -> "txakurra") should
+/// spell the doubled form in the phrase text or use variants.
+fn basque_inflect_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let text = value.to_string();
+    let (case, plural) = parse_basque_context(context);
+    let suffix = basque_definite_suffix(case, plural);
+    let stem = text.strip_suffix('a').unwrap_or(&text);
+    Ok(format!("{stem}{suffix}"))
+}
+
+// =============================================================================
+// Swahili Agreement Transform
+// =============================================================================
+
+/// Swahili noun classes supported by `@agree`.
+#[derive(Clone, Copy)]
+enum SwahiliClass {
+    /// Class 1: people, singular (m-/mw-)
+    Cl1,
+    /// Class 2: people, plural (wa-)
+    Cl2,
+    /// Class 7: things, singular (ki-/ch-)
+    Cl7,
+    /// Class 8: things, plural (vi-/vy-)
+    Cl8,
+}
+
+impl SwahiliClass {
+    /// The plural class paired with a singular class.
+    fn plural(self) -> Self {
+        match self {
+            SwahiliClass::Cl1 | SwahiliClass::Cl2 => SwahiliClass::Cl2,
+            SwahiliClass::Cl7 | SwahiliClass::Cl8 => SwahiliClass::Cl8,
+        }
+    }
+}
+
+/// Read the noun class from `:cl1`, `:cl2`, `:cl7`, or `:cl8` tags.
+fn parse_swahili_class(value: &Value) -> Result<SwahiliClass, EvalError> {
+    const CLASSES: [(&str, SwahiliClass); 4] = [
+        ("cl1", SwahiliClass::Cl1),
+        ("cl2", SwahiliClass::Cl2),
+        ("cl7", SwahiliClass::Cl7),
+        ("cl8", SwahiliClass::Cl8),
+    ];
+    CLASSES
+        .iter()
+        .find(|(tag, _)| value.has_tag(tag))
+        .map(|(_, class)| *class)
+        .ok_or_else(|| EvalError::MissingTag {
+            transform: "agree".to_string(),
+            expected: CLASSES.iter().map(|(tag, _)| (*tag).to_string()).collect(),
+            phrase: value.to_string(),
+        })
+}
+
+/// Prefix an adjective stem with the agreement marker for a noun class.
+///
+/// Before a vowel-initial stem the prefix glides or merges: m- becomes mw-,
+/// ki- becomes ch-, vi- becomes vy-, and wa- fuses with e/i into we-
+/// ("-ema" -> "mwema", "chema", "vyema", "wema").
+fn swahili_adjective(class: SwahiliClass, stem: &str) -> String {
+    let first = stem.chars().next();
+    let vowel_initial = first.is_some_and(|c| "aeiou".contains(c));
+    if !vowel_initial {
+        let prefix = match class {
+            SwahiliClass::Cl1 => "m",
+            SwahiliClass::Cl2 => "wa",
+            SwahiliClass::Cl7 => "ki",
+            SwahiliClass::Cl8 => "vi",
+        };
+        return format!("{prefix}{stem}");
+    }
+    match class {
+        SwahiliClass::Cl1 => format!("mw{stem}"),
+        SwahiliClass::Cl2 if first == Some('i') => format!("we{}", &stem[1..]),
+        SwahiliClass::Cl2 => format!("w{stem}"),
+        SwahiliClass::Cl7 => format!("ch{stem}"),
+        SwahiliClass::Cl8 => format!("vy{stem}"),
+    }
+}
+
+/// Swahili @agree transform.
+///
+/// Adjectives agree with the noun class of the noun they modify. The value is
+/// the noun, which carries a class tag (`:cl1`, `:cl2`, `:cl7`, `:cl8`); the
+/// context names the adjective stem and optionally the number, e.g.
+/// `{@agree:zuri mtoto}` -> "mzuri" or `{@agree:zuri($n) mtoto}` -> "wazuri"
+/// for n != 1. Plural selects the paired class (1 -> 2, 7 -> 8).
+fn swahili_agree_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let class = parse_swahili_class(value)?;
+    let Some(Value::String(ctx)) = context else {
+        return Err(EvalError::InvalidTransformInput {
+            transform: "agree".to_string(),
+            expected: "an adjective stem context such as ':zuri'".to_string(),
+            value: value.to_string(),
+        });
+    };
+    let mut parts = ctx.split('.');
+    let stem = parts.next().unwrap_or_default();
+    let plural = parts.any(|part| match part {
+        "pl" | "other" => true,
+        _ => part.parse::<i64>().is_ok_and(|n| n != 1),
+    });
+    let class = if plural { class.plural() } else { class };
+    Ok(swahili_adjective(class, stem))
+}
+
+// =============================================================================
+// Irish Mutation Transform
+// =============================================================================
+
+/// Consonants that take séimhiú (lenition) by inserting h after them.
+const IRISH_LENITABLE: &str = "bcdfgmpst";
+
+/// Eclipsis (urú) prefixes for each eclipsable initial consonant.
+const IRISH_ECLIPSIS: [(char, &str); 7] = [
+    ('b', "m"),
+    ('c', "g"),
+    ('d', "n"),
+    ('f', "bh"),
+    ('g', "n"),
+    ('p', "b"),
+    ('t', "d"),
+];
+
+fn is_irish_vowel(c: char) -> bool {
+    "aeiouáéíóúAEIOUÁÉÍÓÚ".contains(c)
+}
+
+/// Apply séimhiú: "cáca" -> "cháca", "Seán" -> "Sheán".
+///
+/// Only b, c, d, f, g, m, p, s, t lenite, and s only before a vowel or l, n, r
+/// ("stór" is unchanged). Words already followed by h are left alone.
+fn irish_lenite(text: &str) -> String {
+    let mut chars = text.chars();
+    let Some(first) = chars.next() else {
+        return String::new();
+    };
+    let second = chars.next();
+    let lenitable = IRISH_LENITABLE.contains(first.to_ascii_lowercase());
+    let already_lenited = second.is_some_and(|c| c.eq_ignore_ascii_case(&'h'));
+    let blocked_s = first.eq_ignore_ascii_case(&'s')
+        && !second.is_some_and(|c| is_irish_vowel(c) || "lnrLNR".contains(c));
+    if !lenitable || already_lenited || blocked_s {
+        return text.to_string();
+    }
+    let all_caps = text.chars().any(char::is_alphabetic)
+        && !text.chars().any(char::is_lowercase)
+        && second.is_some();
+    let h = if all_caps { 'H' } else { 'h' };
+    let rest = &text[first.len_utf8()..];
+    format!("{first}{h}{rest}")
+}
+
+/// Apply urú: "bád" -> "mbád", "úll" -> "n-úll", "Éire" -> "nÉire".
+///
+/// The prefix stays lowercase before a capital ("mBád"). Vowels take "n-",
+/// written without the hyphen before a capital. Words already carrying their
+/// eclipsis prefix are left alone, as are consonants that do not eclipse.
+fn irish_eclipse(text: &str) -> String {
+    let Some(first) = text.chars().next() else {
+        return String::new();
+    };
+    if is_irish_vowel(first) {
+        let separator = if first.is_uppercase() { "" } else { "-" };
+        return format!("n{separator}{text}");
+    }
+    let lowered = text.to_lowercase();
+    let already_eclipsed = IRISH_ECLIPSIS.iter().any(|(c, prefix)| {
+        lowered
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with(*c))
+    }) || lowered.starts_with("n-");
+    if already_eclipsed {
+        return text.to_string();
+    }
+    let lower = first.to_ascii_lowercase();
+    let Some((_, prefix)) = IRISH_ECLIPSIS.iter().find(|(c, _)| *c == lower) else {
+        return text.to_string();
+    };
+    format!("{prefix}{text}")
+}
+
+/// Irish @mutate transform.
+///
+/// Initial mutations are triggered by the preceding word, so the context names
+/// the mutation: `{@mutate:len bád}` -> "bhád" (séimhiú, after "mo", "do",
+/// "a" his) and `{@mutate:ecl bád}` -> "mbád" (urú, after "ár", "bhur", "a"
+/// their, and "i").
+fn irish_mutate_transform(value: &Value, context: Option<&Value>) -> Result<String, EvalError> {
+    let text = value.to_string();
+    match context {
+        Some(Value::String(ctx)) if ctx == "len" => Ok(irish_lenite(&text)),
+        Some(Value::String(ctx)) if ctx == "ecl" => Ok(irish_eclipse(&text)),
+        _ => Err(EvalError::InvalidTransformInput {
+            transform: "mutate".to_string(),
+            expected: "a mutation context ':len' or ':ecl'".to_string(),
+            value: text,
+        }),
+    }
+}
+
 // =============================================================================
 // Hindi Transforms
 // =============================================================================
@@ -2541,14 +5699,66 @@ fn hindi_postposition_transform(value: &Value, postposition: &str) -> Result<Str
 /// Language-specific transforms take precedence over universal transforms.
 #[derive(Default)]
 pub struct TransformRegistry {
-    // Reserved for future language-specific transform registration.
-    // Currently all transforms are resolved via TransformKind::get().
+    /// Optional formatter used by `@date` and `@time` before the built-in tables.
+    date_formatter: Option<DateFormatter>,
+    /// Plural rules consulted before the built-in CLDR rules, by language code.
+    plural_rules: Vec<(String, PluralRule)>,
+    /// Optional Thai segmenter used instead of the bundled word list.
+    word_segmenter: Option<WordSegmenter>,
+    /// Compound splitters used by `@break`, by language code.
+    compound_splitters: Vec<(String, CompoundSplitter)>,
+    /// Lookup tables used by `@map`, by table name.
+    lookup_tables: Vec<(String, LookupTable)>,
+    /// Apostrophe written by elided articles.
+    apostrophe_style: ApostropheStyle,
+    /// Digit set written by `@num` and `@count`.
+    digit_style: DigitStyle,
+    /// How German "ß" is uppercased.
+    sharp_s_style: SharpSStyle,
+    /// Format that parameter values are escaped for.
+    output_target: OutputTarget,
+    /// Whether `@autocap` capitalizes at sentence starts.
+    auto_sentence_cap: bool,
+    /// Register variant that terms and phrases default to.
+    register: Register,
+    /// Whether literal text is pseudo-localized.
+    pseudo_localize: bool,
 }
 
+/// Process-wide built-in transform registry returned by [`TransformRegistry::builtin`].
+static BUILTIN_TRANSFORMS: TransformRegistry = TransformRegistry::new();
+
 impl TransformRegistry {
-    /// Create a new registry with universal transforms registered.
-    pub fn new() -> Self {
-        Self {}
+    /// Create a registry with the built-in transforms and default settings.
+    ///
+    /// No date formatter is set, so `@date` and `@time` use the built-in
+    /// pattern tables. There are no plural rule overrides, Thai segmenter,
+    /// compound splitters, or lookup tables. Output uses ASCII apostrophes,
+    /// Latin digits, "SS" for an uppercased "ß", plain text escaping, and the
+    /// formal register, with `@autocap` and pseudo-localization off.
+    pub const fn new() -> Self {
+        Self {
+            date_formatter: None,
+            plural_rules: Vec::new(),
+            word_segmenter: None,
+            compound_splitters: Vec::new(),
+            lookup_tables: Vec::new(),
+            apostrophe_style: ApostropheStyle::Ascii,
+            digit_style: DigitStyle::Latin,
+            sharp_s_style: SharpSStyle::Double,
+            output_target: OutputTarget::Plain,
+            auto_sentence_cap: false,
+            register: Register::Formal,
+            pseudo_localize: false,
+        }
+    }
+
+    /// Returns the shared registry of built-in transforms.
+    ///
+    /// The returned reference is the same for every call, so evaluation paths
+    /// that only need built-in transforms never construct a registry.
+    pub fn builtin() -> &'static TransformRegistry {
+        &BUILTIN_TRANSFORMS
     }
 
     /// Get a transform by name for a language.
@@ -2576,6 +5786,220 @@ impl TransformRegistry {
     pub fn has_transform(&self, name: &str, lang: &str) -> bool {
         self.get(name, lang).is_some()
     }
+
+    /// Install a formatter consulted by `@date` and `@time` before the
+    /// built-in pattern tables.
+    pub fn set_date_formatter(
+        &mut self,
+        formatter: impl Fn(i64, DateTimeComponent, DateStyle, &str) -> Option<String>
+        + Send
+        + Sync
+        + 'static,
+    ) {
+        self.date_formatter = Some(Box::new(formatter));
+    }
+
+    /// Install a plural rule for `lang`, consulted before the built-in CLDR
+    /// rules. Registering a rule for the same language again replaces it.
+    ///
+    /// A rule for a primary language such as `pt` also applies to tags like
+    /// `pt-BR` that have no rule of their own.
+    pub fn set_plural_rule(
+        &mut self,
+        lang: impl Into<String>,
+        rule: impl Fn(i64) -> &'static str + Send + Sync + 'static,
+    ) {
+        let lang = lang.into();
+        self.plural_rules.retain(|(existing, _)| *existing != lang);
+        self.plural_rules.push((lang, Box::new(rule)));
+    }
+
+    /// Install a Thai word segmenter used by `@wordbreak` and
+    /// `@truncate_words` in place of the bundled word list.
+    pub fn set_word_segmenter(
+        &mut self,
+        segmenter: impl Fn(&str) -> Vec<usize> + Send + Sync + 'static,
+    ) {
+        self.word_segmenter = Some(Box::new(segmenter));
+    }
+
+    /// Install the compound splitter used by `@break` for `lang`. Registering a
+    /// splitter for the same language again replaces it.
+    ///
+    /// As with plural rules, a splitter for a primary language such as `de`
+    /// also applies to tags like `de-AT` that have no splitter of their own.
+    pub fn set_compound_splitter(
+        &mut self,
+        lang: impl Into<String>,
+        splitter: impl Fn(&str) -> Vec<usize> + Send + Sync + 'static,
+    ) {
+        let lang = lang.into();
+        self.compound_splitters
+            .retain(|(existing, _)| *existing != lang);
+        self.compound_splitters.push((lang, Box::new(splitter)));
+    }
+
+    /// The compound splitter installed for `lang` or its primary language.
+    fn compound_splitter(&self, lang: &str) -> Option<&CompoundSplitter> {
+        let splitter = |code: &str| {
+            self.compound_splitters
+                .iter()
+                .find(|(existing, _)| existing == code)
+                .map(|(_, splitter)| splitter)
+        };
+        splitter(lang).or_else(|| primary_language_subtag(lang).and_then(splitter))
+    }
+
+    /// The plural category of `n` in `lang`, from a rule installed with
+    /// [`Self::set_plural_rule`] or else the built-in [`plural_category`].
+    pub fn plural_category(&self, lang: &str, n: i64) -> &'static str {
+        let rule = |code: &str| {
+            self.plural_rules
+                .iter()
+                .find(|(existing, _)| existing == code)
+                .map(|(_, rule)| rule)
+        };
+        match rule(lang).or_else(|| primary_language_subtag(lang).and_then(rule)) {
+            Some(rule) => rule(n),
+            None => plural_category(lang, n),
+        }
+    }
+
+    /// The apostrophe written by elided articles.
+    pub fn apostrophe_style(&self) -> ApostropheStyle {
+        self.apostrophe_style
+    }
+
+    /// Set the apostrophe written by elided articles (French `@le`, `@de`,
+    /// `@au` and Italian `@il`, `@un`, `@di`, `@a`).
+    pub fn set_apostrophe_style(&mut self, style: ApostropheStyle) {
+        self.apostrophe_style = style;
+    }
+
+    /// The digit set written by `@num` and `@count`.
+    pub fn digit_style(&self) -> DigitStyle {
+        self.digit_style
+    }
+
+    /// Set the digit set written by `@num` and `@count`. A `:native` or
+    /// `:latn` context on `@num` still overrides it.
+    pub fn set_digit_style(&mut self, style: DigitStyle) {
+        self.digit_style = style;
+    }
+
+    /// How `@upper` and `@cap` write German "ß".
+    pub fn sharp_s_style(&self) -> SharpSStyle {
+        self.sharp_s_style
+    }
+
+    /// Set how `@upper` and `@cap` write German "ß". Other languages are
+    /// unaffected.
+    pub fn set_sharp_s_style(&mut self, style: SharpSStyle) {
+        self.sharp_s_style = style;
+    }
+
+    /// The format that parameter values are escaped for.
+    pub fn output_target(&self) -> OutputTarget {
+        self.output_target
+    }
+
+    /// Set the format that parameter values are escaped for.
+    pub fn set_output_target(&mut self, target: OutputTarget) {
+        self.output_target = target;
+    }
+
+    /// Whether `@autocap` capitalizes values that start a sentence.
+    pub fn auto_sentence_cap(&self) -> bool {
+        self.auto_sentence_cap
+    }
+
+    /// Set whether `@autocap` capitalizes values that start a sentence. When
+    /// disabled (the default), `@autocap` leaves text unchanged.
+    pub fn set_auto_sentence_cap(&mut self, enabled: bool) {
+        self.auto_sentence_cap = enabled;
+    }
+
+    /// Register variant (`formal` or `informal`) that definitions default to.
+    pub fn register(&self) -> Register {
+        self.register
+    }
+
+    /// Set the register variant that definitions default to.
+    pub fn set_register(&mut self, register: Register) {
+        self.register = register;
+    }
+
+    /// Whether literal text is pseudo-localized.
+    pub fn pseudo_localize(&self) -> bool {
+        self.pseudo_localize
+    }
+
+    /// Set whether literal text in templates is written with accented
+    /// look-alike letters.
+    pub fn set_pseudo_localize(&mut self, enabled: bool) {
+        self.pseudo_localize = enabled;
+    }
+
+    /// Register a lookup table for `@map:name`, replacing any table with the
+    /// same name.
+    pub fn set_lookup_table(&mut self, name: impl Into<String>, table: LookupTable) {
+        let name = name.into();
+        self.lookup_tables.retain(|(existing, _)| *existing != name);
+        self.lookup_tables.push((name, table));
+    }
+
+    /// The lookup table registered under `name`, if any.
+    pub fn lookup_table(&self, name: &str) -> Option<&LookupTable> {
+        self.lookup_tables
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, table)| table)
+    }
+
+    /// Execute a transform, applying any registry-level customization.
+    ///
+    /// Identical to [`TransformKind::execute`] except that `@date` and `@time`
+    /// consult the formatter installed with [`Self::set_date_formatter`],
+    /// elided articles use [`Self::apostrophe_style`], numbers use
+    /// [`Self::digit_style`], German "ß" uses [`Self::sharp_s_style`], Thai
+    /// word boundaries use the segmenter from [`Self::set_word_segmenter`], and
+    /// `@break` uses the splitter from [`Self::set_compound_splitter`].
+    pub fn execute(
+        &self,
+        kind: TransformKind,
+        value: &Value,
+        context: Option<&Value>,
+        lang: &str,
+    ) -> Result<String, EvalError> {
+        let formatter = self.date_formatter.as_ref();
+        let segmenter = self.word_segmenter.as_ref();
+        match kind {
+            TransformKind::Date => {
+                date_time_transform(value, context, lang, DateTimeComponent::Date, formatter)
+            }
+            TransformKind::Time => {
+                date_time_transform(value, context, lang, DateTimeComponent::Time, formatter)
+            }
+            TransformKind::TruncateWords => {
+                truncate_words_transform(&value.to_string(), context, lang, segmenter)
+            }
+            TransformKind::ThaiWordBreak => {
+                Ok(thai_word_break_transform(&value.to_string(), segmenter))
+            }
+            TransformKind::CompoundBreak => Ok(compound_break_transform(
+                &value.to_string(),
+                self.compound_splitter(lang),
+            )),
+            _ => kind.execute_with_style(
+                value,
+                context,
+                lang,
+                self.apostrophe_style,
+                self.digit_style,
+                self.sharp_s_style,
+            ),
+        }
+    }
 }
 
 fn primary_language_subtag(lang: &str) -> Option<&str> {
@@ -2592,11 +6016,44 @@ fn transform_kind_from_id(id: TransformId) -> TransformKind {
         TransformId::Cap => TransformKind::Cap,
         TransformId::Upper => TransformKind::Upper,
         TransformId::Lower => TransformKind::Lower,
+        TransformId::Date => TransformKind::Date,
+        TransformId::Time => TransformKind::Time,
+        TransformId::Spell => TransformKind::Spell,
+        TransformId::Currency => TransformKind::Currency,
+        TransformId::Num => TransformKind::Num,
+        TransformId::Sentence => TransformKind::Sentence,
+        TransformId::List => TransformKind::List,
+        TransformId::Title => TransformKind::Title,
+        TransformId::Lang => TransformKind::Lang,
+        TransformId::TruncateWords => TransformKind::TruncateWords,
+        TransformId::Duration => TransformKind::Duration,
+        TransformId::CaseFold => TransformKind::CaseFold,
+        TransformId::Quote => TransformKind::Quote,
+        TransformId::Map => TransformKind::Map,
+        TransformId::AutoCap => TransformKind::AutoCap,
+        TransformId::StripMarkup => TransformKind::StripMarkup,
+        TransformId::EscapeMarkup => TransformKind::EscapeMarkup,
+        TransformId::Formal => TransformKind::Formal,
+        TransformId::Informal => TransformKind::Informal,
+        TransformId::Super => TransformKind::Super,
+        TransformId::Sub => TransformKind::Sub,
+        TransformId::NoOp => TransformKind::NoOp,
+        TransformId::CompoundBreak => TransformKind::CompoundBreak,
+        TransformId::Scientific => TransformKind::Scientific,
+        TransformId::CountNoun => TransformKind::CountNoun,
+        TransformId::Month => TransformKind::Month,
+        TransformId::Weekday => TransformKind::Weekday,
         TransformId::EnglishA => TransformKind::EnglishA,
         TransformId::EnglishThe => TransformKind::EnglishThe,
         TransformId::EnglishPlural => TransformKind::EnglishPlural,
+        TransformId::EnglishPossessive => TransformKind::EnglishPossessive,
+        TransformId::EnglishGenitive => TransformKind::EnglishGenitive,
+        TransformId::EnglishCount => TransformKind::EnglishCount,
+        TransformId::EnglishPronoun => TransformKind::EnglishPronoun,
         TransformId::GermanDer => TransformKind::GermanDer,
         TransformId::GermanEin => TransformKind::GermanEin,
+        TransformId::GermanKein => TransformKind::GermanKein,
+        TransformId::GermanGenitive => TransformKind::GermanGenitive,
         TransformId::DutchDe => TransformKind::DutchDe,
         TransformId::DutchEen => TransformKind::DutchEen,
         TransformId::SpanishEl => TransformKind::SpanishEl,
@@ -2605,11 +6062,13 @@ fn transform_kind_from_id(id: TransformId) -> TransformKind {
         TransformId::PortugueseUm => TransformKind::PortugueseUm,
         TransformId::PortugueseDe => TransformKind::PortugueseDe,
         TransformId::PortugueseEm => TransformKind::PortugueseEm,
+        TransformId::PortuguesePor => TransformKind::PortuguesePor,
         TransformId::FrenchLe => TransformKind::FrenchLe,
         TransformId::FrenchUn => TransformKind::FrenchUn,
         TransformId::FrenchDe => TransformKind::FrenchDe,
         TransformId::FrenchAu => TransformKind::FrenchAu,
         TransformId::FrenchLiaison => TransformKind::FrenchLiaison,
+        TransformId::FrenchPunct => TransformKind::FrenchPunct,
         TransformId::ItalianIl => TransformKind::ItalianIl,
         TransformId::ItalianUn => TransformKind::ItalianUn,
         TransformId::ItalianDi => TransformKind::ItalianDi,
@@ -2618,19 +6077,27 @@ fn transform_kind_from_id(id: TransformId) -> TransformKind {
         TransformId::GreekEnas => TransformKind::GreekEnas,
         TransformId::RomanianDef => TransformKind::RomanianDef,
         TransformId::ArabicAl => TransformKind::ArabicAl,
+        TransformId::ArabicConnect => TransformKind::ArabicConnect,
         TransformId::PersianEzafe => TransformKind::PersianEzafe,
+        TransformId::RussianNeg => TransformKind::RussianNeg,
         TransformId::ChineseCount => TransformKind::ChineseCount,
         TransformId::JapaneseCount => TransformKind::JapaneseCount,
         TransformId::KoreanCount => TransformKind::KoreanCount,
         TransformId::VietnameseCount => TransformKind::VietnameseCount,
         TransformId::ThaiCount => TransformKind::ThaiCount,
+        TransformId::ThaiWordBreak => TransformKind::ThaiWordBreak,
         TransformId::BengaliCount => TransformKind::BengaliCount,
         TransformId::IndonesianPlural => TransformKind::IndonesianPlural,
+        TransformId::MalayCount => TransformKind::MalayCount,
         TransformId::KoreanParticle => TransformKind::KoreanParticle,
         TransformId::TurkishInflect => TransformKind::TurkishInflect,
         TransformId::FinnishInflect => TransformKind::FinnishInflect,
         TransformId::HungarianInflect => TransformKind::HungarianInflect,
+        TransformId::BasqueInflect => TransformKind::BasqueInflect,
+        TransformId::SwahiliAgree => TransformKind::SwahiliAgree,
+        TransformId::IrishMutate => TransformKind::IrishMutate,
         TransformId::JapaneseParticle => TransformKind::JapaneseParticle,
+        TransformId::JapaneseRuby => TransformKind::JapaneseRuby,
         TransformId::HindiKa => TransformKind::HindiKa,
         TransformId::HindiKo => TransformKind::HindiKo,
         TransformId::HindiSe => TransformKind::HindiSe,
@@ -0,0 +1,152 @@
+//! Registry-free validation of a single template, for live-editing tools.
+
+use std::ops::Range;
+
+use rlf_semantics::{accepted_transform_names, languages_with_transform};
+
+use crate::interpreter::error::{TemplateError, compute_suggestions};
+use crate::interpreter::transforms::TransformRegistry;
+use crate::parser::{ParseError, parse_template};
+
+/// Checks a template's syntax and transform names for `lang`.
+///
+/// Parses `src` as a template body (the text between the quotes of a
+/// definition) and checks every `@transform` it names against the language's
+/// transform table. Phrase and term references are not resolved, since there
+/// is no registry to look them up in. Returns every problem found, each with
+/// the byte range it covers; a syntax error stops validation, so it is always
+/// reported alone.
+///
+/// ```
+/// use rlf::interpreter::TemplateError;
+///
+/// assert!(rlf::validate_template("{@der:acc karte}", "de").is_ok());
+///
+/// let errors = rlf::validate_template("Draw {@uper card}.", "en").unwrap_err();
+/// let TemplateError::UnknownTransform { suggestions, span, .. } = &errors[0] else {
+///     panic!("expected an unknown transform");
+/// };
+/// assert_eq!(suggestions, &["upper"]);
+/// assert_eq!(*span, 6..11);
+/// ```
+pub fn validate_template(src: &str, lang: &str) -> Result<(), Vec<TemplateError>> {
+    if let Err(error) = parse_template(src) {
+        return Err(vec![syntax_error(src, &error)]);
+    }
+
+    let registry = TransformRegistry::new();
+    let errors: Vec<TemplateError> = transform_names(src)
+        .into_iter()
+        .filter(|(name, _)| !registry.has_transform(name, lang))
+        .map(|(name, span)| unknown_transform(name, span, lang))
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn unknown_transform(name: &str, span: Range<usize>, lang: &str) -> TemplateError {
+    let available_in = languages_with_transform(name);
+    if available_in.is_empty() {
+        let accepted: Vec<String> = accepted_transform_names(lang)
+            .iter()
+            .map(|n| (*n).to_string())
+            .collect();
+        TemplateError::UnknownTransform {
+            name: name.to_string(),
+            span,
+            suggestions: compute_suggestions(name, &accepted),
+        }
+    } else {
+        TemplateError::WrongLanguageTransform {
+            name: name.to_string(),
+            language: lang.to_string(),
+            span,
+            available_in: available_in.into_iter().map(str::to_string).collect(),
+        }
+    }
+}
+
+/// Convert a parse error's line and column back to a byte span in `src`.
+fn syntax_error(src: &str, error: &ParseError) -> TemplateError {
+    let (line, column, message) = match error {
+        ParseError::Syntax {
+            line,
+            column,
+            message,
+        } => (*line, *column, message.clone()),
+        ParseError::UnexpectedEof { line, column } => {
+            (*line, *column, "unexpected end of input".to_string())
+        }
+        ParseError::InvalidUtf8 => (1, 1, error.to_string()),
+    };
+    let line_start: usize = src
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let start = (line_start + column.saturating_sub(1)).min(src.len());
+    let end = src[start..]
+        .chars()
+        .next()
+        .map_or(start, |c| start + c.len_utf8());
+    TemplateError::Syntax {
+        span: start..end,
+        message,
+    }
+}
+
+/// Every transform name written in `src`, with the span of its `@name`.
+///
+/// Transforms only appear at the start of an interpolation, so this scans for
+/// `{` (skipping `{{` escapes) followed by `@name` tokens and their contexts.
+/// Automatic capitalization (`{Card}`) adds no `@cap` here, which is fine since
+/// `@cap` is valid everywhere.
+fn transform_names(src: &str) -> Vec<(&str, Range<usize>)> {
+    let bytes = src.as_bytes();
+    let mut names = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'{' {
+            i += 1;
+            continue;
+        }
+        if bytes.get(i + 1) == Some(&b'{') {
+            i += 2;
+            continue;
+        }
+        i += 1;
+        loop {
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            if bytes.get(i) != Some(&b'@') || bytes.get(i + 1) == Some(&b'@') {
+                break;
+            }
+            let start = i;
+            i += 1;
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            names.push((&src[start + 1..i], start..i));
+            // Skip a `:static` context and a `($param)` context.
+            if bytes.get(i) == Some(&b':') {
+                i += 1;
+                while i < bytes.len()
+                    && (bytes[i].is_ascii_alphanumeric() || matches!(bytes[i], b'_' | b'.' | b'-'))
+                {
+                    i += 1;
+                }
+            }
+            if bytes.get(i) == Some(&b'(') {
+                while i < bytes.len() && bytes[i] != b')' {
+                    i += 1;
+                }
+                i += 1;
+            }
+        }
+    }
+    names
+}
@@ -0,0 +1,65 @@
+//! Translation completeness checks for build scripts and tests.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::interpreter::error::{LoadError, VerifyError};
+use crate::parser::{PhraseDefinition, parse_file};
+
+/// Checks that every phrase in `source` is defined for each `required` language.
+///
+/// Translations are read from `{dir}/{language}.rlf`. `source` is the RLF text
+/// of the source language. Returns [`VerifyError::MissingPhrases`] listing the
+/// missing names per language, or [`VerifyError::Load`] if a file cannot be
+/// read or parsed. Only phrase names are compared; extra translated phrases
+/// are ignored.
+///
+/// Call this from `build.rs` to fail the build on untranslated strings:
+///
+/// ```no_run
+/// let source = std::fs::read_to_string("src/strings.rlf")?;
+/// println!("cargo:rerun-if-changed=assets/localization");
+/// if let Err(error) = rlf::verify_translations("assets/localization", &["de", "fr"], &source) {
+///     panic!("{error}");
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn verify_translations(
+    dir: impl AsRef<Path>,
+    required: &[&str],
+    source: &str,
+) -> Result<(), VerifyError> {
+    let source_defs = parse_file(source)
+        .map_err(|e| LoadError::from_parse_error(e, PathBuf::from("<source>")))?;
+
+    let mut missing = BTreeMap::new();
+    for language in required {
+        let path = dir.as_ref().join(format!("{language}.rlf"));
+        let translated = read_definitions(&path)?;
+        let names: HashSet<&str> = translated.iter().map(|d| d.name.as_str()).collect();
+        let mut absent: Vec<String> = source_defs
+            .iter()
+            .filter(|def| !names.contains(def.name.as_str()))
+            .map(|def| def.name.clone())
+            .collect();
+        if !absent.is_empty() {
+            absent.sort();
+            missing.insert((*language).to_string(), absent);
+        }
+    }
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(VerifyError::MissingPhrases { missing })
+    }
+}
+
+fn read_definitions(path: &Path) -> Result<Vec<PhraseDefinition>, LoadError> {
+    let content = fs::read_to_string(path).map_err(|e| LoadError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    parse_file(&content).map_err(|e| LoadError::from_parse_error(e, path.to_path_buf()))
+}
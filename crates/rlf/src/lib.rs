@@ -8,14 +8,21 @@ mod global;
 #[cfg(feature = "global-locale")]
 pub use global::{language, set_language, with_locale, with_locale_mut};
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 pub use interpreter::{
-    EvalContext, EvalError, EvalWarning, LoadError, LoadWarning, Locale, PhraseRegistry,
-    TransformRegistry, compute_suggestions, lint_definitions, run_lints,
+    ApostropheStyle, CompoundSplitter, DigitStyle, EvalContext, EvalError, EvalWarning,
+    LanguageSupport, LoadError, LoadWarning, Locale, LookupEntry, LookupTable, OutputTarget,
+    PhraseInfo, PhraseRegistry, Register, SharpSStyle, TemplateError, TransformRegistry,
+    VerifyError, WordSegmenter, compute_suggestions, language_support, lint_definitions,
+    lint_literal_numbers, lint_max_length, lint_mixed_language, lint_plural_categories, run_lints,
+    supported_languages, validate_template, verify_translations,
 };
-pub use types::{Phrase, PhraseId, Tag, Value, VariantKey};
+pub use types::{NumValue, Phrase, PhraseId, RichSpan, Tag, TextValue, Value, VariantKey};
 
-// Re-export the rlf! macro
-pub use rlf_macros::rlf;
+// Re-export the rlf! and rlf_format! macros
+pub use rlf_macros::{rlf, rlf_format};
 
 /// Creates a `HashMap<String, Value>` from key-value pairs.
 ///
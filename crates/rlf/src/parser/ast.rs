@@ -2,6 +2,10 @@
 //!
 //! These types are public to enable external tooling (linters, formatters, etc.).
 
+use std::ops::RangeInclusive;
+
+pub use rlf_semantics::ParamType;
+
 use crate::types::Tag;
 
 /// A parsed template string containing segments.
@@ -21,6 +25,15 @@ pub enum Segment {
         reference: Reference,
         selectors: Vec<Selector>,
     },
+    /// A conditional: {?$param: "body"}
+    ///
+    /// Renders `body` only when the parameter is bound to a non-empty value.
+    Conditional {
+        /// Parameter name without the `$` prefix.
+        parameter: String,
+        /// Template rendered when the parameter is present.
+        body: Template,
+    },
 }
 
 /// A transform applied to a reference (e.g., @cap, @a, @der:acc, @count($n)).
@@ -60,6 +73,19 @@ pub enum Reference {
     NumberLiteral(i64),
     /// Literal string argument in a phrase call: {trigger("Attack")}
     StringLiteral(String),
+    /// First present, non-empty alternative: {$nick || $name || "Player"}
+    ///
+    /// Alternatives are parameters, term names, phrase calls, and literals,
+    /// separated by `||` or `|`. Unbound parameters, and terms and phrases
+    /// the translation doesn't define, count as absent, so
+    /// `{glossary_term | "term"}` gives an inline default.
+    Coalesce(Vec<Reference>),
+    /// Named argument in a phrase call: {draw(n: 3)}
+    ///
+    /// Only appears in `PhraseCall` arguments. Named arguments bind to the
+    /// parameter of that name; the remaining arguments fill the other
+    /// parameters in order.
+    Named { name: String, value: Box<Reference> },
 }
 
 /// A selector for variant selection.
@@ -70,6 +96,9 @@ pub enum Selector {
     /// Parameterized selector with $ prefix: :$n, :$entity
     /// The String stores the name without the `$` prefix.
     Parameter(String),
+    /// Ordinal selector on a parameter: :#$n
+    /// Picks the variant by CLDR ordinal category ("1st", "2nd", "3rd").
+    Ordinal(String),
     /// Explicit default selector: :*
     Default,
 }
@@ -97,6 +126,8 @@ pub struct PhraseDefinition {
     pub name: String,
     /// Parameter names (empty if no parameters).
     pub parameters: Vec<String>,
+    /// Declared parameter types, parallel to `parameters` (`None` if untyped).
+    pub(crate) parameter_types: Vec<Option<ParamType>>,
     /// Metadata tags (e.g., :fem, :masc, :a).
     pub tags: Vec<Tag>,
     /// :from(param) inheritance (None if not present).
@@ -105,6 +136,8 @@ pub struct PhraseDefinition {
     pub match_params: Vec<String>,
     /// Phrase body (simple template, variants, or match).
     pub body: PhraseBody,
+    /// Attributes written before the definition (`@note("...")`, `@maxlen(N)`).
+    pub attributes: Vec<PhraseAttribute>,
     /// Whether the definition had an explicit empty parameter list `()`.
     ///
     /// This is used for validation: `name() = ...` is an error because empty
@@ -112,6 +145,24 @@ pub struct PhraseDefinition {
     pub has_empty_parens: bool,
 }
 
+impl PhraseDefinition {
+    /// Declared parameter types, parallel to `parameters` (`None` if untyped).
+    pub fn parameter_types(&self) -> &[Option<ParamType>] {
+        &self.parameter_types
+    }
+}
+
+/// Metadata written before a definition for translators and tooling.
+///
+/// Attributes do not change how a phrase evaluates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhraseAttribute {
+    /// `@note("shown on button")`: context for translators.
+    Note(String),
+    /// `@maxlen(20)`: the rendered text should fit in this many characters.
+    MaxLength(usize),
+}
+
 /// The body of a phrase definition.
 #[derive(Debug, Clone, PartialEq)]
 pub enum PhraseBody {
@@ -161,6 +212,7 @@ pub struct MatchBranch {
 /// A single key in a `:match` branch.
 ///
 /// Supports named keys (`one`, `other`, `masc`), numeric keys (`0`, `1`, `2`),
+/// inclusive numeric ranges (`1..3`, `4..`, `..2`) in single-parameter blocks,
 /// and multi-parameter dot-notation keys (`1.masc`, `*other.fem`).
 /// Each dot-separated component has an independent default marker.
 #[derive(Debug, Clone, PartialEq)]
@@ -173,3 +225,32 @@ pub struct MatchKey {
     /// For multi-param match with dot notation, one element per dimension.
     pub default_dimensions: Vec<bool>,
 }
+
+impl MatchKey {
+    /// The numbers this key matches, if it is a range key like `1..3`.
+    ///
+    /// Both bounds are inclusive; an omitted bound is open (`4..` is 4 and
+    /// above, `..2` is 2 and below). Returns `None` for other keys.
+    pub fn numeric_range(&self) -> Option<RangeInclusive<i64>> {
+        parse_numeric_range(&self.value)
+    }
+}
+
+/// Parse `lo..hi`, `lo..`, or `..hi` into an inclusive range.
+pub(crate) fn parse_numeric_range(key: &str) -> Option<RangeInclusive<i64>> {
+    let (lo, hi) = key.split_once("..")?;
+    if lo.is_empty() && hi.is_empty() {
+        return None;
+    }
+    let lo = if lo.is_empty() {
+        i64::MIN
+    } else {
+        lo.parse().ok()?
+    };
+    let hi = if hi.is_empty() {
+        i64::MAX
+    } else {
+        hi.parse().ok()?
+    };
+    Some(lo..=hi)
+}
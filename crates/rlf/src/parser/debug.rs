@@ -0,0 +1,270 @@
+//! Stable, indented text rendering of the AST for parser snapshot tests.
+
+use std::fmt::Write;
+use std::mem;
+
+use crate::parser::ast::{
+    DefinitionKind, MatchBranch, MatchKey, PhraseAttribute, PhraseBody, PhraseDefinition,
+    Reference, Segment, Selector, Template, Transform, TransformContext, VariantEntryBody,
+};
+
+/// Renders parsed definitions as compact, indented text.
+///
+/// Unlike `#[derive(Debug)]`, the output is close to RLF source notation and
+/// stays stable across field additions, which makes it suitable for snapshot
+/// tests. Each definition starts with `term` or `phrase` followed by its
+/// signature, tags and `:from`; the body is indented beneath it. Templates are
+/// shown as a sequence of quoted literals and `{...}` interpolations.
+///
+/// # Example
+///
+/// ```
+/// use rlf::parser::{AstDebug, parse_file};
+///
+/// let definitions = parse_file(r#"
+///     card = :fem { one: "card", *other: "cards" };
+///     draw($n) = "Draw {@cap @a card:$n}.";
+/// "#).unwrap();
+///
+/// assert_eq!(definitions.to_debug_string(), "\
+/// term card :fem
+///   one: \"card\"
+///   *other: \"cards\"
+/// phrase draw($n)
+///   \"Draw \" {@cap @a card:$n} \".\"
+/// ");
+/// ```
+pub trait AstDebug {
+    /// Render this node and its children, one line per definition or entry.
+    fn to_debug_string(&self) -> String;
+}
+
+impl AstDebug for [PhraseDefinition] {
+    fn to_debug_string(&self) -> String {
+        let mut out = String::new();
+        for definition in self {
+            write_definition(&mut out, definition);
+        }
+        out
+    }
+}
+
+impl AstDebug for PhraseDefinition {
+    fn to_debug_string(&self) -> String {
+        let mut out = String::new();
+        write_definition(&mut out, self);
+        out
+    }
+}
+
+impl AstDebug for Template {
+    fn to_debug_string(&self) -> String {
+        let mut out = String::new();
+        write_template(&mut out, self);
+        out
+    }
+}
+
+fn write_definition(out: &mut String, definition: &PhraseDefinition) {
+    for attribute in &definition.attributes {
+        match attribute {
+            PhraseAttribute::Note(note) => writeln!(out, "@note({note:?})"),
+            PhraseAttribute::MaxLength(len) => writeln!(out, "@maxlen({len})"),
+        }
+        .unwrap();
+    }
+
+    out.push_str(match definition.kind {
+        DefinitionKind::Term => "term ",
+        DefinitionKind::Phrase => "phrase ",
+    });
+    out.push_str(&definition.name);
+    if !definition.parameters.is_empty() || definition.has_empty_parens {
+        let params: Vec<String> = definition
+            .parameters
+            .iter()
+            .enumerate()
+            .map(
+                |(i, name)| match definition.parameter_types.get(i).copied().flatten() {
+                    Some(ty) => format!("${name}: {}", ty.name()),
+                    None => format!("${name}"),
+                },
+            )
+            .collect();
+        write!(out, "({})", params.join(", ")).unwrap();
+    }
+    for tag in &definition.tags {
+        write!(out, " :{tag}").unwrap();
+    }
+    if let Some(param) = &definition.from_param {
+        write!(out, " :from(${param})").unwrap();
+    }
+    out.push('\n');
+
+    match &definition.body {
+        PhraseBody::Simple(template) => {
+            out.push_str("  ");
+            write_template(out, template);
+            out.push('\n');
+        }
+        PhraseBody::Variants(entries) => {
+            for entry in entries {
+                out.push_str("  ");
+                if entry.is_default {
+                    out.push('*');
+                }
+                out.push_str(&entry.keys.join(", "));
+                out.push(':');
+                match &entry.body {
+                    VariantEntryBody::Template(template) => {
+                        out.push(' ');
+                        write_template(out, template);
+                        out.push('\n');
+                    }
+                    VariantEntryBody::Match {
+                        match_params,
+                        branches,
+                    } => {
+                        out.push(' ');
+                        write_match_header(out, match_params);
+                        write_branches(out, branches, "    ");
+                    }
+                }
+            }
+        }
+        PhraseBody::Match(branches) => {
+            out.push_str("  ");
+            write_match_header(out, &definition.match_params);
+            write_branches(out, branches, "    ");
+        }
+    }
+}
+
+fn write_match_header(out: &mut String, params: &[String]) {
+    let params: Vec<String> = params.iter().map(|p| format!("${p}")).collect();
+    writeln!(out, ":match({})", params.join(", ")).unwrap();
+}
+
+fn write_branches(out: &mut String, branches: &[MatchBranch], indent: &str) {
+    for branch in branches {
+        out.push_str(indent);
+        let keys: Vec<String> = branch.keys.iter().map(match_key).collect();
+        write!(out, "{}: ", keys.join(", ")).unwrap();
+        write_template(out, &branch.template);
+        out.push('\n');
+    }
+}
+
+fn match_key(key: &MatchKey) -> String {
+    key.value
+        .split('.')
+        .enumerate()
+        .map(|(i, part)| {
+            if key.default_dimensions.get(i).copied().unwrap_or(false) {
+                format!("*{part}")
+            } else {
+                part.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn write_template(out: &mut String, template: &Template) {
+    let mut first = true;
+    let mut literal = String::new();
+    let mut separate = |out: &mut String| {
+        if !mem::replace(&mut first, false) {
+            out.push(' ');
+        }
+    };
+    for segment in &template.segments {
+        if let Segment::Literal(text) = segment {
+            literal.push_str(text);
+            continue;
+        }
+        if !literal.is_empty() {
+            separate(out);
+            write!(out, "{:?}", mem::take(&mut literal)).unwrap();
+        }
+        separate(out);
+        match segment {
+            Segment::Literal(_) => unreachable!(),
+            Segment::Interpolation {
+                transforms,
+                reference,
+                selectors,
+            } => {
+                out.push('{');
+                for transform in transforms {
+                    write_transform(out, transform);
+                    out.push(' ');
+                }
+                write_reference(out, reference);
+                for selector in selectors {
+                    match selector {
+                        Selector::Identifier(name) => write!(out, ":{name}"),
+                        Selector::Parameter(name) => write!(out, ":${name}"),
+                        Selector::Ordinal(name) => write!(out, ":#${name}"),
+                        Selector::Default => write!(out, ":*"),
+                    }
+                    .unwrap();
+                }
+                out.push('}');
+            }
+            Segment::Conditional { parameter, body } => {
+                write!(out, "{{?${parameter}: ").unwrap();
+                write_template(out, body);
+                out.push('}');
+            }
+        }
+    }
+    if !literal.is_empty() {
+        separate(out);
+        write!(out, "{literal:?}").unwrap();
+    }
+    if first {
+        out.push_str("\"\"");
+    }
+}
+
+fn write_transform(out: &mut String, transform: &Transform) {
+    write!(out, "@{}", transform.name).unwrap();
+    match &transform.context {
+        TransformContext::None => {}
+        TransformContext::Static(context) => write!(out, ":{context}").unwrap(),
+        TransformContext::Dynamic(param) => write!(out, "(${param})").unwrap(),
+        TransformContext::Both(context, param) => write!(out, ":{context}(${param})").unwrap(),
+    }
+}
+
+fn write_reference(out: &mut String, reference: &Reference) {
+    match reference {
+        Reference::Identifier(name) => out.push_str(name),
+        Reference::Parameter(name) => write!(out, "${name}").unwrap(),
+        Reference::PhraseCall { name, args } => {
+            write!(out, "{name}(").unwrap();
+            for (i, arg) in args.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_reference(out, arg);
+            }
+            out.push(')');
+        }
+        Reference::NumberLiteral(n) => write!(out, "{n}").unwrap(),
+        Reference::StringLiteral(text) => write!(out, "{text:?}").unwrap(),
+        Reference::Named { name, value } => {
+            write!(out, "{name}: ").unwrap();
+            write_reference(out, value);
+        }
+        Reference::Coalesce(alternatives) => {
+            for (i, alternative) in alternatives.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(" || ");
+                }
+                write_reference(out, alternative);
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! Parses `.rlf` files containing phrase definitions.
 
 use std::collections::HashSet;
+use std::ops::RangeInclusive;
 
 use super::ast::*;
 use super::error::ParseError;
@@ -46,145 +47,459 @@ pub fn parse_file(input: &str) -> Result<Vec<PhraseDefinition>, ParseError> {
     }
 }
 
+/// Parse an entire .rlf file, recovering from errors in individual definitions.
+///
+/// Unlike [`parse_file`], a malformed definition does not abort the parse: the
+/// error is recorded and parsing resumes at the next line that starts a
+/// definition (`name =`, `name($p) =`, or an `@attribute`). Returns every
+/// definition that parsed and validated, along with all errors in file order.
+///
+/// ```
+/// use rlf::parser::parse_file_recovering;
+///
+/// let (definitions, errors) = parse_file_recovering(r#"
+///     hello = "Hello";
+///     broken = "unterminated;
+///     bye = "Bye";
+///     also broken = "x";
+/// "#);
+/// assert_eq!(definitions.len(), 2);
+/// assert_eq!(errors.len(), 2);
+/// ```
+pub fn parse_file_recovering(input: &str) -> (Vec<PhraseDefinition>, Vec<ParseError>) {
+    let mut definitions = Vec::new();
+    let mut errors = Vec::new();
+    let mut remaining = input;
+    let _ = skip_ws_and_comments(&mut remaining);
+    while !remaining.is_empty() {
+        let start = remaining;
+        match phrase_definition(&mut remaining) {
+            Ok(def) => match validate_definition(&def) {
+                Ok(()) => definitions.push(def),
+                Err(ParseError::Syntax {
+                    line: 0, message, ..
+                }) => {
+                    let (line, column) = calculate_position(input, start);
+                    errors.push(ParseError::Syntax {
+                        line,
+                        column,
+                        message,
+                    });
+                }
+                Err(e) => errors.push(e),
+            },
+            Err(e) => {
+                let (line, column) = calculate_position(input, remaining);
+                errors.push(ParseError::Syntax {
+                    line,
+                    column,
+                    message: format!("parse error: {}", e),
+                });
+                remaining = next_definition_start(start);
+            }
+        }
+        let _ = skip_ws_and_comments(&mut remaining);
+    }
+    (definitions, errors)
+}
+
+/// Find the first line after the start of `failed` that begins a definition.
+///
+/// Returns the empty remainder when no later line does.
+fn next_definition_start(failed: &str) -> &str {
+    let mut rest = failed;
+    while let Some(newline) = rest.find('\n') {
+        rest = rest[newline + 1..].trim_start();
+        if starts_definition(rest) {
+            return rest;
+        }
+    }
+    &failed[failed.len()..]
+}
+
+/// Whether `input` begins with a definition header: an attribute or a name,
+/// optional parameter list, and `=`.
+fn starts_definition(input: &str) -> bool {
+    let mut probe = input;
+    if attribute(&mut probe).is_ok() {
+        return true;
+    }
+    let mut probe = input;
+    (
+        snake_case_identifier,
+        skip_ws_and_comments,
+        opt(parameter_list),
+        skip_ws_and_comments,
+        '=',
+    )
+        .parse_next(&mut probe)
+        .is_ok()
+}
+
 /// Validate term/phrase restrictions on parsed definitions.
 fn validate_definitions(definitions: &[PhraseDefinition]) -> Result<(), ParseError> {
-    for def in definitions {
-        // Empty parameter list: name() = ... should use a term instead
-        if def.has_empty_parens {
+    definitions.iter().try_for_each(validate_definition)
+}
+
+/// Validate term/phrase restrictions on a single definition.
+fn validate_definition(def: &PhraseDefinition) -> Result<(), ParseError> {
+    // Empty parameter list: name() = ... should use a term instead
+    if def.has_empty_parens {
+        return Err(ParseError::Syntax {
+            line: 0,
+            column: 0,
+            message: format!(
+                "empty parameter list on '{}' — use a term instead (remove the parentheses)",
+                def.name
+            ),
+        });
+    }
+
+    // :from requires parameters (must be a phrase)
+    if def.kind == DefinitionKind::Term && def.from_param.is_some() {
+        return Err(ParseError::Syntax {
+            line: 0,
+            column: 0,
+            message: format!(":from requires parameters on definition '{}'", def.name),
+        });
+    }
+
+    // :match requires parameters (must be a phrase)
+    if def.kind == DefinitionKind::Term && !def.match_params.is_empty() {
+        return Err(ParseError::Syntax {
+            line: 0,
+            column: 0,
+            message: format!(":match requires parameters on definition '{}'", def.name),
+        });
+    }
+
+    // :match parameters must be declared in the phrase signature
+    for mp in &def.match_params {
+        if !def.parameters.contains(mp) {
             return Err(ParseError::Syntax {
                 line: 0,
                 column: 0,
                 message: format!(
-                    "empty parameter list on '{}' — use a term instead (remove the parentheses)",
-                    def.name
+                    ":match parameter '{}' is not declared in phrase '{}' — add it to the parameter list",
+                    mp, def.name
                 ),
             });
         }
+    }
 
-        // :from requires parameters (must be a phrase)
-        if def.kind == DefinitionKind::Term && def.from_param.is_some() {
-            return Err(ParseError::Syntax {
-                line: 0,
-                column: 0,
-                message: format!(":from requires parameters on definition '{}'", def.name),
-            });
+    // {?$param: ...} conditionals must name a declared parameter
+    validate_parameter_uses(def)?;
+
+    // Validate * default markers and numeric keys in variant blocks
+    if let PhraseBody::Variants(entries) = &def.body {
+        validate_unique_keys(
+            &def.name,
+            "variant",
+            entries.iter().flat_map(|entry| &entry.keys),
+        )?;
+
+        validate_variant_ranges(def, entries)?;
+
+        let mut default_count = 0;
+        for entry in entries {
+            if entry.is_default {
+                default_count += 1;
+
+                // * cannot appear on multi-dimensional keys (keys containing a dot)
+                if entry.keys.iter().any(|k| k.contains('.')) {
+                    return Err(ParseError::Syntax {
+                        line: 0,
+                        column: 0,
+                        message: format!(
+                            "'*' cannot be used on multi-dimensional key '{}' in definition '{}'",
+                            entry.keys.first().unwrap_or(&String::new()),
+                            def.name
+                        ),
+                    });
+                }
+            }
+
+            // Numeric keys in term variant blocks are only allowed as
+            // buckets in a block with range keys
+            if def.kind == DefinitionKind::Term {
+                for key in entry
+                    .keys
+                    .iter()
+                    .filter(|key| !is_numeric_bucket(key, entries))
+                {
+                    for component in key.split('.') {
+                        if component.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+                            return Err(ParseError::Syntax {
+                                line: 0,
+                                column: 0,
+                                message: format!(
+                                    "term variant keys must be named identifiers — use ':match' or range keys like '1..3' for numeric branching (found '{}' in '{}')",
+                                    key, def.name
+                                ),
+                            });
+                        }
+                    }
+                }
+            }
         }
 
-        // :match requires parameters (must be a phrase)
-        if def.kind == DefinitionKind::Term && !def.match_params.is_empty() {
+        // At most one * per variant block
+        if default_count > 1 {
             return Err(ParseError::Syntax {
                 line: 0,
                 column: 0,
-                message: format!(":match requires parameters on definition '{}'", def.name),
+                message: format!(
+                    "multiple '*' default markers in variant block of '{}' — at most one is allowed",
+                    def.name
+                ),
             });
         }
 
-        // :match parameters must be declared in the phrase signature
-        for mp in &def.match_params {
-            if !def.parameters.contains(mp) {
-                return Err(ParseError::Syntax {
-                    line: 0,
-                    column: 0,
-                    message: format!(
-                        ":match parameter '{}' is not declared in phrase '{}' — add it to the parameter list",
-                        mp, def.name
-                    ),
-                });
-            }
-        }
-
-        // Validate * default markers and numeric keys in variant blocks
-        if let PhraseBody::Variants(entries) = &def.body {
-            let mut default_count = 0;
-            for entry in entries {
-                if entry.is_default {
-                    default_count += 1;
-
-                    // * cannot appear on multi-dimensional keys (keys containing a dot)
-                    if entry.keys.iter().any(|k| k.contains('.')) {
+        // Validate :match blocks inside variant entries
+        for entry in entries {
+            if let VariantEntryBody::Match {
+                match_params,
+                branches,
+            } = &entry.body
+            {
+                // :match parameters must be declared in the phrase signature
+                for mp in match_params {
+                    if !def.parameters.contains(mp) {
                         return Err(ParseError::Syntax {
                             line: 0,
                             column: 0,
                             message: format!(
-                                "'*' cannot be used on multi-dimensional key '{}' in definition '{}'",
-                                entry.keys.first().unwrap_or(&String::new()),
-                                def.name
+                                ":match parameter '{}' in variant entry is not declared in phrase '{}' — add it to the parameter list",
+                                mp, def.name
                             ),
                         });
                     }
                 }
 
-                // Numeric keys in term variant blocks are not allowed
-                if def.kind == DefinitionKind::Term {
-                    for key in &entry.keys {
-                        for component in key.split('.') {
-                            if component.chars().next().is_some_and(|c| c.is_ascii_digit()) {
-                                return Err(ParseError::Syntax {
-                                    line: 0,
-                                    column: 0,
-                                    message: format!(
-                                        "term variant keys must be named identifiers — use ':match' for numeric branching (found '{}' in '{}')",
-                                        key, def.name
-                                    ),
-                                });
-                            }
-                        }
-                    }
+                // Create a temporary def for default validation
+                let temp_def = PhraseDefinition {
+                    kind: def.kind,
+                    name: def.name.clone(),
+                    parameters: def.parameters.clone(),
+                    parameter_types: def.parameter_types.clone(),
+                    tags: def.tags.clone(),
+                    from_param: def.from_param.clone(),
+                    match_params: match_params.clone(),
+                    body: PhraseBody::Match(branches.clone()),
+                    attributes: def.attributes.clone(),
+                    has_empty_parens: false,
+                };
+                validate_match_keys(&temp_def, branches)?;
+            }
+        }
+    }
+
+    // Validate * default markers in match blocks
+    if let PhraseBody::Match(branches) = &def.body {
+        validate_match_keys(def, branches)?;
+    }
+    Ok(())
+}
+
+/// Validate that every conditional and transform context parameter in a
+/// definition names one of its parameters.
+fn validate_parameter_uses(def: &PhraseDefinition) -> Result<(), ParseError> {
+    let templates: Vec<&Template> = match &def.body {
+        PhraseBody::Simple(template) => vec![template],
+        PhraseBody::Match(branches) => branches.iter().map(|b| &b.template).collect(),
+        PhraseBody::Variants(entries) => entries
+            .iter()
+            .flat_map(|entry| match &entry.body {
+                VariantEntryBody::Template(template) => vec![template],
+                VariantEntryBody::Match { branches, .. } => {
+                    branches.iter().map(|b| &b.template).collect()
                 }
+            })
+            .collect(),
+    };
+    templates
+        .into_iter()
+        .try_for_each(|template| validate_template_parameters(def, template))
+}
+
+fn validate_template_parameters(
+    def: &PhraseDefinition,
+    template: &Template,
+) -> Result<(), ParseError> {
+    for segment in &template.segments {
+        let (parameter, body) = match segment {
+            Segment::Literal(_) => continue,
+            Segment::Interpolation { transforms, .. } => {
+                validate_transform_parameters(def, transforms)?;
+                continue;
             }
+            Segment::Conditional { parameter, body } => (parameter, body),
+        };
+        if !def.parameters.contains(parameter) {
+            return Err(ParseError::Syntax {
+                line: 0,
+                column: 0,
+                message: format!(
+                    "conditional parameter '${}' is not declared in '{}' — add it to the parameter list",
+                    parameter, def.name
+                ),
+            });
+        }
+        validate_template_parameters(def, body)?;
+    }
+    Ok(())
+}
 
-            // At most one * per variant block
-            if default_count > 1 {
-                return Err(ParseError::Syntax {
-                    line: 0,
-                    column: 0,
-                    message: format!(
-                        "multiple '*' default markers in variant block of '{}' — at most one is allowed",
-                        def.name
-                    ),
-                });
+fn validate_transform_parameters(
+    def: &PhraseDefinition,
+    transforms: &[Transform],
+) -> Result<(), ParseError> {
+    for transform in transforms {
+        let parameter = match &transform.context {
+            TransformContext::Dynamic(parameter) | TransformContext::Both(_, parameter) => {
+                parameter
             }
+            TransformContext::None | TransformContext::Static(_) => continue,
+        };
+        if !def.parameters.contains(parameter) {
+            return Err(ParseError::Syntax {
+                line: 0,
+                column: 0,
+                message: format!(
+                    "transform context parameter '${}' in '@{}' is not declared in '{}' — add it to the parameter list",
+                    parameter, transform.name, def.name
+                ),
+            });
+        }
+    }
+    Ok(())
+}
 
-            // Validate :match blocks inside variant entries
-            for entry in entries {
-                if let VariantEntryBody::Match {
-                    match_params,
-                    branches,
-                } = &entry.body
-                {
-                    // :match parameters must be declared in the phrase signature
-                    for mp in match_params {
-                        if !def.parameters.contains(mp) {
-                            return Err(ParseError::Syntax {
-                                line: 0,
-                                column: 0,
-                                message: format!(
-                                    ":match parameter '{}' in variant entry is not declared in phrase '{}' — add it to the parameter list",
-                                    mp, def.name
-                                ),
-                            });
-                        }
-                    }
+/// Validate that a match block has unique keys and exactly one default per dimension.
+fn validate_match_keys(def: &PhraseDefinition, branches: &[MatchBranch]) -> Result<(), ParseError> {
+    validate_unique_keys(
+        &def.name,
+        "match",
+        branches
+            .iter()
+            .flat_map(|branch| branch.keys.iter().map(|key| &key.value)),
+    )?;
+    validate_match_ranges(def, branches)?;
+    validate_match_defaults(def, branches)
+}
 
-                    // Create a temporary def for default validation
-                    let temp_def = PhraseDefinition {
-                        kind: def.kind,
-                        name: def.name.clone(),
-                        parameters: def.parameters.clone(),
-                        tags: def.tags.clone(),
-                        from_param: def.from_param.clone(),
-                        match_params: match_params.clone(),
-                        body: PhraseBody::Match(branches.clone()),
-                        has_empty_parens: false,
-                    };
-                    validate_match_defaults(&temp_def, branches)?;
-                }
-            }
+/// Validate numeric range keys: only in single-parameter blocks, never empty,
+/// and never overlapping each other.
+fn validate_match_ranges(
+    def: &PhraseDefinition,
+    branches: &[MatchBranch],
+) -> Result<(), ParseError> {
+    let keys = || branches.iter().flat_map(|branch| &branch.keys);
+    if !keys().any(|key| key.numeric_range().is_some()) {
+        return Ok(());
+    }
+    if def.match_params.len() != 1 {
+        return Err(ParseError::Syntax {
+            line: 0,
+            column: 0,
+            message: format!(
+                "numeric range keys need a single :match parameter in '{}'",
+                def.name
+            ),
+        });
+    }
+
+    validate_range_keys(&def.name, "match", keys().map(|key| &key.value))
+}
+
+/// Validate numeric range keys in a variant block: never empty, never
+/// overlapping, and backed by an `other` catch-all for the numbers no key
+/// covers.
+fn validate_variant_ranges(
+    def: &PhraseDefinition,
+    entries: &[VariantEntry],
+) -> Result<(), ParseError> {
+    let keys = || entries.iter().flat_map(|entry| &entry.keys);
+    if !keys().any(|key| parse_numeric_range(key).is_some()) {
+        return Ok(());
+    }
+    if !keys().any(|key| key == "other") {
+        return Err(ParseError::Syntax {
+            line: 0,
+            column: 0,
+            message: format!(
+                "numeric range keys in '{}' need an 'other' variant for the numbers they do not cover",
+                def.name
+            ),
+        });
+    }
+    validate_range_keys(&def.name, "variant", keys())
+}
+
+/// Reject range keys that are empty or overlap another range key.
+///
+/// Exact numeric keys take precedence over ranges, so an exact key inside a
+/// range is not an overlap.
+fn validate_range_keys<'a>(
+    name: &str,
+    block: &str,
+    keys: impl IntoIterator<Item = &'a String>,
+) -> Result<(), ParseError> {
+    let error = |message: String| ParseError::Syntax {
+        line: 0,
+        column: 0,
+        message,
+    };
+    let mut ranges: Vec<(&str, RangeInclusive<i64>)> = Vec::new();
+    for key in keys {
+        let Some(range) = parse_numeric_range(key) else {
+            continue;
+        };
+        if range.is_empty() {
+            return Err(error(format!(
+                "empty range key '{key}' in '{name}' — the lower bound is above the upper bound"
+            )));
+        }
+        if let Some((other, _)) = ranges
+            .iter()
+            .find(|(_, seen)| seen.start() <= range.end() && range.start() <= seen.end())
+        {
+            return Err(error(format!(
+                "overlapping keys '{other}' and '{key}' in {block} block of '{name}'"
+            )));
         }
+        ranges.push((key, range));
+    }
+    Ok(())
+}
+
+/// Whether `key` is a number or numeric range in a variant block that uses
+/// range keys.
+fn is_numeric_bucket(key: &str, entries: &[VariantEntry]) -> bool {
+    (key.parse::<i64>().is_ok() || parse_numeric_range(key).is_some())
+        && entries
+            .iter()
+            .flat_map(|entry| &entry.keys)
+            .any(|key| parse_numeric_range(key).is_some())
+}
 
-        // Validate * default markers in match blocks
-        if let PhraseBody::Match(branches) = &def.body {
-            validate_match_defaults(def, branches)?;
+/// Reject a key that appears twice in one variant or match block.
+///
+/// At most one of the entries could ever be selected, so a duplicate is
+/// always an authoring mistake.
+fn validate_unique_keys<'a>(
+    name: &str,
+    block: &str,
+    keys: impl IntoIterator<Item = &'a String>,
+) -> Result<(), ParseError> {
+    let mut seen = HashSet::new();
+    for key in keys {
+        if !seen.insert(key.as_str()) {
+            return Err(ParseError::Syntax {
+                line: 0,
+                column: 0,
+                message: format!("duplicate key '{key}' in {block} block of '{name}'"),
+            });
         }
     }
     Ok(())
@@ -279,15 +594,19 @@ fn line_comment<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
     preceded("//", take_while(0.., |c| c != '\n')).parse_next(input)
 }
 
-/// Parse a phrase definition: name(params)? = tags? from? match? body ;
+/// Parse a phrase definition: attributes? name(params)? = tags? from? match? body ;
 fn phrase_definition(input: &mut &str) -> ModalResult<PhraseDefinition> {
+    let attributes: Vec<PhraseAttribute> =
+        repeat(0.., terminated(attribute, skip_ws_and_comments)).parse_next(input)?;
     let name = snake_case_identifier(input)?;
     skip_ws_and_comments(input)?;
 
     // Optional parameter list — track whether parens were present
-    let parsed_params: Option<Vec<String>> = opt(parameter_list).parse_next(input)?;
+    let parsed_params: Option<Vec<(String, Option<ParamType>)>> =
+        opt(parameter_list).parse_next(input)?;
     let has_empty_parens = matches!(&parsed_params, Some(v) if v.is_empty());
-    let parameters: Vec<String> = parsed_params.unwrap_or_default();
+    let (parameters, parameter_types): (Vec<String>, Vec<Option<ParamType>>) =
+        parsed_params.unwrap_or_default().into_iter().unzip();
     skip_ws_and_comments(input)?;
 
     // Equals sign
@@ -349,14 +668,47 @@ fn phrase_definition(input: &mut &str) -> ModalResult<PhraseDefinition> {
         kind,
         name,
         parameters,
+        parameter_types,
         tags,
         from_param,
         match_params,
         body,
+        attributes,
         has_empty_parens,
     })
 }
 
+/// Whether `line` holds only definition attributes, like
+/// `@note("Start button") @maxlen(12)`, and optionally a trailing comment.
+pub(crate) fn is_attribute_line(line: &str) -> bool {
+    let mut probe = line.trim_start();
+    let attributes: ModalResult<Vec<PhraseAttribute>> =
+        repeat(1.., terminated(attribute, skip_ws_and_comments)).parse_next(&mut probe);
+    attributes.is_ok() && probe.is_empty()
+}
+
+/// Parse a definition attribute: @note("text") or @maxlen(N)
+fn attribute(input: &mut &str) -> ModalResult<PhraseAttribute> {
+    '@'.parse_next(input)?;
+    let name = simple_identifier(input)?;
+    '('.parse_next(input)?;
+    ws(input)?;
+    let attribute = match name {
+        "note" => PhraseAttribute::Note(string_literal(input)?),
+        "maxlen" => {
+            let digits: &str = take_while(1.., |c: char| c.is_ascii_digit()).parse_next(input)?;
+            let max = digits
+                .parse()
+                .map_err(|_| ErrMode::Backtrack(ContextError::new()))?;
+            PhraseAttribute::MaxLength(max)
+        }
+        _ => return Err(ErrMode::Backtrack(ContextError::new())),
+    };
+    ws(input)?;
+    ')'.parse_next(input)?;
+    Ok(attribute)
+}
+
 /// Parse a snake_case identifier (lowercase start, alphanumeric + underscore).
 fn snake_case_identifier(input: &mut &str) -> ModalResult<String> {
     let ident: &str =
@@ -372,12 +724,12 @@ fn snake_case_identifier(input: &mut &str) -> ModalResult<String> {
 }
 
 /// Parse a parameter list: ($param1, $param2, ...)
-fn parameter_list(input: &mut &str) -> ModalResult<Vec<String>> {
+fn parameter_list(input: &mut &str) -> ModalResult<Vec<(String, Option<ParamType>)>> {
     delimited(
         '(',
         separated(
             0..,
-            preceded(skip_ws_and_comments, parameter_name),
+            preceded(skip_ws_and_comments, (parameter_name, opt(parameter_type))),
             (skip_ws_and_comments, ',', skip_ws_and_comments),
         ),
         preceded(skip_ws_and_comments, ')'),
@@ -396,6 +748,16 @@ fn parameter_name(input: &mut &str) -> ModalResult<String> {
     .parse_next(input)
 }
 
+/// Parse a parameter type annotation: `: num`, `: text`, or `: phrase`.
+fn parameter_type(input: &mut &str) -> ModalResult<ParamType> {
+    preceded(
+        (skip_ws_and_comments, ':', skip_ws_and_comments),
+        take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '_')
+            .verify_map(ParamType::from_name),
+    )
+    .parse_next(input)
+}
+
 /// Parse a tag: :name
 fn tag(input: &mut &str) -> ModalResult<Tag> {
     preceded(
@@ -527,9 +889,25 @@ fn match_key(input: &mut &str, num_params: usize) -> ModalResult<MatchKey> {
 
 /// Parse a single component of a match key (identifier or number).
 fn match_key_component(input: &mut &str) -> ModalResult<String> {
-    take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '_')
-        .map(|s: &str| s.to_string())
-        .parse_next(input)
+    alt((
+        range_key,
+        take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '_')
+            .map(|s: &str| s.to_string()),
+    ))
+    .parse_next(input)
+}
+
+fn range_bound<'a>(input: &mut &'a str) -> ModalResult<&'a str> {
+    take_while(1.., |c: char| c.is_ascii_digit()).parse_next(input)
+}
+
+/// Parse a numeric range key: `1..3`, `4..`, or `..2`.
+fn range_key(input: &mut &str) -> ModalResult<String> {
+    let (lo, _, hi) = (opt(range_bound), "..", opt(range_bound)).parse_next(input)?;
+    if lo.is_none() && hi.is_none() {
+        return Err(ErrMode::Backtrack(ContextError::new()));
+    }
+    Ok(format!("{}..{}", lo.unwrap_or(""), hi.unwrap_or("")))
 }
 
 /// Parse a phrase body: simple template or variant block.
@@ -632,12 +1010,23 @@ fn template_content(input: &mut &str) -> ModalResult<Template> {
 fn template_segment(input: &mut &str) -> ModalResult<Segment> {
     alt((
         template_escape_sequence,
+        conditional,
         interpolation,
         template_literal_char,
     ))
     .parse_next(input)
 }
 
+/// Parse a conditional: {?$param: "body"}
+fn conditional(input: &mut &str) -> ModalResult<Segment> {
+    let _ = ('{', ws, '?', ws, '$').parse_next(input)?;
+    let parameter = simple_identifier(input)?.to_string();
+    let _ = (ws, ':', ws).parse_next(input)?;
+    let body = template_string(input)?;
+    let _ = (ws, '}').parse_next(input)?;
+    Ok(Segment::Conditional { parameter, body })
+}
+
 /// Parse template escapes: `{{`, `}}`, `\"`, `\\`, and `\u{HEX}`.
 fn template_escape_sequence(input: &mut &str) -> ModalResult<Segment> {
     if let Some(remaining) = input.strip_prefix("{{") {
@@ -720,7 +1109,13 @@ fn interpolation_content(input: &mut &str) -> ModalResult<Segment> {
     ws(input)?;
     let mut transforms: Vec<Transform> =
         repeat(0.., terminated(transform, ws)).parse_next(input)?;
-    let parsed_ref = reference(input)?;
+    let parsed_ref = match opt(coalesce).parse_next(input)? {
+        Some(reference) => ParsedReference {
+            reference,
+            auto_cap: false,
+        },
+        None => reference(input)?,
+    };
     let selectors: Vec<Selector> = repeat(0.., selector).parse_next(input)?;
     ws(input)?;
 
@@ -749,22 +1144,32 @@ fn ws(input: &mut &str) -> ModalResult<()> {
         .parse_next(input)
 }
 
-/// Parse a transform: @name, @name:context, @name($param), or @name:context($param)
+/// Parse a transform: @name, @name:context, @name($param), @name:$param, or
+/// @name:context($param)
 fn transform(input: &mut &str) -> ModalResult<Transform> {
     let _ = '@'.parse_next(input)?;
     let name: &str = simple_identifier(input)?;
 
-    // Parse optional static context (:literal)
+    // Parse optional static context (:literal) or parameter context (:$param)
+    let mut colon_param = None;
     let static_ctx =
         if input.starts_with(':') && !input[1..].starts_with(|c: char| c.is_whitespace()) {
             let _ = ':'.parse_next(input)?;
-            Some(transform_context_identifier(input)?.to_string())
+            if input.starts_with('$') {
+                let _ = '$'.parse_next(input)?;
+                colon_param = Some(simple_identifier(input)?.to_string());
+                None
+            } else {
+                Some(transform_context_identifier(input)?.to_string())
+            }
         } else {
             None
         };
 
     // Parse optional dynamic context ($param)
-    let dynamic_ctx = if input.starts_with('(') {
+    let dynamic_ctx = if colon_param.is_some() {
+        colon_param
+    } else if input.starts_with('(') {
         let _ = '('.parse_next(input)?;
         ws(input)?;
         let _ = '$'.parse_next(input)?;
@@ -876,16 +1281,54 @@ fn reference(input: &mut &str) -> ModalResult<ParsedReference> {
     })
 }
 
+/// Parse a coalesce: two or more alternatives separated by `||` or `|`.
+fn coalesce(input: &mut &str) -> ModalResult<Reference> {
+    let first = coalesce_alternative(input)?;
+    let rest: Vec<Reference> = repeat(
+        1..,
+        preceded((ws, alt(("||", "|")), ws), coalesce_alternative),
+    )
+    .parse_next(input)?;
+    let mut alternatives = vec![first];
+    alternatives.extend(rest);
+    Ok(Reference::Coalesce(alternatives))
+}
+
+/// Parse a coalesce alternative: a phrase call or a reference argument.
+fn coalesce_alternative(input: &mut &str) -> ModalResult<Reference> {
+    alt((
+        (simple_identifier, phrase_call_args).map(|(name, args)| Reference::PhraseCall {
+            name: name.to_string(),
+            args,
+        }),
+        reference_arg,
+    ))
+    .parse_next(input)
+}
+
 /// Parse phrase call arguments.
 fn phrase_call_args(input: &mut &str) -> ModalResult<Vec<Reference>> {
     delimited(
         ('(', ws),
-        separated(0.., reference_arg, (ws, ',', ws)),
+        separated(0.., call_arg, (ws, ',', ws)),
         (ws, ')'),
     )
     .parse_next(input)
 }
 
+/// Parse a phrase call argument, optionally named: `3` or `n: 3`.
+fn call_arg(input: &mut &str) -> ModalResult<Reference> {
+    let name = opt(terminated(simple_identifier, (ws, ':', ws))).parse_next(input)?;
+    let value = reference_arg(input)?;
+    Ok(match name {
+        Some(name) => Reference::Named {
+            name: name.to_string(),
+            value: Box::new(value),
+        },
+        None => value,
+    })
+}
+
 /// Parse a reference argument: $param, bare term name, number literal, or string literal.
 fn reference_arg(input: &mut &str) -> ModalResult<Reference> {
     if input.starts_with('$') {
@@ -915,6 +1358,13 @@ fn number_literal_arg(input: &mut &str) -> ModalResult<Reference> {
 
 /// Parse a string literal argument: "text" with escape support for \" and \\.
 fn string_literal_arg(input: &mut &str) -> ModalResult<Reference> {
+    string_literal
+        .map(Reference::StringLiteral)
+        .parse_next(input)
+}
+
+/// Parse a quoted string literal with escape support for \" and \\.
+fn string_literal(input: &mut &str) -> ModalResult<String> {
     let _ = '"'.parse_next(input)?;
     let mut result = String::new();
     loop {
@@ -929,15 +1379,20 @@ fn string_literal_arg(input: &mut &str) -> ModalResult<Reference> {
             result.push(any.parse_next(input)?);
         }
     }
-    Ok(Reference::StringLiteral(result))
+    Ok(result)
 }
 
-/// Parse a selector: :identifier, :$param, or :*
+/// Parse a selector: :identifier, :$param, :#$param, or :*
 fn selector(input: &mut &str) -> ModalResult<Selector> {
     ':'.parse_next(input)?;
     if input.starts_with('*') {
         let _ = '*'.parse_next(input)?;
         Ok(Selector::Default)
+    } else if input.starts_with("#$") {
+        let _ = "#$".parse_next(input)?;
+        selector_identifier
+            .map(|s| Selector::Ordinal(s.to_string()))
+            .parse_next(input)
     } else if input.starts_with('$') {
         let _ = '$'.parse_next(input)?;
         selector_identifier
@@ -983,7 +1438,9 @@ fn merge_literals(segments: Vec<Segment>) -> Vec<Segment> {
                     result.push(Segment::Literal(text));
                 }
             }
-            other @ Segment::Interpolation { .. } => result.push(other),
+            other @ (Segment::Interpolation { .. } | Segment::Conditional { .. }) => {
+                result.push(other);
+            }
         }
     }
 
@@ -5,11 +5,16 @@
 //! or external tooling.
 
 pub mod ast;
+mod debug;
 pub mod error;
 mod file;
 mod template;
+mod visit;
 
 pub use ast::*;
+pub use debug::AstDebug;
 pub use error::ParseError;
-pub use file::parse_file;
+pub(crate) use file::is_attribute_line;
+pub use file::{parse_file, parse_file_recovering};
 pub use template::parse_template;
+pub use visit::{TemplateVisitor, walk_definition, walk_template};
@@ -10,7 +10,7 @@
 
 use super::ast::*;
 use super::error::ParseError;
-use winnow::combinator::{alt, delimited, opt, repeat, separated, terminated};
+use winnow::combinator::{alt, delimited, opt, preceded, repeat, separated, terminated};
 use winnow::error::{ContextError, ErrMode};
 use winnow::prelude::*;
 use winnow::token::{any, none_of, take_while};
@@ -81,16 +81,58 @@ fn merge_literals(segments: Vec<Segment>) -> Vec<Segment> {
                     result.push(Segment::Literal(text));
                 }
             }
-            other @ Segment::Interpolation { .. } => result.push(other),
+            other @ (Segment::Interpolation { .. } | Segment::Conditional { .. }) => {
+                result.push(other);
+            }
         }
     }
 
     result
 }
 
-/// Parse a single segment (escape, interpolation, or literal).
+/// Parse a single segment (escape, conditional, interpolation, or literal).
 fn segment(input: &mut &str) -> ModalResult<Segment> {
-    alt((template_escape_sequence, interpolation, literal_char)).parse_next(input)
+    alt((
+        template_escape_sequence,
+        conditional,
+        interpolation,
+        literal_char,
+    ))
+    .parse_next(input)
+}
+
+/// Parse a conditional: {?$param: "body"}
+///
+/// The body is a quoted template, so unlike top-level text it cannot contain
+/// an unescaped `"`.
+fn conditional(input: &mut &str) -> ModalResult<Segment> {
+    let _ = ('{', ws, '?', ws, '$').parse_next(input)?;
+    let parameter = identifier(input)?.to_string();
+    let _ = (ws, ':', ws, '"').parse_next(input)?;
+    let segments: Vec<Segment> = repeat(
+        0..,
+        alt((
+            template_escape_sequence,
+            conditional,
+            interpolation,
+            quoted_literal_char,
+        )),
+    )
+    .parse_next(input)?;
+    let _ = ('"', ws, '}').parse_next(input)?;
+    Ok(Segment::Conditional {
+        parameter,
+        body: Template {
+            segments: merge_literals(segments),
+        },
+    })
+}
+
+/// Parse a literal character inside a quoted conditional body.
+fn quoted_literal_char(input: &mut &str) -> ModalResult<Segment> {
+    none_of(['{', '}', '"', '\\'])
+        .map(|c: char| Segment::Literal(c.to_string()))
+        .parse_next(input)
 }
 
 /// Parse template escapes: `{{`, `}}`, `\"`, `\\`, and `\u{HEX}`.
@@ -175,7 +217,13 @@ fn interpolation_content(input: &mut &str) -> ModalResult<Segment> {
     ws(input)?;
     let mut transforms: Vec<Transform> =
         repeat(0.., terminated(transform, ws)).parse_next(input)?;
-    let parsed_ref = reference(input)?;
+    let parsed_ref = match opt(coalesce).parse_next(input)? {
+        Some(reference) => ParsedReference {
+            reference,
+            auto_cap: false,
+        },
+        None => reference(input)?,
+    };
     let selectors: Vec<Selector> = repeat(0.., selector).parse_next(input)?;
     ws(input)?;
 
@@ -204,22 +252,39 @@ fn ws(input: &mut &str) -> ModalResult<()> {
         .parse_next(input)
 }
 
-/// Parse a transform: @name, @name:context, @name($param), or @name:context($param)
+/// Parse at least one whitespace character.
+fn ws1(input: &mut &str) -> ModalResult<()> {
+    take_while(1.., |c: char| c.is_ascii_whitespace())
+        .void()
+        .parse_next(input)
+}
+
+/// Parse a transform: @name, @name:context, @name($param), @name:$param, or
+/// @name:context($param)
 fn transform(input: &mut &str) -> ModalResult<Transform> {
     let _ = '@'.parse_next(input)?;
     let name: &str = identifier(input)?;
 
-    // Parse optional static context (:literal)
+    // Parse optional static context (:literal) or parameter context (:$param)
+    let mut colon_param = None;
     let static_ctx =
         if input.starts_with(':') && !input[1..].starts_with(|c: char| c.is_whitespace()) {
             let _ = ':'.parse_next(input)?;
-            Some(transform_context_identifier(input)?.to_string())
+            if input.starts_with('$') {
+                let _ = '$'.parse_next(input)?;
+                colon_param = Some(identifier(input)?.to_string());
+                None
+            } else {
+                Some(transform_context_identifier(input)?.to_string())
+            }
         } else {
             None
         };
 
     // Parse optional dynamic context ($param)
-    let dynamic_ctx = if input.starts_with('(') {
+    let dynamic_ctx = if colon_param.is_some() {
+        colon_param
+    } else if input.starts_with('(') {
         let _ = '('.parse_next(input)?;
         ws(input)?;
         let _ = '$'.parse_next(input)?;
@@ -347,16 +412,57 @@ fn reference(input: &mut &str) -> ModalResult<ParsedReference> {
     })
 }
 
+/// Parse a coalesce: two or more alternatives separated by `||` or `|`.
+fn coalesce(input: &mut &str) -> ModalResult<Reference> {
+    let first = coalesce_alternative(input)?;
+    let rest: Vec<Reference> = repeat(
+        1..,
+        preceded((ws, alt(("||", "|")), ws), coalesce_alternative),
+    )
+    .parse_next(input)?;
+    let mut alternatives = vec![first];
+    alternatives.extend(rest);
+    Ok(Reference::Coalesce(alternatives))
+}
+
+/// Parse a coalesce alternative: a phrase call or a reference argument.
+fn coalesce_alternative(input: &mut &str) -> ModalResult<Reference> {
+    alt((
+        (identifier, phrase_call_args).map(|(name, args)| Reference::PhraseCall {
+            name: name.to_string(),
+            args,
+        }),
+        reference_arg,
+    ))
+    .parse_next(input)
+}
+
 /// Parse phrase call arguments: (arg1, arg2, ...)
 fn phrase_call_args(input: &mut &str) -> ModalResult<Vec<Reference>> {
     delimited(
         ('(', ws),
-        separated(0.., reference_arg, (ws, ',', ws)),
+        separated(0.., call_arg, (ws, ',', ws)),
         (ws, ')'),
     )
     .parse_next(input)
 }
 
+/// Parse a phrase call argument, optionally named: `3` or `n: 3`.
+///
+/// The `:` of a named argument must be followed by whitespace, so that
+/// `f(card:one)` stays a selector expression rather than a name.
+fn call_arg(input: &mut &str) -> ModalResult<Reference> {
+    let name = opt(terminated(identifier, (ws, ':', ws1))).parse_next(input)?;
+    let value = reference_arg(input)?;
+    Ok(match name {
+        Some(name) => Reference::Named {
+            name: name.to_string(),
+            value: Box::new(value),
+        },
+        None => value,
+    })
+}
+
 /// Parse a reference argument: $param, bare term name, number literal, or string literal.
 fn reference_arg(input: &mut &str) -> ModalResult<Reference> {
     if input.starts_with('$') {
@@ -403,12 +509,17 @@ fn string_literal_arg(input: &mut &str) -> ModalResult<Reference> {
     Ok(Reference::StringLiteral(result))
 }
 
-/// Parse a selector: :identifier, :$param, or :*
+/// Parse a selector: :identifier, :$param, :#$param, or :*
 fn selector(input: &mut &str) -> ModalResult<Selector> {
     ':'.parse_next(input)?;
     if input.starts_with('*') {
         let _ = '*'.parse_next(input)?;
         Ok(Selector::Default)
+    } else if input.starts_with("#$") {
+        let _ = "#$".parse_next(input)?;
+        selector_identifier
+            .map(|s| Selector::Ordinal(s.to_string()))
+            .parse_next(input)
     } else if input.starts_with('$') {
         let _ = '$'.parse_next(input)?;
         selector_identifier
@@ -0,0 +1,132 @@
+//! Visitor over the template AST for custom analysis.
+//!
+//! Implement [`TemplateVisitor`] and drive it with [`walk_template`] or
+//! [`walk_definition`] to build project-specific lints without re-implementing
+//! the traversal.
+
+use crate::parser::ast::{
+    MatchBranch, PhraseBody, PhraseDefinition, Reference, Segment, Selector, Template, Transform,
+    VariantEntryBody,
+};
+
+/// Callbacks invoked while walking a template.
+///
+/// Every method has an empty default, so implementors only override the nodes
+/// they care about. For an interpolation, [`visit_substitution`] is called
+/// first, then [`visit_transform`] for each transform, then
+/// [`visit_phrase_ref`] for each term or phrase the reference names.
+///
+/// [`visit_substitution`]: TemplateVisitor::visit_substitution
+/// [`visit_transform`]: TemplateVisitor::visit_transform
+/// [`visit_phrase_ref`]: TemplateVisitor::visit_phrase_ref
+///
+/// # Example
+///
+/// ```
+/// use rlf::parser::{TemplateVisitor, parse_file, walk_definition};
+///
+/// struct PhraseRefCounter {
+///     count: usize,
+/// }
+///
+/// impl TemplateVisitor for PhraseRefCounter {
+///     fn visit_phrase_ref(&mut self, _name: &str, _args: &[rlf::parser::Reference]) {
+///         self.count += 1;
+///     }
+/// }
+///
+/// let definitions = parse_file(r#"
+///     card = { one: "card", other: "cards" };
+///     cards($n) = "{$n} {card:$n}";
+///     draw($n) = "Draw {cards($n)} and {@cap card}.";
+/// "#).unwrap();
+///
+/// let mut counter = PhraseRefCounter { count: 0 };
+/// walk_definition(&definitions[2], &mut counter);
+/// assert_eq!(counter.count, 2);
+/// ```
+pub trait TemplateVisitor {
+    /// Called for each literal text segment.
+    fn visit_literal(&mut self, _text: &str) {}
+
+    /// Called for each interpolation, before its transforms and references.
+    fn visit_substitution(&mut self, _reference: &Reference, _selectors: &[Selector]) {}
+
+    /// Called for each transform applied in an interpolation.
+    fn visit_transform(&mut self, _transform: &Transform) {}
+
+    /// Called for each term or phrase referenced by name.
+    ///
+    /// Bare references like `{card}` have empty `args`. Phrase calls like
+    /// `{cards($n)}` pass their arguments, and term names used as arguments
+    /// are visited as references of their own.
+    fn visit_phrase_ref(&mut self, _name: &str, _args: &[Reference]) {}
+
+    /// Called for each `{?$param: "..."}` conditional, before its body.
+    fn visit_conditional(&mut self, _parameter: &str) {}
+}
+
+/// Walk every segment of a template, including conditional bodies.
+pub fn walk_template(template: &Template, visitor: &mut impl TemplateVisitor) {
+    for segment in &template.segments {
+        match segment {
+            Segment::Literal(text) => visitor.visit_literal(text),
+            Segment::Interpolation {
+                transforms,
+                reference,
+                selectors,
+            } => {
+                visitor.visit_substitution(reference, selectors);
+                for transform in transforms {
+                    visitor.visit_transform(transform);
+                }
+                walk_reference(reference, visitor);
+            }
+            Segment::Conditional { parameter, body } => {
+                visitor.visit_conditional(parameter);
+                walk_template(body, visitor);
+            }
+        }
+    }
+}
+
+/// Walk every template in a definition's body.
+pub fn walk_definition(definition: &PhraseDefinition, visitor: &mut impl TemplateVisitor) {
+    match &definition.body {
+        PhraseBody::Simple(template) => walk_template(template, visitor),
+        PhraseBody::Variants(entries) => {
+            for entry in entries {
+                match &entry.body {
+                    VariantEntryBody::Template(template) => walk_template(template, visitor),
+                    VariantEntryBody::Match { branches, .. } => walk_branches(branches, visitor),
+                }
+            }
+        }
+        PhraseBody::Match(branches) => walk_branches(branches, visitor),
+    }
+}
+
+fn walk_branches(branches: &[MatchBranch], visitor: &mut impl TemplateVisitor) {
+    for branch in branches {
+        walk_template(&branch.template, visitor);
+    }
+}
+
+fn walk_reference(reference: &Reference, visitor: &mut impl TemplateVisitor) {
+    match reference {
+        Reference::Identifier(name) => visitor.visit_phrase_ref(name, &[]),
+        Reference::PhraseCall { name, args } => {
+            visitor.visit_phrase_ref(name, args);
+            for arg in args {
+                walk_reference(arg, visitor);
+            }
+        }
+        Reference::Coalesce(alternatives) => {
+            for alternative in alternatives {
+                walk_reference(alternative, visitor);
+            }
+        }
+        Reference::Named { value, .. } => walk_reference(value, visitor),
+        Reference::Parameter(_) | Reference::NumberLiteral(_) | Reference::StringLiteral(_) => {}
+    }
+}
@@ -1,11 +1,14 @@
 mod phrase;
 mod phrase_id;
+mod rich;
 mod tag;
 mod value;
 mod variant_key;
 
 pub use phrase::Phrase;
 pub use phrase_id::PhraseId;
+pub use rich::RichSpan;
 pub use tag::Tag;
-pub use value::Value;
+pub use value::{NumValue, TextValue, Value};
+pub(crate) use value::{civil_from_unix_seconds, time_of_day};
 pub use variant_key::VariantKey;
@@ -1,9 +1,13 @@
 use std::collections::{BTreeSet, HashMap};
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Range;
 
 use bon::Builder;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
-use super::{Tag, VariantKey};
+use super::rich::parse_rich_text;
+use super::{RichSpan, Tag, VariantKey};
 
 /// A localized phrase that can have multiple variants and metadata tags.
 ///
@@ -50,6 +54,16 @@ pub struct Phrase {
     /// article hints (`:a`, `:an`), or other language-specific metadata.
     #[builder(default)]
     pub tags: Vec<Tag>,
+
+    /// Key of the variant chosen when this phrase was evaluated, read by
+    /// [`Phrase::selected_variant`].
+    #[builder(skip)]
+    pub(crate) selected_variant: Option<VariantKey>,
+
+    /// Byte ranges of `text` rendered from caller-supplied values, whose
+    /// `<...>` [`Phrase::to_rich`] keeps as text rather than markup.
+    #[builder(skip)]
+    pub(crate) plain: Vec<Range<usize>>,
 }
 
 impl Phrase {
@@ -59,12 +73,39 @@ impl Phrase {
     }
 
     /// Transforms this phrase's text using `f`, preserving tags and variants.
+    ///
+    /// The new text is treated as authored text: [`Phrase::to_rich`] parses
+    /// all of its markup.
     pub fn map_text(self, f: impl FnOnce(String) -> String) -> Phrase {
-        Phrase::builder()
+        let mut phrase = Phrase::builder()
             .text(f(self.text))
             .variants(self.variants)
             .tags(self.tags)
-            .build()
+            .build();
+        phrase.selected_variant = self.selected_variant;
+        phrase
+    }
+
+    /// Returns the key of the variant selected during evaluation, if any.
+    ///
+    /// For a `:match` body this is the chosen branch. Otherwise it is the
+    /// variant the first parameter selector picked, such as `other` for
+    /// `{card:$n}` with `n = 3`. Callers can use it to drive secondary
+    /// agreement without re-running plural rules. It does not affect the
+    /// displayed text.
+    ///
+    /// ```
+    /// use rlf::Locale;
+    ///
+    /// let mut locale = Locale::new();
+    /// locale.load_translations_str("en", r#"
+    ///     draw($n) = :match($n) { 1: "Draw a card.", *other: "Draw {$n} cards." };
+    /// "#).unwrap();
+    /// let phrase = locale.call_phrase("draw", &[3.into()]).unwrap();
+    /// assert_eq!(phrase.selected_variant().unwrap().as_str(), "other");
+    /// ```
+    pub fn selected_variant(&self) -> Option<&VariantKey> {
+        self.selected_variant.as_ref()
     }
 
     /// Get a specific variant by key, with fallback resolution.
@@ -100,7 +141,7 @@ impl Phrase {
     /// ```
     pub fn variant(&self, key: &str) -> &str {
         // Try exact match
-        if let Some(v) = self.variants.get(&VariantKey::new(key)) {
+        if let Some(v) = self.variants.get(key) {
             return v;
         }
 
@@ -108,7 +149,7 @@ impl Phrase {
         let mut current = key;
         while let Some(dot_pos) = current.rfind('.') {
             current = &current[..dot_pos];
-            if let Some(v) = self.variants.get(&VariantKey::new(current)) {
+            if let Some(v) = self.variants.get(current) {
                 return v;
             }
         }
@@ -145,18 +186,28 @@ impl Phrase {
             return Phrase::empty();
         }
 
-        let text = phrases
-            .iter()
-            .map(|p| p.text.as_str())
-            .collect::<Vec<_>>()
-            .join(separator);
+        let mut text = String::new();
+        let mut plain = Vec::new();
+        for (index, phrase) in phrases.iter().enumerate() {
+            if index > 0 {
+                text.push_str(separator);
+            }
+            let offset = text.len();
+            plain.extend(
+                phrase
+                    .plain
+                    .iter()
+                    .map(|range| range.start + offset..range.end + offset),
+            );
+            text.push_str(&phrase.text);
+        }
 
         let shared_keys: BTreeSet<&VariantKey> = phrases
             .first()
             .map(|p| {
                 p.variants
                     .keys()
-                    .filter(|k| phrases[1..].iter().all(|q| q.variants.contains_key(k)))
+                    .filter(|k| phrases[1..].iter().all(|q| q.variants.contains_key(*k)))
                     .collect()
             })
             .unwrap_or_default();
@@ -173,7 +224,63 @@ impl Phrase {
             })
             .collect();
 
-        Phrase::builder().text(text).variants(variants).build()
+        let mut joined = Phrase::builder().text(text).variants(variants).build();
+        joined.plain = plain;
+        joined
+    }
+
+    /// Splits the default text into styled spans.
+    ///
+    /// Interprets `<b>`, `<i>`, `<u>`, and `<color=...>` markup, including
+    /// nested tags, so renderers can style text without parsing the flat
+    /// string returned by `to_string()`. Unrecognized tags are kept as text,
+    /// and so is anything inside text that came from a caller-supplied
+    /// parameter value, so a value such as `"<b>Bob"` cannot add styling.
+    ///
+    /// ```
+    /// use rlf::Phrase;
+    ///
+    /// let phrase = Phrase::builder().text("Gain <b>2</b>".to_string()).build();
+    /// let spans = phrase.to_rich();
+    /// assert_eq!(spans[0].text, "Gain ");
+    /// assert!(spans[1].bold);
+    /// ```
+    pub fn to_rich(&self) -> Vec<RichSpan> {
+        parse_rich_text(&self.text, &self.plain)
+    }
+
+    /// Number of visible characters in the default text, excluding markup.
+    pub fn char_count(&self) -> usize {
+        self.to_rich()
+            .iter()
+            .map(|span| span.text.chars().count())
+            .sum()
+    }
+
+    /// Number of user-perceived characters in the default text, excluding
+    /// markup.
+    ///
+    /// ```
+    /// use rlf::Phrase;
+    ///
+    /// let phrase = Phrase::builder().text("<b>Hi</b>".to_string()).build();
+    /// assert_eq!(phrase.grapheme_count(), 2);
+    /// ```
+    pub fn grapheme_count(&self) -> usize {
+        self.visible_text().graphemes(true).count()
+    }
+
+    /// Width of the default text in terminal columns, excluding markup.
+    ///
+    /// East Asian wide and fullwidth characters count as two columns, so
+    /// "カード" is 6 columns wide while "card" is 4.
+    pub fn display_width(&self) -> usize {
+        self.visible_text().width()
+    }
+
+    /// The default text with recognized markup tags removed.
+    fn visible_text(&self) -> String {
+        self.to_rich().into_iter().map(|span| span.text).collect()
     }
 }
 
@@ -0,0 +1,155 @@
+//! Structured rich-text spans parsed from phrase markup.
+
+use std::mem;
+use std::ops::Range;
+
+/// A run of text with uniform styling.
+///
+/// Produced by [`Phrase::to_rich`](super::Phrase::to_rich), which interprets the
+/// `<b>`, `<i>`, `<u>`, and `<color=...>` markup tags in the phrase text so that
+/// renderers can style spans directly instead of parsing the flat string.
+/// Unrecognized tags are kept as literal text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichSpan {
+    /// The text of this span, with markup removed.
+    pub text: String,
+    /// Whether the span is inside a `<b>` tag.
+    pub bold: bool,
+    /// Whether the span is inside an `<i>` tag.
+    pub italic: bool,
+    /// Whether the span is inside a `<u>` tag.
+    pub underline: bool,
+    /// The innermost `<color=...>` value, e.g. `#2E7D32`.
+    pub color: Option<String>,
+}
+
+/// Markup tags recognized by the rich-text parser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Markup {
+    Open(Style, Option<String>),
+    Close(Style),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Bold,
+    Italic,
+    Underline,
+    Color,
+}
+
+/// Nesting state while walking the text.
+#[derive(Default)]
+struct StyleStack {
+    bold: usize,
+    italic: usize,
+    underline: usize,
+    colors: Vec<String>,
+}
+
+impl StyleStack {
+    fn apply(&mut self, markup: Markup) {
+        match markup {
+            Markup::Open(Style::Bold, _) => self.bold += 1,
+            Markup::Open(Style::Italic, _) => self.italic += 1,
+            Markup::Open(Style::Underline, _) => self.underline += 1,
+            Markup::Open(Style::Color, value) => self.colors.push(value.unwrap_or_default()),
+            Markup::Close(Style::Bold) => self.bold = self.bold.saturating_sub(1),
+            Markup::Close(Style::Italic) => self.italic = self.italic.saturating_sub(1),
+            Markup::Close(Style::Underline) => self.underline = self.underline.saturating_sub(1),
+            Markup::Close(Style::Color) => {
+                self.colors.pop();
+            }
+        }
+    }
+
+    fn span(&self, text: String) -> RichSpan {
+        RichSpan {
+            text,
+            bold: self.bold > 0,
+            italic: self.italic > 0,
+            underline: self.underline > 0,
+            color: self.colors.last().cloned(),
+        }
+    }
+}
+
+/// Split marked-up text into styled spans, merging adjacent runs with the same
+/// style and dropping empty ones.
+///
+/// A tag overlapping one of the `plain` byte ranges is kept as text.
+pub(crate) fn parse_rich_text(text: &str, plain: &[Range<usize>]) -> Vec<RichSpan> {
+    let mut spans: Vec<RichSpan> = Vec::new();
+    let mut stack = StyleStack::default();
+    let mut pending = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        let (before, tag_start) = rest.split_at(start);
+        pending.push_str(before);
+        let offset = text.len() - tag_start.len();
+        let markup = tag_start
+            .find('>')
+            .filter(|end| {
+                !plain
+                    .iter()
+                    .any(|range| range.start <= offset + end && offset < range.end)
+            })
+            .and_then(|end| parse_markup(&tag_start[1..end]).map(|m| (m, end)));
+        let Some((markup, end)) = markup else {
+            pending.push('<');
+            rest = &tag_start[1..];
+            continue;
+        };
+        push_span(&mut spans, stack.span(mem::take(&mut pending)));
+        stack.apply(markup);
+        rest = &tag_start[end + 1..];
+    }
+    pending.push_str(rest);
+    push_span(&mut spans, stack.span(pending));
+    spans
+}
+
+/// Parse the inside of a `<...>` tag, returning `None` for unrecognized tags.
+fn parse_markup(tag: &str) -> Option<Markup> {
+    if let Some(name) = tag.strip_prefix('/') {
+        return style_for(name.trim()).map(Markup::Close);
+    }
+    let (name, value) = match tag.split_once('=') {
+        Some((name, value)) => (
+            name.trim(),
+            Some(value.trim().trim_matches('"').to_string()),
+        ),
+        None => (tag.trim(), None),
+    };
+    let style = style_for(name)?;
+    (value.is_some() == (style == Style::Color)).then_some(Markup::Open(style, value))
+}
+
+fn style_for(name: &str) -> Option<Style> {
+    match name {
+        "b" => Some(Style::Bold),
+        "i" => Some(Style::Italic),
+        "u" => Some(Style::Underline),
+        "color" => Some(Style::Color),
+        _ => None,
+    }
+}
+
+/// Append a span, merging it into the previous one when the styles match.
+fn push_span(spans: &mut Vec<RichSpan>, span: RichSpan) {
+    if span.text.is_empty() {
+        return;
+    }
+    if let Some(last) = spans.last_mut() {
+        let same_style = last.bold == span.bold
+            && last.italic == span.italic
+            && last.underline == span.underline
+            && last.color == span.color;
+        if same_style {
+            last.text.push_str(&span.text);
+            return;
+        }
+    }
+    spans.push(span);
+}
@@ -1,3 +1,4 @@
+use std::borrow::Borrow;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::ops::Deref;
 
@@ -30,6 +31,16 @@ impl Deref for Tag {
     }
 }
 
+/// Lets a `HashSet<Tag>` be queried with a plain `&str`.
+///
+/// The derived `Hash` and `Eq` delegate to the inner `String`, which agree
+/// with `str`'s, so lookups need not allocate a `Tag`.
+impl Borrow<str> for Tag {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
 impl From<&str> for Tag {
     fn from(s: &str) -> Self {
         Self(s.to_string())
@@ -1,4 +1,6 @@
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::num::TryFromIntError;
 
 use super::Phrase;
 
@@ -34,6 +36,17 @@ pub enum Value {
 
     /// A phrase value (carries variants and tags).
     Phrase(Phrase),
+
+    /// A point in time as seconds since the Unix epoch (UTC).
+    ///
+    /// Formatted by the `@date` and `@time` transforms.
+    DateTime(i64),
+
+    /// A list of values.
+    ///
+    /// Joined with the language's conjunction by the `@list` transform and
+    /// selects plural variants by its length.
+    List(Vec<Value>),
 }
 
 impl Value {
@@ -62,6 +75,22 @@ impl Value {
         }
     }
 
+    /// Get this value as a timestamp in epoch seconds, if it is one.
+    pub fn as_datetime(&self) -> Option<i64> {
+        match self {
+            Value::DateTime(secs) => Some(*secs),
+            _ => None,
+        }
+    }
+
+    /// Get this value's items, if it is a list.
+    pub fn as_list(&self) -> Option<&[Value]> {
+        match self {
+            Value::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
     /// Get this value as a phrase, if it is one.
     pub fn as_phrase(&self) -> Option<&Phrase> {
         match self {
@@ -94,11 +123,68 @@ impl Display for Value {
             Value::Float(n) => write!(f, "{n}"),
             Value::String(s) => write!(f, "{s}"),
             Value::Phrase(p) => write!(f, "{p}"),
+            Value::DateTime(secs) => {
+                let (year, month, day) = civil_from_unix_seconds(*secs);
+                let (hour, minute, second) = time_of_day(*secs);
+                write!(
+                    f,
+                    "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z"
+                )
+            }
+            Value::List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
+/// Converts epoch seconds to a proleptic Gregorian `(year, month, day)` in UTC.
+pub(crate) fn civil_from_unix_seconds(secs: i64) -> (i64, u32, u32) {
+    let days = secs.div_euclid(86_400);
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe.div_euclid(1_460) + doe.div_euclid(36_524) - doe.div_euclid(146_096))
+        .div_euclid(365);
+    let doy = doe - (365 * yoe + yoe.div_euclid(4) - yoe.div_euclid(100));
+    let mp = (5 * doy + 2).div_euclid(153);
+    let day = doy - (153 * mp + 2).div_euclid(5) + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month as u32, day as u32)
+}
+
+/// Converts epoch seconds to `(hour, minute, second)` within the UTC day.
+pub(crate) fn time_of_day(secs: i64) -> (u32, u32, u32) {
+    let in_day = secs.rem_euclid(86_400);
+    let hour = in_day.div_euclid(3_600);
+    let minute = in_day.rem_euclid(3_600).div_euclid(60);
+    let second = in_day.rem_euclid(60);
+    (hour as u32, minute as u32, second as u32)
+}
+
 // From implementations for common types
+//
+// Integers are stored as `i64`. Unsigned values above `i64::MAX` saturate to
+// `i64::MAX` rather than wrapping to a negative count.
+
+impl From<i8> for Value {
+    fn from(n: i8) -> Self {
+        Value::Number(i64::from(n))
+    }
+}
+
+impl From<i16> for Value {
+    fn from(n: i16) -> Self {
+        Value::Number(i64::from(n))
+    }
+}
 
 impl From<i32> for Value {
     fn from(n: i32) -> Self {
@@ -112,6 +198,24 @@ impl From<i64> for Value {
     }
 }
 
+impl From<isize> for Value {
+    fn from(n: isize) -> Self {
+        Value::Number(n as i64)
+    }
+}
+
+impl From<u8> for Value {
+    fn from(n: u8) -> Self {
+        Value::Number(i64::from(n))
+    }
+}
+
+impl From<u16> for Value {
+    fn from(n: u16) -> Self {
+        Value::Number(i64::from(n))
+    }
+}
+
 impl From<u32> for Value {
     fn from(n: u32) -> Self {
         Value::Number(n as i64)
@@ -120,13 +224,13 @@ impl From<u32> for Value {
 
 impl From<u64> for Value {
     fn from(n: u64) -> Self {
-        Value::Number(n as i64)
+        Value::Number(i64::try_from(n).unwrap_or(i64::MAX))
     }
 }
 
 impl From<usize> for Value {
     fn from(n: usize) -> Self {
-        Value::Number(n as i64)
+        Value::Number(i64::try_from(n).unwrap_or(i64::MAX))
     }
 }
 
@@ -154,8 +258,158 @@ impl From<&str> for Value {
     }
 }
 
+impl From<&String> for Value {
+    fn from(s: &String) -> Self {
+        Value::String(s.clone())
+    }
+}
+
+impl From<Cow<'_, str>> for Value {
+    fn from(s: Cow<'_, str>) -> Self {
+        Value::String(s.into_owned())
+    }
+}
+
 impl From<Phrase> for Value {
     fn from(p: Phrase) -> Self {
         Value::Phrase(p)
     }
 }
+
+impl From<&Phrase> for Value {
+    fn from(p: &Phrase) -> Self {
+        Value::Phrase(p.clone())
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(items: Vec<T>) -> Self {
+        Value::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+/// An argument for a phrase parameter declared `$name: num`.
+///
+/// Generated phrase functions take `impl Into<NumValue>` for `num` parameters,
+/// so passing a string is a compile error. Converts from the signed integer
+/// types, `u8`, `u16`, `u32`, and the float types; `u64` and `usize` convert
+/// with `try_into()`, which fails above `i64::MAX` instead of changing the
+/// count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumValue(NumRepr);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumRepr {
+    Integer(i64),
+    Float(f64),
+}
+
+impl From<i8> for NumValue {
+    fn from(n: i8) -> Self {
+        NumValue(NumRepr::Integer(i64::from(n)))
+    }
+}
+
+impl From<i16> for NumValue {
+    fn from(n: i16) -> Self {
+        NumValue(NumRepr::Integer(i64::from(n)))
+    }
+}
+
+impl From<i32> for NumValue {
+    fn from(n: i32) -> Self {
+        NumValue(NumRepr::Integer(i64::from(n)))
+    }
+}
+
+impl From<i64> for NumValue {
+    fn from(n: i64) -> Self {
+        NumValue(NumRepr::Integer(n))
+    }
+}
+
+impl From<isize> for NumValue {
+    fn from(n: isize) -> Self {
+        NumValue(NumRepr::Integer(n as i64))
+    }
+}
+
+impl From<u8> for NumValue {
+    fn from(n: u8) -> Self {
+        NumValue(NumRepr::Integer(i64::from(n)))
+    }
+}
+
+impl From<u16> for NumValue {
+    fn from(n: u16) -> Self {
+        NumValue(NumRepr::Integer(i64::from(n)))
+    }
+}
+
+impl From<u32> for NumValue {
+    fn from(n: u32) -> Self {
+        NumValue(NumRepr::Integer(i64::from(n)))
+    }
+}
+
+impl TryFrom<u64> for NumValue {
+    type Error = TryFromIntError;
+
+    fn try_from(n: u64) -> Result<Self, Self::Error> {
+        i64::try_from(n).map(|n| NumValue(NumRepr::Integer(n)))
+    }
+}
+
+impl TryFrom<usize> for NumValue {
+    type Error = TryFromIntError;
+
+    fn try_from(n: usize) -> Result<Self, Self::Error> {
+        i64::try_from(n).map(|n| NumValue(NumRepr::Integer(n)))
+    }
+}
+
+impl From<f32> for NumValue {
+    fn from(n: f32) -> Self {
+        NumValue(NumRepr::Float(n as f64))
+    }
+}
+
+impl From<f64> for NumValue {
+    fn from(n: f64) -> Self {
+        NumValue(NumRepr::Float(n))
+    }
+}
+
+impl From<NumValue> for Value {
+    fn from(n: NumValue) -> Self {
+        match n.0 {
+            NumRepr::Integer(n) => Value::Number(n),
+            NumRepr::Float(n) => Value::Float(n),
+        }
+    }
+}
+
+/// An argument for a phrase parameter declared `$name: text`.
+///
+/// Generated phrase functions take `impl Into<TextValue>` for `text`
+/// parameters, so passing a number or phrase is a compile error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextValue(String);
+
+impl From<String> for TextValue {
+    fn from(s: String) -> Self {
+        TextValue(s)
+    }
+}
+
+impl From<&str> for TextValue {
+    fn from(s: &str) -> Self {
+        TextValue(s.to_string())
+    }
+}
+
+impl From<TextValue> for Value {
+    fn from(s: TextValue) -> Self {
+        Value::String(s.0)
+    }
+}
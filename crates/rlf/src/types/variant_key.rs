@@ -1,3 +1,4 @@
+use std::borrow::Borrow;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::ops::Deref;
 
@@ -30,6 +31,15 @@ impl Deref for VariantKey {
     }
 }
 
+/// Allows `phrase.variants.get("nom.one")` without building a key first.
+///
+/// Hashing and equality come from the wrapped `String`, so they match `str`.
+impl Borrow<str> for VariantKey {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
 impl From<&str> for VariantKey {
     fn from(s: &str) -> Self {
         Self(s.to_string())
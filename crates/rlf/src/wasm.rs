@@ -0,0 +1,114 @@
+//! JavaScript bindings for the `wasm` feature.
+//!
+//! [`WasmLocale`] wraps a [`Locale`] so browser builds can load translations
+//! and evaluate phrases from JavaScript. Results are plain strings; load and
+//! evaluation errors are thrown as JavaScript `Error`s carrying the error's
+//! message.
+
+use serde_json::Value as JsonValue;
+use wasm_bindgen::prelude::*;
+
+use crate::{Locale, Value};
+
+/// A [`Locale`] exposed to JavaScript.
+///
+/// ```js
+/// const locale = new WasmLocale("en");
+/// locale.load("en", 'cards($n) = :match($n) { 1: "a card", *other: "{$n} cards" };');
+/// locale.call_phrase("cards", "[3]"); // "3 cards"
+/// ```
+#[wasm_bindgen]
+pub struct WasmLocale {
+    locale: Locale,
+}
+
+#[wasm_bindgen]
+impl WasmLocale {
+    /// Create a locale whose current language is `language`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(language: &str) -> WasmLocale {
+        WasmLocale {
+            locale: Locale::with_language(language),
+        }
+    }
+
+    /// The current language.
+    pub fn language(&self) -> String {
+        self.locale.language().to_string()
+    }
+
+    /// Switch the current language.
+    pub fn set_language(&mut self, language: &str) {
+        self.locale.set_language(language);
+    }
+
+    /// Load RLF source for `language`, replacing its previous phrases, and
+    /// return the number of definitions loaded.
+    pub fn load(&mut self, language: &str, source: &str) -> Result<usize, JsError> {
+        self.try_load(language, source)
+            .map_err(|e| JsError::new(&e))
+    }
+
+    /// Evaluate a parameterless phrase or term in the current language.
+    pub fn get_phrase(&self, name: &str) -> Result<String, JsError> {
+        self.try_get_phrase(name).map_err(|e| JsError::new(&e))
+    }
+
+    /// Call a phrase with arguments given as a JSON array, such as `[3, "Ana"]`.
+    ///
+    /// Integers become numbers, other numbers become floats, and strings stay
+    /// strings; any other JSON value is an error.
+    pub fn call_phrase(&self, name: &str, params_json: &str) -> Result<String, JsError> {
+        self.try_call_phrase(name, params_json)
+            .map_err(|e| JsError::new(&e))
+    }
+}
+
+impl WasmLocale {
+    /// [`Self::load`], returning the error message instead of throwing.
+    ///
+    /// A `JsError` can only be built on wasm32, so native callers and tests
+    /// use these methods.
+    pub fn try_load(&mut self, language: &str, source: &str) -> Result<usize, String> {
+        self.locale
+            .load_translations_str(language, source)
+            .map_err(|e| e.to_string())
+    }
+
+    /// [`Self::get_phrase`], returning the error message instead of throwing.
+    pub fn try_get_phrase(&self, name: &str) -> Result<String, String> {
+        self.locale
+            .get_phrase(name)
+            .map(|phrase| phrase.to_string())
+            .map_err(|e| e.to_string())
+    }
+
+    /// [`Self::call_phrase`], returning the error message instead of throwing.
+    pub fn try_call_phrase(&self, name: &str, params_json: &str) -> Result<String, String> {
+        let args = parse_args(params_json)?;
+        self.locale
+            .call_phrase(name, &args)
+            .map(|phrase| phrase.to_string())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Convert a JSON array of arguments into phrase values.
+fn parse_args(params_json: &str) -> Result<Vec<Value>, String> {
+    let json: JsonValue =
+        serde_json::from_str(params_json).map_err(|e| format!("invalid params JSON: {e}"))?;
+    let JsonValue::Array(items) = json else {
+        return Err("params must be a JSON array".to_string());
+    };
+    items
+        .into_iter()
+        .map(|item| match item {
+            JsonValue::Number(n) => match n.as_i64() {
+                Some(i) => Ok(Value::Number(i)),
+                None => Ok(Value::Float(n.as_f64().unwrap_or_default())),
+            },
+            JsonValue::String(s) => Ok(Value::String(s)),
+            other => Err(format!("unsupported param value: {other}")),
+        })
+        .collect()
+}
@@ -0,0 +1,77 @@
+//! Tests that the ASCII `@cap` fast path matches the Unicode path exactly.
+//!
+//! A trailing em dash makes the text non-ASCII, which sends it down the
+//! Unicode path, and is never the character `@cap` capitalizes.
+
+use rlf::Value;
+use rlf::interpreter::TransformKind;
+
+const SLOW_PATH_SUFFIX: &str = "\u{2014}";
+
+fn cap(text: &str, lang: &str) -> String {
+    TransformKind::Cap
+        .execute(&Value::from(text), None, lang)
+        .unwrap()
+}
+
+fn slow_path_cap(text: &str, lang: &str) -> String {
+    let result = cap(&format!("{text}{SLOW_PATH_SUFFIX}"), lang);
+    result.strip_suffix(SLOW_PATH_SUFFIX).unwrap().to_string()
+}
+
+const INPUTS: &[&str] = &[
+    "",
+    "card",
+    "Card",
+    "c",
+    "istanbul",
+    "iI",
+    "1st place",
+    " leading space",
+    "\r\nline",
+    "<b>bold</b>",
+    "<color=#112233>ice</color> age",
+    "<b></b>",
+    "<b>",
+    "<unclosed",
+    "zebra-ZEBRA",
+    "~tilde",
+    "<b>  hello</b>",
+    "  <i>\"quoted\"</i>",
+];
+
+#[test]
+fn fast_path_matches_slow_path() {
+    for lang in [
+        "en", "de", "fr", "nl", "el", "lt", "ru", "tr", "az", "tr-TR",
+    ] {
+        for input in INPUTS {
+            assert_eq!(
+                cap(input, lang),
+                slow_path_cap(input, lang),
+                "@cap mismatch for {input:?} in {lang}"
+            );
+        }
+    }
+}
+
+#[test]
+fn fast_path_matches_slow_path_for_all_ascii_characters() {
+    for byte in 0u8..=127 {
+        let c = char::from(byte);
+        if c == '<' {
+            continue;
+        }
+        let input = format!("{c}abc");
+        for lang in ["en", "tr"] {
+            assert_eq!(cap(&input, lang), slow_path_cap(&input, lang), "{input:?}");
+        }
+    }
+}
+
+#[test]
+fn turkic_locales_keep_dotted_capital_i() {
+    assert_eq!(cap("istanbul", "tr"), "\u{0130}stanbul");
+    assert_eq!(cap("ilham", "az"), "\u{0130}lham");
+    assert_eq!(cap("istanbul", "en"), "Istanbul");
+}
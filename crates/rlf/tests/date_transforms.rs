@@ -0,0 +1,124 @@
+//! Tests for the @date and @time transforms.
+
+use rlf::interpreter::{DateStyle, DateTimeComponent, EvalError, TransformKind};
+use rlf::{Locale, Value};
+
+/// 2024-03-15 10:30:00 UTC.
+const TIMESTAMP: i64 = 1_710_498_600;
+
+fn locale_with(lang: &str, source: &str) -> Locale {
+    let mut locale = Locale::with_language(lang);
+    locale.load_translations_str(lang, source).unwrap();
+    locale
+}
+
+#[test]
+fn english_medium_date() {
+    let locale = locale_with("en", r#"logged($t) = "Logged {@date $t}.";"#);
+    let phrase = locale
+        .call_phrase("logged", &[Value::DateTime(TIMESTAMP)])
+        .unwrap();
+    assert_eq!(phrase.to_string(), "Logged Mar 15, 2024.");
+}
+
+#[test]
+fn english_date_styles() {
+    let value = Value::DateTime(TIMESTAMP);
+    let short = Value::from("short");
+    let long = Value::from("long");
+    assert_eq!(
+        TransformKind::Date
+            .execute(&value, Some(&short), "en")
+            .unwrap(),
+        "3/15/2024"
+    );
+    assert_eq!(
+        TransformKind::Date
+            .execute(&value, Some(&long), "en")
+            .unwrap(),
+        "March 15, 2024"
+    );
+}
+
+#[test]
+fn localized_long_dates() {
+    let value = Value::DateTime(TIMESTAMP);
+    let long = Value::from("long");
+    assert_eq!(
+        TransformKind::Date
+            .execute(&value, Some(&long), "de")
+            .unwrap(),
+        "15. März 2024"
+    );
+    assert_eq!(
+        TransformKind::Date
+            .execute(&value, Some(&long), "es")
+            .unwrap(),
+        "15 de marzo de 2024"
+    );
+    assert_eq!(
+        TransformKind::Date
+            .execute(&value, Some(&long), "fr-CA")
+            .unwrap(),
+        "15 mars 2024"
+    );
+}
+
+#[test]
+fn unknown_language_date_uses_iso_format() {
+    let value = Value::DateTime(TIMESTAMP);
+    assert_eq!(
+        TransformKind::Date.execute(&value, None, "ja").unwrap(),
+        "2024-03-15"
+    );
+}
+
+#[test]
+fn english_and_german_time() {
+    let locale = locale_with("en", r#"at($t) = "at {@time:short $t}";"#);
+    let phrase = locale
+        .call_phrase("at", &[Value::DateTime(TIMESTAMP)])
+        .unwrap();
+    assert_eq!(phrase.to_string(), "at 10:30 AM");
+
+    let value = Value::DateTime(TIMESTAMP + 5 * 3600);
+    assert_eq!(
+        TransformKind::Time.execute(&value, None, "de").unwrap(),
+        "15:30:00"
+    );
+}
+
+#[test]
+fn custom_date_formatter_takes_precedence() {
+    let mut locale = locale_with("en", r#"logged($t) = "{@date:long $t}";"#);
+    locale
+        .transforms_mut()
+        .set_date_formatter(|secs, component, style, lang| {
+            (component == DateTimeComponent::Date && style == DateStyle::Long)
+                .then(|| format!("{lang}:{secs}"))
+        });
+    let phrase = locale
+        .call_phrase("logged", &[Value::DateTime(TIMESTAMP)])
+        .unwrap();
+    assert_eq!(phrase.to_string(), format!("en:{TIMESTAMP}"));
+}
+
+#[test]
+fn date_rejects_non_timestamp_values() {
+    let err = TransformKind::Date
+        .execute(&Value::from("tomorrow"), None, "en")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "date"
+    ));
+}
+
+#[test]
+fn datetime_value_displays_as_iso_8601() {
+    assert_eq!(
+        Value::DateTime(TIMESTAMP).to_string(),
+        "2024-03-15T10:30:00Z"
+    );
+    assert_eq!(Value::DateTime(-1).to_string(), "1969-12-31T23:59:59Z");
+}
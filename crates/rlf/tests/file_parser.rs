@@ -1,7 +1,8 @@
 //! Integration tests for .rlf file parsing
 
 use rlf::parser::{
-    DefinitionKind, PhraseBody, Reference, Segment, TransformContext, VariantEntryBody, parse_file,
+    AstDebug, DefinitionKind, ParamType, ParseError, PhraseAttribute, PhraseBody, Reference,
+    Segment, TransformContext, VariantEntryBody, parse_file, parse_file_recovering,
 };
 use rlf::types::Tag;
 
@@ -34,6 +35,26 @@ fn test_phrase_with_multiple_parameters() {
     assert_eq!(phrases[0].parameters, vec!["amount", "target"]);
 }
 
+#[test]
+fn test_phrase_with_typed_parameters() {
+    let phrases = parse_file(r#"show($n: num, $name: text, $p : phrase, $any) = "{$n}";"#).unwrap();
+    assert_eq!(phrases[0].parameters, vec!["n", "name", "p", "any"]);
+    assert_eq!(
+        phrases[0].parameter_types(),
+        vec![
+            Some(ParamType::Num),
+            Some(ParamType::Text),
+            Some(ParamType::Phrase),
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_parameter_type_is_error() {
+    assert!(parse_file(r#"draw($n: count) = "{$n}";"#).is_err());
+}
+
 #[test]
 fn test_phrase_with_tag() {
     let phrases = parse_file(r#"card = :fem "carta";"#).unwrap();
@@ -277,7 +298,7 @@ fn test_template_with_transforms() {
                 assert_eq!(transforms[0].name, "cap");
                 assert_eq!(transforms[1].name, "a");
             }
-            Segment::Literal(_) => panic!("expected interpolation"),
+            Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
         },
         _ => panic!("expected simple body"),
     }
@@ -479,7 +500,9 @@ fn test_auto_capitalization_adds_cap_transform() {
                     assert_eq!(transforms[0].context, TransformContext::None);
                     assert_eq!(*reference, Reference::Identifier("card".into()));
                 }
-                Segment::Literal(_) => panic!("expected interpolation"),
+                Segment::Literal(_) | Segment::Conditional { .. } => {
+                    panic!("expected interpolation")
+                }
             }
         }
         _ => panic!("expected simple body"),
@@ -501,7 +524,7 @@ fn test_auto_capitalization_with_existing_transforms() {
                 assert_eq!(transforms[1].name, "a");
                 assert_eq!(*reference, Reference::Identifier("card".into()));
             }
-            Segment::Literal(_) => panic!("expected interpolation"),
+            Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
         },
         _ => panic!("expected simple body"),
     }
@@ -522,7 +545,7 @@ fn test_auto_capitalization_with_selector() {
                 assert_eq!(*reference, Reference::Identifier("card".into()));
                 assert_eq!(selectors.len(), 1);
             }
-            Segment::Literal(_) => panic!("expected interpolation"),
+            Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
         },
         _ => panic!("expected simple body"),
     }
@@ -548,7 +571,9 @@ fn test_auto_capitalization_with_underscores() {
                     assert_eq!(transforms[0].name, "cap");
                     assert_eq!(*reference, Reference::Identifier("fire_elemental".into()));
                 }
-                Segment::Literal(_) => panic!("expected interpolation"),
+                Segment::Literal(_) | Segment::Conditional { .. } => {
+                    panic!("expected interpolation")
+                }
             }
         }
         _ => panic!("expected simple body"),
@@ -569,7 +594,9 @@ fn test_no_auto_capitalization_for_lowercase() {
                 Segment::Interpolation { transforms, .. } => {
                     assert!(transforms.is_empty());
                 }
-                Segment::Literal(_) => panic!("expected interpolation"),
+                Segment::Literal(_) | Segment::Conditional { .. } => {
+                    panic!("expected interpolation")
+                }
             }
         }
         _ => panic!("expected simple body"),
@@ -641,7 +668,9 @@ fn test_dynamic_transform_context() {
                     assert_eq!(transforms[0].context, TransformContext::Dynamic("n".into()));
                     assert_eq!(*reference, Reference::Identifier("card".into()));
                 }
-                Segment::Literal(_) => panic!("expected interpolation"),
+                Segment::Literal(_) | Segment::Conditional { .. } => {
+                    panic!("expected interpolation")
+                }
             }
         }
         _ => panic!("expected simple body"),
@@ -661,7 +690,7 @@ fn test_static_transform_context_unchanged() {
                     TransformContext::Static("acc".into())
                 );
             }
-            Segment::Literal(_) => panic!("expected interpolation"),
+            Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
         },
         _ => panic!("expected simple body"),
     }
@@ -680,7 +709,7 @@ fn test_both_static_and_dynamic_context() {
                     TransformContext::Both("lit".into(), "n".into())
                 );
             }
-            Segment::Literal(_) => panic!("expected interpolation"),
+            Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
         },
         _ => panic!("expected simple body"),
     }
@@ -717,7 +746,9 @@ fn test_phrase_call_with_number_literal() {
                     }
                     _ => panic!("expected phrase call"),
                 },
-                Segment::Literal(_) => panic!("expected interpolation"),
+                Segment::Literal(_) | Segment::Conditional { .. } => {
+                    panic!("expected interpolation")
+                }
             }
         }
         _ => panic!("expected simple body"),
@@ -744,7 +775,7 @@ fn test_phrase_call_with_string_literal() {
                 }
                 _ => panic!("expected phrase call"),
             },
-            Segment::Literal(_) => panic!("expected interpolation"),
+            Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
         },
         _ => panic!("expected simple body"),
     }
@@ -768,7 +799,7 @@ fn test_phrase_call_with_string_literal_escape() {
                 }
                 _ => panic!("expected phrase call"),
             },
-            Segment::Literal(_) => panic!("expected interpolation"),
+            Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
         },
         _ => panic!("expected simple body"),
     }
@@ -795,7 +826,7 @@ fn test_phrase_call_mixed_literal_args() {
                 }
                 _ => panic!("expected phrase call"),
             },
-            Segment::Literal(_) => panic!("expected interpolation"),
+            Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
         },
         _ => panic!("expected simple body"),
     }
@@ -995,6 +1026,85 @@ fn test_multiple_default_markers_is_error() {
     );
 }
 
+#[test]
+fn test_duplicate_variant_key_is_error() {
+    let result = parse_file(
+        r#"
+        card = { one: "a", one: "b", other: "c" };
+    "#,
+    );
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("duplicate key 'one' in variant block of 'card'"),
+        "expected duplicate key error, got: {err}"
+    );
+}
+
+#[test]
+fn test_duplicate_match_key_is_error() {
+    let result = parse_file(
+        r#"
+        cards($n) = :match($n) { one: "a card", 1: "one card", one, *other: "{$n} cards" };
+    "#,
+    );
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("duplicate key 'one' in match block of 'cards'"),
+        "expected duplicate key error, got: {err}"
+    );
+}
+
+#[test]
+fn test_match_range_keys_parse() {
+    let defs = parse_file(
+        r#"
+        threat($n) = :match($n) { 0: "calm", 1..3: "tense", 4..: "critical", *other: "unknown" };
+    "#,
+    )
+    .unwrap();
+    let PhraseBody::Match(branches) = &defs[0].body else {
+        panic!("expected match body");
+    };
+    assert_eq!(branches[1].keys[0].value, "1..3");
+    assert_eq!(branches[1].keys[0].numeric_range(), Some(1..=3));
+    assert_eq!(branches[2].keys[0].numeric_range(), Some(4..=i64::MAX));
+    assert_eq!(branches[0].keys[0].numeric_range(), None);
+}
+
+#[test]
+fn test_exact_key_inside_range_is_not_overlap() {
+    let source = r#"
+        threat($n) = :match($n) { 2: "two", ..5: "low", *other: "high" };
+        mood = { 2: "two", ..5: "low", other: "high" };
+    "#;
+    assert!(parse_file(source).is_ok());
+}
+
+#[test]
+fn test_overlapping_match_range_keys_is_error() {
+    for (source, expected) in [
+        (
+            r#"threat($n) = :match($n) { 1..3: "tense", 3..: "critical", *other: "calm" };"#,
+            "overlapping keys '1..3' and '3..' in match block of 'threat'",
+        ),
+        (
+            r#"mood = { 1..3: "tense", 3..: "critical", other: "calm" };"#,
+            "overlapping keys '1..3' and '3..' in variant block of 'mood'",
+        ),
+        (
+            r#"mood = { 0: "calm", 1..: "tense" };"#,
+            "need an 'other' variant",
+        ),
+        (
+            r#"threat($n) = :match($n) { 5..1: "never", *other: "calm" };"#,
+            "empty range key '5..1' in 'threat'",
+        ),
+    ] {
+        let err = parse_file(source).unwrap_err().to_string();
+        assert!(err.contains(expected), "expected '{expected}', got: {err}");
+    }
+}
+
 #[test]
 fn test_default_marker_on_multidimensional_key_is_error() {
     let result = parse_file(
@@ -1430,7 +1540,7 @@ fn test_bodyless_from() {
                         "body-less :from should have no selectors"
                     );
                 }
-                Segment::Literal(_) => {
+                Segment::Literal(_) | Segment::Conditional { .. } => {
                     panic!("expected interpolation segment in body-less :from")
                 }
             }
@@ -1503,3 +1613,160 @@ fn test_phrase_variant_block_with_tags() {
     assert_eq!(phrases[0].tags, vec![Tag::new("a")]);
     assert!(matches!(phrases[0].body, PhraseBody::Variants(_)));
 }
+
+#[test]
+fn test_transform_context_param_not_declared_is_error() {
+    for source in [
+        r#"destroy = "Zerstöre {@der:$case karte}.";"#,
+        r#"draw($n) = "抽{@count($m) card}";"#,
+    ] {
+        let err = parse_file(source).unwrap_err().to_string();
+        assert!(
+            err.contains("transform context parameter '$") && err.contains("is not declared in"),
+            "expected undeclared transform context param error, got: {err}"
+        );
+    }
+}
+
+#[test]
+fn test_conditional_param_not_declared_is_error() {
+    let result = parse_file(
+        r#"
+        draw($n) = "Draw {$n} cards{?$suffix: " {$suffix}"}";
+    "#,
+    );
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("conditional parameter '$suffix' is not declared in 'draw'"),
+        "expected undeclared conditional param error, got: {err}"
+    );
+}
+
+#[test]
+fn test_attributes_before_definition() {
+    let phrases = parse_file(
+        r#"
+        // Main menu
+        @note("Shown on the start button")
+        @maxlen(20)
+        start = "Start";
+        draw($n) = "Draw {$n}.";
+    "#,
+    )
+    .unwrap();
+    assert_eq!(
+        phrases[0].attributes,
+        vec![
+            PhraseAttribute::Note("Shown on the start button".to_string()),
+            PhraseAttribute::MaxLength(20),
+        ]
+    );
+    assert_eq!(phrases[0].name, "start");
+    assert!(phrases[1].attributes.is_empty());
+}
+
+#[test]
+fn test_unknown_attribute_is_error() {
+    assert!(parse_file(r#"@context("x") start = "Start";"#).is_err());
+}
+
+// =============================================================================
+// Error recovery
+// =============================================================================
+
+#[test]
+fn test_recovering_reports_every_error() {
+    let (definitions, errors) = parse_file_recovering(
+        r#"
+        hello = "Hello!";
+        BadName = "text";
+        card = { one: "card", other: "cards" };
+        draw($n) = "Draw {$n} {card:$n}"
+        discard($n) = "Discard {$n}.";
+    "#,
+    );
+    let names: Vec<&str> = definitions.iter().map(|d| d.name.as_str()).collect();
+    assert_eq!(names, vec!["hello", "card", "discard"]);
+    assert_eq!(errors.len(), 2);
+    let lines: Vec<usize> = errors
+        .iter()
+        .map(|e| match e {
+            ParseError::Syntax { line, .. } => *line,
+            other => panic!("unexpected error {other:?}"),
+        })
+        .collect();
+    assert_eq!(lines, vec![3, 6]);
+}
+
+#[test]
+fn test_recovering_skips_multi_line_definition() {
+    let (definitions, errors) = parse_file_recovering(
+        r#"
+        card = {
+            one: "card",
+            other "cards",
+        };
+        hello = "Hello!";
+    "#,
+    );
+    assert_eq!(errors.len(), 1);
+    assert_eq!(definitions.len(), 1);
+    assert_eq!(definitions[0].name, "hello");
+}
+
+#[test]
+fn test_recovering_reports_validation_errors_with_position() {
+    let (definitions, errors) = parse_file_recovering(
+        r#"
+        hello = "Hello!";
+        start() = "Start";
+    "#,
+    );
+    assert_eq!(definitions.len(), 1);
+    assert!(matches!(
+        errors.as_slice(),
+        [ParseError::Syntax { line: 3, message, .. }] if message.contains("empty parameter list")
+    ));
+}
+
+#[test]
+fn test_recovering_matches_parse_file_on_valid_input() {
+    let source = r#"
+        @note("Shown on the main menu")
+        start = "Start";
+        cards($n) = "{$n} cards";
+    "#;
+    let (definitions, errors) = parse_file_recovering(source);
+    assert!(errors.is_empty());
+    assert_eq!(definitions, parse_file(source).unwrap());
+}
+
+#[test]
+fn test_debug_string_snapshot() {
+    let phrases = parse_file(
+        r##"
+        card = :a :fem { one: "card", *other: "cards" };
+        @note("shown on the draw button")
+        draw($n: num, $p) = :from($p) "Draw {@cap @count($n) card:$n}{?$p: " for {$p}"}.";
+        pick($n) = :match($n) {
+            1: "one {@der:acc card}",
+            *other: "{$n} {cards($n, 2, "x")}",
+        };
+        "##,
+    )
+    .unwrap();
+    assert_eq!(
+        phrases.to_debug_string(),
+        r#"term card :a :fem
+  one: "card"
+  *other: "cards"
+@note("shown on the draw button")
+phrase draw($n: num, $p) :from($p)
+  "Draw " {@cap @count($n) card:$n} {?$p: " for " {$p}} "."
+phrase pick($n)
+  :match($n)
+    1: "one " {@der:acc card}
+    *other: {$n} " " {cards($n, 2, "x")}
+"#
+    );
+}
@@ -0,0 +1,41 @@
+//! Tests for the `.rlf` source formatter.
+
+use rlf::formatter::format_file;
+
+#[test]
+fn attribute_lines_preserved() {
+    let input = "  @note(\"Start button\")\n@maxlen(12)\nstart = \"Start\";";
+    assert_eq!(
+        format_file(input, 100),
+        "@note(\"Start button\")\n@maxlen(12)\nstart = \"Start\";\n"
+    );
+}
+
+#[test]
+fn attribute_line_holds_only_attributes() {
+    let cases = [
+        (
+            "@note(\"Start (main menu)\") @maxlen(12)\nstart = \"Start\";",
+            "@note(\"Start (main menu)\") @maxlen(12)\nstart = \"Start\";\n",
+        ),
+        (
+            "@maxlen(12) // button\nstart = \"Start\";",
+            "@maxlen(12) // button\nstart = \"Start\";\n",
+        ),
+        (
+            "@maxlen(8) draw($n)\n  = \"Draw {$n}\";",
+            "@maxlen(8) draw($n) = \"Draw {$n}\";\n",
+        ),
+        (
+            "@note(\"x\") start =\n  \"Start (now)\";",
+            "@note(\"x\") start = \"Start (now)\";\n",
+        ),
+        (
+            "@unknown(1)\nstart = \"Start\";",
+            "@unknown(1) start = \"Start\";\n",
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(format_file(input, 100), expected, "{input}");
+    }
+}
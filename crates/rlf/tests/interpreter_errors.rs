@@ -1,7 +1,11 @@
 //! Tests for error types and error message formatting.
 
-use rlf::{EvalError, LoadError, Locale, PhraseId, compute_suggestions};
+use rlf::parser::ParseError;
+use rlf::{
+    EvalError, LoadError, LoadWarning, Locale, Phrase, PhraseId, Value, compute_suggestions,
+};
 use std::collections::HashMap;
+use std::error::Error;
 use std::io::{self, ErrorKind};
 use std::path::PathBuf;
 
@@ -56,6 +60,7 @@ fn load_error_parse_displays_location() {
         line: 42,
         column: 15,
         message: "unexpected token".to_string(),
+        source: None,
     };
     let msg = err.to_string();
     assert!(msg.contains("translations/ru.rlf"));
@@ -63,6 +68,31 @@ fn load_error_parse_displays_location() {
     assert!(msg.contains("unexpected token"));
 }
 
+#[test]
+fn load_error_parse_source_is_parse_error() {
+    let mut locale = Locale::new();
+    let err = locale
+        .load_translations_str("ru", "card = \"unterminated;")
+        .unwrap_err();
+    let source = err.source().expect("parse failure should have a source");
+    let parse_error = source
+        .downcast_ref::<ParseError>()
+        .expect("source should be a ParseError");
+    assert!(parse_error.to_string().starts_with("syntax error at"));
+    assert!(parse_error.source().is_none());
+}
+
+#[test]
+fn load_warning_is_std_error() {
+    let warning = LoadWarning::RedundantPassthroughBlock {
+        name: "card".to_string(),
+        language: "de".to_string(),
+    };
+    let error: &dyn Error = &warning;
+    assert!(error.to_string().contains("redundant passthrough"));
+    assert!(error.source().is_none());
+}
+
 #[test]
 fn load_error_no_path_for_reload() {
     let err = LoadError::NoPathForReload {
@@ -173,6 +203,45 @@ fn unknown_transform_via_eval_str() {
     assert!(msg.contains("@nonexistent"));
 }
 
+#[test]
+fn german_transform_in_english_reports_wrong_language() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"karte = "card";"#)
+        .unwrap();
+
+    let err = locale.eval_str("{@der karte}", HashMap::new()).unwrap_err();
+    let EvalError::WrongLanguageTransform {
+        ref name,
+        ref available_in,
+    } = err
+    else {
+        panic!("expected WrongLanguageTransform, got: {err:?}");
+    };
+    assert_eq!(name, "der");
+    assert_eq!(available_in, &["de"]);
+    assert!(err.to_string().contains("defined for: de"));
+}
+
+#[test]
+fn count_transform_in_german_lists_defining_languages() {
+    let mut locale = Locale::with_language("de");
+    locale
+        .load_translations_str("de", r#"karte = "Karte";"#)
+        .unwrap();
+
+    let err = locale
+        .eval_str("{@count karte}", HashMap::new())
+        .unwrap_err();
+    let EvalError::WrongLanguageTransform { available_in, .. } = err else {
+        panic!("expected WrongLanguageTransform, got: {err:?}");
+    };
+    assert_eq!(
+        available_in,
+        ["en", "zh", "ja", "ko", "vi", "th", "bn", "ms"]
+    );
+}
+
 #[test]
 fn unknown_transform_displays_name() {
     let err = EvalError::UnknownTransform {
@@ -195,3 +264,94 @@ fn phrase_not_found_by_id_displays_id() {
     let msg = err.to_string();
     assert!(msg.contains("99999"));
 }
+
+#[test]
+fn typed_parameter_rejects_wrong_value_type() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            card = { one: "card", *other: "cards" };
+            draw($n: num) = "Draw {$n} {card:$n}.";
+            greet($name: text) = "Hello, {$name}!";
+            "#,
+        )
+        .unwrap();
+
+    let err = locale
+        .call_phrase("draw", &[Value::from("three")])
+        .unwrap_err();
+    let EvalError::TypeMismatch {
+        ref phrase,
+        ref parameter,
+        ref expected,
+        ref value,
+    } = err
+    else {
+        panic!("expected TypeMismatch, got: {err:?}");
+    };
+    assert_eq!(phrase, "draw");
+    assert_eq!(parameter, "n");
+    assert_eq!(expected, "num");
+    assert_eq!(value, "three");
+    assert_eq!(
+        err.to_string(),
+        "phrase 'draw' parameter '$n' expects num, got 'three'"
+    );
+
+    let card = Phrase::builder().text("card".to_string()).build();
+    assert!(matches!(
+        locale.call_phrase("greet", &[Value::from(card)]),
+        Err(EvalError::TypeMismatch { .. })
+    ));
+    assert_eq!(
+        locale
+            .call_phrase("draw", &[Value::from(3)])
+            .unwrap()
+            .to_string(),
+        "Draw 3 cards."
+    );
+}
+
+#[test]
+fn typed_parameter_checked_in_nested_call() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            draw($n: num) = "Draw {$n}.";
+            wrapper($s) = "{draw($s)}";
+            "#,
+        )
+        .unwrap();
+
+    let err = locale
+        .call_phrase("wrapper", &[Value::from("x")])
+        .unwrap_err();
+    assert!(matches!(err, EvalError::TypeMismatch { .. }));
+}
+
+#[test]
+fn untyped_translation_checked_against_source_declaration() {
+    let mut locale = Locale::builder().language("de").build();
+    locale
+        .load_translations_str("en", r#"draw($n: num) = "Draw {$n}.";"#)
+        .unwrap();
+    locale
+        .load_translations_str("de", r#"draw($n) = "Ziehe {$n}.";"#)
+        .unwrap();
+
+    let err = locale
+        .call_phrase("draw", &[Value::from("drei")])
+        .unwrap_err();
+    assert!(matches!(err, EvalError::TypeMismatch { ref expected, .. } if expected == "num"));
+    assert_eq!(
+        locale
+            .call_phrase("draw", &[Value::from(3)])
+            .unwrap()
+            .to_string(),
+        "Ziehe 3."
+    );
+}
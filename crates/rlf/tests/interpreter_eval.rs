@@ -1,7 +1,7 @@
 //! Integration tests for interpreter evaluation.
 
-use rlf::interpreter::EvalError;
-use rlf::{Locale, PhraseId, PhraseRegistry, Value};
+use rlf::interpreter::{EvalError, TransformRegistry};
+use rlf::{Locale, OutputTarget, PhraseId, PhraseRegistry, Value};
 use std::collections::HashMap;
 
 // =============================================================================
@@ -117,6 +117,51 @@ fn eval_numeric_variant_selector_russian() {
     assert_eq!(twenty_one.to_string(), "Возьмите 21 карта.");
 }
 
+#[test]
+fn eval_ordinal_variant_selector_english() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        nth = { one: "st", two: "nd", few: "rd", other: "th" };
+        attempt($n) = "the {$n}{nth:#$n} time";
+    "#,
+        )
+        .unwrap();
+
+    for (n, expected) in [
+        (1, "the 1st time"),
+        (2, "the 2nd time"),
+        (3, "the 3rd time"),
+        (4, "the 4th time"),
+        (11, "the 11th time"),
+        (21, "the 21st time"),
+    ] {
+        let result = registry
+            .call_phrase("en", "attempt", &[Value::from(n)])
+            .unwrap();
+        assert_eq!(result.to_string(), expected);
+    }
+}
+
+#[test]
+fn eval_ordinal_selector_is_distinct_from_cardinal() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        form = { one: "one", two: "two", few: "few", other: "other" };
+        both($n) = "{form:$n}/{form:#$n}";
+    "#,
+        )
+        .unwrap();
+
+    let result = registry
+        .call_phrase("en", "both", &[Value::from(2)])
+        .unwrap();
+    assert_eq!(result.to_string(), "other/two");
+}
+
 // =============================================================================
 // Multi-dimensional Variants
 // =============================================================================
@@ -2950,6 +2995,66 @@ fn eval_phrase_call_with_string_literal_escape() {
     assert_eq!(result.to_string(), "[He said \"hi\"]");
 }
 
+#[test]
+fn eval_phrase_call_with_named_args() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        card = { one: "card", other: "cards" };
+        draw($n, $target) = "Draw {$n} {card:$n} for {$target}.";
+        positional = "{draw(3, "you")}";
+        named = "{draw(n: 3, target: "you")}";
+        reversed = "{draw(target: "you", n: 3)}";
+        mixed = "{draw(target: "you", 3)}";
+    "#,
+        )
+        .unwrap();
+    let expected = registry.get_phrase("en", "positional").unwrap().to_string();
+    assert_eq!(expected, "Draw 3 cards for you.");
+    for name in ["named", "reversed", "mixed"] {
+        let result = registry.get_phrase("en", name).unwrap();
+        assert_eq!(result.to_string(), expected, "{name}");
+    }
+}
+
+#[test]
+fn error_unknown_named_arg() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        draw($n, $target) = "Draw {$n} for {$target}.";
+        bad = "{draw(count: 3, target: "you")}";
+    "#,
+        )
+        .unwrap();
+    let err = registry.get_phrase("en", "bad").unwrap_err();
+    assert!(matches!(
+        &err,
+        EvalError::UnknownArgument { phrase, name } if phrase == "draw" && name == "count"
+    ));
+    assert_eq!(err.to_string(), "phrase 'draw' has no parameter '$count'");
+}
+
+#[test]
+fn error_duplicate_named_arg() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        draw($n, $target) = "Draw {$n} for {$target}.";
+        bad = "{draw(n: 3, n: 4)}";
+    "#,
+        )
+        .unwrap();
+    let err = registry.get_phrase("en", "bad").unwrap_err();
+    assert!(matches!(
+        &err,
+        EvalError::DuplicateArgument { phrase, name } if phrase == "draw" && name == "n"
+    ));
+}
+
 #[test]
 fn eval_phrase_call_with_number_literal_zero() {
     let mut registry = PhraseRegistry::new();
@@ -3809,3 +3914,278 @@ fn eval_phrase_variant_block_in_optional_context() {
         .unwrap();
     assert_eq!(result.to_string(), "to draw 3 cards");
 }
+
+// =============================================================================
+// Conditionals
+// =============================================================================
+
+#[test]
+fn eval_conditional_with_parameter_present() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"draw($n, $suffix) = "Draw {$n} cards{?$suffix: " {$suffix}"}.";"#)
+        .unwrap();
+    let result = registry
+        .call_phrase("en", "draw", &[Value::from(3), Value::from("now")])
+        .unwrap();
+    assert_eq!(result.to_string(), "Draw 3 cards now.");
+}
+
+#[test]
+fn eval_conditional_with_empty_parameter() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"draw($n, $suffix) = "Draw {$n} cards{?$suffix: " {$suffix}"}.";"#)
+        .unwrap();
+    let result = registry
+        .call_phrase("en", "draw", &[Value::from(3), Value::from("")])
+        .unwrap();
+    assert_eq!(result.to_string(), "Draw 3 cards.");
+}
+
+#[test]
+fn eval_conditional_with_parameter_absent() {
+    let registry = PhraseRegistry::new();
+    let params = HashMap::from([("n".to_string(), Value::from(3))]);
+    let result = registry
+        .eval_str(r#"Draw {$n} cards{?$suffix: " {$suffix}"}."#, "en", params)
+        .unwrap();
+    assert_eq!(result.to_string(), "Draw 3 cards.");
+}
+
+// =============================================================================
+// Coalesce ({$a || $b || "fallback"})
+// =============================================================================
+
+fn coalesce_registry() -> PhraseRegistry {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"display($nick, $name) = "{$nick || $name || "Player"} joined.";"#)
+        .unwrap();
+    registry
+}
+
+#[test]
+fn eval_coalesce_picks_first_alternative() {
+    let result = coalesce_registry()
+        .call_phrase("en", "display", &[Value::from("Ace"), Value::from("Ana")])
+        .unwrap();
+    assert_eq!(result.to_string(), "Ace joined.");
+}
+
+#[test]
+fn eval_coalesce_skips_empty_alternative() {
+    let result = coalesce_registry()
+        .call_phrase("en", "display", &[Value::from(""), Value::from("Ana")])
+        .unwrap();
+    assert_eq!(result.to_string(), "Ana joined.");
+}
+
+#[test]
+fn eval_coalesce_falls_back_to_literal() {
+    let result = coalesce_registry()
+        .call_phrase("en", "display", &[Value::from(""), Value::from("")])
+        .unwrap();
+    assert_eq!(result.to_string(), "Player joined.");
+}
+
+#[test]
+fn eval_coalesce_treats_unbound_parameter_as_absent() {
+    let registry = PhraseRegistry::new();
+    let params = HashMap::from([("name".to_string(), Value::from("Ana"))]);
+    let result = registry
+        .eval_str(r#"{@upper $nick || $name} joined."#, "en", params)
+        .unwrap();
+    assert_eq!(result.to_string(), "ANA joined.");
+}
+
+#[test]
+fn eval_coalesce_uses_defined_term() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            glossary_term = "keyword";
+            see = "See {glossary_term || "term"}.";
+        "#,
+        )
+        .unwrap();
+    let result = registry.get_phrase("en", "see").unwrap();
+    assert_eq!(result.to_string(), "See keyword.");
+}
+
+#[test]
+fn eval_coalesce_treats_undefined_term_as_absent() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"see = "See {glossary_term || "term"}.";"#)
+        .unwrap();
+    assert_eq!(locale.get_phrase("see").unwrap().to_string(), "See term.");
+
+    // Outside a coalesce, a missing term is still an error
+    locale
+        .load_translations_str("en", r#"see = "See {glossary_term}.";"#)
+        .unwrap();
+    assert!(matches!(
+        locale.get_phrase("see"),
+        Err(EvalError::PhraseNotFound { .. })
+    ));
+}
+
+#[test]
+fn eval_coalesce_accepts_single_bar_separator() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            keyword = "keyword";
+            see = "See {glossary_term | "term"}.";
+            see_keyword = "See {keyword | "term"}.";
+        "#,
+        )
+        .unwrap();
+    assert_eq!(locale.get_phrase("see").unwrap().to_string(), "See term.");
+    assert_eq!(
+        locale.get_phrase("see_keyword").unwrap().to_string(),
+        "See keyword."
+    );
+}
+
+#[test]
+fn eval_coalesce_treats_undefined_phrase_call_as_absent() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            cards($n) = "{$n} cards";
+            draw($n) = "Draw {cards($n) || "some cards"}.";
+            discard($n) = "Discard {keyword_list($n) | cards($n)}.";
+            gain($n) = "Gain {relics($n) || "relics"}.";
+        "#,
+        )
+        .unwrap();
+    let n = [Value::from(2)];
+    assert_eq!(
+        locale.call_phrase("draw", &n).unwrap().to_string(),
+        "Draw 2 cards."
+    );
+    assert_eq!(
+        locale.call_phrase("discard", &n).unwrap().to_string(),
+        "Discard 2 cards."
+    );
+    assert_eq!(
+        locale.call_phrase("gain", &n).unwrap().to_string(),
+        "Gain relics."
+    );
+}
+
+#[test]
+fn eval_coalesce_requires_an_alternative() {
+    let mut registry = PhraseRegistry::new();
+    assert!(registry.load_phrases(r#"bad($a) = "{$a ||}";"#).is_err());
+    assert!(registry.load_phrases(r#"bad($a) = "{|| $a}";"#).is_err());
+}
+
+// =============================================================================
+// HTML Output Target
+// =============================================================================
+
+fn html_locale(source: &str) -> Locale {
+    let mut locale = Locale::new();
+    locale.load_translations_str("en", source).unwrap();
+    locale.set_output_target(OutputTarget::Html);
+    locale
+}
+
+#[test]
+fn html_target_escapes_parameter_values() {
+    let locale = html_locale(r#"says($name) = "<b>{$name}</b> says hi";"#);
+    let result = locale
+        .call_phrase("says", &[Value::from("<script>alert('x')</script> & co")])
+        .unwrap();
+    assert_eq!(
+        result.to_string(),
+        "<b>&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt; &amp; co</b> says hi"
+    );
+}
+
+#[test]
+fn html_target_keeps_template_markup_and_phrase_arguments() {
+    let locale = html_locale(
+        r#"
+        ancient = "<color=#2E7D32><b>Ancient</b></color>";
+        dissolve($target) = "Dissolve <i>{$target}</i>.";
+    "#,
+    );
+    let ancient = locale.get_phrase("ancient").unwrap();
+    let result = locale
+        .call_phrase("dissolve", &[Value::Phrase(ancient)])
+        .unwrap();
+    assert_eq!(
+        result.to_string(),
+        "Dissolve <i><color=#2E7D32><b>Ancient</b></color></i>."
+    );
+}
+
+#[test]
+fn html_target_escapes_nested_phrase_parameters_once() {
+    let locale = html_locale(
+        r#"
+        name($n) = "<b>{$n}</b>";
+        greet($n) = "Hi {name($n)}";
+    "#,
+    );
+    let result = locale.call_phrase("greet", &[Value::from("a&b")]).unwrap();
+    assert_eq!(result.to_string(), "Hi <b>a&amp;b</b>");
+}
+
+#[test]
+fn html_target_escapes_parameter_transform_context() {
+    let locale = html_locale(
+        r#"
+        edge = "edge";
+        border($owner) = "<i>{@genitive($owner) edge}</i>";
+        price($amount, $code) = "{@currency($code) $amount}";
+    "#,
+    );
+    let border = locale
+        .call_phrase("border", &[Value::from("<script>x</script>")])
+        .unwrap();
+    assert_eq!(
+        border.to_string(),
+        "<i>the edge of the &lt;script&gt;x&lt;/script&gt;</i>"
+    );
+    let price = locale
+        .call_phrase("price", &[Value::from(5), Value::from("<b>")])
+        .unwrap();
+    assert!(!price.to_string().contains("<b>"));
+}
+
+#[test]
+fn html_target_applies_to_phrase_registry_with_transforms() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"says($name) = "<b>{$name}</b> says hi";"#)
+        .unwrap();
+    let mut transforms = TransformRegistry::new();
+    transforms.set_output_target(OutputTarget::Html);
+    let args = [Value::from("<script>")];
+    let escaped = registry
+        .call_phrase_with_transforms(&transforms, "en", "says", &args)
+        .unwrap();
+    assert_eq!(escaped.to_string(), "<b>&lt;script&gt;</b> says hi");
+    let plain = registry.call_phrase("en", "says", &args).unwrap();
+    assert_eq!(plain.to_string(), "<b><script></b> says hi");
+}
+
+#[test]
+fn plain_target_does_not_escape() {
+    let mut locale = html_locale(r#"says($name) = "{$name}!";"#);
+    locale.set_output_target(OutputTarget::Plain);
+    let result = locale
+        .call_phrase("says", &[Value::from("<script>")])
+        .unwrap();
+    assert_eq!(result.to_string(), "<script>!");
+}
@@ -1,8 +1,13 @@
 //! Tests for interpreter foundation: registry, context, plural rules.
 
 use rlf::Value;
-use rlf::interpreter::{EvalContext, EvalError, PhraseRegistry, plural_category};
+use rlf::interpreter::{
+    EvalContext, EvalError, LanguageSupport, LoadError, PhraseRegistry, language_support,
+    plural_category, supported_languages,
+};
 use std::collections::HashMap;
+use std::io::Cursor;
+use std::path::PathBuf;
 
 // === PhraseRegistry Tests ===
 
@@ -65,6 +70,133 @@ fn registry_detects_hash_collision() {
     );
 }
 
+#[test]
+fn registry_load_from_reader() {
+    let mut registry = PhraseRegistry::new();
+    let reader = Cursor::new(
+        r#"
+        hello = "Hello, world!";
+        card = { one: "card", other: "cards" };
+    "#,
+    );
+    assert_eq!(registry.load_phrases_from_reader(reader).unwrap(), 2);
+    let phrase = registry.get_phrase("en", "hello").unwrap();
+    assert_eq!(phrase.to_string(), "Hello, world!");
+}
+
+#[test]
+fn registry_load_from_reader_reports_line_and_column() {
+    let mut registry = PhraseRegistry::new();
+    let reader = Cursor::new("hello = \"Hello\";\n\nbroken = ;\n");
+    let error = registry.load_phrases_from_reader(reader).unwrap_err();
+    let LoadError::Parse { path, line, .. } = error else {
+        panic!("expected parse error, got {error:?}");
+    };
+    assert_eq!(path, PathBuf::from("<reader>"));
+    assert_eq!(line, 3);
+}
+
+#[test]
+fn registry_load_from_reader_rejects_invalid_utf8() {
+    let mut registry = PhraseRegistry::new();
+    let reader = Cursor::new(b"hello = \"\xFF\";".to_vec());
+    let error = registry.load_phrases_from_reader(reader).unwrap_err();
+    assert!(error.to_string().contains("invalid UTF-8"), "got: {error}");
+}
+
+#[test]
+fn registry_remove_makes_lookups_fail() {
+    let mut registry = PhraseRegistry::with_capacity(4);
+    registry
+        .load_phrases(
+            r#"
+        card = { one: "card", other: "cards" };
+        draw($n) = "Draw {$n} {card:$n}.";
+        hello = "Hello!";
+    "#,
+        )
+        .unwrap();
+    registry.eval_str("{hello}", "en", HashMap::new()).unwrap();
+
+    let removed = registry.remove("card").unwrap();
+    assert_eq!(removed.name, "card");
+    assert!(registry.remove("card").is_none());
+    assert_eq!(registry.len(), 2);
+    assert_eq!(registry.template_cache_len(), 0);
+
+    assert!(registry.get("card").is_none());
+    let id = rlf::PhraseId::from_name("card").as_u128();
+    assert!(registry.get_by_id(id).is_none());
+    assert!(registry.name_for_id(id).is_none());
+    assert!(matches!(
+        registry.get_phrase("en", "card"),
+        Err(EvalError::PhraseNotFound { .. })
+    ));
+    // Phrases that reference the removed one fail too.
+    assert!(matches!(
+        registry.call_phrase("en", "draw", &[Value::from(2)]),
+        Err(EvalError::PhraseNotFound { .. })
+    ));
+    assert_eq!(
+        registry.get_phrase("en", "hello").unwrap().to_string(),
+        "Hello!"
+    );
+}
+
+#[test]
+fn registry_remove_by_id_and_clear() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"hello = "Hello!"; bye = "Bye!";"#)
+        .unwrap();
+
+    let id = rlf::PhraseId::from_name("hello").as_u128();
+    assert_eq!(registry.remove_by_id(id).unwrap().name, "hello");
+    assert!(registry.remove_by_id(id).is_none());
+    assert!(registry.get("hello").is_none());
+
+    registry.clear();
+    assert!(registry.is_empty());
+    assert!(registry.get("bye").is_none());
+
+    // The registry stays usable after clearing.
+    registry.load_phrases(r#"bye = "Later!";"#).unwrap();
+    assert_eq!(
+        registry.get_phrase("en", "bye").unwrap().to_string(),
+        "Later!"
+    );
+}
+
+#[test]
+fn registry_content_hash_is_stable_and_tracks_changes() {
+    let source = r#"
+        card = :a { one: "card", other: "cards" };
+        draw($n) = "Draw {$n} {card:$n}.";
+        hello = "Hello!";
+    "#;
+    let load = |content: &str| {
+        let mut registry = PhraseRegistry::new();
+        registry.load_phrases(content).unwrap();
+        registry
+    };
+    let first = load(source);
+    let second = load(source);
+    assert_eq!(first.content_hash(), second.content_hash());
+
+    // Order and formatting of the source do not matter.
+    let reordered = load(
+        r#"hello = "Hello!"; draw($n) = "Draw {$n} {card:$n}.";
+        card = :a { one: "card", other: "cards" };"#,
+    );
+    assert_eq!(first.content_hash(), reordered.content_hash());
+
+    let changed = load(&source.replace("Hello!", "Hi!"));
+    assert_ne!(first.content_hash(), changed.content_hash());
+    let retagged = load(&source.replace(":a {", ":an {"));
+    assert_ne!(first.content_hash(), retagged.content_hash());
+    assert_ne!(first.content_hash(), PhraseRegistry::new().content_hash());
+}
+
 // === EvalContext Tests ===
 
 #[test]
@@ -147,6 +279,85 @@ fn plural_russian() {
     assert_eq!(plural_category("ru", 25), "many");
 }
 
+#[test]
+fn plural_russian_exhaustive() {
+    let cases = [
+        (0, "many"),
+        (1, "one"),
+        (2, "few"),
+        (4, "few"),
+        (5, "many"),
+        (11, "many"),
+        (12, "many"),
+        (14, "many"),
+        (21, "one"),
+        (22, "few"),
+        (25, "many"),
+        (100, "many"),
+        (101, "one"),
+        (111, "many"),
+        (112, "many"),
+        (1001, "one"),
+    ];
+    for (n, expected) in cases {
+        assert_eq!(plural_category("ru", n), expected, "ru n={n}");
+    }
+}
+
+#[test]
+fn russian_variant_selection_uses_plural_category() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            card = { one: "карта", few: "карты", many: "карт" };
+            draw($n) = "{$n} {card:$n}";
+        "#,
+        )
+        .unwrap();
+    let draw = |n: i64| {
+        registry
+            .call_phrase("ru", "draw", &[Value::from(n)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(draw(1), "1 карта");
+    assert_eq!(draw(2), "2 карты");
+    assert_eq!(draw(5), "5 карт");
+    assert_eq!(draw(11), "11 карт");
+    assert_eq!(draw(21), "21 карта");
+}
+
+#[test]
+fn plural_ukrainian_matches_russian() {
+    for n in [1, 2, 5, 11, 21, 22, 25, 100, 101] {
+        assert_eq!(
+            plural_category("uk", n),
+            plural_category("ru", n),
+            "uk n={n}"
+        );
+    }
+}
+
+#[test]
+fn plural_russian_with_region_subtag() {
+    assert_eq!(plural_category("ru-RU", 2), "few");
+    assert_eq!(plural_category("ru_RU", 5), "many");
+    assert_eq!(plural_category("uk-UA", 21), "one");
+}
+
+#[test]
+fn plural_other_slavic_languages() {
+    assert_eq!(plural_category("cs", 1), "one");
+    assert_eq!(plural_category("cs", 3), "few");
+    assert_eq!(plural_category("cs", 5), "other");
+    assert_eq!(plural_category("pl", 22), "few");
+    assert_eq!(plural_category("pl", 25), "many");
+    assert_eq!(plural_category("hr", 21), "one");
+    assert_eq!(plural_category("sr-Latn", 3), "few");
+    assert_eq!(plural_category("sl", 2), "two");
+}
+
 #[test]
 fn plural_arabic() {
     // Arabic has all 6 categories
@@ -158,6 +369,16 @@ fn plural_arabic() {
     assert_eq!(plural_category("ar", 100), "other");
 }
 
+#[test]
+fn plural_irish() {
+    // Irish distinguishes one, two, few (3-6), many (7-10), and other
+    assert_eq!(plural_category("ga", 1), "one");
+    assert_eq!(plural_category("ga", 2), "two");
+    assert_eq!(plural_category("ga", 5), "few");
+    assert_eq!(plural_category("ga", 8), "many");
+    assert_eq!(plural_category("ga", 20), "other");
+}
+
 #[test]
 fn plural_japanese() {
     // Japanese has only "other" for all numbers
@@ -212,3 +433,34 @@ fn plural_all_supported_languages() {
         );
     }
 }
+
+// === Supported Languages Tests ===
+
+#[test]
+fn supported_languages_lists_known_codes() {
+    let languages = supported_languages();
+    for code in ["de", "ja", "ar", "ga", "fi"] {
+        assert!(languages.contains(&code), "missing {code}");
+    }
+    assert!(!languages.contains(&"tlh"));
+    assert!(languages.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn language_support_reports_features() {
+    assert_eq!(
+        language_support("de-AT"),
+        Some(LanguageSupport {
+            code: "de",
+            plural_rules: true,
+            transforms: true,
+        })
+    );
+    let polish = language_support("pl").unwrap();
+    assert!(polish.plural_rules);
+    assert!(!polish.transforms);
+    let finnish = language_support("fi").unwrap();
+    assert!(!finnish.plural_rules);
+    assert!(finnish.transforms);
+    assert_eq!(language_support("tlh"), None);
+}
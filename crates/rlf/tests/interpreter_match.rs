@@ -952,3 +952,258 @@ fn match_no_branch_matches_no_default_is_error() {
         .unwrap();
     assert_eq!(result.to_string(), "feminine");
 }
+
+// =============================================================================
+// Selected variant metadata
+// =============================================================================
+
+#[test]
+fn match_records_selected_variant() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        draw($n) = :match($n) {
+            1: "Draw a card.",
+            *other: "Draw {$n} cards.",
+        };
+    "#,
+        )
+        .unwrap();
+
+    let one = registry
+        .call_phrase("en", "draw", &[Value::from(1)])
+        .unwrap();
+    let three = registry
+        .call_phrase("en", "draw", &[Value::from(3)])
+        .unwrap();
+
+    assert_eq!(one.selected_variant().unwrap().as_str(), "1");
+    assert_eq!(three.selected_variant().unwrap().as_str(), "other");
+    assert_eq!(three.to_string(), "Draw 3 cards.");
+}
+
+#[test]
+fn match_records_cldr_category_key() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        cards($n) = :match($n) {
+            one: "{$n} карта",
+            few: "{$n} карты",
+            *many: "{$n} карт",
+        };
+    "#,
+        )
+        .unwrap();
+
+    let key = |n: i64| {
+        let phrase = registry
+            .call_phrase("ru", "cards", &[Value::from(n)])
+            .unwrap();
+        phrase.selected_variant().unwrap().as_str().to_string()
+    };
+    assert_eq!(key(1), "one");
+    assert_eq!(key(3), "few");
+    assert_eq!(key(5), "many");
+}
+
+#[test]
+fn match_records_tag_key_for_from_phrase() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        ally = :masc "ally";
+        destroy($t) = :from($t) :match($t) {
+            masc: "destroy him",
+            *fem: "destroy her",
+        };
+    "#,
+        )
+        .unwrap();
+
+    let ally = registry.get_phrase("en", "ally").unwrap();
+    let result = registry
+        .call_phrase("en", "destroy", &[Value::Phrase(ally)])
+        .unwrap();
+    assert_eq!(result.selected_variant().unwrap().as_str(), "masc");
+}
+
+#[test]
+fn variant_selection_records_selected_key() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        card = { one: "card", *other: "cards" };
+        draw($n) = "Draw {$n} {card:$n}.";
+        draw_one = "Draw a {card:one}.";
+    "#,
+        )
+        .unwrap();
+
+    let key = |n: i64| {
+        let phrase = registry
+            .call_phrase("en", "draw", &[Value::from(n)])
+            .unwrap();
+        phrase.selected_variant().unwrap().as_str().to_string()
+    };
+    assert_eq!(key(1), "one");
+    assert_eq!(key(3), "other");
+    assert!(
+        registry
+            .get_phrase("en", "draw_one")
+            .unwrap()
+            .selected_variant()
+            .is_none()
+    );
+}
+
+#[test]
+fn non_match_phrases_have_no_selected_variant() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        hello = "Hello";
+        card = { one: "card", *other: "cards" };
+    "#,
+        )
+        .unwrap();
+
+    assert!(
+        registry
+            .get_phrase("en", "hello")
+            .unwrap()
+            .selected_variant()
+            .is_none()
+    );
+    assert!(
+        registry
+            .get_phrase("en", "card")
+            .unwrap()
+            .selected_variant()
+            .is_none()
+    );
+}
+
+// =============================================================================
+// Numeric range keys
+// =============================================================================
+
+#[test]
+fn match_range_keys_select_each_bucket() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        threat($n) = :match($n) {
+            0: "calm",
+            1..3: "tense",
+            4..: "critical",
+            *other: "unknown",
+        };
+    "#,
+        )
+        .unwrap();
+
+    let threat = |n: i64| {
+        registry
+            .call_phrase("en", "threat", &[Value::from(n)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(threat(0), "calm");
+    assert_eq!(threat(1), "tense");
+    assert_eq!(threat(3), "tense");
+    assert_eq!(threat(4), "critical");
+    assert_eq!(threat(1000), "critical");
+    assert_eq!(threat(-1), "unknown");
+}
+
+#[test]
+fn match_range_key_wins_over_plural_category() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        turns($n) = :match($n) {
+            ..1: "last turn",
+            one: "one turn",
+            *other: "{$n} turns",
+        };
+    "#,
+        )
+        .unwrap();
+
+    let result = registry
+        .call_phrase("en", "turns", &[Value::from(1)])
+        .unwrap();
+    assert_eq!(result.to_string(), "last turn");
+    assert_eq!(result.selected_variant().unwrap().as_str(), "..1");
+
+    let result = registry
+        .call_phrase("en", "turns", &[Value::from("5")])
+        .unwrap();
+    assert_eq!(result.to_string(), "5 turns");
+}
+
+#[test]
+fn exact_key_inside_range_takes_precedence() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        threat($n) = :match($n) {
+            2: "two",
+            1..3: "tense",
+            *other: "calm",
+        };
+    "#,
+        )
+        .unwrap();
+
+    let threat = |n: i64| {
+        registry
+            .call_phrase("en", "threat", &[Value::from(n)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(threat(1), "tense");
+    assert_eq!(threat(2), "two");
+    assert_eq!(threat(3), "tense");
+}
+
+#[test]
+fn term_range_keys_select_each_bucket() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+        threat = { 0: "calm", 2: "edgy", 1..3: "tense", 4..: "critical", other: "unknown" };
+        status($n) = "{threat:$n}";
+    "#,
+        )
+        .unwrap();
+
+    let status = |n: i64| {
+        registry
+            .call_phrase("en", "status", &[Value::from(n)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(status(0), "calm");
+    assert_eq!(status(1), "tense");
+    assert_eq!(status(2), "edgy");
+    assert_eq!(status(3), "tense");
+    assert_eq!(status(40), "critical");
+    assert_eq!(status(-1), "unknown");
+
+    let result = registry
+        .call_phrase("ru", "status", &[Value::from(-5)])
+        .unwrap();
+    assert_eq!(result.to_string(), "unknown");
+    assert_eq!(result.selected_variant().unwrap().as_str(), "other");
+}
@@ -1,8 +1,13 @@
 //! Integration tests for transform execution in the interpreter.
 
-use rlf::interpreter::{EvalError, Locale, TransformKind, TransformRegistry};
-use rlf::{Phrase, PhraseRegistry, Tag, Value, VariantKey};
+use rlf::interpreter::{
+    ApostropheStyle, DigitStyle, EvalError, Locale, LookupEntry, LookupTable, OutputTarget,
+    Register, SharpSStyle, TransformKind, TransformRegistry,
+};
+use rlf::{Phrase, PhraseId, PhraseRegistry, Tag, Value, VariantKey};
 use std::collections::HashMap;
+use std::ptr;
+use std::slice;
 
 // =============================================================================
 // Basic Case Transforms
@@ -215,6 +220,1169 @@ fn test_lower_greek() {
     assert_eq!(result.to_string(), "\u{03b1}\u{03b2}\u{03b3}");
 }
 
+#[test]
+fn test_lower_greek_final_sigma() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"lower_greek($text) = "{@lower $text}";"#)
+        .unwrap();
+    let lower = |text: &str| {
+        registry
+            .call_phrase("el", "lower_greek", &[Value::from(text)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(lower("ΟΔΟΣ"), "οδος");
+    assert_eq!(lower("ΟΔΟΣ, ΟΔΟΣ."), "οδος, οδος.");
+}
+
+#[test]
+fn test_lower_greek_medial_sigma() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"lower_greek($text) = "{@lower $text}";"#)
+        .unwrap();
+    let result = registry
+        .call_phrase("el", "lower_greek", &[Value::from("ΣΟΦΙΑ ΚΟΣΜΟΣ")])
+        .unwrap();
+    assert_eq!(result.to_string(), "σοφια κοσμος");
+}
+
+#[test]
+fn test_lower_greek_final_sigma_skips_markup() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"lower_greek($text) = "{@lower $text}";"#)
+        .unwrap();
+    let lower = |text: &str| {
+        registry
+            .call_phrase("el", "lower_greek", &[Value::from(text)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(lower("ΟΔΟ<b>Σ</b>"), "οδο<b>ς</b>");
+    assert_eq!(lower("ΟΔΟΣ<b>ΚΑΙ</b>"), "οδοσ<b>και</b>");
+    assert_eq!(lower("Σ'ΑΓΑΠΩ"), "σ'αγαπω");
+}
+
+// =============================================================================
+// Sentence Case (@sentence)
+// =============================================================================
+
+#[test]
+fn sentence_case_all_caps() {
+    let result = TransformKind::Sentence
+        .execute(&Value::from("HELLO. HOW ARE YOU?"), None, "en")
+        .unwrap();
+    assert_eq!(result, "Hello. How are you?");
+}
+
+#[test]
+fn sentence_case_multibyte() {
+    let result = TransformKind::Sentence
+        .execute(&Value::from("ÉLAN VITAL! ÇA VA? «OUI.»"), None, "fr")
+        .unwrap();
+    assert_eq!(result, "Élan vital! Ça va? «Oui.»");
+    let decomposed = TransformKind::Sentence
+        .execute(&Value::from("E\u{0301}TAT. E\u{0301}TAT"), None, "fr")
+        .unwrap();
+    assert_eq!(decomposed, "E\u{0301}tat. E\u{0301}tat");
+}
+
+#[test]
+fn sentence_case_needs_space_after_terminator() {
+    let result = TransformKind::Sentence
+        .execute(
+            &Value::from("COSTS 3.5 GOLD, E.G. FOR <b>ELVES</b>. DONE"),
+            None,
+            "en",
+        )
+        .unwrap();
+    assert_eq!(result, "Costs 3.5 gold, e.g. for <b>elves</b>. Done");
+}
+
+#[test]
+fn sentence_case_turkish() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"s($t) = "{@sentence $t}";"#)
+        .unwrap();
+    let result = registry
+        .call_phrase("tr", "s", &[Value::from("İYİ. ILIK SU")])
+        .unwrap();
+    assert_eq!(result.to_string(), "İyi. Ilık su");
+}
+
+#[test]
+fn title_case_lowers_and_capitalizes_words() {
+    let result = TransformKind::Title
+        .execute(&Value::from("the XP  <b>bonus</b>"), None, "en")
+        .unwrap();
+    assert_eq!(result, "The Xp  <b>Bonus</b>");
+}
+
+#[test]
+fn title_case_keepcaps_preserves_acronyms() {
+    let keepcaps = Value::from("keepcaps");
+    let result = TransformKind::Title
+        .execute(&Value::from("the XP bonus"), Some(&keepcaps), "en")
+        .unwrap();
+    assert_eq!(result, "The XP Bonus");
+    let markup = TransformKind::Title
+        .execute(
+            &Value::from("gain <b>HP</b> and mAX hp"),
+            Some(&keepcaps),
+            "en",
+        )
+        .unwrap();
+    assert_eq!(markup, "Gain <b>HP</b> And Max Hp");
+}
+
+#[test]
+fn title_case_in_template() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            bonus = "the XP bonus";
+            heading = "{@title:keepcaps bonus}";
+            heading_tr = "{@title:tr bonus}";
+        "#,
+        )
+        .unwrap();
+    let heading = registry.get_phrase("en", "heading").unwrap();
+    assert_eq!(heading.to_string(), "The XP Bonus");
+    let turkish = registry.get_phrase("en", "heading_tr").unwrap();
+    assert_eq!(turkish.to_string(), "The Xp Bonus");
+}
+
+// =============================================================================
+// Word Truncation (@truncate_words)
+// =============================================================================
+
+#[test]
+fn truncate_words_keeps_first_words() {
+    let result = TransformKind::TruncateWords
+        .execute(
+            &Value::from("Deal 3 damage to each enemy character."),
+            Some(&Value::from("5")),
+            "en",
+        )
+        .unwrap();
+    assert_eq!(result, "Deal 3 damage to each…");
+}
+
+#[test]
+fn truncate_words_noop_when_within_limit() {
+    let text = "Draw a card.";
+    for limit in ["3", "10"] {
+        let result = TransformKind::TruncateWords
+            .execute(&Value::from(text), Some(&Value::from(limit)), "en")
+            .unwrap();
+        assert_eq!(result, text);
+    }
+}
+
+#[test]
+fn truncate_words_preserves_markup() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"short($d) = "{@truncate_words:2 $d}";"#)
+        .unwrap();
+    let result = registry
+        .call_phrase(
+            "en",
+            "short",
+            &[Value::from(
+                "Gain <b>two extra</b> <color=#AA00FF>points</color>",
+            )],
+        )
+        .unwrap();
+    assert_eq!(
+        result.to_string(),
+        "Gain <b>two…</b><color=#AA00FF></color>"
+    );
+}
+
+#[test]
+fn truncate_words_counts_graphemes_in_unspaced_languages() {
+    let result = TransformKind::TruncateWords
+        .execute(
+            &Value::from("抽一张牌然后弃一张牌"),
+            Some(&Value::from("4")),
+            "zh",
+        )
+        .unwrap();
+    assert_eq!(result, "抽一张牌…");
+}
+
+#[test]
+fn truncate_words_requires_count_context() {
+    let err = TransformKind::TruncateWords
+        .execute(&Value::from("a b c"), None, "en")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "truncate_words"
+    ));
+}
+
+// =============================================================================
+// Case Folding (@case_fold)
+// =============================================================================
+
+#[test]
+fn case_fold_matches_sharp_s_spellings() {
+    let fold = |text: &str| {
+        TransformKind::CaseFold
+            .execute(&Value::from(text), None, "de")
+            .unwrap()
+    };
+    assert_eq!(fold("STRASSE"), "strasse");
+    assert_eq!(fold("straße"), "strasse");
+    assert_eq!(fold("Straße"), fold("STRASSE"));
+}
+
+#[test]
+fn case_fold_greek_final_sigma() {
+    let fold = |text: &str| {
+        TransformKind::CaseFold
+            .execute(&Value::from(text), None, "el")
+            .unwrap()
+    };
+    // @lower keeps the final form; folding maps both sigmas to σ
+    assert_eq!(fold("ΚΟΣΜΟΣ"), "κοσμοσ");
+    assert_eq!(fold("κοσμος"), fold("ΚΟΣΜΟΣ"));
+}
+
+#[test]
+fn case_fold_turkish_dotted_i() {
+    let result = TransformKind::CaseFold
+        .execute(&Value::from("İSTANBUL"), None, "tr")
+        .unwrap();
+    assert_eq!(result, "istanbul");
+}
+
+// =============================================================================
+// German Sharp S (ß / ẞ)
+// =============================================================================
+
+fn german_street(style: SharpSStyle) -> Locale {
+    let source = r#"
+        street = "straße";
+        sign = "{@upper street}";
+        title($word) = "{@cap $word}";
+        quiet($word) = "{@lower $word}";
+    "#;
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+    locale.set_sharp_s_style(style);
+    locale
+}
+
+#[test]
+fn sharp_s_upper_defaults_to_double_s() {
+    let locale = german_street(SharpSStyle::default());
+    assert_eq!(locale.get_phrase("sign").unwrap().to_string(), "STRASSE");
+}
+
+#[test]
+fn sharp_s_upper_capital_style() {
+    let locale = german_street(SharpSStyle::Capital);
+    assert_eq!(
+        locale.get_phrase("sign").unwrap().to_string(),
+        "STRA\u{1E9E}E"
+    );
+    let title = locale.call_phrase("title", &[Value::from("ß")]).unwrap();
+    assert_eq!(title.to_string(), "\u{1E9E}");
+    let title = locale
+        .call_phrase("title", &[Value::from("<b> ßa</b>")])
+        .unwrap();
+    assert_eq!(title.to_string(), "<b> \u{1E9E}a</b>");
+}
+
+#[test]
+fn sharp_s_lower_maps_capital_back() {
+    for style in [SharpSStyle::Double, SharpSStyle::Capital] {
+        let locale = german_street(style);
+        let quiet = locale
+            .call_phrase("quiet", &[Value::from("STRA\u{1E9E}E")])
+            .unwrap();
+        assert_eq!(quiet.to_string(), "straße");
+    }
+}
+
+#[test]
+fn sharp_s_capital_style_is_german_only() {
+    let mut registry = TransformRegistry::new();
+    registry.set_sharp_s_style(SharpSStyle::Capital);
+    let value = Value::from("straße");
+    let english = registry
+        .execute(TransformKind::Upper, &value, None, "en")
+        .unwrap();
+    assert_eq!(english, "STRASSE");
+    let swiss = registry
+        .execute(TransformKind::Upper, &value, None, "de-CH")
+        .unwrap();
+    assert_eq!(swiss, "STRA\u{1E9E}E");
+}
+
+// =============================================================================
+// Quotation Marks (@quote)
+// =============================================================================
+
+fn quote(lang: &str, context: Option<&str>) -> String {
+    let context = context.map(Value::from);
+    TransformKind::Quote
+        .execute(&Value::from("card"), context.as_ref(), lang)
+        .unwrap()
+}
+
+#[test]
+fn quote_uses_language_quotation_marks() {
+    assert_eq!(quote("en", None), "“card”");
+    assert_eq!(quote("de", None), "„card“");
+    assert_eq!(quote("fr", None), "«\u{202F}card\u{202F}»");
+    assert_eq!(quote("ja", None), "「card」");
+    assert_eq!(quote("de-AT", None), "„card“");
+}
+
+#[test]
+fn quote_nested_uses_secondary_marks() {
+    assert_eq!(quote("en", Some("nested")), "‘card’");
+    assert_eq!(quote("de", Some("nested")), "‚card‘");
+    assert_eq!(quote("fr", Some("nested")), "“card”");
+    assert_eq!(quote("ja", Some("nested")), "『card』");
+}
+
+#[test]
+fn quote_in_template() {
+    let source = r#"
+        card = "Karte";
+        named = "Spiele {@quote card}.";
+    "#;
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+    let phrase = locale.get_phrase("named").unwrap();
+    assert_eq!(phrase.to_string(), "Spiele „Karte“.");
+}
+
+fn autocap_locale(enabled: bool) -> Locale {
+    let source = r#"
+        enemy = :an "ogre";
+        arrives = "{@autocap @a enemy} arrives.";
+        warning = "Look out, {arrives}";
+        second = "It roars. <b>{arrives}</b>";
+        named($who) = "{?$who: "{@autocap $who} waves. "}{@autocap $who} leaves.";
+    "#;
+    let mut locale = Locale::new();
+    locale.load_translations_str("en", source).unwrap();
+    locale.set_auto_sentence_cap(enabled);
+    locale
+}
+
+#[test]
+fn autocap_is_a_no_op_unless_enabled() {
+    let locale = autocap_locale(false);
+    assert!(!locale.auto_sentence_cap());
+    assert_eq!(
+        locale.get_phrase("arrives").unwrap().to_string(),
+        "an ogre arrives."
+    );
+}
+
+#[test]
+fn autocap_capitalizes_at_sentence_start_only() {
+    let locale = autocap_locale(true);
+    assert_eq!(
+        locale.get_phrase("arrives").unwrap().to_string(),
+        "An ogre arrives."
+    );
+    assert_eq!(
+        locale.get_phrase("warning").unwrap().to_string(),
+        "Look out, an ogre arrives."
+    );
+    assert_eq!(
+        locale.get_phrase("second").unwrap().to_string(),
+        "It roars. <b>An ogre arrives.</b>"
+    );
+}
+
+#[test]
+fn autocap_tracks_position_through_conditionals() {
+    let locale = autocap_locale(true);
+    let named = locale.call_phrase("named", &[Value::from("ann")]).unwrap();
+    assert_eq!(named.to_string(), "Ann waves. Ann leaves.");
+}
+
+#[test]
+fn autocap_executed_directly_leaves_text_unchanged() {
+    let result = TransformKind::AutoCap
+        .execute(&Value::from("ogre"), None, "en")
+        .unwrap();
+    assert_eq!(result, "ogre");
+}
+
+fn protect_locale() -> Locale {
+    let source = r#"
+        brand = :protect "McDonald";
+        meet($place) = "meet me at {$place}";
+        banner($place) = "{@upper meet($place)}!";
+        quiet($place) = "{@lower meet($place)}";
+        opener($place) = "{$place} is open";
+        titled($place) = "{@cap opener($place)} and {@title opener($place)}";
+        sentence($place) = "{@sentence opener($place)}. {@sentence meet($place)}.";
+        greet($name) = "meet {$name:protect}";
+        named($name) = "{@upper greet($name)}";
+    "#;
+    let mut locale = Locale::new();
+    locale.load_translations_str("en", source).unwrap();
+    locale
+}
+
+#[test]
+fn upper_skips_protected_phrase() {
+    let locale = protect_locale();
+    let brand = locale.get_phrase("brand").unwrap();
+    let banner = locale.call_phrase("banner", &[Value::from(brand.clone())]);
+    assert_eq!(banner.unwrap().to_string(), "MEET ME AT McDonald!");
+    let quiet = locale.call_phrase("quiet", &[Value::from(brand)]);
+    assert_eq!(quiet.unwrap().to_string(), "meet me at McDonald");
+}
+
+#[test]
+fn upper_skips_string_with_inline_protect_tag() {
+    let locale = protect_locale();
+    let named = locale.call_phrase("named", &[Value::from("McDonald")]);
+    assert_eq!(named.unwrap().to_string(), "MEET McDonald");
+}
+
+#[test]
+fn cap_and_sentence_leave_leading_protected_phrase() {
+    let locale = protect_locale();
+    let brand = Value::from(
+        Phrase::builder()
+            .text("iPhone".to_string())
+            .tags(vec![Tag::new("protect")])
+            .build(),
+    );
+    let titled = locale.call_phrase("titled", slice::from_ref(&brand));
+    assert_eq!(
+        titled.unwrap().to_string(),
+        "iPhone is open and iPhone Is Open"
+    );
+    let sentence = locale.call_phrase("sentence", &[brand]);
+    assert_eq!(
+        sentence.unwrap().to_string(),
+        "iPhone is open. Meet me at iPhone."
+    );
+}
+
+#[test]
+fn protection_is_scoped_to_enclosing_transforms() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            brand = :protect "McDonald";
+            shout = "{@upper brand}";
+            banner = "{@upper shout}";
+        "#,
+        )
+        .unwrap();
+    // The protected phrase's own transforms still apply
+    let banner = registry.get_phrase("en", "banner").unwrap();
+    assert_eq!(banner.to_string(), "MCDONALD");
+    assert!(!banner.to_string().contains('\u{FDD0}'));
+}
+
+#[test]
+fn protected_phrase_keeps_case_through_article_transform() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            apple = :protect "Apple";
+            item = :a "{apple} watch";
+            shout = "{@upper @a item}";
+            quoted = "{@quote item}";
+        "#,
+        )
+        .unwrap();
+    let shout = locale.get_phrase("shout").unwrap();
+    assert_eq!(shout.to_string(), "A Apple WATCH");
+    let quoted = locale.get_phrase("quoted").unwrap();
+    assert_eq!(quoted.to_string(), "\u{201C}Apple watch\u{201D}");
+}
+
+#[test]
+fn transform_error_omits_protection_markers() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            apple = :protect "Apple";
+            item = "{apple} watch";
+            x = "{@a item}";
+        "#,
+        )
+        .unwrap();
+    let err = locale.get_phrase("x").unwrap_err();
+    let EvalError::MissingTag { phrase, .. } = &err else {
+        panic!("expected MissingTag, got {err:?}");
+    };
+    assert_eq!(phrase, "Apple watch");
+    assert!(!err.to_string().contains(['\u{FDD0}', '\u{FDD1}']));
+}
+
+#[test]
+fn strip_markup_removes_nested_tags() {
+    let result = TransformKind::StripMarkup
+        .execute(&Value::from("<color=#fff><b>x</b></color>"), None, "en")
+        .unwrap();
+    assert_eq!(result, "x");
+}
+
+#[test]
+fn strip_markup_keeps_literal_angle_brackets() {
+    let strip = |text: &str| {
+        TransformKind::StripMarkup
+            .execute(&Value::from(text), None, "en")
+            .unwrap()
+    };
+    assert_eq!(strip("3 < 5 and <b>7</b> > 6"), "3 < 5 and 7 > 6");
+    assert_eq!(strip("I <3 it"), "I <3 it");
+    assert_eq!(strip("trailing <"), "trailing <");
+}
+
+#[test]
+fn escape_markup_shows_tags_as_literals() {
+    let escape = |text: &str| {
+        TransformKind::EscapeMarkup
+            .execute(&Value::from(text), None, "en")
+            .unwrap()
+    };
+    assert_eq!(
+        escape("<color=#fff><b>x</b></color>"),
+        "&lt;color=#fff&gt;&lt;b&gt;x&lt;/b&gt;&lt;/color&gt;"
+    );
+    assert_eq!(escape("a < b"), "a < b");
+}
+
+#[test]
+fn markup_transforms_in_templates() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            gain = "Gain <b>2</b> points";
+            log = "{@strip_markup gain}";
+            debug = "{@escape_markup gain}";
+        "#,
+        )
+        .unwrap();
+    assert_eq!(
+        locale.get_phrase("log").unwrap().to_string(),
+        "Gain 2 points"
+    );
+    assert_eq!(
+        locale.get_phrase("debug").unwrap().to_string(),
+        "Gain &lt;b&gt;2&lt;/b&gt; points"
+    );
+}
+
+#[test]
+fn superscript_maps_digits() {
+    let sup = |text: &str| {
+        TransformKind::Super
+            .execute(&Value::from(text), None, "en")
+            .unwrap()
+    };
+    assert_eq!(sup("2"), "²");
+    assert_eq!(sup("123"), "¹²³");
+    assert_eq!(sup("n+1"), "ⁿ⁺¹");
+    assert_eq!(
+        TransformKind::Super
+            .execute(&Value::from(42), None, "en")
+            .unwrap(),
+        "⁴²"
+    );
+}
+
+#[test]
+fn superscript_passes_unmapped_characters_through() {
+    let result = TransformKind::Super
+        .execute(&Value::from("<b>Q2</b>★"), None, "en")
+        .unwrap();
+    assert_eq!(result, "<b>Q²</b>★");
+}
+
+#[test]
+fn subscript_maps_digits_in_formula() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"hydrogen($n) = "H{@subscript $n}";"#)
+        .unwrap();
+    let result = locale.call_phrase("hydrogen", &[Value::from(2)]).unwrap();
+    assert_eq!(result.to_string(), "H₂");
+    let result = TransformKind::Sub
+        .execute(&Value::from("x9q"), None, "en")
+        .unwrap();
+    assert_eq!(result, "ₓ₉q");
+}
+
+#[test]
+fn noop_matches_plain_reference() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            x = "<b>ancient</b> relic";
+            plain = "{x}";
+            noop = "{@noop x}";
+            aliases = "{@id x} / {@raw x}";
+            chained = "{@cap @noop x}";
+        "#,
+        )
+        .unwrap();
+    let text = |name: &str| locale.get_phrase(name).unwrap().to_string();
+    assert_eq!(text("noop"), text("plain"));
+    assert_eq!(text("noop"), "<b>ancient</b> relic");
+    assert_eq!(
+        text("aliases"),
+        "<b>ancient</b> relic / <b>ancient</b> relic"
+    );
+    assert_eq!(text("chained"), "<b>Ancient</b> relic");
+}
+
+/// Splits German words before "versicherung" and "gesellschaft".
+fn german_compound_splitter(word: &str) -> Vec<usize> {
+    ["versicherung", "gesellschaft"]
+        .iter()
+        .filter_map(|part| word.find(part))
+        .collect()
+}
+
+#[test]
+fn break_inserts_soft_hyphen_at_compound_boundary() {
+    let mut registry = TransformRegistry::new();
+    registry.set_compound_splitter("de", german_compound_splitter);
+    let result = registry
+        .execute(
+            TransformKind::CompoundBreak,
+            &Value::from("Die Lebensversicherungsgesellschaft zahlt."),
+            None,
+            "de-AT",
+        )
+        .unwrap();
+    assert_eq!(
+        result,
+        "Die Lebens\u{AD}versicherungs\u{AD}gesellschaft zahlt."
+    );
+}
+
+#[test]
+fn break_is_noop_without_splitter() {
+    let mut registry = TransformRegistry::new();
+    registry.set_compound_splitter("de", german_compound_splitter);
+    let text = Value::from("Lebensversicherung");
+    let result = registry
+        .execute(TransformKind::CompoundBreak, &text, None, "fi")
+        .unwrap();
+    assert_eq!(result, "Lebensversicherung");
+    let result = TransformKind::CompoundBreak
+        .execute(&text, None, "de")
+        .unwrap();
+    assert_eq!(result, "Lebensversicherung");
+}
+
+#[test]
+fn break_skips_markup_and_bad_offsets() {
+    let mut registry = TransformRegistry::new();
+    registry.set_compound_splitter("fi", |word| vec![0, 1, word.len(), 99]);
+    let result = registry
+        .execute(
+            TransformKind::CompoundBreak,
+            &Value::from("<b>ä</b>"),
+            None,
+            "fi",
+        )
+        .unwrap();
+    assert_eq!(result, "<b>ä</b>");
+}
+
+fn register_locale() -> Locale {
+    let source = r#"
+        you = { formal: "Sie", informal: "du" };
+        have = { formal: "haben", informal: "hast" };
+        greeting = "Hallo! {@cap have} {you} Zeit?";
+        notice = "Bitte melden {@formal you} sich an.";
+    "#;
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+    locale
+}
+
+#[test]
+fn register_defaults_to_formal() {
+    let locale = register_locale();
+    assert_eq!(locale.register(), Register::Formal);
+    assert_eq!(
+        locale.get_phrase("greeting").unwrap().to_string(),
+        "Hallo! Haben Sie Zeit?"
+    );
+}
+
+#[test]
+fn register_informal_renders_du_forms() {
+    let mut locale = register_locale();
+    locale.set_register(Register::Informal);
+    assert_eq!(
+        locale.get_phrase("greeting").unwrap().to_string(),
+        "Hallo! Hast du Zeit?"
+    );
+    // An explicit @formal ignores the locale's register
+    assert_eq!(
+        locale.get_phrase("notice").unwrap().to_string(),
+        "Bitte melden Sie sich an."
+    );
+}
+
+#[test]
+fn register_transforms_select_variant() {
+    let locale = register_locale();
+    let you = locale.get_phrase("you").unwrap();
+    let select = |kind: TransformKind| kind.execute(&Value::Phrase(you.clone()), None, "de");
+    assert_eq!(select(TransformKind::Formal).unwrap(), "Sie");
+    assert_eq!(select(TransformKind::Informal).unwrap(), "du");
+    // Values without register variants pass through
+    let plain = TransformKind::Informal
+        .execute(&Value::from("Karte"), None, "de")
+        .unwrap();
+    assert_eq!(plain, "Karte");
+}
+
+#[test]
+fn quote_rejects_unknown_context() {
+    let err = TransformKind::Quote
+        .execute(&Value::from("card"), Some(&Value::from("double")), "en")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+}
+
+// =============================================================================
+// Lookup Tables (@map)
+// =============================================================================
+
+fn rarity_locale() -> Locale {
+    let source = r#"
+        rare = :a { one: "rare card", other: "rare cards" };
+        uncommon = :an "uncommon card";
+        found($r) = "You found {@a @map:rarity $r}.";
+        label($r) = "{@cap @map:rarity $r}";
+        missing($r) = "{@map:tier $r}";
+        bare($r) = "{@map $r}";
+    "#;
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+    locale.register_map(
+        "rarity",
+        LookupTable::new()
+            .term("rare", "rare")
+            .term("uncommon", "uncommon")
+            .text("common", "plain card"),
+    );
+    locale
+}
+
+#[test]
+fn map_resolves_keys_to_terms_and_keeps_tags() {
+    let locale = rarity_locale();
+    let found = locale.call_phrase("found", &[Value::from("rare")]).unwrap();
+    assert_eq!(found.to_string(), "You found a rare card.");
+    let found = locale
+        .call_phrase("found", &[Value::from("uncommon")])
+        .unwrap();
+    assert_eq!(found.to_string(), "You found an uncommon card.");
+}
+
+#[test]
+fn map_resolves_keys_to_literal_text() {
+    let locale = rarity_locale();
+    let label = locale
+        .call_phrase("label", &[Value::from("common")])
+        .unwrap();
+    assert_eq!(label.to_string(), "Plain card");
+}
+
+#[test]
+fn map_unknown_key_lists_known_keys() {
+    let locale = rarity_locale();
+    let err = locale
+        .call_phrase("label", &[Value::from("mythic")])
+        .unwrap_err();
+    let EvalError::InvalidTransformInput {
+        transform,
+        expected,
+        value,
+    } = err
+    else {
+        panic!("expected InvalidTransformInput, got {err:?}");
+    };
+    assert_eq!(transform, "map");
+    assert_eq!(expected, "a key of map 'rarity' (common, rare, uncommon)");
+    assert_eq!(value, "mythic");
+}
+
+#[test]
+fn map_unknown_key_uses_fallback() {
+    let mut locale = rarity_locale();
+    locale.register_map(
+        "rarity",
+        LookupTable::new()
+            .term("rare", "rare")
+            .fallback(LookupEntry::Text("unknown card".to_string())),
+    );
+    let label = locale
+        .call_phrase("label", &[Value::from("mythic")])
+        .unwrap();
+    assert_eq!(label.to_string(), "Unknown card");
+}
+
+#[test]
+fn map_requires_a_registered_table() {
+    let locale = rarity_locale();
+    let err = locale
+        .call_phrase("missing", &[Value::from("rare")])
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+    let err = locale
+        .call_phrase("bare", &[Value::from("rare")])
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+}
+
+#[test]
+fn map_output_is_not_html_escaped() {
+    let source = r#"
+        epic = "<b>epic</b>";
+        label($r) = "{@map:rarity $r}";
+    "#;
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+    locale.register_map("rarity", LookupTable::new().term("epic", "epic"));
+    locale.set_output_target(OutputTarget::Html);
+    let label = locale.call_phrase("label", &[Value::from("epic")]).unwrap();
+    assert_eq!(label.to_string(), "<b>epic</b>");
+}
+
+// =============================================================================
+// Durations (@duration)
+// =============================================================================
+
+#[test]
+fn duration_english_long_and_short() {
+    let value = Value::from(3660);
+    let long = TransformKind::Duration.execute(&value, None, "en").unwrap();
+    assert_eq!(long, "1 hour 1 minute");
+    let long = TransformKind::Duration
+        .execute(&value, Some(&Value::from("long")), "en")
+        .unwrap();
+    assert_eq!(long, "1 hour 1 minute");
+    let short = TransformKind::Duration
+        .execute(&value, Some(&Value::from("short")), "en")
+        .unwrap();
+    assert_eq!(short, "1h 1m");
+}
+
+#[test]
+fn duration_omits_zero_components_and_pluralizes() {
+    let cases = [
+        (0, "0 seconds", "0s"),
+        (45, "45 seconds", "45s"),
+        (7_205, "2 hours 5 seconds", "2h 5s"),
+        (90_000, "1 day 1 hour", "1d 1h"),
+    ];
+    for (seconds, long, short) in cases {
+        let value = Value::from(seconds);
+        assert_eq!(
+            TransformKind::Duration.execute(&value, None, "en").unwrap(),
+            long
+        );
+        assert_eq!(
+            TransformKind::Duration
+                .execute(&value, Some(&Value::from("short")), "en")
+                .unwrap(),
+            short
+        );
+    }
+}
+
+#[test]
+fn duration_german_in_template() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            cooldown($s) = "Abklingzeit: {@duration $s}";
+            timer($s) = "{@duration:short $s}";
+        "#,
+        )
+        .unwrap();
+    let long = registry
+        .call_phrase("de", "cooldown", &[Value::from(7_320)])
+        .unwrap();
+    assert_eq!(long.to_string(), "Abklingzeit: 2 Stunden 2 Minuten");
+    let short = registry
+        .call_phrase("de", "timer", &[Value::from(3_601)])
+        .unwrap();
+    assert_eq!(short.to_string(), "1 Std. 1 Sek.");
+}
+
+#[test]
+fn duration_rejects_negative_and_unknown_style() {
+    let err = TransformKind::Duration
+        .execute(&Value::from(-5), None, "en")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "duration"
+    ));
+    let err = TransformKind::Duration
+        .execute(&Value::from(60), Some(&Value::from("narrow")), "en")
+        .unwrap_err();
+    assert!(err.to_string().contains("':long' or ':short'"));
+}
+
+// =============================================================================
+// Language Override (@lang)
+// =============================================================================
+
+#[test]
+fn lang_override_applies_english_article_in_german_template() {
+    let source = r#"
+        card = :a "trading card";
+        karte = :fem "Karte";
+        offer = "{@der:acc karte} oder {@lang:en @a card}";
+        loan = "Er sagt {@cap @lang:en @a card}.";
+        regional = "{@lang:en_GB @a card}";
+    "#;
+
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+    assert_eq!(
+        locale.get_phrase("offer").unwrap().to_string(),
+        "die Karte oder a trading card"
+    );
+    // Transforms left of @lang keep the outer language
+    assert_eq!(
+        locale.get_phrase("loan").unwrap().to_string(),
+        "Er sagt A trading card."
+    );
+    // Region subtags select the primary language's transforms
+    assert_eq!(
+        locale.get_phrase("regional").unwrap().to_string(),
+        "a trading card"
+    );
+}
+
+#[test]
+fn lang_override_without_it_uses_outer_language() {
+    let mut locale = Locale::builder().language("de").build();
+    locale
+        .load_translations_str("de", r#"card = :a "card"; bad = "{@a card}";"#)
+        .unwrap();
+    let err = locale.get_phrase("bad").unwrap_err();
+    assert!(matches!(err, EvalError::WrongLanguageTransform { ref name, .. } if name == "a"));
+}
+
+#[test]
+fn lang_override_rejects_unknown_language() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"card = :a "card"; bad = "{@lang:xx @a card}"; none = "{@lang card}";"#)
+        .unwrap();
+    for name in ["bad", "none"] {
+        let err = registry.get_phrase("de", name).unwrap_err();
+        assert!(matches!(
+            err,
+            EvalError::InvalidTransformInput { ref transform, .. } if transform == "lang"
+        ));
+    }
+}
+
+// =============================================================================
+// List Transform (@list)
+// =============================================================================
+
+fn list(items: &[&str]) -> Value {
+    Value::from(items.to_vec())
+}
+
+#[test]
+fn list_joins_with_conjunction() {
+    let list_of = |items: &[&str], context: Option<&str>, lang: &str| {
+        TransformKind::List
+            .execute(&list(items), context.map(Value::from).as_ref(), lang)
+            .unwrap()
+    };
+    assert_eq!(
+        list_of(&["fire", "ice", "wind"], None, "en"),
+        "fire, ice, and wind"
+    );
+    assert_eq!(list_of(&["fire", "ice"], Some("or"), "en"), "fire or ice");
+    assert_eq!(
+        list_of(&["Feuer", "Eis", "Wind"], None, "de"),
+        "Feuer, Eis und Wind"
+    );
+    assert_eq!(list_of(&["fire"], None, "en"), "fire");
+    assert_eq!(list_of(&[], None, "en"), "");
+    assert_eq!(list_of(&["火", "氷"], None, "ja"), "火, 氷");
+}
+
+#[test]
+fn list_spanish_y_and_o_by_default() {
+    let and = TransformKind::List
+        .execute(&list(&["madres", "padres", "tíos"]), None, "es")
+        .unwrap();
+    assert_eq!(and, "madres, padres y tíos");
+    let or = TransformKind::List
+        .execute(&list(&["siete", "nueve"]), Some(&Value::from("or")), "es")
+        .unwrap();
+    assert_eq!(or, "siete o nueve");
+}
+
+#[test]
+fn list_spanish_e_before_i_sound() {
+    let result = TransformKind::List
+        .execute(&list(&["padres", "hijos"]), None, "es")
+        .unwrap();
+    assert_eq!(result, "padres e hijos");
+    let accented = TransformKind::List
+        .execute(&list(&["España", "<b>Italia</b>"]), None, "es-MX")
+        .unwrap();
+    assert_eq!(accented, "España e <b>Italia</b>");
+    // "hie" starts with a diphthong and keeps "y"
+    let diphthong = TransformKind::List
+        .execute(&list(&["agua", "hielo"]), None, "es")
+        .unwrap();
+    assert_eq!(diphthong, "agua y hielo");
+}
+
+#[test]
+fn list_spanish_u_before_o_sound() {
+    let or = Value::from("or");
+    let words = TransformKind::List
+        .execute(&list(&["siete", "ocho"]), Some(&or), "es")
+        .unwrap();
+    assert_eq!(words, "siete u ocho");
+    let digits = TransformKind::List
+        .execute(&Value::from(vec![7, 8]), Some(&or), "es")
+        .unwrap();
+    assert_eq!(digits, "7 u 8");
+    let silent_h = TransformKind::List
+        .execute(&list(&["mujer", "hombre"]), Some(&or), "es")
+        .unwrap();
+    assert_eq!(silent_h, "mujer u hombre");
+}
+
+#[test]
+fn list_selects_plural_by_length() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            element = { one: "element", other: "elements" };
+            weak($items) = "Weak to {element:$items}: {@list $items}.";
+        "#,
+        )
+        .unwrap();
+    let one = registry
+        .call_phrase("en", "weak", &[list(&["fire"])])
+        .unwrap();
+    assert_eq!(one.to_string(), "Weak to element: fire.");
+    let many = registry
+        .call_phrase("en", "weak", &[list(&["fire", "ice"])])
+        .unwrap();
+    assert_eq!(many.to_string(), "Weak to elements: fire and ice.");
+}
+
+#[test]
+fn list_keeps_order_unless_sorted() {
+    let names = list(&["Örjan", "Zoe", "anna", "Åsa", "Ärla"]);
+    let list_of = |context: &str, lang: &str| {
+        TransformKind::List
+            .execute(&names, Some(&Value::from(context)), lang)
+            .unwrap()
+    };
+    assert_eq!(list_of("raw", "sv"), "Örjan, Zoe, anna, Åsa, Ärla");
+    assert_eq!(list_of("sorted", "sv"), "anna, Zoe, Åsa, Ärla, Örjan");
+    assert_eq!(list_of("sorted", "nb"), "anna, Zoe, Ärla, Örjan, Åsa");
+    assert_eq!(list_of("sorted", "de"), "anna, Ärla, Åsa, Örjan und Zoe");
+}
+
+#[test]
+fn list_sorted_combines_with_or_and_ignores_markup() {
+    let result = TransformKind::List
+        .execute(
+            &list(&["<b>wind</b>", "Éclair", "fire"]),
+            Some(&Value::from("or.sorted")),
+            "en",
+        )
+        .unwrap();
+    assert_eq!(result, "Éclair, fire, or <b>wind</b>");
+}
+
+fn enumerate(items: &[&str], lang: &str) -> String {
+    TransformKind::List
+        .execute(&list(items), Some(&Value::from("enumerate")), lang)
+        .unwrap()
+}
+
+#[test]
+fn list_enumerate_writes_english_steps() {
+    assert_eq!(
+        enumerate(&["draw a card", "play it", "attack!"], "en"),
+        "First, draw a card. Second, play it. Third, attack!"
+    );
+}
+
+#[test]
+fn list_enumerate_uses_language_tables() {
+    assert_eq!(
+        enumerate(&["ziehen", "spielen"], "de_AT"),
+        "Erstens, ziehen. Zweitens, spielen."
+    );
+    assert_eq!(
+        enumerate(&["piocher", "jouer"], "fr"),
+        "1. piocher. 2. jouer."
+    );
+}
+
+#[test]
+fn list_enumerate_falls_back_to_numeric_ordinals() {
+    let steps: Vec<String> = (1..=12).map(|n| format!("step {n}")).collect();
+    let items: Vec<&str> = steps.iter().map(String::as_str).collect();
+    let result = enumerate(&items, "en");
+    assert!(result.starts_with("First, step 1. Second, step 2."));
+    assert!(result.ends_with("Tenth, step 10. 11th, step 11. 12th, step 12."));
+}
+
+#[test]
+fn list_rejects_unknown_context() {
+    let err = TransformKind::List
+        .execute(&list(&["a", "b"]), Some(&Value::from("xor")), "en")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "list"
+    ));
+}
+
 // =============================================================================
 // Turkish Locale-Sensitive Case Mapping
 // =============================================================================
@@ -272,8 +1440,75 @@ fn test_upper_english_i_for_comparison() {
     let result = registry
         .call_phrase("en", "upper_en", &[Value::from("istanbul")])
         .unwrap();
-    // English uppercase produces standard "I": "ISTANBUL"
-    assert_eq!(result.to_string(), "ISTANBUL");
+    // English uppercase produces standard "I": "ISTANBUL"
+    assert_eq!(result.to_string(), "ISTANBUL");
+}
+
+// =============================================================================
+// Case Locale Override
+// =============================================================================
+
+#[test]
+fn test_upper_locale_override_contrasts_with_ambient() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            ambient($text) = "{@upper $text}";
+            turkish($text) = "{@upper:tr $text}";
+        "#,
+        )
+        .unwrap();
+    let ambient = registry
+        .call_phrase("en", "ambient", &[Value::from("istanbul")])
+        .unwrap();
+    let turkish = registry
+        .call_phrase("en", "turkish", &[Value::from("istanbul")])
+        .unwrap();
+    assert_eq!(ambient.to_string(), "ISTANBUL");
+    assert_eq!(turkish.to_string(), "\u{0130}STANBUL");
+}
+
+#[test]
+fn test_case_locale_override_replaces_ambient_language() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            upper_en($text) = "{@upper:en $text}";
+            lower_tr($text) = "{@lower:tr_TR $text}";
+            cap_az($text) = "{@cap:az $text}";
+        "#,
+        )
+        .unwrap();
+    let upper = registry
+        .call_phrase("tr", "upper_en", &[Value::from("istanbul")])
+        .unwrap();
+    let lower = registry
+        .call_phrase("de", "lower_tr", &[Value::from("ISTANBUL")])
+        .unwrap();
+    let cap = registry
+        .call_phrase("en", "cap_az", &[Value::from("istanbul")])
+        .unwrap();
+    assert_eq!(upper.to_string(), "ISTANBUL");
+    assert_eq!(lower.to_string(), "\u{0131}stanbul");
+    assert_eq!(cap.to_string(), "\u{0130}stanbul");
+}
+
+#[test]
+fn test_case_locale_override_rejects_unknown_language() {
+    let err = TransformKind::Upper
+        .execute(
+            &Value::from("istanbul"),
+            Some(&Value::from("klingon")),
+            "en",
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, ref value, .. }
+        if transform == "upper" && value == "klingon"
+    ));
 }
 
 // =============================================================================
@@ -342,6 +1577,62 @@ fn test_unknown_transform_error() {
     );
 }
 
+#[test]
+fn test_builtin_registry_is_shared() {
+    let first = TransformRegistry::builtin();
+    let second = TransformRegistry::builtin();
+    assert!(ptr::eq(first, second));
+    assert_eq!(first.get("cap", "en"), Some(TransformKind::Cap));
+}
+
+#[test]
+fn registry_evaluation_uses_supplied_transforms() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            total($n) = "{@num $n} gold";
+            label = "Gold";
+        "#,
+        )
+        .unwrap();
+    let mut transforms = TransformRegistry::new();
+    transforms.set_digit_style(DigitStyle::Native);
+    transforms.set_pseudo_localize(true);
+
+    let builtin = registry.call_phrase("fa", "total", &[Value::from(1234)]);
+    assert_eq!(builtin.unwrap().to_string(), "1,234 gold");
+    let native = registry
+        .call_phrase_with_transforms(&transforms, "fa", "total", &[Value::from(1234)])
+        .unwrap();
+    assert!(native.to_string().contains("۱٬۲۳۴"));
+
+    let label = registry
+        .get_phrase_with_transforms(&transforms, "en", "label")
+        .unwrap();
+    assert_ne!(label.to_string(), "Gold");
+    let params = HashMap::new();
+    let evaluated = registry
+        .eval_str_with_transforms(&transforms, "{label}", "en", params)
+        .unwrap();
+    assert_eq!(evaluated.to_string(), label.to_string());
+}
+
+#[test]
+fn locale_and_registry_paths_agree() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"total($n) = "{@num $n} gold";"#)
+        .unwrap();
+    locale.set_pseudo_localize(true);
+    let registry = locale.registry().unwrap();
+    let from_registry = registry
+        .call_phrase_with_transforms(locale.transforms(), "en", "total", &[Value::from(5)])
+        .unwrap();
+    let from_locale = locale.call_phrase("total", &[Value::from(5)]).unwrap();
+    assert_eq!(from_registry.to_string(), from_locale.to_string());
+}
+
 // =============================================================================
 // Integration with Templates
 // =============================================================================
@@ -502,8 +1793,29 @@ fn test_cap_whitespace_start() {
     let result = registry
         .call_phrase("en", "cap_ws", &[Value::from("  hello")])
         .unwrap();
-    // The first grapheme is a space, which doesn't change
-    assert_eq!(result.to_string(), "  hello");
+    // Leading whitespace is skipped and the first letter capitalized
+    assert_eq!(result.to_string(), "  Hello");
+}
+
+#[test]
+fn test_cap_skips_markup_whitespace_and_punctuation() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(r#"cap_it($text) = "{@cap $text}";"#)
+        .unwrap();
+    for (input, expected) in [
+        ("<b>  hello</b>", "<b>  Hello</b>"),
+        ("  <i>«hola»</i>", "  <i>«Hola»</i>"),
+        ("¿qué?", "¿Qué?"),
+        ("...and then", "...And then"),
+        ("1st place", "1st place"),
+        ("<b> </b>", "<b> </b>"),
+    ] {
+        let result = registry
+            .call_phrase("en", "cap_it", &[Value::from(input)])
+            .unwrap();
+        assert_eq!(result.to_string(), expected, "{input:?}");
+    }
 }
 
 // =============================================================================
@@ -756,135 +2068,596 @@ fn english_a_with_selector_preserves_tags() {
 
     // :one selector → "card", @a reads :a tag → "a card"
     assert_eq!(
-        locale.get_phrase("draw_one").unwrap().to_string(),
-        "Draw a card."
+        locale.get_phrase("draw_one").unwrap().to_string(),
+        "Draw a card."
+    );
+
+    // :n with n=1 → "card" (one), @a reads :a tag → "a card"
+    assert_eq!(
+        locale
+            .call_phrase("draw_n", &[Value::from(1)])
+            .unwrap()
+            .to_string(),
+        "Draw a card."
+    );
+
+    // :n with n=3 → "cards" (other), @a reads :a tag → "a cards"
+    assert_eq!(
+        locale
+            .call_phrase("draw_n", &[Value::from(3)])
+            .unwrap()
+            .to_string(),
+        "Draw a cards."
+    );
+}
+
+#[test]
+fn english_upper_a_card() {
+    // Test {@upper @a card} -> "A CARD"
+    let source = r#"
+        card = :a "card";
+        shouted = "{@upper @a card}";
+    "#;
+
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+
+    // Right-to-left: @a first ("a card"), then @upper ("A CARD")
+    assert_eq!(locale.get_phrase("shouted").unwrap().to_string(), "A CARD");
+}
+
+// =============================================================================
+// English Plural Transform (@plural)
+// =============================================================================
+
+#[test]
+fn english_plural_on_phrase_with_variants() {
+    let phrase = Phrase::builder()
+        .text("card".to_string())
+        .variants(HashMap::from([
+            (VariantKey::new("one"), "card".to_string()),
+            (VariantKey::new("other"), "cards".to_string()),
+        ]))
+        .build();
+    let value = Value::Phrase(phrase);
+    let transform = TransformKind::EnglishPlural;
+    let result = transform.execute(&value, None, "en").unwrap();
+    assert_eq!(result, "cards");
+}
+
+#[test]
+fn english_plural_on_plain_string() {
+    let value = Value::String("card".to_string());
+    let transform = TransformKind::EnglishPlural;
+    let result = transform.execute(&value, None, "en").unwrap();
+    assert_eq!(result, "card");
+}
+
+#[test]
+fn english_plural_registry_lookup() {
+    let registry = TransformRegistry::new();
+    let transform = registry.get("plural", "en");
+    assert_eq!(transform, Some(TransformKind::EnglishPlural));
+}
+
+#[test]
+fn english_plural_not_available_for_other_languages() {
+    let registry = TransformRegistry::new();
+    assert_eq!(registry.get("plural", "de"), None);
+    assert_eq!(registry.get("plural", "fr"), None);
+}
+
+#[test]
+fn english_plural_in_template() {
+    let source = r#"
+        card = { one: "card", other: "cards" };
+        many_cards = "{@plural card}";
+    "#;
+
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+
+    assert_eq!(
+        locale.get_phrase("many_cards").unwrap().to_string(),
+        "cards"
+    );
+}
+
+#[test]
+fn english_plural_with_phrase_call() {
+    let source = r#"
+        subtype = { one: "subtype", other: "subtypes" };
+        label($s) = "{@plural subtype}";
+    "#;
+
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+
+    assert_eq!(
+        locale
+            .call_phrase("label", &[Value::from("anything")])
+            .unwrap()
+            .to_string(),
+        "subtypes"
+    );
+}
+
+#[test]
+fn english_plural_with_cap() {
+    let source = r#"
+        card = { one: "card", other: "cards" };
+        many_cards_cap = "{@cap @plural card}";
+    "#;
+
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+
+    // Right-to-left: @plural first ("cards"), then @cap ("Cards")
+    assert_eq!(
+        locale.get_phrase("many_cards_cap").unwrap().to_string(),
+        "Cards"
+    );
+}
+
+// =============================================================================
+// English Possessive Transform (@poss/@possessive)
+// =============================================================================
+
+#[test]
+fn english_possessive_appends_apostrophe_s() {
+    let value = Value::from("card");
+    let result = TransformKind::EnglishPossessive
+        .execute(&value, None, "en")
+        .unwrap();
+    assert_eq!(result, "card\u{2019}s");
+}
+
+#[test]
+fn english_possessive_after_final_s() {
+    let value = Value::from("cards");
+    let result = TransformKind::EnglishPossessive
+        .execute(&value, None, "en")
+        .unwrap();
+    assert_eq!(result, "cards\u{2019}");
+}
+
+#[test]
+fn english_possessive_sibilant_tag_keeps_s() {
+    let phrase = Phrase::builder()
+        .text("James".to_string())
+        .tags(vec![Tag::new("sibilant")])
+        .build();
+    let result = TransformKind::EnglishPossessive
+        .execute(&Value::Phrase(phrase), None, "en")
+        .unwrap();
+    assert_eq!(result, "James\u{2019}s");
+}
+
+#[test]
+fn english_possessive_ascii_context() {
+    let value = Value::from("card");
+    let ascii = Value::from("ascii");
+    let result = TransformKind::EnglishPossessive
+        .execute(&value, Some(&ascii), "en")
+        .unwrap();
+    assert_eq!(result, "card's");
+}
+
+#[test]
+fn english_possessive_reads_final_s_past_markup() {
+    let value = Value::from("<b>bosses</b>");
+    let result = TransformKind::EnglishPossessive
+        .execute(&value, None, "en")
+        .unwrap();
+    assert_eq!(result, "<b>bosses</b>\u{2019}");
+}
+
+#[test]
+fn english_possessive_rejects_unknown_context() {
+    let value = Value::from("card");
+    let err = TransformKind::EnglishPossessive
+        .execute(&value, Some(&Value::from("curly")), "en")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "poss"
+    ));
+}
+
+#[test]
+fn english_possessive_in_template() {
+    let source = r#"
+        card = { one: "card", other: "cards" };
+        boss = :sibilant "boss";
+        ability = "{@the @poss card} ability";
+        abilities = "{@possessive card:other} abilities";
+        orders = "{@poss:ascii boss} orders";
+    "#;
+
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+
+    assert_eq!(
+        locale.get_phrase("ability").unwrap().to_string(),
+        "the card\u{2019}s ability"
+    );
+    assert_eq!(
+        locale.get_phrase("abilities").unwrap().to_string(),
+        "cards\u{2019} abilities"
+    );
+    assert_eq!(
+        locale.get_phrase("orders").unwrap().to_string(),
+        "boss's orders"
+    );
+}
+
+// =============================================================================
+// Genitive Constructions (@genitive)
+// =============================================================================
+
+#[test]
+fn english_genitive_in_template() {
+    let source = r#"
+        edge = "edge";
+        forest = { one: "forest", other: "forests" };
+        woods = "woods";
+        edge_of($owner) = "{@cap @genitive($owner) edge}";
+        forest_edge = "{edge_of(forest)}";
+        woods_edge = "{edge_of(woods)}";
+    "#;
+
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+
+    assert_eq!(
+        locale.get_phrase("forest_edge").unwrap().to_string(),
+        "The edge of the forest"
+    );
+    assert_eq!(
+        locale.get_phrase("woods_edge").unwrap().to_string(),
+        "The edge of the woods"
+    );
+}
+
+#[test]
+fn english_genitive_omits_article_for_proper_nouns() {
+    let source = r#"
+        edge = "edge";
+        avalon = :proper "Avalon";
+        forest = { one: "forest", other: "forests" };
+        forests($n) = "{forest:$n}";
+        edge_of($owner) = "{@genitive($owner) edge}";
+        avalon_edge = "{edge_of(avalon)}";
+    "#;
+
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+
+    assert_eq!(
+        locale.get_phrase("avalon_edge").unwrap().to_string(),
+        "the edge of Avalon"
+    );
+    let forests = locale.call_phrase("forests", &[Value::from(2)]).unwrap();
+    assert_eq!(
+        locale
+            .call_phrase("edge_of", &[Value::Phrase(forests)])
+            .unwrap()
+            .to_string(),
+        "the edge of the forests"
+    );
+}
+
+#[test]
+fn german_genitive_in_template() {
+    let source = r#"
+        rand = :masc { nom: "Rand", gen: "Randes" };
+        wald = :masc { nom: "Wald", gen: "Waldes" };
+        stadt = :fem "Stadt";
+        rand_von($owner) = "{@genitive($owner) rand}";
+        waldrand = "{rand_von(wald)}";
+        stadtrand = "{rand_von(stadt)}";
+    "#;
+
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+
+    assert_eq!(
+        locale.get_phrase("waldrand").unwrap().to_string(),
+        "der Rand des Waldes"
+    );
+    assert_eq!(
+        locale.get_phrase("stadtrand").unwrap().to_string(),
+        "der Rand der Stadt"
     );
+}
 
-    // :n with n=1 → "card" (one), @a reads :a tag → "a card"
+#[test]
+fn german_genitive_uses_plural_article_for_plural_nouns() {
+    let source = r#"
+        rand = :masc { nom: "Rand", gen: "Randes", other: "Ränder" };
+        wald = :masc { nom: "Wald", gen: "Waldes", other: "Wälder" };
+        waelder($n) = :masc "{wald:$n}";
+        rand_von($owner) = "{@genitive($owner) rand}";
+        raender_von($owner, $n) = "{@genitive($owner) rand:$n}";
+        waldraender = "{raender_von(wald, 2)}";
+    "#;
+
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+
+    let waelder = locale.call_phrase("waelder", &[Value::from(2)]).unwrap();
     assert_eq!(
         locale
-            .call_phrase("draw_n", &[Value::from(1)])
+            .call_phrase("rand_von", &[Value::Phrase(waelder)])
             .unwrap()
             .to_string(),
-        "Draw a card."
+        "der Rand der Wälder"
     );
-
-    // :n with n=3 → "cards" (other), @a reads :a tag → "a cards"
     assert_eq!(
-        locale
-            .call_phrase("draw_n", &[Value::from(3)])
-            .unwrap()
-            .to_string(),
-        "Draw a cards."
+        locale.get_phrase("waldraender").unwrap().to_string(),
+        "die Ränder des Waldes"
     );
 }
 
 #[test]
-fn english_upper_a_card() {
-    // Test {@upper @a card} -> "A CARD"
-    let source = r#"
-        card = :a "card";
-        shouted = "{@upper @a card}";
-    "#;
-
-    let mut locale = Locale::builder().language("en").build();
-    locale.load_translations_str("en", source).unwrap();
+fn genitive_without_owner_context_is_error() {
+    let value = Value::from("edge");
+    let err = TransformKind::EnglishGenitive
+        .execute(&value, None, "en")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "genitive"
+    ));
+}
 
-    // Right-to-left: @a first ("a card"), then @upper ("A CARD")
-    assert_eq!(locale.get_phrase("shouted").unwrap().to_string(), "A CARD");
+#[test]
+fn german_genitive_owner_missing_gender_error() {
+    let head = Value::Phrase(
+        Phrase::builder()
+            .text("Rand".to_string())
+            .tags(vec![Tag::new("masc")])
+            .build(),
+    );
+    let owner = Value::from("Wald");
+    let err = TransformKind::GermanGenitive
+        .execute(&head, Some(&owner), "de")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::MissingTag { ref transform, .. } if transform == "genitive"
+    ));
 }
 
 // =============================================================================
-// English Plural Transform (@plural)
+// English Count Transform (@count)
 // =============================================================================
 
 #[test]
-fn english_plural_on_phrase_with_variants() {
-    let phrase = Phrase::builder()
-        .text("card".to_string())
-        .variants(HashMap::from([
-            (VariantKey::new("one"), "card".to_string()),
-            (VariantKey::new("other"), "cards".to_string()),
-        ]))
-        .build();
-    let value = Value::Phrase(phrase);
-    let transform = TransformKind::EnglishPlural;
-    let result = transform.execute(&value, None, "en").unwrap();
-    assert_eq!(result, "cards");
+fn english_count_selects_plural_variant() {
+    let source = r#"
+        card = :a { one: "card", other: "cards" };
+        draw($n) = "Draw {@count($n) card}.";
+    "#;
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+    let draw = |n: i64| {
+        locale
+            .call_phrase("draw", &[Value::from(n)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(draw(0), "Draw 0 cards.");
+    assert_eq!(draw(1), "Draw 1 card.");
+    assert_eq!(draw(2), "Draw 2 cards.");
 }
 
 #[test]
-fn english_plural_on_plain_string() {
-    let value = Value::String("card".to_string());
-    let transform = TransformKind::EnglishPlural;
-    let result = transform.execute(&value, None, "en").unwrap();
-    assert_eq!(result, "card");
+fn english_count_words_for_zero_and_one() {
+    let source = r#"
+        card = :a { one: "card", other: "cards" };
+        event = :an { one: "event", other: "events" };
+        draw($n) = "Draw {@count:words($n) card}.";
+        trigger($n) = "Trigger {@count:words($n) event}.";
+    "#;
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+    let call = |name: &str, n: i64| {
+        locale
+            .call_phrase(name, &[Value::from(n)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(call("draw", 0), "Draw no cards.");
+    assert_eq!(call("draw", 1), "Draw a card.");
+    assert_eq!(call("draw", 2), "Draw 2 cards.");
+    assert_eq!(call("trigger", 1), "Trigger an event.");
 }
 
 #[test]
-fn english_plural_registry_lookup() {
-    let registry = TransformRegistry::new();
-    let transform = registry.get("plural", "en");
-    assert_eq!(transform, Some(TransformKind::EnglishPlural));
+fn english_count_words_rejects_non_integer_count() {
+    let card = Phrase::builder()
+        .text("card".to_string())
+        .tags(vec![Tag::new("a")])
+        .build();
+    for context in ["words.0.5", "words.abc"] {
+        let err = TransformKind::EnglishCount
+            .execute(
+                &Value::Phrase(card.clone()),
+                Some(&Value::from(context)),
+                "en",
+            )
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                EvalError::InvalidTransformInput { ref transform, .. } if transform == "count"
+            ),
+            "{context}: {err:?}"
+        );
+    }
 }
 
 #[test]
-fn english_plural_not_available_for_other_languages() {
-    let registry = TransformRegistry::new();
-    assert_eq!(registry.get("plural", "de"), None);
-    assert_eq!(registry.get("plural", "fr"), None);
+fn english_count_words_requires_article_tag_for_one() {
+    let card = Value::Phrase(
+        Phrase::builder()
+            .text("card".to_string())
+            .variants(HashMap::from([
+                (VariantKey::new("one"), "card".to_string()),
+                (VariantKey::new("other"), "cards".to_string()),
+            ]))
+            .build(),
+    );
+    let count = |context: &str| {
+        TransformKind::EnglishCount.execute(&card, Some(&Value::from(context)), "en")
+    };
+    assert_eq!(count("words.0").unwrap(), "no cards");
+    assert!(matches!(
+        count("words.1").unwrap_err(),
+        EvalError::MissingTag { ref transform, .. } if transform == "count"
+    ));
 }
 
+// =============================================================================
+// Count-and-Noun Transform (@count_noun)
+// =============================================================================
+
 #[test]
-fn english_plural_in_template() {
+fn count_noun_words_for_zero_one_and_many() {
     let source = r#"
-        card = { one: "card", other: "cards" };
-        many_cards = "{@plural card}";
+        card = :a { one: "card", other: "cards" };
+        draw($n) = "Draw {@count_noun:words($n) card}.";
+        plain($n) = "{@count_noun($n) card}";
     "#;
-
     let mut locale = Locale::builder().language("en").build();
     locale.load_translations_str("en", source).unwrap();
-
-    assert_eq!(
-        locale.get_phrase("many_cards").unwrap().to_string(),
-        "cards"
-    );
+    let call = |name: &str, n: i64| {
+        locale
+            .call_phrase(name, &[Value::from(n)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(call("draw", 0), "Draw no cards.");
+    assert_eq!(call("draw", 1), "Draw a card.");
+    assert_eq!(call("draw", 2), "Draw 2 cards.");
+    assert_eq!(call("plain", 0), "0 cards");
+    assert_eq!(call("plain", 1), "1 card");
 }
 
 #[test]
-fn english_plural_with_phrase_call() {
+fn count_noun_uses_language_plural_rules() {
     let source = r#"
-        subtype = { one: "subtype", other: "subtypes" };
-        label($s) = "{@plural subtype}";
+        karta = :fem { one: "карта", few: "карты", many: "карт", other: "карты" };
+        draw($n) = "Возьмите {@count_noun($n) karta}.";
     "#;
-
-    let mut locale = Locale::builder().language("en").build();
-    locale.load_translations_str("en", source).unwrap();
-
-    assert_eq!(
+    let mut locale = Locale::builder().language("ru").build();
+    locale.load_translations_str("ru", source).unwrap();
+    let draw = |n: i64| {
         locale
-            .call_phrase("label", &[Value::from("anything")])
+            .call_phrase("draw", &[Value::from(n)])
             .unwrap()
-            .to_string(),
-        "subtypes"
-    );
+            .to_string()
+    };
+    assert_eq!(draw(1), "Возьмите 1 карта.");
+    assert_eq!(draw(3), "Возьмите 3 карты.");
+    assert_eq!(draw(5), "Возьмите 5 карт.");
 }
 
 #[test]
-fn english_plural_with_cap() {
+fn count_noun_rejects_missing_or_non_integer_count() {
+    let card = Value::from("card");
+    let contexts = [None, Some(Value::from(2.5)), Some(Value::from("abc"))];
+    for context in contexts {
+        let err = TransformKind::CountNoun
+            .execute(&card, context.as_ref(), "en")
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                EvalError::InvalidTransformInput { ref transform, .. } if transform == "count_noun"
+            ),
+            "{context:?}: {err:?}"
+        );
+    }
+}
+
+#[test]
+fn count_noun_words_is_english_only() {
+    let card = Value::from("karte");
+    let err = TransformKind::CountNoun
+        .execute(&card, Some(&Value::from("words.0")), "de")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "count_noun"
+    ));
+}
+
+// =============================================================================
+// English Pronoun Transform (@pronoun)
+// =============================================================================
+
+fn pronoun(gender: &str, kind: &str) -> String {
+    TransformKind::EnglishPronoun
+        .execute(&Value::from(gender), Some(&Value::from(kind)), "en")
+        .unwrap()
+}
+
+#[test]
+fn english_pronoun_all_genders_and_types() {
+    let expected = [
+        ("male", ["he", "him", "his", "his", "himself"]),
+        ("female", ["she", "her", "her", "hers", "herself"]),
+        ("neuter", ["it", "it", "its", "its", "itself"]),
+        ("other", ["they", "them", "their", "theirs", "themselves"]),
+    ];
+    let kinds = ["subj", "obj", "poss", "poss_pron", "refl"];
+    for (gender, forms) in expected {
+        for (kind, form) in kinds.iter().zip(forms) {
+            assert_eq!(pronoun(gender, kind), form, "{gender} {kind}");
+        }
+    }
+}
+
+#[test]
+fn english_pronoun_defaults_to_subject() {
+    let result = TransformKind::EnglishPronoun
+        .execute(&Value::from("female"), None, "en")
+        .unwrap();
+    assert_eq!(result, "she");
+}
+
+#[test]
+fn english_pronoun_in_template() {
     let source = r#"
-        card = { one: "card", other: "cards" };
-        many_cards_cap = "{@cap @plural card}";
+        hero = :female "Alice";
+        played($name, $gender) = "{$name} played {@pronoun:poss $gender} card.";
+        hero_played($who) = "{$who} played {@pronoun:poss $who} card.";
     "#;
-
     let mut locale = Locale::builder().language("en").build();
     locale.load_translations_str("en", source).unwrap();
+    let played = locale
+        .call_phrase("played", &[Value::from("Sam"), Value::from("other")])
+        .unwrap();
+    assert_eq!(played.to_string(), "Sam played their card.");
+    let hero = locale.get_phrase("hero").unwrap();
+    let played = locale
+        .call_phrase("hero_played", &[Value::from(hero)])
+        .unwrap();
+    assert_eq!(played.to_string(), "Alice played her card.");
+}
 
-    // Right-to-left: @plural first ("cards"), then @cap ("Cards")
-    assert_eq!(
-        locale.get_phrase("many_cards_cap").unwrap().to_string(),
-        "Cards"
-    );
+#[test]
+fn english_pronoun_rejects_unknown_gender_and_type() {
+    let err = TransformKind::EnglishPronoun
+        .execute(&Value::from("robot"), None, "en")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+    let err = TransformKind::EnglishPronoun
+        .execute(&Value::from("male"), Some(&Value::from("dat")), "en")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
 }
 
 // =============================================================================
@@ -1039,6 +2812,31 @@ fn german_der_with_case_context() {
     );
 }
 
+#[test]
+fn german_der_with_parameter_case_context() {
+    let source = r#"
+        karte = :fem "Karte";
+        charakter = :masc "Charakter";
+        target($case, $thing) = "{@der:$case $thing}";
+    "#;
+
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+    let karte = locale.get_phrase("karte").unwrap();
+    let charakter = locale.get_phrase("charakter").unwrap();
+
+    let target = |case: &str, thing| {
+        locale
+            .call_phrase("target", &[Value::from(case), Value::Phrase(thing)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(target("acc", charakter.clone()), "den Charakter");
+    assert_eq!(target("acc", karte.clone()), "die Karte");
+    assert_eq!(target("dat", charakter), "dem Charakter");
+    assert_eq!(target("dat", karte), "der Karte");
+}
+
 #[test]
 fn german_ein_in_template() {
     let source = r#"
@@ -1173,6 +2971,103 @@ fn german_all_cases_neuter() {
     );
 }
 
+#[test]
+fn german_der_with_inline_tag_on_string_param() {
+    let source = r#"
+        hund = :masc "Hund";
+        katze = :fem "Katze";
+        greet($name) = "Hallo, {@der $name:masc}!";
+        accuse($name) = "Frag {@der:acc $name:fem}.";
+    "#;
+
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+
+    assert_eq!(
+        locale
+            .call_phrase("greet", &[Value::from("Bäcker")])
+            .unwrap()
+            .to_string(),
+        "Hallo, der Bäcker!"
+    );
+    assert_eq!(
+        locale
+            .call_phrase("accuse", &[Value::from("Wirtin")])
+            .unwrap()
+            .to_string(),
+        "Frag die Wirtin."
+    );
+}
+
+#[test]
+fn undeclared_selector_on_string_param_is_missing_variant() {
+    let source = r#"
+        hund = :masc "Hund";
+        greet($name) = "Hallo, {@der $name:mask}!";
+    "#;
+
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+
+    let err = locale
+        .call_phrase("greet", &[Value::from("Bäcker")])
+        .unwrap_err();
+    assert!(
+        matches!(&err, EvalError::MissingVariant { key, .. } if key == "mask"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn inline_tag_is_undeclared_once_its_last_definition_is_removed() {
+    let source = r#"
+        hund = :masc "Hund";
+        baum = :masc "Baum";
+        greet($name) = "Hallo, {@der $name:masc}!";
+    "#;
+
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+
+    assert!(locale.remove_phrase("de", PhraseId::from_name("hund").as_u128()));
+    assert_eq!(
+        locale
+            .call_phrase("greet", &[Value::from("Bäcker")])
+            .unwrap()
+            .to_string(),
+        "Hallo, der Bäcker!"
+    );
+
+    assert!(locale.remove_phrase("de", PhraseId::from_name("baum").as_u128()));
+    let err = locale
+        .call_phrase("greet", &[Value::from("Bäcker")])
+        .unwrap_err();
+    assert!(
+        matches!(&err, EvalError::MissingVariant { key, .. } if key == "masc"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn inline_tag_does_not_override_phrase_variant_selection() {
+    let source = r#"
+        karte = :fem { one: "Karte", other: "Karten" };
+        show($k) = "{@der:nom.other $k:other}";
+    "#;
+
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+
+    let karte = locale.get_phrase("karte").unwrap();
+    assert_eq!(
+        locale
+            .call_phrase("show", &[Value::from(karte)])
+            .unwrap()
+            .to_string(),
+        "die Karten"
+    );
+}
+
 // =============================================================================
 // German Plural Article Tests (@der with .other context)
 // =============================================================================
@@ -1332,6 +3227,75 @@ fn german_der_numeric_plural_context() {
     assert_eq!(result, "die Karte"); // plural nom = "die"
 }
 
+// =============================================================================
+// German Negative Article (@kein/@keine)
+// =============================================================================
+
+fn kein(text: &str, gender: &str, context: &str) -> String {
+    let phrase = Phrase::builder()
+        .text(text.to_string())
+        .tags(vec![Tag::new(gender)])
+        .build();
+    TransformKind::GermanKein
+        .execute(&Value::Phrase(phrase), Some(&Value::from(context)), "de")
+        .unwrap()
+}
+
+#[test]
+fn german_kein_singular_all_cases() {
+    let cases = ["nom", "acc", "dat", "gen"];
+    let expected = [
+        ("Charakter", "masc", ["kein", "keinen", "keinem", "keines"]),
+        ("Karte", "fem", ["keine", "keine", "keiner", "keiner"]),
+        ("Ereignis", "neut", ["kein", "kein", "keinem", "keines"]),
+    ];
+    for (text, gender, articles) in expected {
+        for (case, article) in cases.iter().zip(articles) {
+            assert_eq!(
+                kein(text, gender, case),
+                format!("{article} {text}"),
+                "{gender} {case}"
+            );
+        }
+    }
+}
+
+#[test]
+fn german_kein_plural_all_cases() {
+    assert_eq!(kein("Karten", "fem", "other"), "keine Karten");
+    assert_eq!(kein("Karten", "fem", "acc.other"), "keine Karten");
+    assert_eq!(kein("Karten", "fem", "dat.other"), "keinen Karten");
+    assert_eq!(kein("Charaktere", "masc", "gen.other"), "keiner Charaktere");
+}
+
+#[test]
+fn german_kein_in_template() {
+    let source = r#"
+        karte = :fem { one: "Karte", other: "Karten" };
+        charakter = :masc "Charakter";
+        no_cards = "Du hast {@keine:acc.other karte}.";
+        no_character = "Du hast {@kein:acc charakter}.";
+    "#;
+    let mut locale = Locale::builder().language("de").build();
+    locale.load_translations_str("de", source).unwrap();
+    assert_eq!(
+        locale.get_phrase("no_cards").unwrap().to_string(),
+        "Du hast keine Karten."
+    );
+    assert_eq!(
+        locale.get_phrase("no_character").unwrap().to_string(),
+        "Du hast keinen Charakter."
+    );
+}
+
+#[test]
+fn german_kein_requires_gender() {
+    let err = TransformKind::GermanKein
+        .execute(&Value::from("Karte"), None, "de")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::MissingTag { .. }));
+}
+
 // =============================================================================
 // Dutch Article Transforms (@de/@het, @een)
 // =============================================================================
@@ -1890,6 +3854,61 @@ fn portuguese_em_contraction_feminine() {
     assert_eq!(result, "na mao"); // em + a = na
 }
 
+#[test]
+fn portuguese_por_contraction_masculine() {
+    let phrase = Phrase::builder()
+        .text("vazio".to_string())
+        .tags(vec![Tag::new("masc")])
+        .build();
+    let value = Value::Phrase(phrase);
+    let transform = TransformKind::PortuguesePor;
+    let result = transform.execute(&value, None, "pt").unwrap();
+    assert_eq!(result, "pelo vazio"); // por + o = pelo
+}
+
+#[test]
+fn portuguese_por_contraction_feminine() {
+    let phrase = Phrase::builder()
+        .text("carta".to_string())
+        .tags(vec![Tag::new("fem")])
+        .build();
+    let value = Value::Phrase(phrase);
+    let transform = TransformKind::PortuguesePor;
+    let result = transform.execute(&value, None, "pt").unwrap();
+    assert_eq!(result, "pela carta"); // por + a = pela
+}
+
+#[test]
+fn portuguese_por_contraction_plural() {
+    let transform = TransformKind::PortuguesePor;
+    let context = Value::String("other".to_string());
+    let inimigos = Phrase::builder()
+        .text("inimigos".to_string())
+        .tags(vec![Tag::new("masc")])
+        .build();
+    let result = transform
+        .execute(&Value::Phrase(inimigos), Some(&context), "pt")
+        .unwrap();
+    assert_eq!(result, "pelos inimigos"); // por + os = pelos
+    let cartas = Phrase::builder()
+        .text("cartas".to_string())
+        .tags(vec![Tag::new("fem")])
+        .build();
+    let result = transform
+        .execute(&Value::Phrase(cartas), Some(&context), "pt")
+        .unwrap();
+    assert_eq!(result, "pelas cartas"); // por + as = pelas
+}
+
+#[test]
+fn portuguese_por_registered() {
+    let registry = TransformRegistry::new();
+    assert_eq!(
+        registry.get("por", "pt"),
+        Some(TransformKind::PortuguesePor)
+    );
+}
+
 #[test]
 fn portuguese_transform_aliases() {
     let registry = TransformRegistry::new();
@@ -2271,6 +4290,63 @@ fn french_de_plural() {
     assert_eq!(result, "des cartes"); // de + les = des
 }
 
+#[test]
+fn french_de_negated_partitive() {
+    let pain = Value::Phrase(
+        Phrase::builder()
+            .text("pain".to_string())
+            .tags(vec![Tag::new("masc")])
+            .build(),
+    );
+    let eau = Value::Phrase(
+        Phrase::builder()
+            .text("eau".to_string())
+            .tags(vec![Tag::new("fem"), Tag::new("vowel")])
+            .build(),
+    );
+    let neg = Value::String("neg".to_string());
+    let transform = TransformKind::FrenchDe;
+
+    assert_eq!(transform.execute(&pain, None, "fr").unwrap(), "du pain");
+    assert_eq!(
+        transform.execute(&pain, Some(&neg), "fr").unwrap(),
+        "de pain"
+    );
+    assert_eq!(transform.execute(&eau, None, "fr").unwrap(), "de l'eau");
+    assert_eq!(transform.execute(&eau, Some(&neg), "fr").unwrap(), "d'eau");
+}
+
+#[test]
+fn french_de_negated_plural_in_template() {
+    let source = r#"
+        pomme = :fem { one: "pomme", other: "pommes" };
+        some_apples = "Il y a {@de:other pomme}.";
+        no_apples($n) = "Il n'y a pas {@de:neg($n) pomme}.";
+    "#;
+    let mut locale = Locale::builder().language("fr").build();
+    locale.load_translations_str("fr", source).unwrap();
+
+    assert_eq!(
+        locale.get_phrase("some_apples").unwrap().to_string(),
+        "Il y a des pommes."
+    );
+    assert_eq!(
+        locale
+            .call_phrase("no_apples", &[Value::from("other")])
+            .unwrap()
+            .to_string(),
+        "Il n'y a pas de pommes."
+    );
+}
+
+#[test]
+fn french_de_negation_does_not_require_gender() {
+    let value = Value::String("chance".to_string());
+    let neg = Value::String("neg".to_string());
+    let result = TransformKind::FrenchDe.execute(&value, Some(&neg), "fr");
+    assert_eq!(result.unwrap(), "de chance");
+}
+
 #[test]
 fn french_au_contraction_masculine() {
     let phrase = Phrase::builder()
@@ -2929,6 +5005,62 @@ fn french_liaison_transform_direct() {
     assert_eq!(result, "beau");
 }
 
+#[test]
+fn french_punct_spaces_high_punctuation() {
+    let transform = TransformKind::FrenchPunct;
+    let punct = |text: &str| transform.execute(&Value::from(text), None, "fr").unwrap();
+
+    assert_eq!(punct("Bonjour!"), "Bonjour\u{202F}!");
+    assert_eq!(punct("Bonjour !"), "Bonjour\u{202F}!");
+    assert_eq!(punct("Vraiment?!"), "Vraiment\u{202F}?!");
+    assert_eq!(
+        punct("Attention: piochez; puis"),
+        "Attention\u{202F}: piochez\u{202F}; puis"
+    );
+    assert_eq!(
+        punct("À 10:30, voir https://x.fr"),
+        "À 10:30, voir https://x.fr"
+    );
+}
+
+#[test]
+fn french_punct_does_not_double_existing_spaces() {
+    let transform = TransformKind::FrenchPunct;
+    let spaced = "« Bonjour\u{202F}! »";
+    let result = transform.execute(&Value::from(spaced), None, "fr").unwrap();
+    assert_eq!(result, "«\u{202F}Bonjour\u{202F}!\u{202F}»");
+    let again = transform
+        .execute(&Value::from(result.as_str()), None, "fr")
+        .unwrap();
+    assert_eq!(again, result);
+
+    let nbsp = "Oui\u{00A0}!";
+    assert_eq!(
+        transform.execute(&Value::from(nbsp), None, "fr").unwrap(),
+        nbsp
+    );
+}
+
+#[test]
+fn french_punct_handles_guillemets_and_markup() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            quote($text) = "Il dit «{$text}»";
+            say($text) = "{@punct quote($text)}";
+        "#,
+        )
+        .unwrap();
+    let result = registry
+        .call_phrase("fr", "say", &[Value::from("<b>Salut</b>!")])
+        .unwrap();
+    assert_eq!(
+        result.to_string(),
+        "Il dit «\u{202F}<b>Salut</b>\u{202F}!\u{202F}»"
+    );
+}
+
 #[test]
 fn italian_il_in_template() {
     let source = r#"
@@ -3202,6 +5334,96 @@ fn all_phase7_transforms_work() {
     );
 }
 
+// =============================================================================
+// Elision Apostrophe Style
+// =============================================================================
+
+const ELISION_SOURCE: &str = r#"
+    ami = :masc :vowel "ami";
+    the_friend = "{@le ami}";
+"#;
+
+#[test]
+fn elision_defaults_to_ascii_apostrophe() {
+    let mut locale = Locale::builder().language("fr").build();
+    locale.load_translations_str("fr", ELISION_SOURCE).unwrap();
+
+    assert_eq!(locale.apostrophe_style(), ApostropheStyle::Ascii);
+    let text = locale.get_phrase("the_friend").unwrap().to_string();
+    assert_eq!(text, "l'ami");
+    assert_eq!(text.chars().nth(1), Some('\u{0027}'));
+}
+
+#[test]
+fn elision_with_curly_apostrophe() {
+    let mut locale = Locale::builder().language("fr").build();
+    locale.load_translations_str("fr", ELISION_SOURCE).unwrap();
+    locale.set_apostrophe_style(ApostropheStyle::Curly);
+
+    let text = locale.get_phrase("the_friend").unwrap().to_string();
+    assert_eq!(text, "l\u{2019}ami");
+    assert_eq!(text.chars().nth(1), Some('\u{2019}'));
+}
+
+#[test]
+fn curly_apostrophe_applies_to_all_elided_articles() {
+    let source = r#"
+        ami = :masc :vowel "ami";
+        eau = :fem :vowel "eau";
+        pain = :masc "pain";
+
+        of_friend = "{@de ami}";
+        to_friend = "{@au ami}";
+        no_water = "{@de:neg eau}";
+        no_bread = "{@de:neg pain}";
+    "#;
+    let mut locale = Locale::builder().language("fr").build();
+    locale.load_translations_str("fr", source).unwrap();
+    locale.set_apostrophe_style(ApostropheStyle::Curly);
+    let text = |name: &str| locale.get_phrase(name).unwrap().to_string();
+
+    assert_eq!(text("of_friend"), "de l\u{2019}ami");
+    assert_eq!(text("to_friend"), "\u{e0} l\u{2019}ami");
+    assert_eq!(text("no_water"), "d\u{2019}eau");
+    assert_eq!(text("no_bread"), "de pain");
+
+    let italian = r#"
+        amica = :fem :vowel "amica";
+        the_friend = "{@il amica}";
+        a_friend = "{@un amica}";
+        of_friend = "{@di amica}";
+        to_friend = "{@a amica}";
+    "#;
+    let mut locale = Locale::builder().language("it").build();
+    locale.load_translations_str("it", italian).unwrap();
+    locale.set_apostrophe_style(ApostropheStyle::Curly);
+    let text = |name: &str| locale.get_phrase(name).unwrap().to_string();
+
+    assert_eq!(text("the_friend"), "l\u{2019}amica");
+    assert_eq!(text("a_friend"), "un\u{2019}amica");
+    assert_eq!(text("of_friend"), "dell\u{2019}amica");
+    assert_eq!(text("to_friend"), "all\u{2019}amica");
+}
+
+#[test]
+fn curly_apostrophe_leaves_value_text_untouched() {
+    let mut registry = TransformRegistry::new();
+    registry.set_apostrophe_style(ApostropheStyle::Curly);
+    let value = Value::Phrase(
+        Phrase::builder()
+            .text("aujourd'hui".to_string())
+            .tags(vec![Tag::new("masc"), Tag::new("vowel")])
+            .build(),
+    );
+
+    assert_eq!(
+        registry
+            .execute(TransformKind::FrenchLe, &value, None, "fr")
+            .unwrap(),
+        "l\u{2019}aujourd'hui"
+    );
+}
+
 // =============================================================================
 // Greek Transform Tests (Phase 8)
 // =============================================================================
@@ -3386,19 +5608,53 @@ fn greek_o_plural_accusative() {
 }
 
 #[test]
-fn greek_o_plural_dative() {
-    // Feminine plural dative: ταις
+fn greek_o_dative_is_error_in_modern_greek() {
+    // Modern Greek has no dative; bare `dat` must not produce "ταις"
     let phrase = Phrase::builder()
-        .text("κάρταις".to_string())
+        .text("κάρτες".to_string())
         .tags(vec![Tag::new("fem")])
         .build();
     let value = Value::Phrase(phrase);
     let context = Value::String("dat.other".to_string());
     let transform = TransformKind::GreekO;
+    let err = transform
+        .execute(&value, Some(&context), "el")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("the dative is archaic") && err.contains("dat.katharevousa"),
+        "got: {err}"
+    );
+}
+
+#[test]
+fn greek_o_plural_dative_katharevousa() {
+    // Archaic feminine plural dative, opted into explicitly: ταις
+    let phrase = Phrase::builder()
+        .text("κάρταις".to_string())
+        .tags(vec![Tag::new("fem")])
+        .build();
+    let value = Value::Phrase(phrase);
+    let context = Value::String("dat.other.katharevousa".to_string());
+    let transform = TransformKind::GreekO;
     let result = transform.execute(&value, Some(&context), "el").unwrap();
     assert_eq!(result, "ταις κάρταις");
 }
 
+#[test]
+fn greek_o_katharevousa_ignored_for_variant_lookup() {
+    let source = r#"
+        karta = :fem { one: "κάρτα", other: "κάρτες", dat.other: "κάρταις" };
+        old = "{@i:dat.other.katharevousa karta}";
+    "#;
+    let mut locale = Locale::builder().language("el").build();
+    locale.load_translations_str("el", source).unwrap();
+    assert_eq!(
+        locale.get_phrase("old").unwrap().to_string(),
+        "ταις κάρταις"
+    );
+}
+
 #[test]
 fn greek_o_singular_case_only_still_works() {
     // Backwards compatible: "gen" alone means genitive singular
@@ -3610,6 +5866,47 @@ fn greek_o_missing_gender_tag() {
     assert!(matches!(result, Err(EvalError::MissingTag { .. })));
 }
 
+#[test]
+fn greek_enas_plural_has_no_article() {
+    // Greek has no plural indefinite article: "κάρτες", not "μία κάρτες"
+    let phrase = Phrase::builder()
+        .text("κάρτα".to_string())
+        .tags(vec![Tag::new("fem")])
+        .variants(HashMap::from([
+            (VariantKey::new("one"), "κάρτα".to_string()),
+            (VariantKey::new("other"), "κάρτες".to_string()),
+            (VariantKey::new("acc.other"), "κάρτες".to_string()),
+        ]))
+        .build();
+    let value = Value::Phrase(phrase);
+    let transform = TransformKind::GreekEnas;
+    for context in ["other", "acc.other"] {
+        let context = Value::String(context.to_string());
+        let result = transform.execute(&value, Some(&context), "el").unwrap();
+        assert_eq!(result, "κάρτες");
+    }
+}
+
+#[test]
+fn greek_enas_dative_is_error_in_modern_greek() {
+    let phrase = Phrase::builder()
+        .text("φίλο".to_string())
+        .tags(vec![Tag::new("masc")])
+        .build();
+    let value = Value::Phrase(phrase);
+    let context = Value::String("dat".to_string());
+    let transform = TransformKind::GreekEnas;
+    assert!(matches!(
+        transform.execute(&value, Some(&context), "el"),
+        Err(EvalError::InvalidTransformInput { .. })
+    ));
+    let context = Value::String("dat.katharevousa".to_string());
+    assert_eq!(
+        transform.execute(&value, Some(&context), "el").unwrap(),
+        "ενί φίλο"
+    );
+}
+
 #[test]
 fn greek_enas_missing_gender_tag() {
     // No gender tag produces MissingTag error
@@ -4004,6 +6301,98 @@ fn arabic_al_output_bytes() {
     assert_eq!(result_chars, expected_chars);
 }
 
+#[test]
+fn arabic_connect_al_output_bytes() {
+    // "ال" + moon letter qaf: alef joins backward, qaf is dual-joining
+    let registry = TransformRegistry::new();
+    let al = registry.get("al", "ar").unwrap();
+    let connect = registry.get("connect", "ar").unwrap();
+    assert_eq!(connect, TransformKind::ArabicConnect);
+
+    let phrase = Phrase::builder()
+        .text("ق".to_string()) // qaf (U+0642)
+        .tags(vec![Tag::new("moon")])
+        .build();
+    let article = al.execute(&Value::Phrase(phrase), None, "ar").unwrap();
+    let result = connect.execute(&Value::from(article), None, "ar").unwrap();
+
+    let expected_chars: Vec<char> = vec![
+        '\u{200D}', // ZERO WIDTH JOINER (alef joins to a preceding letter)
+        '\u{0627}', // ARABIC LETTER ALEF
+        '\u{0644}', // ARABIC LETTER LAM
+        '\u{0642}', // ARABIC LETTER QAF
+        '\u{200D}', // ZERO WIDTH JOINER (qaf joins to the following letter)
+    ];
+    assert_eq!(result.chars().collect::<Vec<_>>(), expected_chars);
+}
+
+#[test]
+fn arabic_connect_end_of_right_joining_letter_uses_zwnj() {
+    // Dal (U+062F) only joins backward, so the boundary is a non-joiner
+    let result = TransformKind::ArabicConnect
+        .execute(
+            &Value::from("\u{0628}\u{062F}"),
+            Some(&Value::from("end")),
+            "ar",
+        )
+        .unwrap();
+    assert_eq!(
+        result.chars().collect::<Vec<_>>(),
+        vec!['\u{0628}', '\u{062F}', '\u{200C}']
+    );
+}
+
+#[test]
+fn arabic_connect_skips_marks_and_non_arabic_edges() {
+    // Beh + shadda: the mark is transparent, so beh still connects forward
+    let result = TransformKind::ArabicConnect
+        .execute(
+            &Value::from("\u{0628}\u{0651}"),
+            Some(&Value::from("end")),
+            "ar",
+        )
+        .unwrap();
+    assert_eq!(
+        result.chars().collect::<Vec<_>>(),
+        vec!['\u{0628}', '\u{0651}', '\u{200D}']
+    );
+
+    // Digits and Latin text at the edges get no joiners
+    let result = TransformKind::ArabicConnect
+        .execute(&Value::from("3 x"), None, "fa")
+        .unwrap();
+    assert_eq!(result, "3 x");
+}
+
+#[test]
+fn arabic_connect_persian_start_only() {
+    // Persian gaf (U+06AF) at the start of a fragment
+    let registry = TransformRegistry::new();
+    let connect = registry.get("connect", "fa").unwrap();
+    let result = connect
+        .execute(
+            &Value::from("\u{06AF}\u{0644}"),
+            Some(&Value::from("start")),
+            "fa",
+        )
+        .unwrap();
+    assert_eq!(
+        result.chars().collect::<Vec<_>>(),
+        vec!['\u{200D}', '\u{06AF}', '\u{0644}']
+    );
+}
+
+#[test]
+fn arabic_connect_rejects_unknown_context() {
+    let err = TransformKind::ArabicConnect
+        .execute(&Value::from("\u{0628}"), Some(&Value::from("middle")), "ar")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "connect"
+    ));
+}
+
 // =============================================================================
 // Persian Transforms (Phase 8) - @ezafe connector
 // =============================================================================
@@ -4191,6 +6580,114 @@ fn persian_transform_not_available_for_other_languages() {
     );
 }
 
+// =============================================================================
+// Russian Genitive of Negation (@neg)
+// =============================================================================
+
+fn russian_card() -> Value {
+    Value::Phrase(
+        Phrase::builder()
+            .text("карта".to_string())
+            .variants(HashMap::from([
+                (VariantKey::new("nom.one"), "карта".to_string()),
+                (VariantKey::new("nom.many"), "карт".to_string()),
+                (VariantKey::new("acc.one"), "карту".to_string()),
+                (VariantKey::new("gen.one"), "карты".to_string()),
+                (VariantKey::new("gen.many"), "карт".to_string()),
+            ]))
+            .tags(vec![Tag::new("fem")])
+            .build(),
+    )
+}
+
+#[test]
+fn russian_neg_selects_genitive() {
+    let card = russian_card();
+    let transform = TransformKind::RussianNeg;
+    assert_eq!(transform.execute(&card, None, "ru").unwrap(), "карты");
+    let other = Value::String("other".to_string());
+    assert_eq!(
+        transform.execute(&card, Some(&other), "ru").unwrap(),
+        "карт"
+    );
+    assert_eq!(
+        transform
+            .execute(&card, Some(&Value::Number(5)), "ru")
+            .unwrap(),
+        "карт"
+    );
+}
+
+#[test]
+fn russian_neg_follows_russian_plural_rules() {
+    let card = Value::Phrase(
+        Phrase::builder()
+            .text("карта".to_string())
+            .variants(HashMap::from([
+                (VariantKey::new("gen.one"), "карты".to_string()),
+                (VariantKey::new("gen.few"), "карт·few".to_string()),
+                (VariantKey::new("gen.many"), "карт".to_string()),
+            ]))
+            .build(),
+    );
+    let neg = |context: Value| {
+        TransformKind::RussianNeg
+            .execute(&card, Some(&context), "ru")
+            .unwrap()
+    };
+    assert_eq!(neg(Value::Number(21)), "карты");
+    assert_eq!(neg(Value::Number(3)), "карт·few");
+    assert_eq!(neg(Value::Number(11)), "карт");
+    assert_eq!(neg(Value::from("21")), "карты");
+    assert_eq!(neg(Value::from("few")), "карт·few");
+    assert_eq!(neg(Value::from("other")), "карт");
+    let result = TransformKind::RussianNeg.execute(&card, Some(&Value::from("plural")), "ru");
+    assert!(matches!(
+        result,
+        Err(EvalError::InvalidTransformInput { transform, .. }) if transform == "neg"
+    ));
+}
+
+#[test]
+fn russian_neg_differs_from_affirmative_in_template() {
+    let source = r#"
+        card = :fem {
+            nom.one: "карта", nom.few: "карты", nom.many: "карт",
+            acc.one: "карту", acc.few: "карты", acc.many: "карт",
+            gen.one: "карты", gen.few: "карт", gen.many: "карт"
+        };
+        has_card = "У вас есть {card:nom:one}.";
+        no_card = "У вас нет {@neg card}.";
+        take_card = "Возьмите {card:acc:one}.";
+        dont_take_card = "Не берите {@neg card}.";
+    "#;
+    let mut locale = Locale::builder().language("ru").build();
+    locale.load_translations_str("ru", source).unwrap();
+
+    let get = |name: &str| locale.get_phrase(name).unwrap().to_string();
+    assert_eq!(get("has_card"), "У вас есть карта.");
+    assert_eq!(get("no_card"), "У вас нет карты.");
+    assert_eq!(get("take_card"), "Возьмите карту.");
+    assert_eq!(get("dont_take_card"), "Не берите карты.");
+}
+
+#[test]
+fn russian_neg_requires_genitive_variants() {
+    let value = Value::String("карта".to_string());
+    let result = TransformKind::RussianNeg.execute(&value, None, "ru");
+    assert!(matches!(
+        result,
+        Err(EvalError::InvalidTransformInput { transform, .. }) if transform == "neg"
+    ));
+}
+
+#[test]
+fn russian_neg_registry_lookup() {
+    let registry = TransformRegistry::new();
+    assert_eq!(registry.get("neg", "ru"), Some(TransformKind::RussianNeg));
+    assert_eq!(registry.get("neg", "en"), None);
+}
+
 // =============================================================================
 // CJK Count Transforms (Phase 9)
 // =============================================================================
@@ -4280,6 +6777,48 @@ fn chinese_count_missing_tag() {
     assert!(matches!(result, Err(EvalError::MissingTag { .. })));
 }
 
+#[test]
+fn chinese_count_japanese_counter_is_unknown_classifier() {
+    // :mai is a Japanese counter, not a Chinese classifier
+    let phrase = Phrase::builder()
+        .text("牌".to_string())
+        .tags(vec![Tag::new("mai")])
+        .build();
+    let err = TransformKind::ChineseCount
+        .execute(&Value::Phrase(phrase), Some(&Value::Number(3)), "zh")
+        .unwrap_err();
+    let EvalError::UnknownClassifier { lang, tag, known } = &err else {
+        panic!("expected UnknownClassifier, got {err:?}");
+    };
+    assert_eq!(lang, "zh");
+    assert_eq!(tag, "mai");
+    assert!(known.contains(&"zhang".to_string()));
+    assert_eq!(
+        err.to_string(),
+        "classifier tag ':mai' is not a classifier in 'zh', known: zhang, ge, ming, wei, tiao, ben, zhi"
+    );
+}
+
+#[test]
+fn japanese_count_chinese_classifier_is_unknown_classifier() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            card = :zhang "カード";
+            draw($n) = "{@count($n) card}";
+        "#,
+        )
+        .unwrap();
+    let err = registry
+        .call_phrase("ja", "draw", &[Value::from(2)])
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::UnknownClassifier { ref lang, ref tag, .. } if lang == "ja" && tag == "zhang"
+    ));
+}
+
 #[test]
 fn chinese_count_default_to_one() {
     // Without context, default to count=1
@@ -4487,8 +7026,12 @@ fn cjk_count_transform_registry_lookup() {
 #[test]
 fn cjk_count_not_available_for_other_languages() {
     let registry = TransformRegistry::new();
-    // CJK count transforms should not be available for non-CJK languages
-    assert_eq!(registry.get("count", "en"), None);
+    // CJK count transforms should not be available for non-CJK languages;
+    // English has its own article-aware @count
+    assert_eq!(
+        registry.get("count", "en"),
+        Some(TransformKind::EnglishCount)
+    );
     assert_eq!(registry.get("count", "de"), None);
     assert_eq!(registry.get("count", "es"), None);
 }
@@ -4685,6 +7228,60 @@ fn thai_count_missing_tag() {
     assert!(matches!(result, Err(EvalError::MissingTag { .. })));
 }
 
+// -----------------------------------------------------------------------------
+// Thai @wordbreak Transform Tests
+// -----------------------------------------------------------------------------
+
+#[test]
+fn thai_wordbreak_inserts_zero_width_spaces() {
+    // "จั่วการ์ดหนึ่งใบ" (draw one card) -> จั่ว|การ์ด|หนึ่ง|ใบ
+    let result = TransformKind::ThaiWordBreak
+        .execute(&Value::from("จั่วการ์ดหนึ่งใบ"), None, "th")
+        .unwrap();
+    assert_eq!(result, "จั่ว\u{200B}การ์ด\u{200B}หนึ่ง\u{200B}ใบ");
+}
+
+#[test]
+fn thai_wordbreak_keeps_unknown_text_together() {
+    // Only Thai runs are split; Latin text and unknown words stay intact.
+    let result = TransformKind::ThaiWordBreak
+        .execute(&Value::from("Draw 2 กขคการ์ด!"), None, "th")
+        .unwrap();
+    assert_eq!(result, "Draw 2 กขค\u{200B}การ์ด!");
+}
+
+#[test]
+fn thai_wordbreak_uses_registered_segmenter() {
+    let mut registry = TransformRegistry::new();
+    registry.set_word_segmenter(|run| run.char_indices().map(|(i, _)| i).collect());
+    let result = registry
+        .execute(
+            TransformKind::ThaiWordBreak,
+            &Value::from("กขค"),
+            None,
+            "th",
+        )
+        .unwrap();
+    assert_eq!(result, "ก\u{200B}ข\u{200B}ค");
+}
+
+#[test]
+fn thai_truncate_words_respects_word_boundaries() {
+    let text = Value::from("จั่วการ์ดหนึ่งใบ");
+    let result = TransformKind::TruncateWords
+        .execute(&text, Some(&Value::from("2")), "th")
+        .unwrap();
+    assert_eq!(result, "จั่วการ์ด…");
+
+    let broken = TransformKind::ThaiWordBreak
+        .execute(&text, None, "th")
+        .unwrap();
+    let result = TransformKind::TruncateWords
+        .execute(&Value::from(broken), Some(&Value::from("3")), "th")
+        .unwrap();
+    assert_eq!(result, "จั่ว\u{200B}การ์ด\u{200B}หนึ่ง…");
+}
+
 // -----------------------------------------------------------------------------
 // Bengali @count Transform Tests
 // -----------------------------------------------------------------------------
@@ -4804,6 +7401,10 @@ fn sea_transforms_registered() {
     // Thai @count
     assert!(registry.get("count", "th").is_some());
     assert_eq!(registry.get("count", "th"), Some(TransformKind::ThaiCount));
+    assert_eq!(
+        registry.get("wordbreak", "th"),
+        Some(TransformKind::ThaiWordBreak)
+    );
 
     // Bengali @count
     assert!(registry.get("count", "bn").is_some());
@@ -4833,6 +7434,67 @@ fn sea_count_default_to_one() {
     assert_eq!(result, "1 cái ban");
 }
 
+// -----------------------------------------------------------------------------
+// Malay @count Transform Tests
+// -----------------------------------------------------------------------------
+
+#[test]
+fn malay_count_ekor() {
+    // :ekor "kucing" (cat) with context 3 -> "3 ekor kucing"
+    let phrase = Phrase::builder()
+        .text("kucing".to_string())
+        .tags(vec![Tag::new("ekor")])
+        .build();
+    let result = TransformKind::MalayCount
+        .execute(&Value::Phrase(phrase), Some(&Value::Number(3)), "ms")
+        .unwrap();
+    assert_eq!(result, "3 ekor kucing");
+}
+
+#[test]
+fn malay_count_buah() {
+    // :buah "kereta" (car) with context 2 -> "2 buah kereta"
+    let phrase = Phrase::builder()
+        .text("kereta".to_string())
+        .tags(vec![Tag::new("buah")])
+        .build();
+    let result = TransformKind::MalayCount
+        .execute(&Value::Phrase(phrase), Some(&Value::Number(2)), "ms")
+        .unwrap();
+    assert_eq!(result, "2 buah kereta");
+}
+
+#[test]
+fn malay_count_without_classifier() {
+    // Classifiers are optional in Malay: "5 kad"
+    let phrase = Phrase::builder().text("kad".to_string()).build();
+    let result = TransformKind::MalayCount
+        .execute(&Value::Phrase(phrase), Some(&Value::Number(5)), "ms")
+        .unwrap();
+    assert_eq!(result, "5 kad");
+}
+
+#[test]
+fn malay_count_keeps_noun_singular() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            kucing = :ekor "kucing";
+            see_cats($n) = "Saya nampak {@count($n) kucing}.";
+            "#,
+        )
+        .unwrap();
+    let result = registry
+        .call_phrase("ms", "see_cats", &[Value::from(3)])
+        .unwrap();
+    assert_eq!(result.to_string(), "Saya nampak 3 ekor kucing.");
+    assert_eq!(
+        TransformRegistry::new().get("count", "ms"),
+        Some(TransformKind::MalayCount)
+    );
+}
+
 // =============================================================================
 // Korean @particle Transform Tests (Phase 9)
 // =============================================================================
@@ -5097,6 +7759,76 @@ fn japanese_particle_registered() {
     );
 }
 
+// =============================================================================
+// Japanese @ruby Transform Tests
+// =============================================================================
+
+fn japanese_reading(base: &str, reading: &str) -> Value {
+    let mut variants = HashMap::new();
+    variants.insert(VariantKey::new("base"), base.to_string());
+    variants.insert(VariantKey::new("reading"), reading.to_string());
+    Value::Phrase(
+        Phrase::builder()
+            .text(base.to_string())
+            .variants(variants)
+            .build(),
+    )
+}
+
+#[test]
+fn japanese_ruby_markup() {
+    let kanji = japanese_reading("漢字", "かんじ");
+    let result = TransformKind::JapaneseRuby
+        .execute(&kanji, Some(&Value::from("ruby")), "ja")
+        .unwrap();
+    assert_eq!(result, "<ruby>漢字<rt>かんじ</rt></ruby>");
+}
+
+#[test]
+fn japanese_ruby_plain() {
+    let kanji = japanese_reading("漢字", "かんじ");
+    let transform = TransformKind::JapaneseRuby;
+    assert_eq!(transform.execute(&kanji, None, "ja").unwrap(), "漢字");
+    assert_eq!(
+        transform
+            .execute(&kanji, Some(&Value::from("plain")), "ja")
+            .unwrap(),
+        "漢字"
+    );
+}
+
+#[test]
+fn japanese_ruby_missing_reading_falls_back() {
+    let card = Value::Phrase(Phrase::builder().text("カード".to_string()).build());
+    let result = TransformKind::JapaneseRuby
+        .execute(&card, Some(&Value::from("ruby")), "ja")
+        .unwrap();
+    assert_eq!(result, "カード");
+}
+
+#[test]
+fn japanese_ruby_in_template() {
+    let source = r#"
+        spell = { base: "呪文", reading: "じゅもん" };
+        cast = "{@ruby:ruby spell}を唱える";
+        cast_plain = "{@ruby spell}を唱える";
+    "#;
+    let mut locale = Locale::builder().language("ja").build();
+    locale.load_translations_str("ja", source).unwrap();
+    let call = |name: &str| locale.call_phrase(name, &[]).unwrap().to_string();
+    assert_eq!(call("cast"), "<ruby>呪文<rt>じゅもん</rt></ruby>を唱える");
+    assert_eq!(call("cast_plain"), "呪文を唱える");
+}
+
+#[test]
+fn japanese_ruby_unknown_mode_error() {
+    let kanji = japanese_reading("漢字", "かんじ");
+    let err = TransformKind::JapaneseRuby
+        .execute(&kanji, Some(&Value::from("kana")), "ja")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+}
+
 // =============================================================================
 // Turkish @inflect Transform Tests (Phase 9)
 // =============================================================================
@@ -6693,6 +9425,20 @@ fn finnish_inflect_ill_consonant_ending() {
     assert_eq!(result, "maanan");
 }
 
+#[test]
+fn finnish_inflect_ill_decomposed_vowel_ending() {
+    // "a" + combining diaeresis is one vowel grapheme and is duplicated whole
+    let phrase = Phrase::builder()
+        .text("py\u{0308}ta\u{0308}".to_string())
+        .tags(vec![Tag::new("front")])
+        .build();
+    let value = Value::Phrase(phrase);
+    let transform = TransformKind::FinnishInflect;
+    let context = Value::String("ill".to_string());
+    let result = transform.execute(&value, Some(&context), "fi").unwrap();
+    assert_eq!(result, "py\u{0308}ta\u{0308}a\u{0308}n");
+}
+
 // =============================================================================
 // Hungarian @inflect Transform
 // =============================================================================
@@ -6745,25 +9491,25 @@ fn hungarian_inflect_nom_front() {
 
 #[test]
 fn hungarian_inflect_nom_round() {
-    // :round "tükör" + :nom -> "tükör"
+    // :round "gyümölcs" + :nom -> "gyümölcs"
     let phrase = Phrase::builder()
-        .text("tükör".to_string())
+        .text("gyümölcs".to_string())
         .tags(vec![Tag::new("round")])
         .build();
     let value = Value::Phrase(phrase);
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("nom".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "tükör");
+    assert_eq!(result, "gyümölcs");
 }
 
 // -----------------------------------------------------------------------------
-// Hungarian @inflect - Plural (-ok/-ek/-ök)
+// Hungarian @inflect - Plural (-ok/-ak/-ek/-ök)
 // -----------------------------------------------------------------------------
 
 #[test]
 fn hungarian_inflect_pl_back() {
-    // :back "ház" + :pl -> "házok"
+    // :back "ház" + :pl -> "házak"
     let phrase = Phrase::builder()
         .text("ház".to_string())
         .tags(vec![Tag::new("back")])
@@ -6772,7 +9518,7 @@ fn hungarian_inflect_pl_back() {
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("pl".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "házok");
+    assert_eq!(result, "házak");
 }
 
 #[test]
@@ -6791,25 +9537,25 @@ fn hungarian_inflect_pl_front() {
 
 #[test]
 fn hungarian_inflect_pl_round() {
-    // :round "tükör" + :pl -> "tükörök"
+    // :round "gyümölcs" + :pl -> "gyümölcsök"
     let phrase = Phrase::builder()
-        .text("tükör".to_string())
+        .text("gyümölcs".to_string())
         .tags(vec![Tag::new("round")])
         .build();
     let value = Value::Phrase(phrase);
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("pl".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "tükörök");
+    assert_eq!(result, "gyümölcsök");
 }
 
 // -----------------------------------------------------------------------------
-// Hungarian @inflect - Accusative (-ot/-et/-öt)
+// Hungarian @inflect - Accusative (-ot/-at/-et/-öt)
 // -----------------------------------------------------------------------------
 
 #[test]
 fn hungarian_inflect_acc_back() {
-    // :back "ház" + :acc -> "házot"
+    // :back "ház" + :acc -> "házat"
     let phrase = Phrase::builder()
         .text("ház".to_string())
         .tags(vec![Tag::new("back")])
@@ -6818,7 +9564,7 @@ fn hungarian_inflect_acc_back() {
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("acc".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "házot");
+    assert_eq!(result, "házat");
 }
 
 #[test]
@@ -6837,16 +9583,16 @@ fn hungarian_inflect_acc_front() {
 
 #[test]
 fn hungarian_inflect_acc_round() {
-    // :round "tükör" + :acc -> "tüköröt"
+    // :round "gyümölcs" + :acc -> "gyümölcsöt"
     let phrase = Phrase::builder()
-        .text("tükör".to_string())
+        .text("gyümölcs".to_string())
         .tags(vec![Tag::new("round")])
         .build();
     let value = Value::Phrase(phrase);
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("acc".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "tüköröt");
+    assert_eq!(result, "gyümölcsöt");
 }
 
 // -----------------------------------------------------------------------------
@@ -7011,16 +9757,16 @@ fn hungarian_inflect_sup_front() {
 
 #[test]
 fn hungarian_inflect_sup_round() {
-    // :round "tükör" + :sup -> "tükörön"
+    // :round "gyümölcs" + :sup -> "gyümölcsön"
     let phrase = Phrase::builder()
-        .text("tükör".to_string())
+        .text("gyümölcs".to_string())
         .tags(vec![Tag::new("round")])
         .build();
     let value = Value::Phrase(phrase);
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("sup".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "tükörön");
+    assert_eq!(result, "gyümölcsön");
 }
 
 // -----------------------------------------------------------------------------
@@ -7185,16 +9931,16 @@ fn hungarian_inflect_all_front() {
 
 #[test]
 fn hungarian_inflect_all_round() {
-    // :round "tükör" + :all -> "tükörhöz"
+    // :round "gyümölcs" + :all -> "gyümölcshöz"
     let phrase = Phrase::builder()
-        .text("tükör".to_string())
+        .text("gyümölcs".to_string())
         .tags(vec![Tag::new("round")])
         .build();
     let value = Value::Phrase(phrase);
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("all".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "tükörhöz");
+    assert_eq!(result, "gyümölcshöz");
 }
 
 // -----------------------------------------------------------------------------
@@ -7327,7 +10073,7 @@ fn hungarian_inflect_ess_back() {
 
 #[test]
 fn hungarian_inflect_poss1sg_back() {
-    // :back "ház" + :poss1sg -> "házom"
+    // :back "ház" + :poss1sg -> "házam"
     let phrase = Phrase::builder()
         .text("ház".to_string())
         .tags(vec![Tag::new("back")])
@@ -7336,7 +10082,7 @@ fn hungarian_inflect_poss1sg_back() {
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("poss1sg".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "házom");
+    assert_eq!(result, "házam");
 }
 
 #[test]
@@ -7355,21 +10101,21 @@ fn hungarian_inflect_poss1sg_front() {
 
 #[test]
 fn hungarian_inflect_poss1sg_round() {
-    // :round "tükör" + :poss1sg -> "tükörröm" (simplified, using -öm suffix)
+    // :round "gyümölcs" + :poss1sg -> "gyümölcsöm"
     let phrase = Phrase::builder()
-        .text("tükör".to_string())
+        .text("gyümölcs".to_string())
         .tags(vec![Tag::new("round")])
         .build();
     let value = Value::Phrase(phrase);
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("poss1sg".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "tükör\u{00f6}m");
+    assert_eq!(result, "gyümölcsöm");
 }
 
 #[test]
 fn hungarian_inflect_poss2sg_back() {
-    // :back "ház" + :poss2sg -> "házod"
+    // :back "ház" + :poss2sg -> "házad"
     let phrase = Phrase::builder()
         .text("ház".to_string())
         .tags(vec![Tag::new("back")])
@@ -7378,7 +10124,7 @@ fn hungarian_inflect_poss2sg_back() {
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("poss2sg".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "házod");
+    assert_eq!(result, "házad");
 }
 
 #[test]
@@ -7439,7 +10185,7 @@ fn hungarian_inflect_poss1pl_front() {
 
 #[test]
 fn hungarian_inflect_poss2pl_back() {
-    // :back "ház" + :poss2pl -> "háztok"
+    // :back "ház" + :poss2pl -> "házatok"
     let phrase = Phrase::builder()
         .text("ház".to_string())
         .tags(vec![Tag::new("back")])
@@ -7448,12 +10194,12 @@ fn hungarian_inflect_poss2pl_back() {
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("poss2pl".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "háztok");
+    assert_eq!(result, "házatok");
 }
 
 #[test]
 fn hungarian_inflect_poss2pl_front() {
-    // :front "kert" + :poss2pl -> "kerttek"
+    // :front "kert" + :poss2pl -> "kertetek"
     let phrase = Phrase::builder()
         .text("kert".to_string())
         .tags(vec![Tag::new("front")])
@@ -7462,21 +10208,21 @@ fn hungarian_inflect_poss2pl_front() {
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("poss2pl".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "kerttek");
+    assert_eq!(result, "kertetek");
 }
 
 #[test]
 fn hungarian_inflect_poss2pl_round() {
-    // :round "tükör" + :poss2pl -> "tükörtök"
+    // :round "gyümölcs" + :poss2pl -> "gyümölcsötök"
     let phrase = Phrase::builder()
-        .text("tükör".to_string())
+        .text("gyümölcs".to_string())
         .tags(vec![Tag::new("round")])
         .build();
     let value = Value::Phrase(phrase);
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("poss2pl".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "tükörtök");
+    assert_eq!(result, "gyümölcsötök");
 }
 
 #[test]
@@ -7513,7 +10259,7 @@ fn hungarian_inflect_poss3pl_front() {
 
 #[test]
 fn hungarian_inflect_pl_dat_back() {
-    // :back "ház" + :pl.dat -> "házoknak"
+    // :back "ház" + :pl.dat -> "házaknak"
     let phrase = Phrase::builder()
         .text("ház".to_string())
         .tags(vec![Tag::new("back")])
@@ -7522,7 +10268,7 @@ fn hungarian_inflect_pl_dat_back() {
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("pl.dat".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "házoknak");
+    assert_eq!(result, "házaknak");
 }
 
 #[test]
@@ -7541,7 +10287,7 @@ fn hungarian_inflect_pl_ine_front() {
 
 #[test]
 fn hungarian_inflect_poss1sg_dat_back() {
-    // :back "ház" + :poss1sg.dat -> "házomnak"
+    // :back "ház" + :poss1sg.dat -> "házamnak"
     let phrase = Phrase::builder()
         .text("ház".to_string())
         .tags(vec![Tag::new("back")])
@@ -7550,21 +10296,78 @@ fn hungarian_inflect_poss1sg_dat_back() {
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("poss1sg.dat".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "házomnak");
+    assert_eq!(result, "házamnak");
 }
 
 #[test]
 fn hungarian_inflect_pl_abl_round() {
-    // :round "tükör" + :pl.abl -> "tükörök" + "től" -> "tüköröktől"
+    // :round "gyümölcs" + :pl.abl -> "gyümölcsök" + "től" -> "gyümölcsöktől"
     let phrase = Phrase::builder()
-        .text("tükör".to_string())
+        .text("gyümölcs".to_string())
         .tags(vec![Tag::new("round")])
         .build();
     let value = Value::Phrase(phrase);
     let transform = TransformKind::HungarianInflect;
     let context = Value::String("pl.abl".to_string());
     let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "tüköröktől");
+    assert_eq!(result, "gyümölcsöktől");
+}
+
+// -----------------------------------------------------------------------------
+// Hungarian @inflect - Stem shape
+// -----------------------------------------------------------------------------
+
+fn hungarian_inflect(text: &str, tags: &[&str], context: &str) -> String {
+    let phrase = Phrase::builder()
+        .text(text.to_string())
+        .tags(tags.iter().map(|t| Tag::new(*t)).collect())
+        .build();
+    let context = Value::String(context.to_string());
+    TransformKind::HungarianInflect
+        .execute(&Value::Phrase(phrase), Some(&context), "hu")
+        .unwrap()
+}
+
+#[test]
+fn hungarian_inflect_vowel_final_lengthens() {
+    assert_eq!(hungarian_inflect("alma", &["back"], "acc"), "almát");
+    assert_eq!(hungarian_inflect("alma", &["back"], "pl"), "almák");
+    assert_eq!(hungarian_inflect("alma", &["back"], "sup"), "almán");
+    assert_eq!(hungarian_inflect("alma", &["back"], "poss1sg"), "almám");
+    assert_eq!(hungarian_inflect("alma", &["back"], "poss3sg"), "almája");
+    assert_eq!(hungarian_inflect("alma", &["back"], "poss3pl"), "almájuk");
+    assert_eq!(hungarian_inflect("alma", &["back"], "ine"), "almában");
+    assert_eq!(hungarian_inflect("kefe", &["front"], "acc"), "kefét");
+    assert_eq!(hungarian_inflect("alma", &["back"], "ess"), "almaként");
+}
+
+#[test]
+fn hungarian_inflect_bare_accusative_after_sonorant() {
+    assert_eq!(hungarian_inflect("asztal", &["back"], "acc"), "asztalt");
+    assert_eq!(hungarian_inflect("bor", &["back"], "acc"), "bort");
+    assert_eq!(hungarian_inflect("ember", &["front"], "acc"), "embert");
+    assert_eq!(hungarian_inflect("asztal", &["back"], "pl"), "asztalok");
+}
+
+#[test]
+fn hungarian_inflect_low_tag_lowers_linking_vowel() {
+    assert_eq!(
+        hungarian_inflect("föld", &["round", "low"], "acc"),
+        "földet"
+    );
+    assert_eq!(hungarian_inflect("föld", &["round", "low"], "pl"), "földek");
+    assert_eq!(
+        hungarian_inflect("föld", &["round", "low"], "sup"),
+        "földön"
+    );
+    assert_eq!(hungarian_inflect("toll", &["back", "low"], "acc"), "tollat");
+}
+
+#[test]
+fn hungarian_inflect_plural_then_accusative() {
+    assert_eq!(hungarian_inflect("ház", &["back"], "pl.acc"), "házakat");
+    assert_eq!(hungarian_inflect("kert", &["front"], "pl.acc"), "kerteket");
+    assert_eq!(hungarian_inflect("alma", &["back"], "pl.acc"), "almákat");
 }
 
 // -----------------------------------------------------------------------------
@@ -7572,47 +10375,291 @@ fn hungarian_inflect_pl_abl_round() {
 // -----------------------------------------------------------------------------
 
 #[test]
-fn hungarian_inflect_missing_harmony() {
-    // No harmony tag -> error
-    let phrase = Phrase::builder().text("ház".to_string()).build();
-    let value = Value::Phrase(phrase);
-    let transform = TransformKind::HungarianInflect;
-    let context = Value::String("dat".to_string());
-    let result = transform.execute(&value, Some(&context), "hu");
-    assert!(result.is_err());
+fn hungarian_inflect_missing_harmony() {
+    // No harmony tag -> error
+    let phrase = Phrase::builder().text("ház".to_string()).build();
+    let value = Value::Phrase(phrase);
+    let transform = TransformKind::HungarianInflect;
+    let context = Value::String("dat".to_string());
+    let result = transform.execute(&value, Some(&context), "hu");
+    assert!(result.is_err());
+}
+
+#[test]
+fn hungarian_inflect_no_context() {
+    // No context -> no suffixes added, word returned as-is
+    let phrase = Phrase::builder()
+        .text("ház".to_string())
+        .tags(vec![Tag::new("back")])
+        .build();
+    let value = Value::Phrase(phrase);
+    let transform = TransformKind::HungarianInflect;
+    let result = transform.execute(&value, None, "hu").unwrap();
+    assert_eq!(result, "ház");
+}
+
+#[test]
+fn hungarian_inflect_unknown_suffix_ignored() {
+    // Unknown suffix parts are ignored
+    let phrase = Phrase::builder()
+        .text("ház".to_string())
+        .tags(vec![Tag::new("back")])
+        .build();
+    let value = Value::Phrase(phrase);
+    let transform = TransformKind::HungarianInflect;
+    let context = Value::String("dat.xyz".to_string());
+    let result = transform.execute(&value, Some(&context), "hu").unwrap();
+    assert_eq!(result, "háznak");
+}
+
+#[test]
+fn hungarian_inflect_not_registered_for_english() {
+    let registry = TransformRegistry::new();
+    assert!(registry.get("inflect", "en").is_none());
+}
+
+// =============================================================================
+// Basque @inflect Transform
+// =============================================================================
+
+fn basque_inflect(text: &str, context: &str) -> String {
+    let value = Value::from(text);
+    let context = Value::String(context.to_string());
+    TransformKind::BasqueInflect
+        .execute(&value, Some(&context), "eu")
+        .unwrap()
+}
+
+#[test]
+fn basque_inflect_registered() {
+    let registry = TransformRegistry::new();
+    assert_eq!(
+        registry.get("inflect", "eu"),
+        Some(TransformKind::BasqueInflect)
+    );
+}
+
+#[test]
+fn basque_inflect_absolutive_singular() {
+    assert_eq!(basque_inflect("etxe", "abs"), "etxea");
+    assert_eq!(basque_inflect("lagun", "abs"), "laguna");
+    // Stem-final -a merges with the article
+    assert_eq!(basque_inflect("neska", "abs"), "neska");
+}
+
+#[test]
+fn basque_inflect_absolutive_plural() {
+    assert_eq!(basque_inflect("etxe", "abs.pl"), "etxeak");
+    assert_eq!(basque_inflect("lagun", "abs.pl"), "lagunak");
+    assert_eq!(basque_inflect("neska", "abs.other"), "neskak");
+}
+
+#[test]
+fn basque_inflect_ergative() {
+    assert_eq!(basque_inflect("lagun", "erg"), "lagunak");
+    assert_eq!(basque_inflect("lagun", "erg.pl"), "lagunek");
+    assert_eq!(basque_inflect("etxe", "erg.pl"), "etxeek");
+    assert_eq!(basque_inflect("neska", "erg.pl"), "neskek");
+}
+
+#[test]
+fn basque_inflect_dative_and_genitive() {
+    assert_eq!(basque_inflect("lagun", "dat"), "lagunari");
+    assert_eq!(basque_inflect("lagun", "dat.pl"), "lagunei");
+    assert_eq!(basque_inflect("lagun", "gen"), "lagunaren");
+    assert_eq!(basque_inflect("lagun", "gen.pl"), "lagunen");
+}
+
+#[test]
+fn basque_inflect_defaults_to_absolutive_singular() {
+    let value = Value::from("etxe");
+    let result = TransformKind::BasqueInflect
+        .execute(&value, None, "eu")
+        .unwrap();
+    assert_eq!(result, "etxea");
+}
+
+#[test]
+fn basque_inflect_number_from_parameter() {
+    let source = r#"
+        friend = "lagun";
+        saw($n) = "{@inflect:erg($n) friend} ikusi du";
+    "#;
+    let mut locale = Locale::builder().language("eu").build();
+    locale.load_translations_str("eu", source).unwrap();
+    let call = |n: i64| {
+        locale
+            .call_phrase("saw", &[Value::from(n)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(call(1), "lagunak ikusi du");
+    assert_eq!(call(3), "lagunek ikusi du");
+}
+
+// =============================================================================
+// Swahili @agree Transform
+// =============================================================================
+
+fn swahili_noun(text: &str, class: &str) -> Value {
+    Value::Phrase(
+        Phrase::builder()
+            .text(text.to_string())
+            .tags(vec![Tag::new(class)])
+            .build(),
+    )
+}
+
+#[test]
+fn swahili_agree_class_1_to_2() {
+    let mtoto = swahili_noun("mtoto", "cl1");
+    let transform = TransformKind::SwahiliAgree;
+    let agree = |context: &str| {
+        transform
+            .execute(&mtoto, Some(&Value::from(context)), "sw")
+            .unwrap()
+    };
+    assert_eq!(agree("zuri"), "mzuri");
+    assert_eq!(agree("zuri.pl"), "wazuri");
+    assert_eq!(agree("ema"), "mwema");
+    assert_eq!(agree("ema.other"), "wema");
+    assert_eq!(agree("ingine.pl"), "wengine");
+}
+
+#[test]
+fn swahili_agree_class_7_to_8() {
+    let kitabu = swahili_noun("kitabu", "cl7");
+    let transform = TransformKind::SwahiliAgree;
+    let agree = |context: &str| {
+        transform
+            .execute(&kitabu, Some(&Value::from(context)), "sw")
+            .unwrap()
+    };
+    assert_eq!(agree("kubwa"), "kikubwa");
+    assert_eq!(agree("kubwa.pl"), "vikubwa");
+    assert_eq!(agree("ema"), "chema");
+    assert_eq!(agree("ema.pl"), "vyema");
+}
+
+#[test]
+fn swahili_agree_number_from_parameter() {
+    let source = r#"
+        child = :cl1 { one: "mtoto", other: "watoto" };
+        good_child($n) = "{child:$n} {@agree:zuri($n) child}";
+    "#;
+    let mut locale = Locale::builder().language("sw").build();
+    locale.load_translations_str("sw", source).unwrap();
+    let call = |n: i64| {
+        locale
+            .call_phrase("good_child", &[Value::from(n)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(call(1), "mtoto mzuri");
+    assert_eq!(call(3), "watoto wazuri");
+}
+
+#[test]
+fn swahili_agree_missing_class_error() {
+    let value = Value::from("mtoto");
+    let err = TransformKind::SwahiliAgree
+        .execute(&value, Some(&Value::from("zuri")), "sw")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::MissingTag { ref transform, ref expected, .. }
+            if transform == "agree" && expected.contains(&"cl1".to_string())
+    ));
+}
+
+#[test]
+fn swahili_agree_requires_stem_context() {
+    let mtoto = swahili_noun("mtoto", "cl1");
+    let err = TransformKind::SwahiliAgree
+        .execute(&mtoto, None, "sw")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+}
+
+// =============================================================================
+// Irish @mutate Transform
+// =============================================================================
+
+fn irish_mutate(text: &str, context: &str) -> String {
+    TransformKind::IrishMutate
+        .execute(&Value::from(text), Some(&Value::from(context)), "ga")
+        .unwrap()
+}
+
+#[test]
+fn irish_mutate_lenition() {
+    assert_eq!(irish_mutate("cáca", "len"), "cháca");
+    assert_eq!(irish_mutate("bád", "len"), "bhád");
+    assert_eq!(irish_mutate("Seán", "len"), "Sheán");
+    assert_eq!(irish_mutate("BÁD", "len"), "BHÁD");
+}
+
+#[test]
+fn irish_mutate_lenition_unchanged() {
+    // Vowels, l/n/r, s-clusters, and already-lenited words are left alone
+    assert_eq!(irish_mutate("úll", "len"), "úll");
+    assert_eq!(irish_mutate("leabhar", "len"), "leabhar");
+    assert_eq!(irish_mutate("stól", "len"), "stól");
+    assert_eq!(irish_mutate("cháca", "len"), "cháca");
+}
+
+#[test]
+fn irish_mutate_eclipsis() {
+    assert_eq!(irish_mutate("bád", "ecl"), "mbád");
+    assert_eq!(irish_mutate("cáca", "ecl"), "gcáca");
+    assert_eq!(irish_mutate("fuinneog", "ecl"), "bhfuinneog");
+    assert_eq!(irish_mutate("teach", "ecl"), "dteach");
+    assert_eq!(irish_mutate("Baile", "ecl"), "mBaile");
+}
+
+#[test]
+fn irish_mutate_eclipsis_vowel_initial() {
+    assert_eq!(irish_mutate("úll", "ecl"), "n-úll");
+    assert_eq!(irish_mutate("Éire", "ecl"), "nÉire");
 }
 
 #[test]
-fn hungarian_inflect_no_context() {
-    // No context -> no suffixes added, word returned as-is
-    let phrase = Phrase::builder()
-        .text("ház".to_string())
-        .tags(vec![Tag::new("back")])
-        .build();
-    let value = Value::Phrase(phrase);
-    let transform = TransformKind::HungarianInflect;
-    let result = transform.execute(&value, None, "hu").unwrap();
-    assert_eq!(result, "ház");
+fn irish_mutate_eclipsis_unchanged() {
+    // Already-eclipsed words and non-eclipsing consonants are left alone
+    assert_eq!(irish_mutate("mbád", "ecl"), "mbád");
+    assert_eq!(irish_mutate("bhfuinneog", "ecl"), "bhfuinneog");
+    assert_eq!(irish_mutate("n-úll", "ecl"), "n-úll");
+    assert_eq!(irish_mutate("leabhar", "ecl"), "leabhar");
 }
 
 #[test]
-fn hungarian_inflect_unknown_suffix_ignored() {
-    // Unknown suffix parts are ignored
-    let phrase = Phrase::builder()
-        .text("ház".to_string())
-        .tags(vec![Tag::new("back")])
-        .build();
-    let value = Value::Phrase(phrase);
-    let transform = TransformKind::HungarianInflect;
-    let context = Value::String("dat.xyz".to_string());
-    let result = transform.execute(&value, Some(&context), "hu").unwrap();
-    assert_eq!(result, "háznak");
+fn irish_mutate_in_template() {
+    let source = r#"
+        boat = "bád";
+        my_boat = "mo {@mutate:len boat}";
+        our_boat = "ár {@mutate:ecl boat}";
+    "#;
+    let mut locale = Locale::builder().language("ga").build();
+    locale.load_translations_str("ga", source).unwrap();
+    let call = |name: &str| locale.call_phrase(name, &[]).unwrap().to_string();
+    assert_eq!(call("my_boat"), "mo bhád");
+    assert_eq!(call("our_boat"), "ár mbád");
 }
 
 #[test]
-fn hungarian_inflect_not_registered_for_english() {
-    let registry = TransformRegistry::new();
-    assert!(registry.get("inflect", "en").is_none());
+fn irish_mutate_requires_context() {
+    let value = Value::from("bád");
+    let err = TransformKind::IrishMutate
+        .execute(&value, None, "ga")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "mutate"
+    ));
+    let err = TransformKind::IrishMutate
+        .execute(&value, Some(&Value::from("h")), "ga")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
 }
 
 // =============================================================================
@@ -8138,3 +11185,523 @@ fn explicit_cap_a_still_works() {
         "An event"
     );
 }
+
+// =============================================================================
+// Spell-Out Transform (@spell)
+// =============================================================================
+
+#[test]
+fn spell_inserts_separator_between_graphemes() {
+    let result = TransformKind::Spell
+        .execute(&Value::from("AB12"), None, "en")
+        .unwrap();
+    assert_eq!(result, "A-B-1-2");
+}
+
+#[test]
+fn spell_strips_markup_and_whitespace() {
+    let result = TransformKind::Spell
+        .execute(&Value::from("<b>X7</b> Q"), None, "de")
+        .unwrap();
+    assert_eq!(result, "X-7-Q");
+}
+
+#[test]
+fn spell_nato_mode_in_english() {
+    let nato = Value::from("nato");
+    let result = TransformKind::Spell
+        .execute(&Value::from("Ab1"), Some(&nato), "en-US")
+        .unwrap();
+    assert_eq!(result, "Alpha Bravo One");
+}
+
+#[test]
+fn spell_nato_context_ignored_outside_english() {
+    let nato = Value::from("nato");
+    let result = TransformKind::Spell
+        .execute(&Value::from("AB"), Some(&nato), "fr")
+        .unwrap();
+    assert_eq!(result, "A-B");
+}
+
+#[test]
+fn spell_separator_context() {
+    let underscore = Value::from("_");
+    let space = Value::from("space");
+    let code = Value::from("AB12");
+    assert_eq!(
+        TransformKind::Spell
+            .execute(&code, Some(&underscore), "de")
+            .unwrap(),
+        "A_B_1_2"
+    );
+    assert_eq!(
+        TransformKind::Spell
+            .execute(&code, Some(&space), "en")
+            .unwrap(),
+        "A B 1 2"
+    );
+}
+
+#[test]
+fn spell_rejects_unknown_context() {
+    let typo = Value::from("nto");
+    let err = TransformKind::Spell
+        .execute(&Value::from("AB"), Some(&typo), "en")
+        .unwrap_err();
+    assert!(
+        matches!(&err, EvalError::InvalidTransformInput { transform, value, .. }
+            if transform == "spell" && value == "nto"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn spell_in_template() {
+    let source = r#"
+        confirm($code) = "Your code is {@spell $code}.";
+        readout($code) = "{@spell:nato $code}";
+        slug($code) = "{@spell:_ $code}";
+    "#;
+
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+
+    assert_eq!(
+        locale
+            .call_phrase("confirm", &[Value::from("ZX9")])
+            .unwrap()
+            .to_string(),
+        "Your code is Z-X-9."
+    );
+    assert_eq!(
+        locale
+            .call_phrase("readout", &[Value::from("ZX9")])
+            .unwrap()
+            .to_string(),
+        "Zulu X-ray Nine"
+    );
+    assert_eq!(
+        locale
+            .call_phrase("slug", &[Value::from("ZX9")])
+            .unwrap()
+            .to_string(),
+        "Z_X_9"
+    );
+}
+
+// =============================================================================
+// Currency Transform (@currency)
+// =============================================================================
+
+#[test]
+fn currency_usd_in_english() {
+    let usd = Value::from("USD");
+    let format = |amount: Value| {
+        TransformKind::Currency
+            .execute(&amount, Some(&usd), "en")
+            .unwrap()
+    };
+    assert_eq!(format(Value::from(1234.5)), "$1,234.50");
+    assert_eq!(format(Value::from(5)), "$5.00");
+    assert_eq!(format(Value::from(1_000_000)), "$1,000,000.00");
+    assert_eq!(format(Value::from(-0.5)), "-$0.50");
+}
+
+#[test]
+fn currency_eur_in_french() {
+    let eur = Value::from("EUR");
+    let result = TransformKind::Currency
+        .execute(&Value::from(1234.5), Some(&eur), "fr")
+        .unwrap();
+    assert_eq!(result, "1\u{202F}234,50\u{00A0}\u{20AC}");
+}
+
+#[test]
+fn currency_minor_units_and_unknown_codes() {
+    let jpy = Value::from("JPY");
+    let chf = Value::from("chf");
+    assert_eq!(
+        TransformKind::Currency
+            .execute(&Value::from(1500), Some(&jpy), "en")
+            .unwrap(),
+        "\u{00A5}1,500"
+    );
+    assert_eq!(
+        TransformKind::Currency
+            .execute(&Value::from(12.345), Some(&chf), "de")
+            .unwrap(),
+        "12,35\u{00A0}CHF"
+    );
+}
+
+#[test]
+fn currency_rejects_non_numeric_value() {
+    let usd = Value::from("USD");
+    for value in [Value::from("lots"), Value::from("12.50")] {
+        let err = TransformKind::Currency
+            .execute(&value, Some(&usd), "en")
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                EvalError::InvalidTransformInput { ref transform, .. } if transform == "currency"
+            ),
+            "{err:?}"
+        );
+    }
+}
+
+#[test]
+fn currency_in_template() {
+    let source = r#"
+        price($amount) = "Price: {@currency:USD $amount}";
+    "#;
+    let mut locale = Locale::builder().language("en").build();
+    locale.load_translations_str("en", source).unwrap();
+    assert_eq!(
+        locale
+            .call_phrase("price", &[Value::from(19.99)])
+            .unwrap()
+            .to_string(),
+        "Price: $19.99"
+    );
+}
+
+// =============================================================================
+// Number Transform (@num) and Digit Styles
+// =============================================================================
+
+#[test]
+fn num_groups_with_language_separators() {
+    let format = |value: Value, lang: &str| TransformKind::Num.execute(&value, None, lang).unwrap();
+    assert_eq!(format(Value::from(1_234_567), "en"), "1,234,567");
+    assert_eq!(format(Value::from(-1234.5), "de"), "-1.234,5");
+    assert_eq!(format(Value::from(" 999 "), "en"), "999");
+}
+
+#[test]
+fn num_native_context_persian_digits() {
+    let native = Value::from("native");
+    let result = TransformKind::Num
+        .execute(&Value::from("1234"), Some(&native), "fa")
+        .unwrap();
+    assert_eq!(result, "\u{06F1}\u{066C}\u{06F2}\u{06F3}\u{06F4}");
+}
+
+#[test]
+fn num_native_context_arabic_indic_digits() {
+    let native = Value::from("native");
+    let result = TransformKind::Num
+        .execute(&Value::from("1234"), Some(&native), "ar")
+        .unwrap();
+    assert_eq!(result, "\u{0661}\u{066C}\u{0662}\u{0663}\u{0664}");
+    let decimal = TransformKind::Num
+        .execute(&Value::from(0.5), Some(&native), "ar")
+        .unwrap();
+    assert_eq!(decimal, "\u{0660}\u{066B}\u{0665}");
+}
+
+#[test]
+fn num_rejects_non_numeric_value_and_unknown_context() {
+    let err = TransformKind::Num
+        .execute(&Value::from("lots"), None, "en")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "num"
+    ));
+    let err = TransformKind::Num
+        .execute(&Value::from(1), Some(&Value::from("arab")), "en")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+}
+
+// =============================================================================
+// Scientific Notation Transform (@scientific)
+// =============================================================================
+
+fn scientific(value: Value, context: Option<&str>, lang: &str) -> String {
+    let context = context.map(Value::from);
+    TransformKind::Scientific
+        .execute(&value, context.as_ref(), lang)
+        .unwrap()
+}
+
+#[test]
+fn scientific_shortest_mantissa() {
+    assert_eq!(scientific(Value::from(1_200_000), None, "en"), "1.2e6");
+    assert_eq!(scientific(Value::from(5), None, "en"), "5e0");
+    assert_eq!(scientific(Value::from(-0.00025), None, "en"), "-2.5e-4");
+    assert_eq!(scientific(Value::from("3000"), None, "en"), "3e3");
+}
+
+#[test]
+fn scientific_significant_digits() {
+    assert_eq!(
+        scientific(Value::from(1_234_567), Some("3"), "en"),
+        "1.23e6"
+    );
+    assert_eq!(
+        scientific(Value::from(1_200_000), Some("3"), "en"),
+        "1.20e6"
+    );
+    assert_eq!(scientific(Value::from(987_654), Some("1"), "en"), "1e6");
+    let digits = Value::from(2);
+    let result = TransformKind::Scientific
+        .execute(&Value::from(45_678), Some(&digits), "en")
+        .unwrap();
+    assert_eq!(result, "4.6e4");
+}
+
+#[test]
+fn scientific_german_decimal_comma() {
+    assert_eq!(scientific(Value::from(1_200_000), None, "de"), "1,2e6");
+    assert_eq!(
+        scientific(Value::from(1_234_567), Some("3"), "de-AT"),
+        "1,23e6"
+    );
+}
+
+#[test]
+fn scientific_engineering_notation() {
+    assert_eq!(scientific(Value::from(12_500), Some("eng"), "en"), "12.5e3");
+    assert_eq!(
+        scientific(Value::from(1_200_000), Some("eng"), "en"),
+        "1.2e6"
+    );
+    assert_eq!(scientific(Value::from(300_000), Some("eng"), "en"), "300e3");
+    assert_eq!(
+        scientific(Value::from(0.0456), Some("eng.3"), "en"),
+        "45.6e-3"
+    );
+    assert_eq!(scientific(Value::from(0), Some("eng"), "en"), "0e0");
+}
+
+#[test]
+fn scientific_rejects_non_numeric_value_and_bad_context() {
+    let err = TransformKind::Scientific
+        .execute(&Value::from("lots"), None, "en")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        EvalError::InvalidTransformInput { ref transform, .. } if transform == "scientific"
+    ));
+    let err = TransformKind::Scientific
+        .execute(&Value::from(1), Some(&Value::from("0")), "en")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+}
+
+#[test]
+fn scientific_in_template() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"damage($n) = "{@scientific:2 $n} damage";"#)
+        .unwrap();
+    let result = locale
+        .call_phrase("damage", &[Value::from(1_250_000)])
+        .unwrap();
+    assert_eq!(result.to_string(), "1.2e6 damage");
+}
+
+#[test]
+fn digit_style_native_on_locale() {
+    let mut locale = Locale::builder().language("fa").build();
+    locale
+        .load_translations_str(
+            "fa",
+            r#"
+            score($n) = "{@num $n}";
+            latin_score($n) = "{@num:latn $n}";
+        "#,
+        )
+        .unwrap();
+    assert_eq!(locale.digit_style(), DigitStyle::Latin);
+    locale.set_digit_style(DigitStyle::Native);
+    let call = |name: &str| {
+        locale
+            .call_phrase(name, &[Value::from(1234)])
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(call("score"), "\u{06F1}\u{066C}\u{06F2}\u{06F3}\u{06F4}");
+    assert_eq!(call("latin_score"), "1,234");
+}
+
+#[test]
+fn digit_style_native_count_in_thai_and_bengali() {
+    let mut registry = TransformRegistry::new();
+    registry.set_digit_style(DigitStyle::Native);
+    let card = Value::Phrase(
+        Phrase::builder()
+            .text("การ์ด".to_string())
+            .tags(vec![Tag::new("bai")])
+            .build(),
+    );
+    let thai = registry
+        .execute(TransformKind::ThaiCount, &card, Some(&Value::from(3)), "th")
+        .unwrap();
+    assert_eq!(thai, "\u{0E53}ใบการ์ด");
+    let book = Value::Phrase(
+        Phrase::builder()
+            .text("বই".to_string())
+            .tags(vec![Tag::new("ta")])
+            .build(),
+    );
+    let bengali = registry
+        .execute(
+            TransformKind::BengaliCount,
+            &book,
+            Some(&Value::from(12)),
+            "bn",
+        )
+        .unwrap();
+    assert!(bengali.starts_with("\u{09E7}\u{09E8}"), "got {bengali}");
+}
+
+#[test]
+fn digit_style_shape_leaves_other_languages_latin() {
+    assert_eq!(DigitStyle::Native.shape("1,234", "en"), "1,234");
+    assert_eq!(DigitStyle::Latin.shape("1,234", "fa"), "1,234");
+    assert_eq!(DigitStyle::Native.shape("42", "fa-IR"), "\u{06F4}\u{06F2}");
+}
+
+// =============================================================================
+// Multibyte Input Robustness
+// =============================================================================
+
+/// Every transform, for every language that defines transforms, must return a
+/// result or an error on multibyte and markup-wrapped input, never panic.
+#[test]
+fn transforms_do_not_panic_on_multibyte_input() {
+    let registry = TransformRegistry::new();
+    let languages = [
+        "en", "de", "nl", "es", "pt", "fr", "it", "el", "ro", "ar", "fa", "ru", "zh", "ja", "ko",
+        "vi", "th", "bn", "id", "tr", "fi", "hu", "eu", "sw", "hi",
+    ];
+    let texts = [
+        "",
+        "ش",
+        "ä",
+        "ö",
+        "e\u{0301}",
+        "\u{1F600}",
+        "한",
+        "<b>é</b>",
+        "<color=#AA00FF>",
+        "ıİ",
+    ];
+    let tags = [
+        "sun", "front", "back", "vowel", "masc", "fem", "neut", "a", "an", "cl1", "cl7", "s", "z",
+        "zero",
+    ];
+    let contexts = [
+        None,
+        Some(Value::from("ill")),
+        Some(Value::from("pl")),
+        Some(Value::from("acc")),
+        Some(Value::from("nato")),
+        Some(Value::from("USD")),
+        Some(Value::from("é")),
+        Some(Value::from(2)),
+    ];
+
+    for lang in languages {
+        for name in rlf_semantics::accepted_transform_names(lang) {
+            let Some(transform) = registry.get(name, lang) else {
+                continue;
+            };
+            for text in texts {
+                let plain = Value::from(text);
+                let tagged = Value::Phrase(
+                    Phrase::builder()
+                        .text(text.to_string())
+                        .tags(tags.iter().map(|t| Tag::new(*t)).collect())
+                        .build(),
+                );
+                for value in [&plain, &tagged] {
+                    for context in &contexts {
+                        let _ = transform.execute(value, context.as_ref(), lang);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Month and Weekday Name Transforms (@month, @weekday)
+// =============================================================================
+
+fn calendar_name(kind: TransformKind, value: Value, context: Option<&str>, lang: &str) -> String {
+    let context = context.map(Value::from);
+    kind.execute(&value, context.as_ref(), lang).unwrap()
+}
+
+#[test]
+fn month_names_long_and_short() {
+    let month = |context, lang| calendar_name(TransformKind::Month, Value::from(3), context, lang);
+    assert_eq!(month(None, "en"), "March");
+    assert_eq!(month(Some("short"), "en"), "Mar");
+    assert_eq!(month(None, "de"), "März");
+    assert_eq!(month(Some("short"), "de"), "März");
+    assert_eq!(month(Some("long"), "fr"), "mars");
+    assert_eq!(
+        calendar_name(TransformKind::Month, Value::from(9), Some("short"), "de_AT"),
+        "Sept."
+    );
+}
+
+#[test]
+fn weekday_names_long_and_short() {
+    let weekday =
+        |context, lang| calendar_name(TransformKind::Weekday, Value::from(1), context, lang);
+    assert_eq!(weekday(None, "en"), "Monday");
+    assert_eq!(weekday(Some("short"), "en"), "Mon");
+    assert_eq!(weekday(None, "es"), "lunes");
+    assert_eq!(weekday(Some("short"), "es"), "lun");
+    assert_eq!(
+        calendar_name(TransformKind::Weekday, Value::from("0"), None, "de"),
+        "Sonntag"
+    );
+}
+
+#[test]
+fn calendar_names_fall_back_to_english() {
+    assert_eq!(
+        calendar_name(TransformKind::Month, Value::from(12), None, "ja"),
+        "December"
+    );
+}
+
+#[test]
+fn calendar_names_reject_out_of_range_input() {
+    for (kind, value) in [
+        (TransformKind::Month, Value::from(0)),
+        (TransformKind::Month, Value::from(13)),
+        (TransformKind::Weekday, Value::from(7)),
+        (TransformKind::Weekday, Value::from(-1)),
+        (TransformKind::Weekday, Value::from(1.5)),
+    ] {
+        let err = kind.execute(&value, None, "en").unwrap_err();
+        assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+    }
+    let err = TransformKind::Month
+        .execute(&Value::from(3), Some(&Value::from("narrow")), "en")
+        .unwrap_err();
+    assert!(matches!(err, EvalError::InvalidTransformInput { .. }));
+}
+
+#[test]
+fn month_transform_in_phrase() {
+    let mut locale = Locale::builder().language("de").build();
+    locale
+        .load_translations_str(
+            "de",
+            r#"due($m, $d) = "fällig im {@month $m}, {@weekday:short $d}";"#,
+        )
+        .unwrap();
+    let due = locale.call_phrase("due", &[Value::from(3), Value::from(1)]);
+    assert_eq!(due.unwrap().to_string(), "fällig im März, Mo.");
+}
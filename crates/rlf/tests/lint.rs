@@ -1,8 +1,11 @@
 //! Tests for the static lint rules.
 
-use rlf::LoadWarning;
-use rlf::lint_definitions;
 use rlf::parser::{PhraseDefinition, parse_file};
+use rlf::{LoadWarning, PhraseRegistry};
+use rlf::{
+    lint_definitions, lint_literal_numbers, lint_max_length, lint_mixed_language,
+    lint_plural_categories,
+};
 
 // =========================================================================
 // Smoke Tests
@@ -708,6 +711,173 @@ fn lint4_ignores_from_with_match() {
     assert_eq!(verbose_warnings.len(), 0);
 }
 
+// =========================================================================
+// Pedantic: Literal Numbers
+// =========================================================================
+
+#[test]
+fn literal_numbers_flags_ordinal() {
+    let defs = parse_file(r#"result = "You finished in 3rd place!";"#).unwrap();
+    let warnings = lint_literal_numbers(&defs, "en");
+    assert_eq!(
+        warnings,
+        vec![LoadWarning::LiteralNumber {
+            name: "result".to_string(),
+            language: "en".to_string(),
+            text: "3rd".to_string(),
+            suggestion: "ordinal".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn literal_numbers_flags_grouped_number() {
+    let defs = parse_file(r#"reward = "Win 1,000 gold";"#).unwrap();
+    let warnings = lint_literal_numbers(&defs, "en");
+    assert!(matches!(
+        warnings.as_slice(),
+        [LoadWarning::LiteralNumber { text, suggestion, .. }]
+        if text == "1,000" && suggestion == "num"
+    ));
+}
+
+#[test]
+fn literal_numbers_ignores_hex_color_markup() {
+    let defs = parse_file(r#"warn = "<color=#112233>Careful</color>";"#).unwrap();
+    assert!(lint_literal_numbers(&defs, "en").is_empty());
+}
+
+#[test]
+fn literal_numbers_checks_text_after_bare_less_than() {
+    let defs = parse_file(r#"odds = "If 2 < 3, win 1,000 gold";"#).unwrap();
+    let texts: Vec<_> = lint_literal_numbers(&defs, "en")
+        .into_iter()
+        .filter_map(|w| match w {
+            LoadWarning::LiteralNumber { text, .. } => Some(text),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(texts, vec!["1,000"]);
+}
+
+#[test]
+fn literal_numbers_only_checks_english() {
+    let defs = parse_file(r#"price = "1,000 Euro, 3rd";"#).unwrap();
+    assert!(lint_literal_numbers(&defs, "de").is_empty());
+    assert!(lint_literal_numbers(&defs, "fr").is_empty());
+    assert_eq!(lint_literal_numbers(&defs, "en_GB").len(), 2);
+}
+
+#[test]
+fn literal_numbers_ignores_plain_digits_and_interpolations() {
+    let defs = parse_file(
+        r#"
+        draw($n) = "Draw {$n} cards, then 2 more.";
+        version = "v2nd 1,5 3px 1234";
+    "#,
+    )
+    .unwrap();
+    assert!(lint_literal_numbers(&defs, "en").is_empty());
+}
+
+#[test]
+fn literal_numbers_checks_variant_and_match_templates() {
+    let defs = parse_file(
+        r#"
+        place = { one: "1st", *other: "2nd" };
+        rank($n) = :match($n) { 1: "the 21ST", *other: "{$n}" };
+    "#,
+    )
+    .unwrap();
+    let texts: Vec<_> = lint_literal_numbers(&defs, "en")
+        .into_iter()
+        .filter_map(|w| match w {
+            LoadWarning::LiteralNumber { text, .. } => Some(text),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(texts, vec!["1st", "2nd", "21ST"]);
+}
+
+#[test]
+fn literal_number_display() {
+    let warning = LoadWarning::LiteralNumber {
+        name: "result".to_string(),
+        language: "en".to_string(),
+        text: "3rd".to_string(),
+        suggestion: "ordinal".to_string(),
+    };
+    assert_eq!(
+        warning.to_string(),
+        "warning: phrase 'result' in 'en' contains literal number '3rd'; pass it as a parameter and format it with @ordinal"
+    );
+}
+
+#[test]
+fn lint_definitions_does_not_include_literal_numbers() {
+    let defs = parse_file(r#"result = "3rd place";"#).unwrap();
+    assert!(lint_definitions(&defs, "en").is_empty());
+}
+
+// =========================================================================
+// Unreachable Plural Categories
+// =========================================================================
+
+#[test]
+fn plural_categories_flags_few_in_english() {
+    let defs = parse_file(r#"card = { one: "card", few: "cards", *other: "cards" };"#).unwrap();
+    let warnings = lint_plural_categories(&defs, "en");
+    assert_eq!(
+        warnings,
+        vec![LoadWarning::UnreachablePluralCategory {
+            name: "card".to_string(),
+            language: "en".to_string(),
+            key: "few".to_string(),
+        }]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "warning: phrase 'card' in 'en' has key 'few', which 'en' plural rules never select"
+    );
+}
+
+#[test]
+fn plural_categories_accepts_language_categories() {
+    let defs = parse_file(
+        r#"
+        card = { one: "карта", few: "карты", *other: "карт" };
+        cards($n) = :match($n) { 1: "одна", many: "много", *other: "{$n}" };
+    "#,
+    )
+    .unwrap();
+    assert!(lint_plural_categories(&defs, "ru").is_empty());
+}
+
+#[test]
+fn plural_categories_checks_key_components_and_nested_match() {
+    let defs = parse_file(
+        r#"
+        card = { nom.one: "a", nom.two: "b", *other: "c" };
+        draw($n) = { nom: :match($n) { zero: "none", *other: "{$n}" }, *other: "x" };
+    "#,
+    )
+    .unwrap();
+    let keys: Vec<_> = lint_plural_categories(&defs, "en")
+        .into_iter()
+        .filter_map(|w| match w {
+            LoadWarning::UnreachablePluralCategory { key, .. } => Some(key),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(keys, vec!["nom.two", "zero"]);
+}
+
+#[test]
+fn plural_categories_skips_unsupported_languages() {
+    let defs = parse_file(r#"card = { few: "x", *other: "y" };"#).unwrap();
+    assert!(lint_plural_categories(&defs, "strings").is_empty());
+}
+
 // =========================================================================
 // Integration: Multiple Lints on Same Definition
 // =========================================================================
@@ -870,3 +1040,123 @@ fn lint_real_world_missing_from() {
         if name == "allied_subtype_plural" && parameter == "t"
     ));
 }
+
+#[test]
+fn lint_max_length_measures_rendered_terms() {
+    let content = r#"
+        card = { one: "Karte", other: "Karten" };
+        @maxlen(8)
+        draw_button = "<b>Ziehe eine {card}</b>";
+        @maxlen(12)
+        pass_button = "Passen";
+        @maxlen(5)
+        discard($n) = "Wirf {$n} ab";
+    "#;
+    let mut registry = PhraseRegistry::new();
+    registry.load_phrases(content).unwrap();
+    let warnings = lint_max_length(&registry, "de");
+    assert_eq!(
+        warnings,
+        vec![
+            LoadWarning::ExceedsMaxLength {
+                name: "discard".to_string(),
+                language: "de".to_string(),
+                length: 9,
+                max: 5,
+            },
+            LoadWarning::ExceedsMaxLength {
+                name: "draw_button".to_string(),
+                language: "de".to_string(),
+                length: 16,
+                max: 8,
+            },
+        ]
+    );
+    assert!(lint_definitions(&parse_file(content).unwrap(), "de").is_empty());
+}
+
+#[test]
+fn lint_max_length_measures_longest_variant() {
+    let mut registry = PhraseRegistry::new();
+    registry
+        .load_phrases(
+            r#"
+            @maxlen(6)
+            card = { one: "Karte", other: "Kartenstapel" };
+            @maxlen(10)
+            draw($n) = :match($n) { 1: "Ziehe eine", *other: "Ziehe {$n} Karten" };
+        "#,
+        )
+        .unwrap();
+    let warnings = lint_max_length(&registry, "de");
+    assert_eq!(
+        warnings,
+        vec![
+            LoadWarning::ExceedsMaxLength {
+                name: "card".to_string(),
+                language: "de".to_string(),
+                length: 12,
+                max: 6,
+            },
+            LoadWarning::ExceedsMaxLength {
+                name: "draw".to_string(),
+                language: "de".to_string(),
+                length: 14,
+                max: 10,
+            },
+        ]
+    );
+}
+
+#[test]
+fn lint_mixed_language_flags_transforms_from_other_languages() {
+    let defs = parse_file(
+        r#"
+        karte = :fem "Karte";
+        take = "Nimm {@the karte}.";
+        draw = "Ziehe {@ein:acc karte} und {@cap karte}.";
+    "#,
+    )
+    .unwrap();
+    let warnings = lint_mixed_language(&defs, "de", None);
+    assert_eq!(
+        warnings,
+        vec![LoadWarning::WrongLanguageTransform {
+            name: "take".to_string(),
+            language: "de".to_string(),
+            transform: "the".to_string(),
+            available_in: vec!["en".to_string()],
+        }]
+    );
+    assert!(lint_mixed_language(&defs, "strings", None).is_empty());
+}
+
+#[test]
+fn lint_mixed_language_flags_text_identical_to_reference() {
+    let source = parse_file(
+        r#"
+        start = "Start game";
+        score($n) = "{$n}";
+        quit = "Quit";
+    "#,
+    )
+    .unwrap();
+    let defs = parse_file(
+        r#"
+        start = "Start game";
+        score($n) = "{$n}";
+        quit = "Beenden";
+    "#,
+    )
+    .unwrap();
+    let warnings = lint_mixed_language(&defs, "de", Some((&source, "en")));
+    assert_eq!(
+        warnings,
+        vec![LoadWarning::UntranslatedText {
+            name: "start".to_string(),
+            language: "de".to_string(),
+            reference: "en".to_string(),
+        }]
+    );
+    assert!(lint_mixed_language(&source, "en", Some((&source, "en"))).is_empty());
+}
@@ -1,10 +1,12 @@
 //! Integration tests for Locale management.
 
-use rlf::{EvalError, LoadError, Locale, Value};
+use rlf::{EvalError, LoadError, LoadWarning, Locale, PhraseId, Value};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fs;
 use std::io::{Seek, Write};
 use std::ptr;
-use tempfile::NamedTempFile;
+use tempfile::{NamedTempFile, TempDir};
 
 // =========================================================================
 // Builder and Basic API
@@ -57,6 +59,71 @@ fn load_translations_str_parses_phrases() {
     assert_eq!(count, 2);
 }
 
+#[test]
+fn phrase_info_exposes_attributes() {
+    let mut locale = Locale::with_language("de");
+    locale
+        .load_translations_str(
+            "de",
+            r#"
+        @note("Button label, keep it short")
+        @note("Imperative mood")
+        @maxlen(12)
+        start = "Starten";
+        hello = "Hallo!";
+    "#,
+        )
+        .unwrap();
+
+    let info = locale.phrase_info("start").unwrap();
+    assert_eq!(info.name(), "start");
+    assert_eq!(
+        info.note().as_deref(),
+        Some("Button label, keep it short\nImperative mood")
+    );
+    assert_eq!(info.max_length(), Some(12));
+    assert_eq!(locale.phrase_info("hello").unwrap().note(), None);
+    assert!(locale.phrase_info("missing").is_none());
+}
+
+#[test]
+fn register_plural_rule_selects_variants_for_custom_language() {
+    let mut locale = Locale::with_language("zz");
+    locale.register_plural_rule("zz", |n| match n {
+        0 => "zero",
+        1 | 2 => "few",
+        _ => "other",
+    });
+    locale
+        .load_translations_str(
+            "zz",
+            r#"
+        card = { zero: "no cards", few: "a couple of cards", other: "many cards" };
+        draw($n) = "{card:$n}";
+        status($n) = :match($n) { zero: "empty", few: "low", *other: "full" };
+    "#,
+        )
+        .unwrap();
+
+    let draw = |n: i64| locale.call_phrase("draw", &[Value::from(n)]).unwrap();
+    assert_eq!(draw(0).to_string(), "no cards");
+    assert_eq!(draw(2).to_string(), "a couple of cards");
+    assert_eq!(draw(7).to_string(), "many cards");
+    let status = locale.call_phrase("status", &[Value::from(1)]).unwrap();
+    assert_eq!(status.to_string(), "low");
+
+    // Region subtags fall back to the rule for the primary language
+    locale.set_language("zz-QQ");
+    locale
+        .load_translations_str(
+            "zz-QQ",
+            r#"draw($n) = "{$n}:{card:$n}"; card = { few: "f", other: "o" };"#,
+        )
+        .unwrap();
+    let draw = locale.call_phrase("draw", &[Value::from(1)]).unwrap();
+    assert_eq!(draw.to_string(), "1:f");
+}
+
 #[test]
 fn load_translations_str_replaces_on_reload() {
     let mut locale = Locale::new();
@@ -144,6 +211,196 @@ fn reloading_language_only_affects_that_language() {
     assert_eq!(phrase.to_string(), "Привет!");
 }
 
+#[test]
+fn remove_phrase_only_affects_that_language() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"hello = "Hello!"; bye = "Bye!";"#)
+        .unwrap();
+    locale
+        .load_translations_str("ru", r#"hello = "Привет!";"#)
+        .unwrap();
+
+    let id = PhraseId::from_name("hello").as_u128();
+    assert!(locale.remove_phrase("en", id));
+    assert!(!locale.remove_phrase("en", id));
+    assert!(!locale.remove_phrase("fr", id));
+
+    assert!(matches!(
+        locale.get_phrase("hello"),
+        Err(EvalError::PhraseNotFound { .. })
+    ));
+    assert!(locale.get_phrase_by_id(id).is_err());
+    assert_eq!(locale.get_phrase("bye").unwrap().to_string(), "Bye!");
+
+    locale.set_language("ru");
+    assert_eq!(locale.get_phrase("hello").unwrap().to_string(), "Привет!");
+}
+
+#[test]
+fn clear_language_unloads_phrases_and_cache() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"hello = "Hello!";"#)
+        .unwrap();
+    locale
+        .load_translations_str("ru", r#"hello = "Привет!";"#)
+        .unwrap();
+    locale.eval_str("{hello}", HashMap::new()).unwrap();
+    assert_eq!(locale.template_cache_len(), 1);
+
+    assert!(locale.clear_language("en"));
+    assert!(!locale.clear_language("en"));
+    assert_eq!(locale.template_cache_len(), 0);
+    assert!(locale.registry_for("en").is_none());
+    assert!(locale.get_phrase("hello").is_err());
+    assert!(locale.registry_for("ru").is_some());
+}
+
+// =========================================================================
+// Translation Overlays
+// =========================================================================
+
+#[test]
+fn overlay_overrides_and_adds_phrases() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            hello = "Hello!";
+            card = "card";
+            "#,
+        )
+        .unwrap();
+
+    let overridden = locale
+        .overlay_translations_str(
+            "en",
+            r#"
+            card = "relic";
+            dragon = "dragon";
+            "#,
+        )
+        .unwrap();
+
+    assert_eq!(overridden, vec!["card"]);
+    assert_eq!(locale.get_phrase("hello").unwrap().to_string(), "Hello!");
+    assert_eq!(locale.get_phrase("card").unwrap().to_string(), "relic");
+    assert_eq!(locale.get_phrase("dragon").unwrap().to_string(), "dragon");
+    assert_eq!(locale.registry().unwrap().len(), 3);
+}
+
+#[test]
+fn overlay_only_affects_its_language() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"card = "card";"#)
+        .unwrap();
+    locale
+        .load_translations_str("de", r#"card = "Karte";"#)
+        .unwrap();
+
+    let overridden = locale
+        .overlay_translations_str("de", r#"card = "Relikt";"#)
+        .unwrap();
+    assert_eq!(overridden, vec!["card"]);
+
+    assert_eq!(locale.get_phrase("card").unwrap().to_string(), "card");
+    locale.set_language("de");
+    assert_eq!(locale.get_phrase("card").unwrap().to_string(), "Relikt");
+}
+
+#[test]
+fn overlay_onto_unloaded_language_adds_phrases() {
+    let mut locale = Locale::with_language("fr");
+    let overridden = locale
+        .overlay_translations_str("fr", r#"card = "carte";"#)
+        .unwrap();
+    assert!(overridden.is_empty());
+    assert_eq!(locale.get_phrase("card").unwrap().to_string(), "carte");
+}
+
+#[test]
+fn overlay_parse_error_leaves_phrases_unchanged() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"card = "card";"#)
+        .unwrap();
+
+    let result = locale.overlay_translations_str("en", r#"card = "relic"; broken = "#);
+    assert!(matches!(result, Err(LoadError::Parse { .. })));
+    assert_eq!(locale.get_phrase("card").unwrap().to_string(), "card");
+}
+
+#[test]
+fn overlay_with_warnings_lints_only_overlay_definitions() {
+    let mut locale = Locale::with_language("de");
+    locale
+        .load_translations_str(
+            "de",
+            r#"
+        @maxlen(2)
+        basis = "Basis";
+        karte = "Karte";
+    "#,
+        )
+        .unwrap();
+
+    let (overridden, warnings) = locale
+        .overlay_translations_str_with_warnings(
+            "de",
+            r#"
+        karte = :fem { one: "Karte", few: "Karten", other: "Karten" };
+        @maxlen(4)
+        relikt = "Relikt";
+    "#,
+        )
+        .unwrap();
+
+    assert_eq!(overridden, vec!["karte"]);
+    assert_eq!(warnings.len(), 2, "warnings: {warnings:?}");
+    assert!(matches!(
+        &warnings[0],
+        LoadWarning::UnreachablePluralCategory { name, key, .. } if name == "karte" && key == "few"
+    ));
+    assert!(matches!(
+        &warnings[1],
+        LoadWarning::ExceedsMaxLength { name, length: 6, max: 4, .. } if name == "relikt"
+    ));
+}
+
+#[test]
+fn load_translations_str_with_warnings_surfaces_lints() {
+    let mut locale = Locale::with_language("de");
+    let warnings = locale
+        .load_translations_str_with_warnings(
+            "de",
+            r#"
+        karte = :fem { one: "Karte", few: "Karten", other: "Karten" };
+        @maxlen(4)
+        start = "Starten";
+        ok = "OK";
+    "#,
+        )
+        .unwrap();
+
+    assert_eq!(warnings.len(), 2, "warnings: {warnings:?}");
+    assert!(matches!(
+        &warnings[0],
+        LoadWarning::UnreachablePluralCategory { name, key, .. } if name == "karte" && key == "few"
+    ));
+    assert!(matches!(
+        &warnings[1],
+        LoadWarning::ExceedsMaxLength { name, length: 7, max: 4, .. } if name == "start"
+    ));
+    assert_eq!(locale.get_phrase("ok").unwrap().to_string(), "OK");
+
+    let result = locale.load_translations_str_with_warnings("de", r#"ok = "#);
+    assert!(matches!(result, Err(LoadError::Parse { .. })));
+    assert_eq!(locale.get_phrase("ok").unwrap().to_string(), "OK");
+}
+
 // =========================================================================
 // Translation Loading from File
 // =========================================================================
@@ -170,6 +427,81 @@ fn load_translations_file_not_found() {
     assert!(matches!(err, LoadError::Io { .. }));
 }
 
+#[test]
+fn builder_load_dir_discovers_language_files() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("de.rlf"), r#"hello = "Hallo!";"#).unwrap();
+    fs::write(dir.path().join("fr.rlf"), r#"hello = "Bonjour !";"#).unwrap();
+    fs::write(dir.path().join("notes.txt"), "not a translation").unwrap();
+    fs::create_dir(dir.path().join("drafts")).unwrap();
+
+    let (mut locale, warnings) = Locale::builder()
+        .language("de")
+        .load_dir(dir.path())
+        .unwrap();
+    assert!(warnings.is_empty());
+    assert_eq!(locale.get_phrase("hello").unwrap().to_string(), "Hallo!");
+    locale.set_language("fr");
+    assert_eq!(locale.get_phrase("hello").unwrap().to_string(), "Bonjour !");
+
+    fs::write(dir.path().join("fr.rlf"), r#"hello = "Salut !";"#).unwrap();
+    locale.reload_translations("fr").unwrap();
+    assert_eq!(locale.get_phrase("hello").unwrap().to_string(), "Salut !");
+}
+
+#[test]
+fn load_dir_returns_lint_warnings() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("en.rlf"),
+        r#"card = { one: "card", few: "cards", *other: "cards" };"#,
+    )
+    .unwrap();
+
+    let mut locale = Locale::new();
+    let warnings = locale.load_dir(dir.path()).unwrap();
+    assert!(matches!(
+        warnings.as_slice(),
+        [LoadWarning::UnreachablePluralCategory { key, .. }] if key == "few"
+    ));
+}
+
+#[test]
+fn load_dir_reports_parse_errors_with_path() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("ru.rlf"), r#"hello = "unterminated;"#).unwrap();
+
+    let err = Locale::new().load_dir(dir.path()).unwrap_err();
+    let LoadError::Parse { path, .. } = err else {
+        panic!("expected parse error, got {err:?}");
+    };
+    assert!(path.ends_with("ru.rlf"));
+}
+
+#[test]
+fn load_dir_failure_leaves_every_language_unchanged() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("de", r#"hello = "Hallo!";"#)
+        .unwrap();
+
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("de.rlf"), r#"hello = "Servus!";"#).unwrap();
+    fs::write(dir.path().join("ru.rlf"), r#"hello = "unterminated;"#).unwrap();
+
+    assert!(matches!(
+        locale.load_dir(dir.path()),
+        Err(LoadError::Parse { .. })
+    ));
+    locale.set_language("de");
+    assert_eq!(locale.get_phrase("hello").unwrap().to_string(), "Hallo!");
+    assert!(matches!(
+        locale.reload_translations("de"),
+        Err(LoadError::NoPathForReload { .. })
+    ));
+    assert!(locale.registry_for("ru").is_none());
+}
+
 // =========================================================================
 // Hot Reload
 // =========================================================================
@@ -260,6 +592,32 @@ fn get_phrase_not_found_returns_error() {
     ));
 }
 
+#[test]
+fn get_phrase_text_borrows_constant_text() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            play = "Play";
+            card = :a { one: "card", other: "cards" };
+            play_card = "Play {@a card}";
+        "#,
+        )
+        .unwrap();
+
+    let plain = locale.get_phrase_text("play").unwrap();
+    assert!(matches!(plain, Cow::Borrowed("Play")));
+    let templated = locale.get_phrase_text("play_card").unwrap();
+    assert!(matches!(templated, Cow::Owned(ref text) if text == "Play a card"));
+    let variants = locale.get_phrase_text("card").unwrap();
+    assert!(matches!(variants, Cow::Owned(ref text) if text == "card"));
+    assert!(matches!(
+        locale.get_phrase_text("missing"),
+        Err(EvalError::PhraseNotFound { .. })
+    ));
+}
+
 #[test]
 fn call_phrase_with_args() {
     let mut locale = Locale::new();
@@ -545,3 +903,48 @@ fn auto_capitalization_with_underscore_identifiers() {
     let phrase = locale.get_phrase("heading").unwrap();
     assert_eq!(phrase.to_string(), "Fire elemental");
 }
+
+// =========================================================================
+// Pseudo-localization
+// =========================================================================
+
+#[test]
+fn pseudo_localize_accents_and_brackets_phrases() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+        card = { one: "card", other: "cards" };
+        draw($n) = "Draw {$n} {card:$n}.";
+        title = "Deck";
+    "#,
+        )
+        .unwrap();
+    locale.set_pseudo_localize(true);
+    assert!(locale.pseudo_localize());
+
+    // "Draw 3 cards." has 13 visible characters, padded by 30%
+    let phrase = locale.call_phrase("draw", &[Value::from(3)]).unwrap();
+    assert_eq!(phrase.to_string(), "[Ðŕåŵ 3 çåŕðš.~~~~]");
+
+    // Constant text takes the same path as evaluated text
+    assert_eq!(locale.get_phrase_text("title").unwrap(), "[Ðéçķ~~]");
+    assert_eq!(locale.get_phrase("title").unwrap().to_string(), "[Ðéçķ~~]");
+}
+
+#[test]
+fn pseudo_localize_keeps_parameter_values_and_markup() {
+    let mut locale = Locale::new();
+    locale
+        .load_translations_str("en", r#"greet($name) = "Hi <b>{$name}</b>!";"#)
+        .unwrap();
+    locale.set_pseudo_localize(true);
+
+    let phrase = locale.call_phrase("greet", &[Value::from("Sam")]).unwrap();
+    assert_eq!(phrase.to_string(), "[Ĥî <b>Sam</b>!~~~]");
+
+    locale.set_pseudo_localize(false);
+    let phrase = locale.call_phrase("greet", &[Value::from("Sam")]).unwrap();
+    assert_eq!(phrase.to_string(), "Hi <b>Sam</b>!");
+}
@@ -1,6 +1,7 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 
-use rlf::{Phrase, Value, params};
+use rlf::{NumValue, Phrase, Value, params};
 
 #[test]
 fn empty_params() {
@@ -63,6 +64,37 @@ fn various_integer_types() {
     assert_eq!(p["usize"].as_number(), Some(50));
 }
 
+#[test]
+fn small_and_pointer_sized_integer_types() {
+    let p = params! {
+        "i8" => -1_i8,
+        "i16" => 2_i16,
+        "isize" => -3_isize,
+        "u8" => 4_u8,
+        "u16" => 5_u16,
+    };
+    assert_eq!(p["i8"].as_number(), Some(-1));
+    assert_eq!(p["i16"].as_number(), Some(2));
+    assert_eq!(p["isize"].as_number(), Some(-3));
+    assert_eq!(p["u8"].as_number(), Some(4));
+    assert_eq!(p["u16"].as_number(), Some(5));
+}
+
+#[test]
+fn oversized_unsigned_integers_saturate() {
+    assert_eq!(Value::from(u64::MAX).as_number(), Some(i64::MAX));
+    assert_eq!(Value::from(usize::MAX).as_number(), Some(i64::MAX));
+    assert_eq!(Value::from(NumValue::from(7_u8)).as_number(), Some(7));
+}
+
+#[test]
+fn oversized_unsigned_num_values_are_errors() {
+    assert!(NumValue::try_from(u64::MAX).is_err());
+    assert!(NumValue::try_from(usize::MAX).is_err());
+    let seven = NumValue::try_from(7_usize).unwrap();
+    assert_eq!(Value::from(seven).as_number(), Some(7));
+}
+
 #[test]
 fn float_types() {
     let p = params! {
@@ -81,6 +113,20 @@ fn owned_string_value() {
     assert_eq!(p["name"].as_string(), Some("Charlie"));
 }
 
+#[test]
+fn borrowed_string_values() {
+    let name = String::from("Dana");
+    let p = params! {
+        "str" => "Eve",
+        "string_ref" => &name,
+        "cow" => Cow::Borrowed("Finn"),
+    };
+    assert_eq!(p["str"].as_string(), Some("Eve"));
+    assert_eq!(p["string_ref"].as_string(), Some("Dana"));
+    assert_eq!(p["cow"].as_string(), Some("Finn"));
+    assert_eq!(name, "Dana");
+}
+
 #[test]
 fn phrase_value() {
     let phrase = Phrase::builder().text("sword".to_string()).build();
@@ -124,3 +170,11 @@ fn mixed_value_types() {
     assert!(p["weapon"].as_phrase().is_some());
     assert_eq!(p["weapon"].to_string(), "sword");
 }
+
+#[test]
+fn phrase_reference_value() {
+    let phrase = Phrase::builder().text("shield".to_string()).build();
+    let p = params! { "item" => &phrase };
+    assert!(p["item"].as_phrase().is_some());
+    assert_eq!(p["item"].to_string(), phrase.to_string());
+}
@@ -1,7 +1,7 @@
 //! Tests confirming that Phrase uses VariantKey and Tag types (not raw
 //! String), matching our documented API.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use rlf::{Phrase, Tag, VariantKey};
 
@@ -49,6 +49,25 @@ fn tag_deref_display_from() {
     assert_eq!(from_str.as_str(), "fem");
 }
 
+#[test]
+fn tag_set_queried_by_str() {
+    let tags: HashSet<Tag> = HashSet::from([Tag::new("masc"), Tag::new("anim")]);
+    assert!(tags.contains("masc"));
+    assert!(tags.contains("anim"));
+    assert!(!tags.contains("fem"));
+}
+
+#[test]
+fn variant_key_map_queried_by_str() {
+    let variants = HashMap::from([
+        (VariantKey::new("nom.one"), "card"),
+        (VariantKey::new("nom.other"), "cards"),
+    ]);
+    assert_eq!(variants.get("nom.one"), Some(&"card"));
+    assert!(variants.contains_key("nom.other"));
+    assert_eq!(variants.get("acc.one"), None);
+}
+
 #[test]
 fn join_empty_returns_empty_phrase() {
     let result = Phrase::join(&[], ", ");
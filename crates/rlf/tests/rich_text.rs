@@ -0,0 +1,200 @@
+//! Tests for structured rich-text output via `Phrase::to_rich`.
+
+use rlf::{Locale, Phrase, RichSpan, Value};
+
+fn phrase(text: &str) -> Phrase {
+    Phrase::builder().text(text.to_string()).build()
+}
+
+fn span(text: &str) -> RichSpan {
+    RichSpan {
+        text: text.to_string(),
+        ..RichSpan::default()
+    }
+}
+
+#[test]
+fn plain_text_is_single_span() {
+    assert_eq!(phrase("Draw a card.").to_rich(), vec![span("Draw a card.")]);
+}
+
+#[test]
+fn empty_text_has_no_spans() {
+    assert!(phrase("").to_rich().is_empty());
+}
+
+#[test]
+fn counts_exclude_markup() {
+    let bold = phrase("<b>Hi</b>");
+    assert_eq!(bold.char_count(), 2);
+    assert_eq!(bold.grapheme_count(), 2);
+    assert_eq!(bold.display_width(), 2);
+}
+
+#[test]
+fn grapheme_count_joins_combining_marks() {
+    let text = phrase("<i>cafe\u{301}</i>");
+    assert_eq!(text.char_count(), 5);
+    assert_eq!(text.grapheme_count(), 4);
+}
+
+#[test]
+fn display_width_counts_wide_characters_twice() {
+    let cjk = phrase("<color=#FF0000>カード</color>");
+    assert_eq!(cjk.grapheme_count(), 3);
+    assert_eq!(cjk.display_width(), 6);
+}
+
+#[test]
+fn counts_keep_unrecognized_tags_as_text() {
+    assert_eq!(phrase("<x>").char_count(), 3);
+}
+
+#[test]
+fn bold_span() {
+    assert_eq!(
+        phrase("Gain <b>2</b> energy").to_rich(),
+        vec![
+            span("Gain "),
+            RichSpan {
+                bold: true,
+                ..span("2")
+            },
+            span(" energy"),
+        ]
+    );
+}
+
+#[test]
+fn nested_bold_inside_color() {
+    assert_eq!(
+        phrase("Dissolve <color=#2E7D32>an <b>Ancient</b></color>.").to_rich(),
+        vec![
+            span("Dissolve "),
+            RichSpan {
+                color: Some("#2E7D32".to_string()),
+                ..span("an ")
+            },
+            RichSpan {
+                bold: true,
+                color: Some("#2E7D32".to_string()),
+                ..span("Ancient")
+            },
+            span("."),
+        ]
+    );
+}
+
+#[test]
+fn nested_color_restores_outer_color() {
+    let spans = phrase("<color=red>a<color=blue>b</color>c</color>").to_rich();
+    let colors: Vec<_> = spans
+        .iter()
+        .map(|s| (s.text.as_str(), s.color.as_deref()))
+        .collect();
+    assert_eq!(
+        colors,
+        vec![("a", Some("red")), ("b", Some("blue")), ("c", Some("red"))]
+    );
+}
+
+#[test]
+fn italic_and_underline() {
+    let spans = phrase("<i><u>note</u></i>").to_rich();
+    assert_eq!(
+        spans,
+        vec![RichSpan {
+            italic: true,
+            underline: true,
+            ..span("note")
+        }]
+    );
+}
+
+#[test]
+fn adjacent_spans_with_same_style_merge() {
+    assert_eq!(
+        phrase("<b>a</b><b>b</b>").to_rich(),
+        vec![RichSpan {
+            bold: true,
+            ..span("ab")
+        }]
+    );
+}
+
+#[test]
+fn unrecognized_tags_and_stray_brackets_stay_as_text() {
+    assert_eq!(
+        phrase("1 < 2 <sprite=3> <b=x>").to_rich(),
+        vec![span("1 < 2 <sprite=3> <b=x>")]
+    );
+}
+
+#[test]
+fn evaluated_phrase_produces_spans() {
+    let mut locale = Locale::with_language("en");
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            ancient = :an "Ancient";
+            subtype($s) = :from($s) "<color=#2E7D32><b>{$s}</b></color>";
+            dissolve($s) = "Dissolve {@a subtype($s)}.";
+            "#,
+        )
+        .unwrap();
+    let ancient = locale.get_phrase("ancient").unwrap();
+    let result = locale
+        .call_phrase("dissolve", &[Value::Phrase(ancient)])
+        .unwrap();
+
+    assert_eq!(
+        result.to_string(),
+        "Dissolve an <color=#2E7D32><b>Ancient</b></color>."
+    );
+    let spans = result.to_rich();
+    assert_eq!(spans.len(), 3);
+    assert_eq!(spans[0], span("Dissolve an "));
+    assert_eq!(spans[1].text, "Ancient");
+    assert!(spans[1].bold);
+    assert_eq!(spans[1].color.as_deref(), Some("#2E7D32"));
+    assert_eq!(spans[2], span("."));
+}
+
+#[test]
+fn parameter_markup_is_plain_text() {
+    let mut locale = Locale::with_language("en");
+    locale
+        .load_translations_str(
+            "en",
+            r#"
+            greet($name) = "<b>Hello</b>, {$name}!";
+            framed($p) = "<i>{$p}</i>";
+            "#,
+        )
+        .unwrap();
+    let greeting = locale
+        .call_phrase("greet", &[Value::from("<b>Bob</b>")])
+        .unwrap();
+
+    assert_eq!(greeting.to_string(), "<b>Hello</b>, <b>Bob</b>!");
+    assert_eq!(
+        greeting.to_rich(),
+        vec![
+            RichSpan {
+                bold: true,
+                ..span("Hello")
+            },
+            span(", <b>Bob</b>!"),
+        ]
+    );
+
+    let framed = locale
+        .call_phrase("framed", &[Value::Phrase(greeting)])
+        .unwrap();
+    let spans = framed.to_rich();
+    assert_eq!(spans.len(), 2);
+    assert!(spans[0].bold && spans[0].italic);
+    assert_eq!(spans[1].text, ", <b>Bob</b>!");
+    assert!(spans[1].italic && !spans[1].bold);
+}
@@ -3,7 +3,10 @@
 //! These tests validate the public API of the template parser against all
 //! syntax forms documented in DESIGN.md.
 
-use rlf::parser::{Reference, Segment, Selector, TransformContext, parse_template};
+use rlf::parser::{
+    Reference, Segment, Selector, TemplateVisitor, Transform, TransformContext, parse_template,
+    walk_template,
+};
 
 // =============================================================================
 // Basic parsing
@@ -48,7 +51,7 @@ fn test_parameter_reference() {
             assert_eq!(*reference, Reference::Parameter("name".into()));
             assert!(selectors.is_empty());
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -61,14 +64,14 @@ fn test_multiple_parameters() {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Parameter("amount".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 
     match &t.segments[3] {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Parameter("target".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -89,7 +92,7 @@ fn test_term_reference() {
             assert_eq!(*reference, Reference::Identifier("card".into()));
             assert!(selectors.is_empty());
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -104,7 +107,7 @@ fn test_selection_literal() {
         Segment::Interpolation { selectors, .. } => {
             assert_eq!(selectors, &[Selector::Identifier("other".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -120,7 +123,18 @@ fn test_selection_parameter() {
             assert_eq!(*reference, Reference::Identifier("card".into()));
             assert_eq!(selectors, &[Selector::Parameter("n".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
+    }
+}
+
+#[test]
+fn test_selection_ordinal_parameter() {
+    let t = parse_template("{nth:#$n}").unwrap();
+    match &t.segments[0] {
+        Segment::Interpolation { selectors, .. } => {
+            assert_eq!(selectors, &[Selector::Ordinal("n".into())]);
+        }
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -133,7 +147,7 @@ fn test_chained_selection_mixed() {
             assert_eq!(selectors[0], Selector::Identifier("acc".into()));
             assert_eq!(selectors[1], Selector::Parameter("n".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -146,7 +160,7 @@ fn test_all_static_selectors() {
             assert_eq!(selectors[0], Selector::Identifier("acc".into()));
             assert_eq!(selectors[1], Selector::Identifier("one".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -160,7 +174,7 @@ fn test_triple_chained_selection() {
             assert_eq!(selectors[1], Selector::Identifier("gender".into()));
             assert_eq!(selectors[2], Selector::Identifier("other".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -178,7 +192,7 @@ fn test_parameter_with_static_selectors() {
             assert_eq!(selectors[0], Selector::Identifier("nom".into()));
             assert_eq!(selectors[1], Selector::Identifier("one".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -194,7 +208,7 @@ fn test_term_with_parameterized_selector() {
             assert_eq!(*reference, Reference::Identifier("allied_adj".into()));
             assert_eq!(selectors, &[Selector::Parameter("entity".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -216,7 +230,7 @@ fn test_transform_cap() {
             assert_eq!(transforms[0].context, TransformContext::None);
             assert_eq!(*reference, Reference::Identifier("card".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -233,7 +247,7 @@ fn test_transform_on_parameter() {
             assert_eq!(transforms[0].name, "cap");
             assert_eq!(*reference, Reference::Parameter("name".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -244,7 +258,7 @@ fn test_transform_upper() {
         Segment::Interpolation { transforms, .. } => {
             assert_eq!(transforms[0].name, "upper");
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -255,7 +269,7 @@ fn test_transform_lower() {
         Segment::Interpolation { transforms, .. } => {
             assert_eq!(transforms[0].name, "lower");
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -270,7 +284,7 @@ fn test_transform_with_context() {
                 TransformContext::Static("acc".into())
             );
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -283,7 +297,7 @@ fn test_chained_transforms() {
             assert_eq!(transforms[0].name, "cap");
             assert_eq!(transforms[1].name, "a");
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -297,7 +311,7 @@ fn test_triple_chained_transforms() {
             assert_eq!(transforms[1].name, "upper");
             assert_eq!(transforms[2].name, "a");
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -317,7 +331,7 @@ fn test_transform_with_context_and_selector() {
             );
             assert_eq!(selectors, &[Selector::Identifier("one".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -339,7 +353,27 @@ fn test_transform_with_dynamic_context() {
             assert_eq!(transforms[0].context, TransformContext::Dynamic("n".into()));
             assert_eq!(*reference, Reference::Identifier("card".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
+    }
+}
+
+#[test]
+fn test_transform_with_colon_parameter_context() {
+    let t = parse_template("{@der:$case karte}").unwrap();
+    match &t.segments[0] {
+        Segment::Interpolation {
+            transforms,
+            reference,
+            ..
+        } => {
+            assert_eq!(transforms[0].name, "der");
+            assert_eq!(
+                transforms[0].context,
+                TransformContext::Dynamic("case".into())
+            );
+            assert_eq!(*reference, Reference::Identifier("karte".into()));
+        }
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -360,7 +394,7 @@ fn test_transform_with_both_contexts() {
             );
             assert_eq!(*reference, Reference::Identifier("ref".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -378,7 +412,7 @@ fn test_transform_dynamic_context_with_selector() {
             assert_eq!(*reference, Reference::Identifier("card".into()));
             assert_eq!(selectors, &[Selector::Parameter("n".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -393,7 +427,7 @@ fn test_static_context_unchanged() {
                 TransformContext::Static("acc".into())
             );
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -413,7 +447,7 @@ fn test_phrase_call_with_param_arg() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -429,7 +463,7 @@ fn test_phrase_call_with_term_arg() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -447,7 +481,7 @@ fn test_phrase_call_multiple_args() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -464,7 +498,7 @@ fn test_phrase_call_mixed_args() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -488,7 +522,7 @@ fn test_phrase_call_with_transform() {
                 _ => panic!("expected phrase call"),
             }
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -511,7 +545,7 @@ fn test_phrase_call_with_selector() {
             }
             assert_eq!(selectors, &[Selector::Identifier("other".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -533,7 +567,7 @@ fn test_phrase_call_with_param_selector() {
             }
             assert_eq!(selectors, &[Selector::Parameter("n".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -611,7 +645,7 @@ fn test_dollar_escape_in_interpolation() {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Identifier("$".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -624,7 +658,7 @@ fn test_escape_in_interpolation_context() {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Identifier("actual".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
     assert_eq!(t.segments[2], Segment::Literal(" after".into()));
 }
@@ -636,7 +670,7 @@ fn test_at_escape_in_interpolation() {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Identifier("@".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -647,7 +681,7 @@ fn test_colon_escape_in_interpolation() {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Identifier(":".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -698,7 +732,7 @@ fn test_auto_capitalization() {
             assert_eq!(transforms[0].context, TransformContext::None);
             assert_eq!(*reference, Reference::Identifier("card".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -715,7 +749,7 @@ fn test_auto_capitalization_preserves_rest() {
             assert_eq!(transforms[0].name, "cap");
             assert_eq!(*reference, Reference::Identifier("cardName".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -732,7 +766,7 @@ fn test_auto_capitalization_with_underscores() {
             assert_eq!(transforms[0].name, "cap");
             assert_eq!(*reference, Reference::Identifier("phrase_name".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -750,7 +784,7 @@ fn test_auto_capitalization_with_selectors() {
             assert_eq!(*reference, Reference::Identifier("card".into()));
             assert_eq!(selectors, &[Selector::Parameter("n".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -768,7 +802,7 @@ fn test_auto_capitalization_with_existing_transforms() {
             assert_eq!(transforms[1].name, "a");
             assert_eq!(*reference, Reference::Identifier("card".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -794,7 +828,7 @@ fn test_auto_capitalization_with_transform_and_phrase_call() {
                 _ => panic!("expected phrase call"),
             }
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -813,7 +847,7 @@ fn test_no_auto_cap_on_parameter() {
             assert!(transforms.is_empty());
             assert_eq!(*reference, Reference::Parameter("Name".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -824,7 +858,7 @@ fn test_lowercase_no_auto_cap() {
         Segment::Interpolation { transforms, .. } => {
             assert!(transforms.is_empty());
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -843,7 +877,7 @@ fn test_draw_cards_template() {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Parameter("n".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 
     assert_eq!(t.segments[2], Segment::Literal(" ".into()));
@@ -857,7 +891,7 @@ fn test_draw_cards_template() {
             assert_eq!(*reference, Reference::Identifier("card".into()));
             assert_eq!(selectors, &[Selector::Parameter("n".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 
     assert_eq!(t.segments[4], Segment::Literal(".".into()));
@@ -872,7 +906,7 @@ fn test_russian_template() {
             assert_eq!(selectors[0], Selector::Identifier("acc".into()));
             assert_eq!(selectors[1], Selector::Parameter("n".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -896,7 +930,7 @@ fn test_german_definite_article() {
             );
             assert_eq!(*reference, Reference::Identifier("karte".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 
     assert_eq!(t.segments[2], Segment::Literal(".".into()));
@@ -923,7 +957,7 @@ fn test_dissolve_subtype() {
                 _ => panic!("expected phrase call"),
             }
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -948,7 +982,7 @@ fn test_dissolve_all() {
             }
             assert_eq!(selectors, &[Selector::Identifier("other".into())]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -963,7 +997,9 @@ fn test_adjacent_interpolations() {
     for i in 0..3 {
         match &t.segments[i] {
             Segment::Interpolation { .. } => {}
-            Segment::Literal(_) => panic!("expected interpolation at index {i}"),
+            Segment::Literal(_) | Segment::Conditional { .. } => {
+                panic!("expected interpolation at index {i}")
+            }
         }
     }
 }
@@ -975,7 +1011,7 @@ fn test_whitespace_in_interpolation() {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Identifier("card".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -992,7 +1028,7 @@ fn test_whitespace_around_transform() {
             assert_eq!(transforms[0].name, "cap");
             assert_eq!(*reference, Reference::Identifier("card".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1003,7 +1039,7 @@ fn test_underscore_in_identifier() {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Identifier("some_name".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1014,7 +1050,7 @@ fn test_numbers_in_identifier() {
         Segment::Interpolation { reference, .. } => {
             assert_eq!(*reference, Reference::Identifier("card2".into()));
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1073,10 +1109,42 @@ fn test_phrase_call_with_number_literal() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
+#[test]
+fn test_phrase_call_with_named_args() {
+    let t = parse_template("{draw(target: $p, n: 3)}").unwrap();
+    match &t.segments[0] {
+        Segment::Interpolation { reference, .. } => match reference {
+            Reference::PhraseCall { name, args } => {
+                assert_eq!(name, "draw");
+                assert_eq!(
+                    args,
+                    &vec![
+                        Reference::Named {
+                            name: "target".into(),
+                            value: Box::new(Reference::Parameter("p".into())),
+                        },
+                        Reference::Named {
+                            name: "n".into(),
+                            value: Box::new(Reference::NumberLiteral(3)),
+                        },
+                    ]
+                );
+            }
+            _ => panic!("expected phrase call"),
+        },
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
+    }
+}
+
+#[test]
+fn test_selector_in_call_arg_is_not_named_arg() {
+    assert!(parse_template("{f(card:one)}").is_err());
+}
+
 #[test]
 fn test_phrase_call_with_string_literal() {
     let t = parse_template(r#"{trigger("Attack")}"#).unwrap();
@@ -1089,7 +1157,7 @@ fn test_phrase_call_with_string_literal() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1105,7 +1173,7 @@ fn test_phrase_call_with_string_literal_escapes() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1121,7 +1189,7 @@ fn test_phrase_call_with_string_literal_backslash_escape() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1137,7 +1205,7 @@ fn test_phrase_call_with_large_number_literal() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1155,7 +1223,7 @@ fn test_phrase_call_mixed_literal_and_param_args() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1170,7 +1238,7 @@ fn test_phrase_call_number_literal_zero() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1185,7 +1253,7 @@ fn test_phrase_call_with_empty_string_literal() {
             }
             _ => panic!("expected phrase call"),
         },
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1205,7 +1273,7 @@ fn test_default_selector() {
             assert_eq!(*reference, Reference::Identifier("card".into()));
             assert_eq!(selectors, &[Selector::Default]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1223,7 +1291,7 @@ fn test_default_selector_with_other_selectors() {
             assert_eq!(selectors[0], Selector::Identifier("nom".into()));
             assert_eq!(selectors[1], Selector::Default);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
     }
 }
 
@@ -1239,6 +1307,132 @@ fn test_parameter_with_default_selector() {
             assert_eq!(*reference, Reference::Parameter("base".into()));
             assert_eq!(selectors, &[Selector::Default]);
         }
-        Segment::Literal(_) => panic!("expected interpolation"),
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
+    }
+}
+
+// =============================================================================
+// Conditionals ({?$param: "..."})
+// =============================================================================
+
+#[test]
+fn test_conditional_segment() {
+    let t = parse_template(r#"Draw {$n} cards{?$suffix: " ({$suffix})"}"#).unwrap();
+    assert_eq!(t.segments.len(), 4);
+    match &t.segments[3] {
+        Segment::Conditional { parameter, body } => {
+            assert_eq!(parameter, "suffix");
+            assert_eq!(body.segments.len(), 3);
+            assert_eq!(body.segments[0], Segment::Literal(" (".into()));
+            assert_eq!(body.segments[2], Segment::Literal(")".into()));
+        }
+        Segment::Literal(_) | Segment::Interpolation { .. } => panic!("expected conditional"),
     }
 }
+
+#[test]
+fn test_conditional_requires_dollar_parameter() {
+    assert!(parse_template(r#"{?suffix: "text"}"#).is_err());
+}
+
+// =============================================================================
+// Coalesce ({$a || $b || "fallback"})
+// =============================================================================
+
+#[test]
+fn test_coalesce_reference() {
+    let t = parse_template(r#"{@cap $nick || $name || player || "Player"}"#).unwrap();
+    match &t.segments[0] {
+        Segment::Interpolation {
+            transforms,
+            reference,
+            ..
+        } => {
+            assert_eq!(transforms.len(), 1);
+            assert_eq!(
+                reference,
+                &Reference::Coalesce(vec![
+                    Reference::Parameter("nick".into()),
+                    Reference::Parameter("name".into()),
+                    Reference::Identifier("player".into()),
+                    Reference::StringLiteral("Player".into()),
+                ])
+            );
+        }
+        Segment::Literal(_) | Segment::Conditional { .. } => panic!("expected interpolation"),
+    }
+}
+
+#[test]
+fn test_coalesce_needs_alternative_after_operator() {
+    assert!(parse_template("{$nick ||}").is_err());
+}
+
+// =============================================================================
+// Visitor
+// =============================================================================
+
+#[derive(Default)]
+struct EventRecorder {
+    events: Vec<String>,
+}
+
+impl TemplateVisitor for EventRecorder {
+    fn visit_literal(&mut self, text: &str) {
+        self.events.push(format!("literal {text}"));
+    }
+
+    fn visit_substitution(&mut self, _reference: &Reference, selectors: &[Selector]) {
+        self.events
+            .push(format!("substitution {}", selectors.len()));
+    }
+
+    fn visit_transform(&mut self, transform: &Transform) {
+        self.events.push(format!("transform {}", transform.name));
+    }
+
+    fn visit_phrase_ref(&mut self, name: &str, args: &[Reference]) {
+        self.events
+            .push(format!("phrase_ref {name} {}", args.len()));
+    }
+
+    fn visit_conditional(&mut self, parameter: &str) {
+        self.events.push(format!("conditional {parameter}"));
+    }
+}
+
+#[test]
+fn test_visitor_event_order() {
+    let t = parse_template("Dissolve {@cap @a subtype(ancient, $n):one}.").unwrap();
+    let mut recorder = EventRecorder::default();
+    walk_template(&t, &mut recorder);
+    assert_eq!(
+        recorder.events,
+        vec![
+            "literal Dissolve ",
+            "substitution 1",
+            "transform cap",
+            "transform a",
+            "phrase_ref subtype 2",
+            "phrase_ref ancient 0",
+            "literal .",
+        ]
+    );
+}
+
+#[test]
+fn test_visitor_walks_conditional_body() {
+    let t = parse_template(r#"{$n}{?$suffix: " {card}"}"#).unwrap();
+    let mut recorder = EventRecorder::default();
+    walk_template(&t, &mut recorder);
+    assert_eq!(
+        recorder.events,
+        vec![
+            "substitution 0",
+            "conditional suffix",
+            "literal  ",
+            "substitution 0",
+            "phrase_ref card 0",
+        ]
+    );
+}
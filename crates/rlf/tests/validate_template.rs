@@ -0,0 +1,71 @@
+//! Tests for `validate_template`.
+
+use rlf::{TemplateError, validate_template};
+
+#[test]
+fn valid_template_passes() {
+    assert_eq!(
+        validate_template("Draw {$n} {@cap card:$n}{?$p: \" for {$p}\"}.", "en"),
+        Ok(())
+    );
+    assert_eq!(
+        validate_template("{@der:acc karte} {@count($n) card}", "de-AT").err(),
+        Some(vec![TemplateError::WrongLanguageTransform {
+            name: "count".to_string(),
+            language: "de-AT".to_string(),
+            span: 18..24,
+            available_in: vec![
+                "en".to_string(),
+                "zh".to_string(),
+                "ja".to_string(),
+                "ko".to_string(),
+                "vi".to_string(),
+                "th".to_string(),
+                "bn".to_string(),
+                "ms".to_string(),
+            ],
+        }])
+    );
+}
+
+#[test]
+fn phrase_references_are_not_resolved() {
+    assert_eq!(validate_template("{no_such_phrase($n)}", "en"), Ok(()));
+}
+
+#[test]
+fn bad_transform_name_suggests_close_match() {
+    let errors = validate_template("Draw {@uper card} and {@upper @lowr $x}.", "en").unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            TemplateError::UnknownTransform {
+                name: "uper".to_string(),
+                span: 6..11,
+                suggestions: vec!["upper".to_string()],
+            },
+            TemplateError::UnknownTransform {
+                name: "lowr".to_string(),
+                span: 30..35,
+                suggestions: vec!["lower".to_string()],
+            },
+        ]
+    );
+    assert_eq!(
+        errors[0].to_string(),
+        "unknown transform '@uper'; did you mean: upper?"
+    );
+}
+
+#[test]
+fn escaped_braces_and_at_signs_are_not_transforms() {
+    assert_eq!(validate_template("{{@bogus}} mail me @bogus", "en"), Ok(()));
+}
+
+#[test]
+fn malformed_substitution_reports_syntax_error() {
+    let errors = validate_template("Draw {card", "en").unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], TemplateError::Syntax { .. }));
+    assert!(errors[0].span().start <= "Draw {card".len());
+}
@@ -0,0 +1,89 @@
+//! Tests for `verify_translations`.
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use rlf::{LoadError, VerifyError, verify_translations};
+use tempfile::TempDir;
+
+const SOURCE: &str = r#"
+    hello = "Hello!";
+    card = { one: "card", *other: "cards" };
+    draw($n) = "Draw {$n} {card:$n}.";
+"#;
+
+fn translations_dir(files: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    for (language, content) in files {
+        fs::write(dir.path().join(format!("{language}.rlf")), content).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn complete_translations_pass() {
+    let dir = translations_dir(&[
+        (
+            "de",
+            r#"hello = "Hallo!"; card = { one: "Karte", *other: "Karten" }; draw($n) = "Ziehe {$n}.";"#,
+        ),
+        (
+            "fr",
+            r#"hello = "Bonjour !"; card = "carte"; draw($n) = "Piochez {$n}."; extra = "x";"#,
+        ),
+    ]);
+    verify_translations(dir.path(), &["de", "fr"], SOURCE).unwrap();
+}
+
+#[test]
+fn incomplete_translations_report_missing_ids_per_language() {
+    let dir = translations_dir(&[
+        ("de", r#"hello = "Hallo!";"#),
+        (
+            "fr",
+            r#"hello = "Bonjour !"; card = "carte"; draw($n) = "Piochez {$n}.";"#,
+        ),
+    ]);
+    let error = verify_translations(dir.path(), &["de", "fr"], SOURCE).unwrap_err();
+
+    let VerifyError::MissingPhrases { missing } = &error else {
+        panic!("expected MissingPhrases, got {error:?}");
+    };
+    assert_eq!(
+        missing,
+        &BTreeMap::from([(
+            "de".to_string(),
+            vec!["card".to_string(), "draw".to_string()]
+        )])
+    );
+    assert_eq!(error.to_string(), "missing translations:\n  de: card, draw");
+}
+
+#[test]
+fn only_required_languages_are_checked() {
+    let dir = translations_dir(&[
+        (
+            "de",
+            r#"hello = "Hallo!"; card = "Karte"; draw($n) = "{$n}";"#,
+        ),
+        ("ja", r#"hello = "こんにちは";"#),
+    ]);
+    verify_translations(dir.path(), &["de"], SOURCE).unwrap();
+}
+
+#[test]
+fn missing_translation_file_is_load_error() {
+    let dir = translations_dir(&[]);
+    let error = verify_translations(dir.path(), &["de"], SOURCE).unwrap_err();
+    assert!(matches!(error, VerifyError::Load(LoadError::Io { .. })));
+}
+
+#[test]
+fn unparsable_translation_file_reports_path() {
+    let dir = translations_dir(&[("de", r#"hello = "Hallo"#)]);
+    let error = verify_translations(dir.path(), &["de"], SOURCE).unwrap_err();
+    let VerifyError::Load(LoadError::Parse { path, .. }) = error else {
+        panic!("expected parse error, got {error:?}");
+    };
+    assert_eq!(path, dir.path().join("de.rlf"));
+}
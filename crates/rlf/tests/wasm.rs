@@ -0,0 +1,83 @@
+//! Tests for the `wasm` feature's JavaScript binding types.
+//!
+//! Building a `JsError` calls into the JavaScript host, which is only
+//! available on wasm32, so the error paths are tested through the `try_`
+//! methods that return the error message.
+
+#![cfg(feature = "wasm")]
+
+use rlf::wasm::WasmLocale;
+
+#[test]
+fn load_and_call_through_bindings() {
+    let mut locale = WasmLocale::new("en");
+    let loaded = locale
+        .load(
+            "en",
+            r#"
+            card = { one: "card", *other: "cards" };
+            draw($n, $who) = "{$who} draws {$n} {card:$n}.";
+        "#,
+        )
+        .ok()
+        .unwrap();
+    assert_eq!(loaded, 2);
+
+    assert_eq!(locale.get_phrase("card").ok().unwrap(), "cards");
+    assert_eq!(
+        locale.call_phrase("draw", r#"[3, "Ana"]"#).ok().unwrap(),
+        "Ana draws 3 cards."
+    );
+}
+
+#[test]
+fn set_language_switches_locale() {
+    let mut locale = WasmLocale::new("en");
+    locale.load("de", r#"card = "Karte";"#).ok().unwrap();
+    locale.set_language("de");
+    assert_eq!(locale.language(), "de");
+    assert_eq!(locale.get_phrase("card").ok().unwrap(), "Karte");
+}
+
+#[test]
+fn unknown_phrase_is_an_error() {
+    let mut locale = WasmLocale::new("en");
+    locale.try_load("en", r#"card = "card";"#).unwrap();
+    let err = locale.try_get_phrase("relic").unwrap_err();
+    assert!(err.contains("relic"), "{err}");
+    let err = locale.try_call_phrase("relic", "[]").unwrap_err();
+    assert!(err.contains("relic"), "{err}");
+}
+
+#[test]
+fn language_without_translations_is_an_error() {
+    let mut locale = WasmLocale::new("en");
+    locale.try_load("en", r#"card = "card";"#).unwrap();
+    locale.set_language("xx");
+    let err = locale.try_get_phrase("card").unwrap_err();
+    assert!(err.contains("card"), "{err}");
+}
+
+#[test]
+fn bad_argument_json_is_an_error() {
+    let mut locale = WasmLocale::new("en");
+    locale
+        .try_load("en", r#"cards($n) = "{$n} cards";"#)
+        .unwrap();
+    let cases = [
+        ("[3", "invalid params JSON"),
+        ("3", "params must be a JSON array"),
+        ("[true]", "unsupported param value: true"),
+    ];
+    for (json, message) in cases {
+        let err = locale.try_call_phrase("cards", json).unwrap_err();
+        assert!(err.contains(message), "{json}: {err}");
+    }
+}
+
+#[test]
+fn load_parse_error_is_an_error() {
+    let mut locale = WasmLocale::new("en");
+    let err = locale.try_load("en", r#"card = "#).unwrap_err();
+    assert!(!err.is_empty());
+}